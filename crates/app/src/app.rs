@@ -1,5 +1,6 @@
 mod context;
 mod message;
+mod players;
 mod session;
 mod status;
 mod view;
@@ -9,20 +10,26 @@ use crate::message::Message;
 use crate::session::Session;
 use crate::status::Status;
 use storage::clients::local::Local;
+use style::button::ButtonClass;
 use style::container::ContainerClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
 use style::theme::Theme;
 use style::theme::variant::ThemeVariant;
 use widgets::Element;
 
 use iced::Length::Fill;
+use iced::Subscription;
 use iced::Task;
 use iced::alignment::Horizontal;
+use iced::widget;
 use iced::widget::column;
 use iced::widget::container;
 use iced::widget::row;
 use iced::widget::rule;
 use iced::widget::space;
 use iced::widget::stack;
+use iced::window;
 use std::path::PathBuf;
 
 /// Unwraps a [`Status`] as [`Status::Ready`], returning a mutable reference to the inner state.
@@ -61,6 +68,9 @@ macro_rules! loading {
 
 pub struct Application {
     status: Status<Loader, Session>,
+
+    /// The window of the game master, closing which exits the application.
+    main_window: window::Id,
 }
 
 struct Loader {}
@@ -82,11 +92,18 @@ impl Application {
 
         let task = Task::batch(tasks).map(Message::Load);
 
+        // Closing the window is intercepted, to guard the unsaved changes of the session.
+        let (main_window, open_task) = window::open(window::Settings {
+            exit_on_close_request: false,
+            ..Default::default()
+        });
+
         let app = Self {
             status: Status::Loading(Box::new(Loader {})),
+            main_window,
         };
 
-        (app, task)
+        (app, Task::batch([open_task.discard(), task]))
     }
 
     pub fn update(&mut self, message: Message) -> Task<Message> {
@@ -97,10 +114,10 @@ impl Application {
                 match load_message {
                     LoadMessage::LocalConnected(Ok(local)) => {
                         tracing::info!("database connection established, app ready");
-                        let session = Session::new(local);
+                        let (session, task) = Session::new(local);
                         self.status = Status::Ready(Box::new(session));
 
-                        Task::none()
+                        task
                     }
                     LoadMessage::LocalConnected(Err(err)) => {
                         tracing::error!("app startup failed: {err:?}");
@@ -118,6 +135,43 @@ impl Application {
 
                 session.close_view(view_id)
             }
+            Message::CloseResolved(choice) => {
+                let session = ready!(self.status);
+
+                session.resolve_close(choice)
+            }
+            Message::WindowCloseRequested(window_id) => {
+                // Without a session, there is no unsaved work to guard.
+                let Some(session) = self.status.as_ready_mut() else {
+                    return window::close(window_id);
+                };
+
+                // The player window holds no work of its own, so it closes right away.
+                if window_id != self.main_window {
+                    return window::close(window_id);
+                }
+
+                session.close_window(window_id)
+            }
+            Message::WindowClosed(window_id) => {
+                if window_id == self.main_window {
+                    return iced::exit();
+                }
+
+                let session = ready!(self.status);
+
+                session.window_closed(window_id)
+            }
+            Message::PlayerWindowToggled => {
+                let session = ready!(self.status);
+
+                session.toggle_player_window()
+            }
+            Message::HandoutHidden => {
+                let session = ready!(self.status);
+
+                session.hide_handout()
+            }
             Message::FocusView(view_id) => {
                 let session = ready!(self.status);
 
@@ -128,14 +182,69 @@ impl Application {
 
                 session.update_view(view_id, view_message)
             }
+            Message::Autosave => {
+                let session = ready!(self.status);
+
+                session.autosave()
+            }
+            Message::DraftsLoaded(result) => {
+                let session = ready!(self.status);
+
+                session.drafts_loaded(result)
+            }
+            Message::RestoreDraft(draft_id) => {
+                let session = ready!(self.status);
+
+                session.restore_draft(draft_id)
+            }
+            Message::DiscardDraft(draft_id) => {
+                let session = ready!(self.status);
+
+                session.discard_draft(draft_id)
+            }
+            Message::CombatsLoaded(result) => {
+                let session = ready!(self.status);
+
+                session.combats_loaded(result)
+            }
+            Message::ResumeCombat(combat_id) => {
+                let session = ready!(self.status);
+
+                session.resume_combat(combat_id)
+            }
+            Message::DiscardCombat(combat_id) => {
+                let session = ready!(self.status);
+
+                session.discard_combat(combat_id)
+            }
         }
     }
 
-    pub fn view(&self) -> Element<'_, Message> {
+    pub fn view(&self, window_id: window::Id) -> Element<'_, Message> {
         match &self.status {
             Status::Loading(loader) => components::text::heading("Loading").into(),
+            Status::Ready(session) if session.player_window() == Some(window_id) => {
+                session.player_view()
+            }
             Status::Ready(session) => {
-                let topbar = row![space::horizontal().width(Fill)].padding(5);
+                let player_window_label = if session.player_window().is_some() {
+                    "Close Player Window"
+                } else {
+                    "Open Player Window"
+                };
+                let player_window = widget::button(components::text::display(player_window_label))
+                    .class(ButtonClass::Ghost)
+                    .padding(INPUT_PADDING)
+                    .on_press(Message::PlayerWindowToggled);
+
+                let hide_handout = widget::button(components::text::display("Hide Handout"))
+                    .class(ButtonClass::Ghost)
+                    .padding(INPUT_PADDING)
+                    .on_press_maybe(session.has_handout().then_some(Message::HandoutHidden));
+
+                let topbar = row![space::horizontal().width(Fill), hide_handout, player_window]
+                    .spacing(BODY_SPACING)
+                    .padding(5);
 
                 let view = session.active_view();
                 let containered_view = container(view)
@@ -153,7 +262,28 @@ impl Application {
         }
     }
 
-    pub fn theme(&self) -> Option<Theme> {
+    pub fn title(&self, window_id: window::Id) -> String {
+        if window_id == self.main_window {
+            String::from("Initiative")
+        } else {
+            String::from("Initiative · Players")
+        }
+    }
+
+    pub fn subscription(&self) -> Subscription<Message> {
+        // Closing the window is intercepted, to guard the unsaved changes of the session.
+        let close_requests = window::close_requests().map(Message::WindowCloseRequested);
+        let closed = window::close_events().map(Message::WindowClosed);
+
+        let session = match &self.status {
+            Status::Loading(_) => Subscription::none(),
+            Status::Ready(session) => session.subscription(),
+        };
+
+        Subscription::batch([close_requests, closed, session])
+    }
+
+    pub fn theme(&self, _window_id: window::Id) -> Option<Theme> {
         if let Status::Ready(session) = &self.status {
             Some(session.context().theme())
         } else {
@@ -1,6 +1,20 @@
 use storage::repositories::Repository;
+use storage::repositories::activities::Activities;
+use storage::repositories::activities::ActivitiesRepository;
+use storage::repositories::bookmarks::Bookmarks;
+use storage::repositories::bookmarks::BookmarksRepository;
+use storage::repositories::combats::Combats;
+use storage::repositories::combats::CombatsRepository;
+use storage::repositories::creatures::Creatures;
+use storage::repositories::creatures::CreaturesRepository;
+use storage::repositories::encounters::Encounters;
+use storage::repositories::encounters::EncountersRepository;
 use storage::repositories::options::Options;
 use storage::repositories::options::OptionsRepository;
+use storage::repositories::spell_drafts::SpellDrafts;
+use storage::repositories::spell_drafts::SpellDraftsRepository;
+use storage::repositories::spellbooks::Spellbooks;
+use storage::repositories::spellbooks::SpellbooksRepository;
 use storage::repositories::spells::Spells;
 use storage::repositories::spells::SpellsRepository;
 use style::theme::Theme;
@@ -36,12 +50,54 @@ impl Context {
     }
 }
 
+impl ActivitiesRepository for Context {
+    fn activities(&self) -> &dyn Activities {
+        self.inner.repository.activities()
+    }
+}
+
+impl BookmarksRepository for Context {
+    fn bookmarks(&self) -> &dyn Bookmarks {
+        self.inner.repository.bookmarks()
+    }
+}
+
+impl CombatsRepository for Context {
+    fn combats(&self) -> &dyn Combats {
+        self.inner.repository.combats()
+    }
+}
+
+impl CreaturesRepository for Context {
+    fn creatures(&self) -> &dyn Creatures {
+        self.inner.repository.creatures()
+    }
+}
+
+impl EncountersRepository for Context {
+    fn encounters(&self) -> &dyn Encounters {
+        self.inner.repository.encounters()
+    }
+}
+
 impl OptionsRepository for Context {
     fn options(&self) -> &dyn Options {
         self.inner.repository.options()
     }
 }
 
+impl SpellDraftsRepository for Context {
+    fn spell_drafts(&self) -> &dyn SpellDrafts {
+        self.inner.repository.spell_drafts()
+    }
+}
+
+impl SpellbooksRepository for Context {
+    fn spellbooks(&self) -> &dyn Spellbooks {
+        self.inner.repository.spellbooks()
+    }
+}
+
 impl SpellsRepository for Context {
     fn spells(&self) -> &dyn Spells {
         self.inner.repository.spells()
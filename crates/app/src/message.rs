@@ -3,14 +3,41 @@ use crate::view::ViewMessage;
 use crate::view::request::Request;
 use storage::Error;
 use storage::clients::local::Local;
+use storage::records::combat::CombatRecord;
+use storage::records::draft::SpellDraftRecord;
+
+use iced::window;
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum Message {
     Load(LoadMessage),
     OpenView(Request),
     CloseView(ViewId),
+    CloseResolved(CloseChoice),
+    WindowCloseRequested(window::Id),
+    WindowClosed(window::Id),
     FocusView(ViewId),
     ViewUpdated(ViewId, ViewMessage),
+
+    /// Autosave the unsaved work of every open view.
+    Autosave,
+    DraftsLoaded(Result<Box<[SpellDraftRecord]>, Error>),
+    RestoreDraft(Uuid),
+    DiscardDraft(Uuid),
+    CombatsLoaded(Result<Box<[CombatRecord]>, Error>),
+    ResumeCombat(Uuid),
+    DiscardCombat(Uuid),
+    PlayerWindowToggled,
+    HandoutHidden,
+}
+
+/// The choice of the user when closing something with unsaved changes.
+#[derive(Debug, Clone, Copy)]
+pub enum CloseChoice {
+    Save,
+    Discard,
+    Cancel,
 }
 
 #[derive(Debug, Clone)]
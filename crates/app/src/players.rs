@@ -0,0 +1,221 @@
+use storage::records::spell::SpellRecord;
+use style::container::ContainerClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
+use style::layout::SECTION_SPACING;
+use style::text::TextClass;
+use types::CombatantKind;
+use types::Encounter;
+use types::Health;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::ContentFit;
+use iced::Length;
+use iced::Length::Fill;
+use iced::Size;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::image::Handle;
+use iced::widget::row;
+use iced::window;
+
+/// The size the player window opens with, fit for a television or a second monitor.
+const WINDOW_SIZE: Size = Size::new(1280.0, 720.0);
+
+/// The space between the edges of the player window and its content.
+const WINDOW_PADDING: u16 = 40;
+
+/// Something the players are shown next to the initiative order.
+#[derive(Debug, Clone)]
+pub enum Handout {
+    Spell(Box<SpellRecord>),
+    Image(Handle),
+}
+
+/// The settings of the player window, which closes without confirmation.
+pub fn settings() -> window::Settings {
+    window::Settings {
+        size: WINDOW_SIZE,
+        ..Default::default()
+    }
+}
+
+/// What the players see: the initiative order of the `encounter`, and the `handout` if any.
+///
+/// The exact hit points of creatures are hidden, only how close they are to dying is shown.
+pub fn view<'a, Message: 'a>(
+    encounter: Option<&'a Encounter>,
+    handout: Option<&'a Handout>,
+) -> Element<'a, Message> {
+    let order = match encounter {
+        Some(encounter) if encounter.has_started() => initiative(encounter),
+        _ => column![
+            components::text::view_title("Initiative"),
+            components::text::view_sub_title("Waiting for the fight to start."),
+        ]
+        .spacing(LABEL_SPACING)
+        .into(),
+    };
+
+    let mut content = row![widget::container(order).width(Length::FillPortion(2))]
+        .spacing(SECTION_SPACING)
+        .height(Fill);
+
+    if let Some(handout) = handout {
+        let handout = match handout {
+            Handout::Spell(spell) => spell_card(spell),
+            Handout::Image(handle) => widget::image(handle.clone())
+                .content_fit(ContentFit::Contain)
+                .width(Fill)
+                .height(Fill)
+                .into(),
+        };
+
+        content = content.push(widget::container(handout).width(Length::FillPortion(3)));
+    }
+
+    widget::container(content)
+        .class(ContainerClass::Background)
+        .padding(WINDOW_PADDING)
+        .width(Fill)
+        .height(Fill)
+        .into()
+}
+
+fn initiative<'a, Message: 'a>(encounter: &'a Encounter) -> Element<'a, Message> {
+    let active = encounter.active().map(|combatant| combatant.id);
+
+    let sub_title = match encounter.active() {
+        Some(combatant) => format!("Round {} · {}'s turn", encounter.round(), combatant.name),
+        None => format!("Round {}", encounter.round()),
+    };
+
+    let mut order = Column::with_capacity(encounter.combatants().len()).spacing(LABEL_SPACING);
+    for combatant in encounter.combatants() {
+        let health = combatant.health();
+
+        let status = match combatant.kind {
+            CombatantKind::Lair(_) => String::from("Lair actions"),
+            CombatantKind::Character => {
+                let hit_points = combatant.hit_points;
+
+                match health {
+                    Health::Healthy | Health::Bloodied => {
+                        format!("HP {}/{}", hit_points.current, hit_points.maximum)
+                    }
+                    Health::Unconscious | Health::Dead => health.to_string(),
+                }
+            }
+            CombatantKind::Creature(_) => health.to_string(),
+        };
+
+        let class = match health {
+            Health::Healthy => TextClass::Normal,
+            Health::Bloodied => TextClass::Warning,
+            Health::Unconscious | Health::Dead => TextClass::Danger,
+        };
+
+        let conditions = combatant
+            .conditions
+            .iter()
+            .map(|applied| applied.condition.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut name = column![components::text::heading(&combatant.name)];
+        if !conditions.is_empty() {
+            name = name.push(components::text::detail(conditions));
+        }
+
+        let content = row![
+            name.width(Fill),
+            components::text::display(status).class(class)
+        ]
+        .spacing(BODY_SPACING)
+        .align_y(Alignment::Center);
+
+        // The combatant whose turn it is stands out from the rest.
+        let class = if active == Some(combatant.id) {
+            ContainerClass::Interaction
+        } else {
+            ContainerClass::Surface
+        };
+
+        order = order.push(
+            widget::container(content)
+                .class(class)
+                .padding(INPUT_PADDING)
+                .width(Fill),
+        );
+    }
+
+    column![
+        components::text::view_title("Initiative"),
+        components::text::view_sub_title(sub_title),
+        widget::scrollable(order),
+    ]
+    .spacing(BODY_SPACING)
+    .into()
+}
+
+fn spell_card<'a, Message: 'a>(spell: &'a SpellRecord) -> Element<'a, Message> {
+    let mut components = Vec::with_capacity(3);
+    if spell.verbal {
+        components.push("V");
+    }
+    if spell.somatic {
+        components.push("S");
+    }
+    if spell.material {
+        components.push("M");
+    }
+
+    let properties = row![
+        property("CASTING TIME", &spell.casting_time),
+        property("RANGE", &spell.range),
+        property("DURATION", &spell.duration),
+        property("COMPONENTS", components.join(", ")),
+    ]
+    .spacing(BODY_SPACING);
+
+    let mut card = column![
+        components::text::view_title(&spell.name),
+        components::text::view_sub_title(format!("{} {}", spell.level, spell.school)),
+        properties,
+        components::text::body(&spell.description),
+    ]
+    .spacing(BODY_SPACING);
+
+    if let Some(at_higher_levels) = &spell.at_higher_levels {
+        card = card.push(
+            column![
+                components::text::label("AT HIGHER LEVELS"),
+                components::text::body(at_higher_levels)
+            ]
+            .spacing(LABEL_SPACING),
+        );
+    }
+
+    widget::container(widget::scrollable(card))
+        .class(ContainerClass::Surface)
+        .padding(WINDOW_PADDING)
+        .width(Fill)
+        .into()
+}
+
+/// A labeled value of the spell card.
+fn property<'a, Message: 'a>(
+    label: &'a str,
+    value: impl widget::text::IntoFragment<'a>,
+) -> Element<'a, Message> {
+    column![
+        components::text::label(label),
+        components::text::body(value)
+    ]
+    .spacing(LABEL_SPACING)
+    .width(Fill)
+    .into()
+}
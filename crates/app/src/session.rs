@@ -1,33 +1,70 @@
 use crate::context::Context;
+use crate::message::CloseChoice;
 use crate::message::Message;
+use crate::players;
+use crate::players::Handout;
 use crate::view::View;
 use crate::view::ViewId;
 use crate::view::ViewMessage;
 use crate::view::Viewable;
+use crate::view::combat::tracker::CombatTracker;
+use crate::view::combat::tracker::message::Effect as CombatTrackerEffect;
+use crate::view::creature::detail::CreatureDetail;
+use crate::view::creature::detail::message::Effect as CreatureDetailEffect;
+use crate::view::creature::form::CreatureForm;
+use crate::view::creature::form::message::Effect as CreatureFormEffect;
+use crate::view::creature::list::CreatureList;
+use crate::view::creature::list::message::Effect as CreatureListEffect;
 use crate::view::dashboard::Dashboard;
 use crate::view::dashboard::message::Effect as DashboardEffect;
+use crate::view::encounter::builder::EncounterBuilder;
+use crate::view::encounter::builder::message::Effect as EncounterBuilderEffect;
+use crate::view::encounter::list::EncounterList;
+use crate::view::encounter::list::message::Effect as EncounterListEffect;
 use crate::view::request::Request;
+use crate::view::spell::detail::SpellDetail;
+use crate::view::spell::detail::message::Effect as SpellDetailEffect;
 use crate::view::spell::form::SpellForm;
 use crate::view::spell::form::message::Effect as SpellFormEffect;
 use crate::view::spell::list::SpellList;
 use crate::view::spell::list::message::Effect as SpellListEffect;
+use crate::view::spellbook::editor::SpellbookEditor;
+use crate::view::spellbook::editor::message::Effect as SpellbookEditorEffect;
+use crate::view::spellbook::list::SpellbookList;
+use crate::view::spellbook::list::message::Effect as SpellbookListEffect;
+use crate::view::spellbook::tracker::SpellbookTracker;
+use crate::view::spellbook::tracker::message::Effect as SpellbookTrackerEffect;
 use components::icon::IconName;
 use iced::widget::Row;
 use iced::widget::scrollable::Direction;
 use iced::widget::scrollable::Scrollbar;
+use storage::Error;
+use storage::records::combat::CombatRecord;
+use storage::records::draft::SpellDraftRecord;
 use storage::repositories::Repository;
+use storage::repositories::combats::CombatsRepository;
+use storage::repositories::spell_drafts::SpellDraftsRepository;
+use style::button::ButtonClass;
 use style::container::ContainerClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
 use style::svg::SvgClass;
+use types::FormMode;
 use widgets::Element;
 
 use iced::Alignment;
 use iced::Length;
 use iced::Padding;
+use iced::Subscription;
 use iced::Task;
 use iced::widget;
 use iced::widget::Space;
 use iced::widget::column;
 use iced::widget::row;
+use iced::window;
+use std::time::Duration;
+use uuid::Uuid;
 
 /// The width a view takes up.
 const VIEW_WIDTH: f32 = 1200.0;
@@ -38,29 +75,71 @@ const OVERVIEW_BAR_HEIGHT: u32 = 30;
 /// The width of each element in the overview bar.
 const OVERVIEW_ELEMENT_WIDTH: u32 = 200;
 
+/// How often the unsaved work of open views is autosaved.
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(5);
+
 pub struct Session {
     context: Context,
     active_view: ViewId,
     dashboard_id: ViewId,
     views: Vec<(ViewId, View)>,
+
+    /// Drafts left over from a previous session, awaiting to be restored or discarded.
+    drafts: Vec<SpellDraftRecord>,
+
+    /// Fights left unfinished in a previous session, awaiting to be resumed or discarded.
+    combats: Vec<CombatRecord>,
+
+    /// A close with unsaved changes, awaiting the user to save, discard or cancel.
+    pending_close: Option<PendingClose>,
+
+    /// The window to close once every view has been saved, cleared if any save fails.
+    closing_window: Option<window::Id>,
+
+    /// The window shown to the players, on a television or a second monitor, if open.
+    player_window: Option<window::Id>,
+
+    /// What the players are shown next to the initiative order.
+    handout: Option<Handout>,
+}
+
+/// What is being closed while there are unsaved changes.
+#[derive(Debug, Clone, Copy)]
+enum PendingClose {
+    View(ViewId),
+    Window(window::Id),
 }
 
 impl Session {
-    pub fn new(repository: impl Repository) -> Self {
+    pub fn new(repository: impl Repository) -> (Self, Task<Message>) {
         let id = ViewId::unique();
-        let dashboard = View::Dashboard(Dashboard::new());
-        let views = vec![(id, dashboard)];
-        let active_view = id;
         let context = Context::new(repository);
+        let (dashboard, dashboard_task) = Dashboard::new(context.clone());
+        let views = vec![(id, View::Dashboard(dashboard))];
+        let active_view = id;
 
         tracing::info!("session initialized");
 
-        Self {
+        let task = Task::batch([
+            Task::perform(load_drafts(context.clone()), Message::DraftsLoaded),
+            Task::perform(load_combats(context.clone()), Message::CombatsLoaded),
+            map_task(dashboard_task, id, ViewMessage::Dashboard),
+        ]);
+
+        let session = Self {
             context,
             active_view,
             dashboard_id: id,
             views,
-        }
+            drafts: Vec::new(),
+            combats: Vec::new(),
+            pending_close: None,
+            closing_window: None,
+            player_window: None,
+            handout: None,
+        };
+
+        (session, task)
     }
 
     pub fn context(&self) -> &Context {
@@ -79,9 +158,37 @@ impl Session {
         };
 
         let view = match active_view {
+            View::CombatTracker(combat_tracker) => {
+                combat_tracker.view().map(ViewMessage::CombatTracker)
+            }
+            View::CreatureDetail(creature_detail) => {
+                creature_detail.view().map(ViewMessage::CreatureDetail)
+            }
+            View::CreatureForm(creature_form) => {
+                creature_form.view().map(ViewMessage::CreatureForm)
+            }
+            View::CreatureList(creature_list) => {
+                creature_list.view().map(ViewMessage::CreatureList)
+            }
             View::Dashboard(dashboard) => dashboard.view().map(ViewMessage::Dashboard),
+            View::EncounterBuilder(encounter_builder) => {
+                encounter_builder.view().map(ViewMessage::EncounterBuilder)
+            }
+            View::EncounterList(encounter_list) => {
+                encounter_list.view().map(ViewMessage::EncounterList)
+            }
+            View::SpellDetail(spell_detail) => spell_detail.view().map(ViewMessage::SpellDetail),
             View::SpellForm(spell_form) => spell_form.view().map(ViewMessage::SpellForm),
             View::SpellList(spell_list) => spell_list.view().map(ViewMessage::SpellList),
+            View::SpellbookEditor(spellbook_editor) => {
+                spellbook_editor.view().map(ViewMessage::SpellbookEditor)
+            }
+            View::SpellbookList(spellbook_list) => {
+                spellbook_list.view().map(ViewMessage::SpellbookList)
+            }
+            View::SpellbookTracker(spellbook_tracker) => {
+                spellbook_tracker.view().map(ViewMessage::SpellbookTracker)
+            }
         }
         .map(move |message| Message::ViewUpdated(self.active_view, message));
 
@@ -95,11 +202,231 @@ impl Session {
             .width(Length::Fill)
             .height(Length::Fill);
 
-        column![overview, divider, scrollable_container]
+        let session = column![overview, divider]
+            .push(self.recovered_drafts())
+            .push(self.unfinished_combats())
+            .push(scrollable_container)
             .align_x(Alignment::Center)
             .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
+            .height(Length::Fill);
+
+        match self.pending_close {
+            Some(pending_close) => components::dialog(session, self.close_dialog(pending_close)),
+            None => session.into(),
+        }
+    }
+
+    /// Listen to the external events of the active view.
+    pub fn subscription(&self) -> Subscription<Message> {
+        let Some(active_view) = self.view(self.active_view) else {
+            return Subscription::none();
+        };
+
+        let subscription = match active_view {
+            View::CombatTracker(combat_tracker) => combat_tracker
+                .subscription()
+                .map(ViewMessage::CombatTracker),
+            View::CreatureDetail(creature_detail) => creature_detail
+                .subscription()
+                .map(ViewMessage::CreatureDetail),
+            View::CreatureForm(creature_form) => {
+                creature_form.subscription().map(ViewMessage::CreatureForm)
+            }
+            View::CreatureList(creature_list) => {
+                creature_list.subscription().map(ViewMessage::CreatureList)
+            }
+            View::Dashboard(dashboard) => dashboard.subscription().map(ViewMessage::Dashboard),
+            View::EncounterBuilder(encounter_builder) => encounter_builder
+                .subscription()
+                .map(ViewMessage::EncounterBuilder),
+            View::EncounterList(encounter_list) => encounter_list
+                .subscription()
+                .map(ViewMessage::EncounterList),
+            View::SpellDetail(spell_detail) => {
+                spell_detail.subscription().map(ViewMessage::SpellDetail)
+            }
+            View::SpellForm(spell_form) => spell_form.subscription().map(ViewMessage::SpellForm),
+            View::SpellList(spell_list) => spell_list.subscription().map(ViewMessage::SpellList),
+            View::SpellbookEditor(spellbook_editor) => spellbook_editor
+                .subscription()
+                .map(ViewMessage::SpellbookEditor),
+            View::SpellbookList(spellbook_list) => spellbook_list
+                .subscription()
+                .map(ViewMessage::SpellbookList),
+            View::SpellbookTracker(spellbook_tracker) => spellbook_tracker
+                .subscription()
+                .map(ViewMessage::SpellbookTracker),
+        };
+
+        let active_view_subscription = subscription
+            .with(self.active_view)
+            .map(|(id, message)| Message::ViewUpdated(id, message));
+
+        let autosave = iced::time::every(AUTOSAVE_INTERVAL).map(|_| Message::Autosave);
+
+        Subscription::batch([active_view_subscription, autosave])
+    }
+
+    /// Autosave the unsaved work of every open view.
+    pub fn autosave(&mut self) -> Task<Message> {
+        let mut tasks = Vec::new();
+
+        for (id, view) in &mut self.views {
+            match view {
+                View::SpellForm(spell_form) => {
+                    tasks.push(map_task(spell_form.autosave(), *id, ViewMessage::SpellForm));
+                }
+                View::CombatTracker(combat_tracker) => {
+                    tasks.push(map_task(
+                        combat_tracker.autosave(),
+                        *id,
+                        ViewMessage::CombatTracker,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Persist the fight of the `CombatTracker` as it is now, if it is open.
+    fn autosave_combat(&mut self) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| matches!(view, View::CombatTracker(_))) else {
+            return Task::none();
+        };
+
+        let Some(View::CombatTracker(combat_tracker)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(combat_tracker.autosave(), id, ViewMessage::CombatTracker)
+    }
+
+    pub fn combats_loaded(&mut self, result: Result<Box<[CombatRecord]>, Error>) -> Task<Message> {
+        match result {
+            Ok(combats) => {
+                if !combats.is_empty() {
+                    tracing::info!(
+                        "recovered {} combats from a previous session",
+                        combats.len()
+                    );
+                }
+
+                self.combats = combats.into_vec();
+            }
+            Err(err) => tracing::error!("failed to load combats: {err:?}"),
+        }
+
+        Task::none()
+    }
+
+    /// Pick up the unfinished fight with `combat_id` in the `CombatTracker`.
+    pub fn resume_combat(&mut self, combat_id: Uuid) -> Task<Message> {
+        let Some(index) = self
+            .combats
+            .iter()
+            .position(|combat| combat.id == combat_id)
+        else {
+            tracing::error!("could not find combat '{combat_id}'");
+
+            return Task::none();
+        };
+
+        let mut tasks = Vec::with_capacity(2);
+
+        let id = match self.view_exists(|view| matches!(view, View::CombatTracker(_))) {
+            Some(id) => id,
+            None => {
+                let id = ViewId::unique();
+                let (combat_tracker, task) = CombatTracker::new(self.context.clone());
+                self.views
+                    .push((id, View::CombatTracker(Box::new(combat_tracker))));
+                tasks.push(map_task(task, id, ViewMessage::CombatTracker));
+
+                id
+            }
+        };
+
+        // The fight under way is never replaced, as it would be lost.
+        if self.is_fighting() {
+            tracing::error!("cannot resume combat '{combat_id}' during another fight");
+
+            return Task::none();
+        }
+
+        let combat = self.combats.remove(index);
+
+        let Some(View::CombatTracker(combat_tracker)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        tasks.push(map_task(
+            combat_tracker.resume(combat),
+            id,
+            ViewMessage::CombatTracker,
+        ));
+        self.active_view = id;
+
+        Task::batch(tasks)
+    }
+
+    /// Whether a fight is under way in the `CombatTracker`.
+    fn is_fighting(&self) -> bool {
+        self.views.iter().any(|(_, view)| match view {
+            View::CombatTracker(combat_tracker) => combat_tracker.has_started(),
+            _ => false,
+        })
+    }
+
+    /// Delete the unfinished fight with `combat_id`, without resuming it.
+    pub fn discard_combat(&mut self, combat_id: Uuid) -> Task<Message> {
+        self.combats.retain(|combat| combat.id != combat_id);
+
+        Task::future(delete_combat(self.context.clone(), combat_id)).discard()
+    }
+
+    pub fn drafts_loaded(
+        &mut self,
+        result: Result<Box<[SpellDraftRecord]>, Error>,
+    ) -> Task<Message> {
+        match result {
+            Ok(drafts) => {
+                if !drafts.is_empty() {
+                    tracing::info!("recovered {} drafts from a previous session", drafts.len());
+                }
+
+                self.drafts = drafts.into_vec();
+            }
+            Err(err) => tracing::error!("failed to load drafts: {err:?}"),
+        }
+
+        Task::none()
+    }
+
+    /// Open the recovered draft with `draft_id` in a new form.
+    pub fn restore_draft(&mut self, draft_id: Uuid) -> Task<Message> {
+        let Some(index) = self.drafts.iter().position(|draft| draft.id == draft_id) else {
+            tracing::error!("could not find draft '{draft_id}'");
+
+            return Task::none();
+        };
+
+        let draft = self.drafts.remove(index);
+
+        let id = ViewId::unique();
+        let (spell_form, task) = SpellForm::from_draft(draft, self.context.clone());
+        self.views.push((id, View::SpellForm(Box::new(spell_form))));
+        self.active_view = id;
+
+        map_task(task, id, ViewMessage::SpellForm)
+    }
+
+    /// Delete the recovered draft with `draft_id`, without restoring it.
+    pub fn discard_draft(&mut self, draft_id: Uuid) -> Task<Message> {
+        self.drafts.retain(|draft| draft.id != draft_id);
+
+        Task::future(delete_draft(self.context.clone(), draft_id)).discard()
     }
 
     /// Update the `View` of `id` with the provided `message`.
@@ -116,6 +443,149 @@ impl Session {
         };
 
         match message {
+            ViewMessage::CombatTracker(combat_tracker_message) => {
+                let View::CombatTracker(combat_tracker) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{combat_tracker_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = combat_tracker.update(combat_tracker_message);
+
+                let mut tasks = Vec::with_capacity(2);
+                tasks.push(map_task(child_task, id, ViewMessage::CombatTracker));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        CombatTrackerEffect::OpenView(request) => {
+                            let task = Task::done(Message::OpenView(request));
+                            tasks.push(task);
+                        }
+                        CombatTrackerEffect::Fought => {
+                            tasks.push(self.reload_dashboard());
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+            ViewMessage::CreatureForm(creature_form_message) => {
+                let View::CreatureForm(creature_form) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{creature_form_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = creature_form.update(creature_form_message);
+                let creature_id = creature_form.id();
+
+                let mut tasks = Vec::with_capacity(5);
+                tasks.push(map_task(child_task, id, ViewMessage::CreatureForm));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        CreatureFormEffect::LoadFailed(err) => {
+                            tracing::error!("closing creature form that failed to load: {err:?}");
+                            tasks.push(Task::done(Message::CloseView(id)));
+                        }
+                        CreatureFormEffect::Saved => {
+                            tasks.push(self.reload_creature_list());
+                            tasks.push(self.reload_combat_creatures());
+                            tasks.push(self.reload_encounter_list());
+                            tasks.push(self.reload_creature_detail(creature_id));
+                            tasks.push(self.reload_dashboard());
+                            tasks.push(self.continue_closing_window());
+                        }
+                        CreatureFormEffect::SaveFailed => {
+                            // The window stays open, so the user can correct what failed to save.
+                            if self.closing_window.take().is_some() {
+                                self.active_view = id;
+                            }
+                        }
+                        CreatureFormEffect::Deleted => {
+                            // The stat block of a deleted creature has nothing left to show.
+                            if let Some(detail_id) = self.view_exists(|view| {
+                                matches!(view, View::CreatureDetail(detail) if Some(detail.id()) == creature_id)
+                            }) {
+                                tasks.push(self.remove_view(detail_id));
+                            }
+
+                            tasks.push(self.remove_view(id));
+                            tasks.push(self.reload_creature_list());
+                            tasks.push(self.reload_combat_creatures());
+                            tasks.push(self.reload_encounter_list());
+                            tasks.push(self.reload_dashboard());
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+            ViewMessage::CreatureDetail(creature_detail_message) => {
+                let View::CreatureDetail(creature_detail) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{creature_detail_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = creature_detail.update(creature_detail_message);
+
+                let mut tasks = Vec::with_capacity(3);
+                tasks.push(map_task(child_task, id, ViewMessage::CreatureDetail));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        CreatureDetailEffect::OpenView(request) => {
+                            let task = Task::done(Message::OpenView(request));
+                            tasks.push(task);
+                        }
+                        CreatureDetailEffect::LoadFailed(err) => {
+                            tracing::error!("closing creature detail that failed to load: {err:?}");
+                            tasks.push(Task::done(Message::CloseView(id)));
+                        }
+                        CreatureDetailEffect::Deleted => {
+                            tasks.push(Task::done(Message::CloseView(id)));
+                            tasks.push(self.reload_creature_list());
+                            tasks.push(self.reload_combat_creatures());
+                            tasks.push(self.reload_encounter_list());
+                            tasks.push(self.reload_dashboard());
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+            ViewMessage::CreatureList(creature_list_message) => {
+                let View::CreatureList(creature_list) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{creature_list_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = creature_list.update(creature_list_message);
+
+                let mut tasks = Vec::with_capacity(2);
+                tasks.push(map_task(child_task, id, ViewMessage::CreatureList));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        CreatureListEffect::OpenView(request) => {
+                            let task = Task::done(Message::OpenView(request));
+                            tasks.push(task);
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
             ViewMessage::Dashboard(dashboard_message) => {
                 let View::Dashboard(dashboard) = view else {
                     tracing::error!(
@@ -152,7 +622,8 @@ impl Session {
 
                 let (child_task, maybe_effect) = spell_form.update(spell_form_message);
 
-                let mapped_task = map_task(child_task, id, ViewMessage::SpellForm);
+                let mut tasks = Vec::with_capacity(6);
+                tasks.push(map_task(child_task, id, ViewMessage::SpellForm));
 
                 if let Some(spell_form_effect) = maybe_effect {
                     match spell_form_effect {
@@ -162,10 +633,77 @@ impl Session {
                             //
                             // effect = Some(TabManagerEffect::LoadFailed(error));
                         }
+                        SpellFormEffect::Saved(spell_id) => {
+                            // The form is replaced by the detail view of the saved spell.
+                            tasks.push(Task::done(Message::CloseView(id)));
+                            tasks.push(Task::done(Message::OpenView(Request::SpellDetail {
+                                id: spell_id,
+                            })));
+                            tasks.push(self.reload_spell_list());
+                            tasks.push(self.reload_combat_spells());
+                            tasks.push(self.reload_dashboard());
+                            tasks.push(self.continue_closing_window());
+                        }
+                        SpellFormEffect::SaveFailed => {
+                            // The window stays open, so the user can correct what failed to save.
+                            if self.closing_window.take().is_some() {
+                                self.active_view = id;
+                            }
+                        }
                     }
                 }
 
-                mapped_task
+                Task::batch(tasks)
+            }
+            ViewMessage::SpellDetail(spell_detail_message) => {
+                let View::SpellDetail(spell_detail) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{spell_detail_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = spell_detail.update(spell_detail_message);
+
+                let mut tasks = Vec::with_capacity(4);
+                tasks.push(map_task(child_task, id, ViewMessage::SpellDetail));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        SpellDetailEffect::OpenView(request) => {
+                            let task = Task::done(Message::OpenView(request));
+                            tasks.push(task);
+                        }
+                        SpellDetailEffect::LoadFailed(err) => {
+                            tracing::error!("closing spell detail that failed to load: {err:?}");
+                            tasks.push(Task::done(Message::CloseView(id)));
+                        }
+                        SpellDetailEffect::Deleted => {
+                            tasks.push(Task::done(Message::CloseView(id)));
+                            tasks.push(self.reload_spell_list());
+                            tasks.push(self.reload_combat_spells());
+                            tasks.push(self.reload_dashboard());
+                        }
+                        SpellDetailEffect::Restored => {
+                            tasks.push(self.reload_spell_list());
+                            tasks.push(self.reload_combat_spells());
+                            tasks.push(self.reload_dashboard());
+                        }
+                        SpellDetailEffect::Viewed => {
+                            tasks.push(self.reload_dashboard());
+                        }
+                        SpellDetailEffect::FavouriteChanged => {
+                            tasks.push(self.reload_spell_list());
+                            tasks.push(self.reload_dashboard());
+                        }
+                        SpellDetailEffect::ShowToPlayers(handout) => {
+                            tasks.push(self.show_handout(handout));
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
             }
             ViewMessage::SpellList(spell_list_message) => {
                 let View::SpellList(spell_list) = view else {
@@ -178,7 +716,7 @@ impl Session {
 
                 let (child_task, maybe_effect) = spell_list.update(spell_list_message);
 
-                let mut tasks = Vec::with_capacity(2);
+                let mut tasks = Vec::with_capacity(5);
                 tasks.push(map_task(child_task, id, ViewMessage::SpellList));
 
                 if let Some(effect) = maybe_effect {
@@ -187,54 +725,538 @@ impl Session {
                             let task = Task::done(Message::OpenView(request));
                             tasks.push(task);
                         }
+                        SpellListEffect::Restored(spell_id) => {
+                            tasks.push(Task::done(Message::OpenView(Request::SpellDetail {
+                                id: spell_id,
+                            })));
+                            tasks.push(self.reload_spell_list());
+                            tasks.push(self.reload_combat_spells());
+                            tasks.push(self.reload_dashboard());
+                        }
                     }
                 }
 
                 Task::batch(tasks)
             }
-        }
-    }
-
-    /// Open the `View` defined by `request`.
-    ///
-    /// Depending on the type of `View` the `request` contains,
-    /// this may instead focus an existing instance of `View`.
-    pub fn open_view(&mut self, request: Request) -> Task<Message> {
-        tracing::debug!("opening view: {request:?}");
-
-        match request {
-            Request::SpellForm { mode } => {
-                let id = ViewId::unique();
-                let (spell_form, task) = SpellForm::new(mode, self.context.clone());
-                let tab = View::SpellForm(Box::new(spell_form));
-                self.views.push((id, tab));
-                self.active_view = id;
-
-                let mapped_task = map_task(task, id, ViewMessage::SpellForm);
-
-                return mapped_task;
-            }
-            Request::SpellList => {
-                // Check if view already exists
-                let Some(id) = self.view_exists(|view| matches!(view, View::SpellList(_))) else {
-                    let id = ViewId::unique();
-                    let new_tab = View::SpellList(Box::new(SpellList::new()));
-                    self.views.push((id, new_tab));
-                    self.active_view = id;
+            ViewMessage::EncounterBuilder(encounter_builder_message) => {
+                let View::EncounterBuilder(encounter_builder) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{encounter_builder_message:?}'"
+                    );
 
                     return Task::none();
                 };
 
-                self.active_view = id;
-            }
-        }
+                let (child_task, maybe_effect) =
+                    encounter_builder.update(encounter_builder_message);
 
-        Task::none()
-    }
+                let mut tasks = Vec::with_capacity(3);
+                tasks.push(map_task(child_task, id, ViewMessage::EncounterBuilder));
 
-    /// Close the `View` of the provided `close_id`.
-    pub fn close_view(&mut self, close_id: ViewId) -> Task<Message> {
-        tracing::debug!("closing view: {close_id:?}");
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        EncounterBuilderEffect::LoadFailed(err) => {
+                            tracing::error!(
+                                "closing encounter builder that failed to load: {err:?}"
+                            );
+                            tasks.push(Task::done(Message::CloseView(id)));
+                        }
+                        EncounterBuilderEffect::OpenView(request) => {
+                            let task = Task::done(Message::OpenView(request));
+                            tasks.push(task);
+                        }
+                        EncounterBuilderEffect::Saved => {
+                            tasks.push(self.reload_encounter_list());
+                            tasks.push(self.reload_dashboard());
+                            tasks.push(self.continue_closing_window());
+                        }
+                        EncounterBuilderEffect::SaveFailed => {
+                            // The window stays open, so the user can correct what failed to save.
+                            if self.closing_window.take().is_some() {
+                                self.active_view = id;
+                            }
+                        }
+                        EncounterBuilderEffect::Deleted => {
+                            tasks.push(self.remove_view(id));
+                            tasks.push(self.reload_encounter_list());
+                            tasks.push(self.reload_dashboard());
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+            ViewMessage::EncounterList(encounter_list_message) => {
+                let View::EncounterList(encounter_list) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{encounter_list_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = encounter_list.update(encounter_list_message);
+
+                let mut tasks = Vec::with_capacity(2);
+                tasks.push(map_task(child_task, id, ViewMessage::EncounterList));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        EncounterListEffect::OpenView(request) => {
+                            let task = Task::done(Message::OpenView(request));
+                            tasks.push(task);
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+            ViewMessage::SpellbookEditor(spellbook_editor_message) => {
+                let View::SpellbookEditor(spellbook_editor) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{spellbook_editor_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = spellbook_editor.update(spellbook_editor_message);
+                let spellbook_id = spellbook_editor.id();
+
+                let mut tasks = Vec::with_capacity(4);
+                tasks.push(map_task(child_task, id, ViewMessage::SpellbookEditor));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        SpellbookEditorEffect::LoadFailed(err) => {
+                            tracing::error!(
+                                "closing spellbook editor that failed to load: {err:?}"
+                            );
+                            tasks.push(Task::done(Message::CloseView(id)));
+                        }
+                        SpellbookEditorEffect::Saved => {
+                            tasks.push(self.reload_spellbook_list());
+                            tasks.push(self.reload_spellbook_tracker(spellbook_id));
+                            tasks.push(self.reload_dashboard());
+                            tasks.push(self.continue_closing_window());
+                        }
+                        SpellbookEditorEffect::SaveFailed => {
+                            // The window stays open, so the user can correct what failed to save.
+                            if self.closing_window.take().is_some() {
+                                self.active_view = id;
+                            }
+                        }
+                        SpellbookEditorEffect::Deleted => {
+                            tasks.push(self.remove_view(id));
+                            tasks.push(self.reload_spellbook_list());
+                            tasks.push(self.reload_dashboard());
+
+                            // The tracker of a deleted spellbook has nothing left to track.
+                            if let Some(tracker_id) = self.view_exists(|view| {
+                                matches!(view, View::SpellbookTracker(tracker) if Some(tracker.id()) == spellbook_id)
+                            }) {
+                                tasks.push(self.remove_view(tracker_id));
+                            }
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+            ViewMessage::SpellbookList(spellbook_list_message) => {
+                let View::SpellbookList(spellbook_list) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{spellbook_list_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) = spellbook_list.update(spellbook_list_message);
+
+                let mut tasks = Vec::with_capacity(2);
+                tasks.push(map_task(child_task, id, ViewMessage::SpellbookList));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        SpellbookListEffect::OpenView(request) => {
+                            let task = Task::done(Message::OpenView(request));
+                            tasks.push(task);
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+            ViewMessage::SpellbookTracker(spellbook_tracker_message) => {
+                let View::SpellbookTracker(spellbook_tracker) = view else {
+                    tracing::error!(
+                        "view with id '{id:?}' does not match message of type '{spellbook_tracker_message:?}'"
+                    );
+
+                    return Task::none();
+                };
+
+                let (child_task, maybe_effect) =
+                    spellbook_tracker.update(spellbook_tracker_message);
+
+                let mut tasks = Vec::with_capacity(2);
+                tasks.push(map_task(child_task, id, ViewMessage::SpellbookTracker));
+
+                if let Some(effect) = maybe_effect {
+                    match effect {
+                        SpellbookTrackerEffect::LoadFailed(err) => {
+                            tracing::error!(
+                                "closing spellbook tracker that failed to load: {err:?}"
+                            );
+                            tasks.push(Task::done(Message::CloseView(id)));
+                        }
+                    }
+                }
+
+                Task::batch(tasks)
+            }
+        }
+    }
+
+    /// Open the `View` defined by `request`.
+    ///
+    /// Depending on the type of `View` the `request` contains,
+    /// this may instead focus an existing instance of `View`.
+    pub fn open_view(&mut self, request: Request) -> Task<Message> {
+        tracing::debug!("opening view: {request:?}");
+
+        match request {
+            Request::SpellForm { mode } => {
+                let id = ViewId::unique();
+                let (spell_form, task) = SpellForm::new(mode, self.context.clone());
+                let tab = View::SpellForm(Box::new(spell_form));
+                self.views.push((id, tab));
+                self.active_view = id;
+
+                let mapped_task = map_task(task, id, ViewMessage::SpellForm);
+
+                return mapped_task;
+            }
+            Request::SpellDetail { id: spell_id } => {
+                // Check if view already exists, in which case it is refreshed.
+                let existing = self.view_exists(
+                    |view| matches!(view, View::SpellDetail(detail) if detail.id() == spell_id),
+                );
+
+                if let Some(id) = existing {
+                    self.active_view = id;
+
+                    if let Some(View::SpellDetail(spell_detail)) = self.view_mut(id) {
+                        return map_task(spell_detail.reload(), id, ViewMessage::SpellDetail);
+                    }
+
+                    return Task::none();
+                }
+
+                let id = ViewId::unique();
+                let (spell_detail, task) = SpellDetail::new(spell_id, self.context.clone());
+                self.views
+                    .push((id, View::SpellDetail(Box::new(spell_detail))));
+                self.active_view = id;
+
+                return map_task(task, id, ViewMessage::SpellDetail);
+            }
+            Request::SpellList => {
+                // Check if view already exists
+                let Some(id) = self.view_exists(|view| matches!(view, View::SpellList(_))) else {
+                    let id = ViewId::unique();
+                    let (spell_list, task) = SpellList::new(self.context.clone());
+                    let new_tab = View::SpellList(Box::new(spell_list));
+                    self.views.push((id, new_tab));
+                    self.active_view = id;
+
+                    return map_task(task, id, ViewMessage::SpellList);
+                };
+
+                self.active_view = id;
+            }
+            Request::CreatureForm { mode } => {
+                // An existing creature is only ever edited in a single form.
+                if let FormMode::Edit(creature_id) = mode
+                    && let Some(id) = self.view_exists(|view| {
+                        matches!(view, View::CreatureForm(form) if form.id() == Some(creature_id))
+                    })
+                {
+                    self.active_view = id;
+
+                    return Task::none();
+                }
+
+                let id = ViewId::unique();
+                let (creature_form, task) = CreatureForm::new(mode, self.context.clone());
+                self.views
+                    .push((id, View::CreatureForm(Box::new(creature_form))));
+                self.active_view = id;
+
+                return map_task(task, id, ViewMessage::CreatureForm);
+            }
+            Request::CombatTracker { encounter } => {
+                let mut tasks = Vec::with_capacity(2);
+
+                // There is only ever a single fight going on, which a launched encounter joins.
+                let id = match self.view_exists(|view| matches!(view, View::CombatTracker(_))) {
+                    Some(id) => id,
+                    None => {
+                        let id = ViewId::unique();
+                        let (combat_tracker, task) = CombatTracker::new(self.context.clone());
+                        let new_tab = View::CombatTracker(Box::new(combat_tracker));
+                        self.views.push((id, new_tab));
+                        tasks.push(map_task(task, id, ViewMessage::CombatTracker));
+
+                        id
+                    }
+                };
+
+                self.active_view = id;
+
+                if let Some(encounter_id) = encounter
+                    && let Some(View::CombatTracker(combat_tracker)) = self.view_mut(id)
+                {
+                    let task = combat_tracker.launch(encounter_id);
+                    tasks.push(map_task(task, id, ViewMessage::CombatTracker));
+                }
+
+                return Task::batch(tasks);
+            }
+            Request::CreatureDetail { id: creature_id } => {
+                // Check if view already exists, in which case it is refreshed.
+                let existing = self.view_exists(
+                    |view| matches!(view, View::CreatureDetail(detail) if detail.id() == creature_id),
+                );
+
+                if let Some(id) = existing {
+                    self.active_view = id;
+
+                    if let Some(View::CreatureDetail(creature_detail)) = self.view_mut(id) {
+                        return map_task(creature_detail.reload(), id, ViewMessage::CreatureDetail);
+                    }
+
+                    return Task::none();
+                }
+
+                let id = ViewId::unique();
+                let (creature_detail, task) =
+                    CreatureDetail::new(creature_id, self.context.clone());
+                self.views
+                    .push((id, View::CreatureDetail(Box::new(creature_detail))));
+                self.active_view = id;
+
+                return map_task(task, id, ViewMessage::CreatureDetail);
+            }
+            Request::CreatureList => {
+                // Check if view already exists
+                let Some(id) = self.view_exists(|view| matches!(view, View::CreatureList(_)))
+                else {
+                    let id = ViewId::unique();
+                    let (creature_list, task) = CreatureList::new(self.context.clone());
+                    let new_tab = View::CreatureList(Box::new(creature_list));
+                    self.views.push((id, new_tab));
+                    self.active_view = id;
+
+                    return map_task(task, id, ViewMessage::CreatureList);
+                };
+
+                self.active_view = id;
+            }
+            Request::EncounterBuilder { mode } => {
+                // An existing encounter is only ever edited in a single builder.
+                if let FormMode::Edit(encounter_id) = mode
+                    && let Some(id) = self.view_exists(|view| {
+                        matches!(view, View::EncounterBuilder(builder) if builder.id() == Some(encounter_id))
+                    })
+                {
+                    self.active_view = id;
+
+                    return Task::none();
+                }
+
+                let id = ViewId::unique();
+                let (encounter_builder, task) = EncounterBuilder::new(mode, self.context.clone());
+                self.views
+                    .push((id, View::EncounterBuilder(Box::new(encounter_builder))));
+                self.active_view = id;
+
+                return map_task(task, id, ViewMessage::EncounterBuilder);
+            }
+            Request::EncounterList => {
+                let Some(id) = self.view_exists(|view| matches!(view, View::EncounterList(_)))
+                else {
+                    let id = ViewId::unique();
+                    let (encounter_list, task) = EncounterList::new(self.context.clone());
+                    self.views
+                        .push((id, View::EncounterList(Box::new(encounter_list))));
+                    self.active_view = id;
+
+                    return map_task(task, id, ViewMessage::EncounterList);
+                };
+
+                self.active_view = id;
+            }
+            Request::SpellbookEditor { mode } => {
+                // An existing spellbook is only ever edited in a single editor.
+                if let FormMode::Edit(spellbook_id) = mode
+                    && let Some(id) = self.view_exists(|view| {
+                        matches!(view, View::SpellbookEditor(editor) if editor.id() == Some(spellbook_id))
+                    })
+                {
+                    self.active_view = id;
+
+                    return Task::none();
+                }
+
+                let id = ViewId::unique();
+                let (spellbook_editor, task) = SpellbookEditor::new(mode, self.context.clone());
+                self.views
+                    .push((id, View::SpellbookEditor(Box::new(spellbook_editor))));
+                self.active_view = id;
+
+                return map_task(task, id, ViewMessage::SpellbookEditor);
+            }
+            Request::SpellbookList => {
+                let Some(id) = self.view_exists(|view| matches!(view, View::SpellbookList(_)))
+                else {
+                    let id = ViewId::unique();
+                    let (spellbook_list, task) = SpellbookList::new(self.context.clone());
+                    self.views
+                        .push((id, View::SpellbookList(Box::new(spellbook_list))));
+                    self.active_view = id;
+
+                    return map_task(task, id, ViewMessage::SpellbookList);
+                };
+
+                self.active_view = id;
+            }
+            Request::SpellbookTracker { id: spellbook_id } => {
+                let existing = self.view_exists(|view| {
+                    matches!(view, View::SpellbookTracker(tracker) if tracker.id() == spellbook_id)
+                });
+
+                if let Some(id) = existing {
+                    self.active_view = id;
+
+                    return Task::none();
+                }
+
+                let id = ViewId::unique();
+                let (spellbook_tracker, task) =
+                    SpellbookTracker::new(spellbook_id, self.context.clone());
+                self.views
+                    .push((id, View::SpellbookTracker(Box::new(spellbook_tracker))));
+                self.active_view = id;
+
+                return map_task(task, id, ViewMessage::SpellbookTracker);
+            }
+        }
+
+        Task::none()
+    }
+
+    /// Close the view of `close_id`, asking for confirmation first if it has unsaved changes.
+    pub fn close_view(&mut self, close_id: ViewId) -> Task<Message> {
+        if self.view(close_id).is_some_and(View::is_dirty) {
+            tracing::debug!("view '{close_id:?}' has unsaved changes, confirming close");
+            self.pending_close = Some(PendingClose::View(close_id));
+
+            return Task::none();
+        }
+
+        self.remove_view(close_id)
+    }
+
+    /// Close the window of `window_id`, asking for confirmation first if any view has unsaved changes.
+    pub fn close_window(&mut self, window_id: window::Id) -> Task<Message> {
+        if self.views.iter().any(|(_, view)| view.is_dirty()) {
+            tracing::debug!("views have unsaved changes, confirming window close");
+            self.pending_close = Some(PendingClose::Window(window_id));
+
+            return Task::none();
+        }
+
+        // The fight is persisted as it stands, to be resumed in the next session.
+        self.autosave_combat().chain(window::close(window_id))
+    }
+
+    /// Resolve the pending close with the `choice` of the user.
+    pub fn resolve_close(&mut self, choice: CloseChoice) -> Task<Message> {
+        let Some(pending_close) = self.pending_close.take() else {
+            tracing::error!("no pending close to resolve");
+
+            return Task::none();
+        };
+
+        match (pending_close, choice) {
+            (_, CloseChoice::Cancel) => Task::none(),
+            // Saving closes the view on its own once it succeeds.
+            (PendingClose::View(id), CloseChoice::Save) => self.save_view(id),
+            (PendingClose::View(id), CloseChoice::Discard) => self.remove_view(id),
+            (PendingClose::Window(window_id), CloseChoice::Save) => {
+                let dirty_ids: Vec<ViewId> = self
+                    .views
+                    .iter()
+                    .filter(|(_, view)| view.is_dirty())
+                    .map(|(id, _)| *id)
+                    .collect();
+
+                // The window only waits on the saves if every unsaved view can be saved.
+                if let Some(&id) = dirty_ids
+                    .iter()
+                    .find(|id| self.view(**id).and_then(View::save_message).is_none())
+                {
+                    tracing::error!("view '{id:?}' cannot be saved, keeping the window open");
+                    self.active_view = id;
+
+                    return Task::none();
+                }
+
+                // A save that fails, even right away, clears this and leaves the window open.
+                self.closing_window = Some(window_id);
+
+                let tasks: Vec<_> = dirty_ids.into_iter().map(|id| self.save_view(id)).collect();
+
+                Task::batch(tasks)
+            }
+            (PendingClose::Window(window_id), CloseChoice::Discard) => {
+                let ids: Vec<ViewId> = self.views.iter().map(|(id, _)| *id).collect();
+                let tasks: Vec<_> = ids.into_iter().map(|id| self.remove_view(id)).collect();
+
+                // The drafts of the discarded views are deleted before the window closes.
+                Task::batch(tasks).chain(window::close(window_id))
+            }
+        }
+    }
+
+    /// Save the unsaved changes of the view of `id`.
+    fn save_view(&mut self, id: ViewId) -> Task<Message> {
+        let Some(message) = self.view(id).and_then(View::save_message) else {
+            tracing::error!("view '{id:?}' cannot be saved");
+
+            return Task::none();
+        };
+
+        self.update_view(id, message)
+    }
+
+    /// Close the window being closed, if every view has been saved.
+    fn continue_closing_window(&mut self) -> Task<Message> {
+        if self.views.iter().any(|(_, view)| view.is_dirty()) {
+            return Task::none();
+        }
+
+        match self.closing_window.take() {
+            Some(window_id) => self.autosave_combat().chain(window::close(window_id)),
+            None => Task::none(),
+        }
+    }
+
+    /// Remove the view of `close_id`, regardless of unsaved changes.
+    fn remove_view(&mut self, close_id: ViewId) -> Task<Message> {
+        tracing::debug!("closing view: {close_id:?}");
 
         // Only recalculate the active view if we are closing the focused one
         if self.active_view == close_id {
@@ -259,12 +1281,87 @@ impl Session {
                 .unwrap_or(self.dashboard_id);
         }
 
+        // Closing a form abandons its unsaved work, so its draft is no longer needed, whereas
+        // a fight is kept as it stands, to be resumed later.
+        let task = match self.view_mut(close_id) {
+            Some(View::SpellForm(spell_form)) => {
+                map_task(spell_form.discard_draft(), close_id, ViewMessage::SpellForm)
+            }
+            Some(View::CombatTracker(combat_tracker)) => map_task(
+                combat_tracker.autosave(),
+                close_id,
+                ViewMessage::CombatTracker,
+            ),
+            _ => Task::none(),
+        };
+
         // Close the view.
         self.views.retain(|(id, _)| *id != close_id);
 
+        task
+    }
+
+    /// The id of the player window, if it is open.
+    pub fn player_window(&self) -> Option<window::Id> {
+        self.player_window
+    }
+
+    /// Open the player window, or close it if it is open.
+    pub fn toggle_player_window(&mut self) -> Task<Message> {
+        match self.player_window.take() {
+            Some(window_id) => window::close(window_id),
+            None => self.open_player_window(),
+        }
+    }
+
+    fn open_player_window(&mut self) -> Task<Message> {
+        if self.player_window.is_some() {
+            return Task::none();
+        }
+
+        let (window_id, task) = window::open(players::settings());
+        self.player_window = Some(window_id);
+
+        task.discard()
+    }
+
+    /// Show `handout` to the players, opening the player window if needed.
+    pub fn show_handout(&mut self, handout: Handout) -> Task<Message> {
+        self.handout = Some(handout);
+
+        self.open_player_window()
+    }
+
+    pub fn hide_handout(&mut self) -> Task<Message> {
+        self.handout = None;
+
         Task::none()
     }
 
+    pub fn has_handout(&self) -> bool {
+        self.handout.is_some()
+    }
+
+    /// Forget the window of `window_id`, which the user closed.
+    pub fn window_closed(&mut self, window_id: window::Id) -> Task<Message> {
+        if self.player_window == Some(window_id) {
+            tracing::debug!("player window closed");
+            self.player_window = None;
+        }
+
+        Task::none()
+    }
+
+    /// The initiative order of the fight in the `CombatTracker`, and the handout if any.
+    pub fn player_view(&self) -> Element<'_, Message> {
+        let encounter = self.views.iter().find_map(|(_, view)| match view {
+            View::CombatTracker(combat_tracker) => Some(combat_tracker.encounter()),
+            _ => None,
+        });
+
+        players::view(encounter, self.handout.as_ref())
+    }
+
     pub fn focus_view(&mut self, view_id: ViewId) -> Task<Message> {
         self.active_view = view_id;
 
@@ -320,6 +1417,305 @@ impl Session {
             .into()
     }
 
+    /// The dialog asking whether to save the unsaved changes of `pending_close`.
+    fn close_dialog(&self, pending_close: PendingClose) -> Element<'_, Message> {
+        let description = match pending_close {
+            PendingClose::View(id) => {
+                let title = self.view(id).map_or("This view", View::title);
+
+                format!("'{title}' has unsaved changes. Save them before closing?")
+            }
+            PendingClose::Window(_) => {
+                let count = self
+                    .views
+                    .iter()
+                    .filter(|(_, view)| view.is_dirty())
+                    .count();
+
+                format!("{count} open views have unsaved changes. Save them before closing?")
+            }
+        };
+
+        let heading = components::text::heading("Unsaved changes");
+        let description = components::text::body(description);
+
+        let cancel = widget::button(components::text::display("Cancel"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press(Message::CloseResolved(CloseChoice::Cancel));
+        let discard = widget::button(components::text::display("Discard"))
+            .class(ButtonClass::Danger)
+            .padding(INPUT_PADDING)
+            .on_press(Message::CloseResolved(CloseChoice::Discard));
+        let save = widget::button(components::text::display("Save"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press(Message::CloseResolved(CloseChoice::Save));
+
+        let actions = row![
+            widget::space::horizontal().width(Length::Fill),
+            cancel,
+            discard,
+            save
+        ]
+        .spacing(BODY_SPACING);
+
+        column![heading, description, actions]
+            .spacing(BODY_SPACING)
+            .into()
+    }
+
+    /// The prompt to restore or discard the drafts recovered from a previous session.
+    fn recovered_drafts(&self) -> Option<Element<'_, Message>> {
+        if self.drafts.is_empty() {
+            return None;
+        }
+
+        let heading = components::text::heading("Recovered unsaved work");
+        let detail = components::text::detail(
+            "These spells were not saved before the application was last closed.",
+        );
+
+        let mut drafts = column![heading, detail].spacing(LABEL_SPACING);
+
+        for draft in &self.drafts {
+            let name = if draft.data.name.trim().is_empty() {
+                "Untitled spell"
+            } else {
+                draft.data.name.as_str()
+            };
+
+            let label = components::text::body(name);
+            let updated_at = components::text::detail(format!("Last edited {}", draft.updated_at));
+
+            let restore = widget::button(components::text::display("Restore"))
+                .class(ButtonClass::Primary)
+                .on_press(Message::RestoreDraft(draft.id));
+            let discard = widget::button(components::text::display("Discard"))
+                .class(ButtonClass::Danger)
+                .on_press(Message::DiscardDraft(draft.id));
+
+            let draft_row = row![
+                label,
+                updated_at,
+                widget::space::horizontal().width(Length::Fill),
+                restore,
+                discard
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+            drafts = drafts.push(draft_row);
+        }
+
+        let prompt = widget::container(drafts)
+            .class(ContainerClass::Surface)
+            .padding(INPUT_PADDING)
+            .max_width(VIEW_WIDTH);
+
+        Some(
+            widget::container(prompt)
+                .center_x(Length::Fill)
+                .padding(Padding::new(0.0).top(10.0))
+                .into(),
+        )
+    }
+
+    /// The prompt to resume or discard the fights left unfinished in a previous session.
+    fn unfinished_combats(&self) -> Option<Element<'_, Message>> {
+        if self.combats.is_empty() {
+            return None;
+        }
+
+        let heading = components::text::heading("Unfinished combats");
+        let detail = components::text::detail(
+            "These fights were still going on when the application was last closed.",
+        );
+
+        // Another fight cannot be resumed while one is under way in the tracker.
+        let can_resume = !self.is_fighting();
+
+        let mut combats = column![heading, detail].spacing(LABEL_SPACING);
+
+        for combat in &self.combats {
+            let names: Vec<_> = combat
+                .data
+                .combatants
+                .iter()
+                .map(|combatant| combatant.name.as_str())
+                .collect();
+
+            let label = components::text::body(format!(
+                "Round {} · {}",
+                combat.data.round,
+                names.join(", ")
+            ));
+            let updated_at = components::text::detail(format!("Last played {}", combat.updated_at));
+
+            let resume = widget::button(components::text::display("Resume"))
+                .class(ButtonClass::Primary)
+                .on_press_maybe(can_resume.then_some(Message::ResumeCombat(combat.id)));
+            let discard = widget::button(components::text::display("Discard"))
+                .class(ButtonClass::Danger)
+                .on_press(Message::DiscardCombat(combat.id));
+
+            let combat_row = row![
+                label,
+                updated_at,
+                widget::space::horizontal().width(Length::Fill),
+                resume,
+                discard
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+            combats = combats.push(combat_row);
+        }
+
+        let prompt = widget::container(combats)
+            .class(ContainerClass::Surface)
+            .padding(INPUT_PADDING)
+            .max_width(VIEW_WIDTH);
+
+        Some(
+            widget::container(prompt)
+                .center_x(Length::Fill)
+                .padding(Padding::new(0.0).top(10.0))
+                .into(),
+        )
+    }
+
+    /// Reload the favourite and recent spells of the dashboard.
+    fn reload_dashboard(&mut self) -> Task<Message> {
+        let id = self.dashboard_id;
+
+        let Some(View::Dashboard(dashboard)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(dashboard.reload(), id, ViewMessage::Dashboard)
+    }
+
+    /// Reload the creatures that can join the fight of the `CombatTracker`, if it is open.
+    fn reload_combat_creatures(&mut self) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| matches!(view, View::CombatTracker(_))) else {
+            return Task::none();
+        };
+
+        let Some(View::CombatTracker(combat_tracker)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(
+            combat_tracker.reload_creatures(),
+            id,
+            ViewMessage::CombatTracker,
+        )
+    }
+
+    /// Reload the spells that can be cast in the fight of the `CombatTracker`, if it is open.
+    fn reload_combat_spells(&mut self) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| matches!(view, View::CombatTracker(_))) else {
+            return Task::none();
+        };
+
+        let Some(View::CombatTracker(combat_tracker)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(
+            combat_tracker.reload_spells(),
+            id,
+            ViewMessage::CombatTracker,
+        )
+    }
+
+    /// Reload the creatures of the `CreatureList`, if it is open.
+    fn reload_creature_list(&mut self) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| matches!(view, View::CreatureList(_))) else {
+            return Task::none();
+        };
+
+        let Some(View::CreatureList(creature_list)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(creature_list.reload(), id, ViewMessage::CreatureList)
+    }
+
+    /// Reload the stat block of the creature with `creature_id`, if it is open.
+    fn reload_creature_detail(&mut self, creature_id: Option<Uuid>) -> Task<Message> {
+        let Some(id) = self.view_exists(
+            |view| matches!(view, View::CreatureDetail(detail) if Some(detail.id()) == creature_id),
+        ) else {
+            return Task::none();
+        };
+
+        let Some(View::CreatureDetail(creature_detail)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(creature_detail.reload(), id, ViewMessage::CreatureDetail)
+    }
+
+    /// Reload the spells of the `SpellList`, if it is open.
+    fn reload_spell_list(&mut self) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| matches!(view, View::SpellList(_))) else {
+            return Task::none();
+        };
+
+        let Some(View::SpellList(spell_list)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(spell_list.reload(), id, ViewMessage::SpellList)
+    }
+
+    /// Reload the encounters of the `EncounterList`, if it is open.
+    fn reload_encounter_list(&mut self) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| matches!(view, View::EncounterList(_))) else {
+            return Task::none();
+        };
+
+        let Some(View::EncounterList(encounter_list)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(encounter_list.reload(), id, ViewMessage::EncounterList)
+    }
+
+    /// Reload the spellbooks of the `SpellbookList`, if it is open.
+    fn reload_spellbook_list(&mut self) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| matches!(view, View::SpellbookList(_))) else {
+            return Task::none();
+        };
+
+        let Some(View::SpellbookList(spellbook_list)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(spellbook_list.reload(), id, ViewMessage::SpellbookList)
+    }
+
+    /// Reload the tracker of the spellbook with `spellbook_id`, if it is open.
+    fn reload_spellbook_tracker(&mut self, spellbook_id: Option<Uuid>) -> Task<Message> {
+        let Some(id) = self.view_exists(|view| {
+            matches!(view, View::SpellbookTracker(tracker) if Some(tracker.id()) == spellbook_id)
+        }) else {
+            return Task::none();
+        };
+
+        let Some(View::SpellbookTracker(spellbook_tracker)) = self.view_mut(id) else {
+            return Task::none();
+        };
+
+        map_task(
+            spellbook_tracker.reload(),
+            id,
+            ViewMessage::SpellbookTracker,
+        )
+    }
+
     /// Get a reference to the `View` associated with `id`, if it exists.
     fn view(&self, id: ViewId) -> Option<&View> {
         self.views
@@ -345,6 +1741,32 @@ impl Session {
     }
 }
 
+async fn load_drafts<C: SpellDraftsRepository>(ctx: C) -> Result<Box<[SpellDraftRecord]>, Error> {
+    let repo = ctx.spell_drafts();
+    repo.list().await
+}
+
+async fn load_combats<C: CombatsRepository>(ctx: C) -> Result<Box<[CombatRecord]>, Error> {
+    let repo = ctx.combats();
+    repo.list().await
+}
+
+async fn delete_combat<C: CombatsRepository>(ctx: C, id: Uuid) {
+    let repo = ctx.combats();
+
+    if let Err(err) = repo.delete(id).await {
+        tracing::error!("failed to delete combat '{id}': {err:?}");
+    }
+}
+
+async fn delete_draft<C: SpellDraftsRepository>(ctx: C, id: Uuid) {
+    let repo = ctx.spell_drafts();
+
+    if let Err(err) = repo.delete(id).await {
+        tracing::error!("failed to delete draft '{id}': {err:?}");
+    }
+}
+
 /// Wrap a given given generic `Task<M>` in the provided `ViewMessage` wrapper.
 ///
 /// This is a helper function that maps output tasks from a `View`, to its specific message type
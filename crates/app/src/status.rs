@@ -23,6 +23,14 @@ impl<Loader, State> Status<Loader, State> {
         }
     }
 
+    pub fn as_ready(&self) -> Option<&State> {
+        if let Status::Ready(state) = self {
+            Some(state)
+        } else {
+            None
+        }
+    }
+
     pub fn as_ready_mut(&mut self) -> Option<&mut State> {
         if let Status::Ready(state) = self {
             Some(state)
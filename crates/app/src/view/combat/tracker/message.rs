@@ -0,0 +1,93 @@
+use crate::view::combat::tracker::DurationKind;
+use crate::view::combat::tracker::Named;
+use crate::view::request::Request;
+use storage::Error;
+use storage::records::creature::CreatureSummary;
+use storage::records::encounter::EncounterRecord;
+use storage::records::spell::SpellSummary;
+use types::Ability;
+use types::Condition;
+use types::Creature;
+use types::DamageType;
+use types::Spell;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    CreatureSelected(Named),
+    CreatureAddRequested,
+    CharacterNameChanged(String),
+    CharacterBonusChanged(String),
+    CharacterDexterityChanged(String),
+    CharacterHitPointsChanged(String),
+    CharacterAdded,
+    InitiativeChanged(Uuid, String),
+    InitiativeRolled(Uuid),
+    MissingInitiativeRolled,
+    SortedByInitiative,
+    GroupInitiativeToggled,
+    CombatStarted,
+    InitiativeEntryConfirmed,
+    InitiativeEntryCancelled,
+    CombatEnded,
+    NextTurn,
+    PreviousTurn,
+    Delayed(Uuid),
+    MovedUp(Uuid),
+    MovedDown(Uuid),
+    Removed(Uuid),
+    TargetToggled(Uuid),
+    ReactionToggled(Uuid),
+    LegendaryActionSpent(Uuid),
+    LegendaryActionRegained(Uuid),
+    LegendaryResistanceSpent(Uuid),
+    LegendaryResistanceRegained(Uuid),
+    TargetsCleared,
+    AmountChanged(String),
+    DamageTypeSelected(DamageType),
+    DamageTypeCleared,
+    DamageSourceSelected(Named),
+    DamageSourceCleared,
+    DamageApplied,
+    HealingApplied,
+    TemporaryHitPointsGranted,
+    ConditionSelected(Condition),
+    CustomConditionChanged(String),
+    DurationSelected(DurationKind),
+    RoundsChanged(String),
+    TurnOfSelected(Named),
+    SaveAbilitySelected(Ability),
+    SaveCleared,
+    SaveDifficultyChanged(String),
+    ConditionApplied,
+    ConditionRemoved(Uuid, Uuid),
+    ConcentrationSelected(Named),
+    ConcentrationCleared,
+    ConcentrationEnded(Uuid),
+    ConcentrationKept(usize),
+    ConcentrationLost(usize),
+    CasterSelected(Named),
+    SpellSelected(Named),
+    SpellCastRequested,
+    SpellLoaded(Uuid, Result<Box<Spell>, Error>),
+    EffectEnded(Uuid),
+    RemindersDismissed,
+    SummaryCopied,
+    CombatSaved(Result<(), Error>),
+    FightRecorded(Result<(), Error>),
+    OpenCreature(Uuid),
+    CreaturesLoaded(Result<Box<[CreatureSummary]>, Error>),
+    CreatureLoaded(Result<Box<Creature>, Error>),
+    EncounterLoaded(Result<Box<EncounterRecord>, Error>),
+    EncounterCreatureLoaded(u32, Result<Box<Creature>, Error>),
+    SpellsLoaded(Result<Box<[SpellSummary]>, Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    OpenView(Request),
+
+    /// A fight was run to its end and recorded in the chronicle.
+    Fought,
+}
@@ -0,0 +1,2078 @@
+pub mod message;
+
+use crate::context::Context;
+use crate::view::Viewable;
+use crate::view::combat::tracker::message::Effect;
+use crate::view::combat::tracker::message::Message;
+use crate::view::request::Request;
+use components::icon::IconName;
+use components::icon::IconSize;
+use components::number_field::NumberFieldState;
+use components::select_field::SelectFieldState;
+use components::text_field::TextFieldState;
+use storage::Error;
+use storage::models::activity::NewActivity;
+use storage::models::combat::NewCombat;
+use storage::records::activity::ActivityKind;
+use storage::records::activity::EntityKind;
+use storage::records::combat::CombatRecord;
+use storage::records::creature::CreatureSummary;
+use storage::records::encounter::EncounterRecord;
+use storage::records::spell::SpellSummary;
+use storage::repositories::activities::ActivitiesRepository;
+use storage::repositories::combats::CombatsRepository;
+use storage::repositories::creatures::CreaturesRepository;
+use storage::repositories::encounters::EncountersRepository;
+use storage::repositories::spells::SpellsRepository;
+use style::button::ButtonClass;
+use style::container::ContainerClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::SECTION_SPACING;
+use style::svg::SvgClass;
+use style::text::TextClass;
+use types::Ability;
+use types::AppliedCondition;
+use types::Choice;
+use types::CombatEvent;
+use types::CombatLog;
+use types::Combatant;
+use types::CombatantKind;
+use types::Condition;
+use types::ConditionDuration;
+use types::Creature;
+use types::DamageType;
+use types::Duration;
+use types::Encounter;
+use types::Health;
+use types::Random;
+use types::SavingThrow;
+use types::Spell;
+use types::TurnEnd;
+use types::Uses;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length::Fill;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use strum::VariantArray;
+use uuid::Uuid;
+
+/// The width of the initiative field of each combatant.
+const INITIATIVE_WIDTH: u32 = 80;
+
+/// The width of the sidebar showing the log of the fight.
+const LOG_WIDTH: u32 = 320;
+
+/// Runs an encounter, keeping the combatants in initiative order and counting turns and rounds.
+pub struct CombatTracker {
+    context: Context,
+    encounter: Encounter,
+
+    /// The id the fight is persisted under, which is kept when resuming it.
+    combat_id: Uuid,
+
+    /// The encounter as it was last persisted, `None` if it is not.
+    persisted: Option<Encounter>,
+
+    /// The creatures of the bestiary that can be added.
+    creatures: SelectFieldState<Named>,
+
+    character_name: TextFieldState,
+    character_bonus: NumberFieldState,
+    character_dexterity: NumberFieldState,
+    character_hit_points: NumberFieldState,
+
+    /// Whether creatures of the same stat block roll initiative once, as a group.
+    group_initiative: bool,
+
+    /// Whether combat waits on the initiatives of the characters to be entered.
+    initiative_entry: bool,
+
+    /// Rolls initiative and flips the coins breaking ties.
+    random: Random,
+
+    /// The initiative fields of the combatants, by combatant id.
+    initiatives: HashMap<Uuid, NumberFieldState>,
+
+    /// The combatants that damage, healing and temporary hit points are applied to.
+    targets: HashSet<Uuid>,
+
+    /// The damage, healing or temporary hit points to apply to the targets.
+    amount: NumberFieldState,
+
+    /// The type of the damage to apply, which is untyped if none is selected.
+    damage_type: SelectFieldState<DamageType>,
+
+    /// The combatant dealing the damage, which is the one whose turn it is if none is selected.
+    damage_source: SelectFieldState<Named>,
+
+    /// The standard condition to apply to the targets.
+    condition: SelectFieldState<Condition>,
+
+    /// The name of a custom condition, applied instead of the standard one if entered.
+    custom_condition: TextFieldState,
+
+    duration: SelectFieldState<DurationKind>,
+    rounds: NumberFieldState,
+
+    /// The combatant whose next turn ends the condition.
+    turn_of: SelectFieldState<Named>,
+
+    /// The concentration that sustains the condition to apply, if it is linked to one.
+    concentration: SelectFieldState<Named>,
+
+    /// The ability of the saving throw repeated at the end of each turn, if there is one.
+    save_ability: SelectFieldState<Ability>,
+    save_difficulty: NumberFieldState,
+
+    /// The spells of the library that can be cast.
+    spells: SelectFieldState<Named>,
+
+    caster: SelectFieldState<Named>,
+
+    /// The concentration saving throws still to be resolved, in the order the damage was taken.
+    concentration_checks: Vec<ConcentrationCheck>,
+
+    /// The conditions that expired, the saving throws due when the last turn ended, and the
+    /// spells cast since.
+    reminders: Vec<String>,
+}
+
+/// Something picked by its name in a select field, like a creature of the bestiary to add, or
+/// a combatant to time a condition by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Named {
+    id: Uuid,
+    name: String,
+}
+
+/// A concentration saving throw that a combatant makes after taking damage.
+struct ConcentrationCheck {
+    combatant: Uuid,
+    saving_throw: SavingThrow,
+}
+
+impl Display for Named {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// How the duration of a condition to apply is entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, VariantArray)]
+pub enum DurationKind {
+    Indefinite,
+    Rounds,
+    UntilEndOfTurn,
+}
+
+impl Display for DurationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurationKind::Indefinite => f.write_str("Until removed"),
+            DurationKind::Rounds => f.write_str("Rounds"),
+            DurationKind::UntilEndOfTurn => f.write_str("Until end of next turn"),
+        }
+    }
+}
+
+impl<'a> CombatTracker {
+    pub fn new(context: Context) -> (Self, Task<Message>) {
+        let mut combat_tracker = Self {
+            context,
+            encounter: Encounter::new(),
+            combat_id: Uuid::new_v4(),
+            persisted: None,
+            creatures: SelectFieldState::new([], None),
+            character_name: TextFieldState::default().required(true),
+            character_bonus: NumberFieldState::new(Some(0)).required(true),
+            character_dexterity: NumberFieldState::new(Some(10))
+                .required(true)
+                .non_negative(true),
+            character_hit_points: NumberFieldState::default()
+                .required(true)
+                .non_negative(true),
+            group_initiative: false,
+            initiative_entry: false,
+            random: Random::new(Random::seed()),
+            initiatives: HashMap::new(),
+            targets: HashSet::new(),
+            amount: NumberFieldState::default().non_negative(true),
+            damage_type: SelectFieldState::new(DamageType::VARIANTS.iter().copied(), None),
+            damage_source: SelectFieldState::new([], None),
+            condition: SelectFieldState::new(Condition::VARIANTS.iter().copied(), None),
+            custom_condition: TextFieldState::default(),
+            duration: SelectFieldState::new(
+                DurationKind::VARIANTS.iter().copied(),
+                Some(DurationKind::Indefinite),
+            )
+            .required(true),
+            rounds: NumberFieldState::new(Some(1))
+                .required(true)
+                .non_negative(true),
+            turn_of: SelectFieldState::new([], None).required(true),
+            save_ability: SelectFieldState::new(Ability::VARIANTS.iter().copied(), None),
+            save_difficulty: NumberFieldState::default()
+                .required(true)
+                .non_negative(true),
+            concentration: SelectFieldState::new([], None),
+            spells: SelectFieldState::new([], None),
+            caster: SelectFieldState::new([], None).required(true),
+            concentration_checks: Vec::new(),
+            reminders: Vec::new(),
+        };
+
+        let task = Task::batch([
+            combat_tracker.reload_creatures(),
+            combat_tracker.reload_spells(),
+        ]);
+
+        (combat_tracker, task)
+    }
+
+    /// Reload the creatures that can be added, leaving the encounter as it is.
+    pub fn reload_creatures(&mut self) -> Task<Message> {
+        Task::perform(
+            load_creatures(self.context.clone()),
+            Message::CreaturesLoaded,
+        )
+    }
+
+    /// Bring the creatures of the saved encounter with `id` into the fight.
+    pub fn launch(&mut self, id: Uuid) -> Task<Message> {
+        Task::perform(
+            load_encounter(self.context.clone(), id),
+            Message::EncounterLoaded,
+        )
+    }
+
+    /// Pick up the fight of `combat` where it was left, in place of the current one.
+    pub fn resume(&mut self, combat: CombatRecord) -> Task<Message> {
+        let encounter = match Encounter::try_from(&combat.data) {
+            Ok(encounter) => encounter,
+            Err(err) => {
+                tracing::error!("failed to resume combat '{}': {err:?}", combat.id);
+                return Task::none();
+            }
+        };
+
+        // A fight that was just ended may not have been cleared from storage yet.
+        let task = match self.persisted.take() {
+            Some(_) => Task::future(delete_combat(self.context.clone(), self.combat_id)).discard(),
+            None => Task::none(),
+        };
+
+        self.initiatives = encounter
+            .combatants()
+            .iter()
+            .map(|combatant| (combatant.id, NumberFieldState::new(combatant.initiative)))
+            .collect();
+        self.combat_id = combat.id;
+        self.persisted = Some(encounter.clone());
+        self.encounter = encounter;
+        self.initiative_entry = false;
+        self.targets.clear();
+        self.concentration_checks.clear();
+        self.reminders.clear();
+
+        self.sync_combatant_choices();
+
+        task
+    }
+
+    /// Persist the fight if it changed since it was last persisted, or clear it from storage
+    /// once it is over.
+    pub fn autosave(&mut self) -> Task<Message> {
+        if !self.encounter.has_started() {
+            return match self.persisted.take() {
+                Some(_) => {
+                    Task::future(delete_combat(self.context.clone(), self.combat_id)).discard()
+                }
+                None => Task::none(),
+            };
+        }
+
+        if self.persisted.as_ref() == Some(&self.encounter) {
+            return Task::none();
+        }
+
+        let combat = NewCombat {
+            id: self.combat_id,
+            data: (&self.encounter).into(),
+        };
+        self.persisted = Some(self.encounter.clone());
+
+        Task::perform(
+            save_combat(self.context.clone(), combat),
+            Message::CombatSaved,
+        )
+    }
+
+    /// Whether a fight is under way, which resuming another would replace.
+    pub fn has_started(&self) -> bool {
+        self.encounter.has_started()
+    }
+
+    /// The encounter being run, as shown in the player window.
+    pub fn encounter(&self) -> &Encounter {
+        &self.encounter
+    }
+
+    /// Reload the spells that can be cast, leaving the encounter as it is.
+    pub fn reload_spells(&mut self) -> Task<Message> {
+        Task::perform(load_spells(self.context.clone()), Message::SpellsLoaded)
+    }
+
+    /// Add `combatant` to the encounter, with a field to enter its initiative.
+    fn add_combatant(&mut self, combatant: Combatant) {
+        self.initiatives
+            .insert(combatant.id, NumberFieldState::new(combatant.initiative));
+        self.encounter.add(combatant);
+
+        self.sync_combatant_choices();
+    }
+
+    /// Offer the current combatants and their concentrations in the select fields, after
+    /// either changed.
+    fn sync_combatant_choices(&mut self) {
+        let combatants = self.encounter.combatants();
+
+        let named = |combatant: &Combatant| Named {
+            id: combatant.id,
+            name: combatant.name.clone(),
+        };
+        let concentrations = combatants.iter().filter_map(|combatant| {
+            let concentration = combatant.concentration.as_ref()?;
+
+            Some(Named {
+                id: concentration.id,
+                name: format!("{} · {}", combatant.name, concentration.spell),
+            })
+        });
+
+        // The selections are kept as long as what they refer to remains.
+        let keep = |selected: Option<&Named>, ids: &[Uuid]| {
+            selected.filter(|named| ids.contains(&named.id)).cloned()
+        };
+        let combatant_ids: Vec<_> = combatants.iter().map(|combatant| combatant.id).collect();
+        let concentration_ids: Vec<_> = concentrations.clone().map(|named| named.id).collect();
+
+        self.turn_of = SelectFieldState::new(
+            combatants.iter().map(named),
+            keep(self.turn_of.selected(), &combatant_ids),
+        )
+        .required(true);
+        self.caster = SelectFieldState::new(
+            combatants.iter().map(named),
+            keep(self.caster.selected(), &combatant_ids),
+        )
+        .required(true);
+        self.damage_source = SelectFieldState::new(
+            combatants.iter().map(named),
+            keep(self.damage_source.selected(), &combatant_ids),
+        );
+        self.concentration = SelectFieldState::new(
+            concentrations,
+            keep(self.concentration.selected(), &concentration_ids),
+        );
+
+        // Checks are moot once the concentration they would keep is gone.
+        self.concentration_checks.retain(|check| {
+            combatants.iter().any(|combatant| {
+                combatant.id == check.combatant && combatant.concentration.is_some()
+            })
+        });
+    }
+
+    /// The condition entered to apply, or `None` with the errors shown if it is incomplete.
+    fn entered_condition(&mut self) -> Option<AppliedCondition> {
+        let custom = self.custom_condition.value().trim();
+
+        let condition = if custom.is_empty() {
+            Choice::BuiltIn(*self.condition.selected()?)
+        } else {
+            Choice::parse(custom)
+        };
+
+        let duration = match self.duration.try_value()? {
+            DurationKind::Indefinite => ConditionDuration::Indefinite,
+            DurationKind::Rounds => {
+                let rounds = self.rounds.try_value()?.unsigned_abs();
+                ConditionDuration::Rounds(rounds.max(1))
+            }
+            DurationKind::UntilEndOfTurn => {
+                ConditionDuration::UntilEndOfTurn(self.turn_of.try_value()?.id)
+            }
+        };
+
+        let save = match self.save_ability.selected().copied() {
+            Some(ability) => Some(SavingThrow {
+                ability,
+                difficulty_class: self.save_difficulty.try_value()?.unsigned_abs(),
+            }),
+            None => None,
+        };
+
+        let applied = AppliedCondition::new(condition, duration, save);
+
+        match self.concentration.selected() {
+            Some(concentration) => Some(applied.with_concentration(concentration.id)),
+            None => Some(applied),
+        }
+    }
+
+    /// The name of the combatant with `id`, which is empty if it is gone.
+    fn combatant_name(&self, id: Uuid) -> String {
+        self.encounter
+            .combatants()
+            .iter()
+            .find(|combatant| combatant.id == id)
+            .map_or_else(String::new, |combatant| combatant.name.clone())
+    }
+
+    /// Have the combatant with `caster` cast `spell` on the targets, starting its timer.
+    fn cast(&mut self, caster: Uuid, spell: Spell) {
+        let name = self.combatant_name(caster);
+        let targets: Vec<_> = self.targets.iter().copied().collect();
+
+        let reminder = match &spell.duration {
+            Choice::BuiltIn(Duration::Instantaneous) => format!("{name} casts {}.", spell.name),
+            duration => format!("{name} casts {}, lasting {duration}.", spell.name),
+        };
+        self.reminders.push(reminder);
+
+        if let Some(broken) = self.encounter.cast(caster, &spell, targets) {
+            self.reminders
+                .push(format!("{name} stops concentrating on {}.", broken.spell));
+        }
+
+        self.sync_combatant_choices();
+    }
+
+    /// Remind of what happened to the conditions at the end of the last turn.
+    fn remind(&mut self, turn_end: TurnEnd) {
+        let name = |id: Uuid| self.combatant_name(id);
+
+        let expired = turn_end
+            .expired
+            .iter()
+            .map(|(id, applied)| format!("{} is no longer {}.", name(*id), applied.condition));
+
+        let saves = turn_end.saves.iter().filter_map(|applied| {
+            let save = applied.save?;
+
+            Some(format!(
+                "{} makes a {save} saving throw to end {}.",
+                name(turn_end.combatant),
+                applied.condition
+            ))
+        });
+
+        let ended_effects = turn_end
+            .ended_effects
+            .iter()
+            .map(|effect| format!("{} cast by {} ends.", effect.spell, name(effect.caster)));
+
+        self.reminders = ended_effects.chain(expired).chain(saves).collect();
+    }
+
+    /// Add `creature`, numbering it if the same creature is already fighting.
+    fn add_creature(&mut self, creature: Creature) {
+        let initiative_bonus = creature.initiative_modifier();
+        let hit_points = u32::try_from(creature.hit_points()).unwrap_or(1);
+
+        let count = self
+            .encounter
+            .combatants()
+            .iter()
+            .filter(|combatant| combatant.kind == CombatantKind::Creature(creature.id))
+            .count();
+
+        let has_lair = !creature.lair_actions.is_empty()
+            && !self
+                .encounter
+                .combatants()
+                .iter()
+                .any(|combatant| combatant.kind == CombatantKind::Lair(creature.id));
+        let lair_name = format!("{} Lair", creature.name);
+        let legendary_actions = creature.legendary_action_uses();
+        let legendary_resistances = creature.legendary_resistances();
+
+        let name = match count {
+            0 => creature.name,
+            count => format!("{} {}", creature.name, count + 1),
+        };
+
+        let mut combatant = Combatant::new(
+            name,
+            CombatantKind::Creature(creature.id),
+            initiative_bonus,
+            hit_points,
+        );
+        combatant.damage_defenses = creature.damage_defenses;
+        combatant.dexterity = creature.abilities[Ability::Dexterity];
+        combatant.experience = creature.challenge_rating.experience();
+        combatant.legendary_actions = (legendary_actions > 0).then(|| Uses::new(legendary_actions));
+        combatant.legendary_resistances =
+            (legendary_resistances > 0).then(|| Uses::new(legendary_resistances));
+
+        self.add_combatant(combatant);
+
+        // Creatures sharing a stat block share a single lair slot.
+        if has_lair {
+            self.add_combatant(Combatant::lair(lair_name, creature.id));
+        }
+    }
+
+    /// The id of the lair of the creature with `creature_id`, if none of its creatures are
+    /// left fighting.
+    fn lair_without_creatures(&self, creature_id: Uuid) -> Option<Uuid> {
+        let combatants = self.encounter.combatants();
+
+        if combatants
+            .iter()
+            .any(|combatant| combatant.kind == CombatantKind::Creature(creature_id))
+        {
+            return None;
+        }
+
+        combatants
+            .iter()
+            .find(|combatant| combatant.kind == CombatantKind::Lair(creature_id))
+            .map(|combatant| combatant.id)
+    }
+
+    fn legendary_actions_mut(&mut self, id: Uuid) -> Option<&mut Uses> {
+        self.encounter.combatant_mut(id)?.legendary_actions.as_mut()
+    }
+
+    fn legendary_resistances_mut(&mut self, id: Uuid) -> Option<&mut Uses> {
+        self.encounter
+            .combatant_mut(id)?
+            .legendary_resistances
+            .as_mut()
+    }
+
+    /// Roll the initiative of the combatant with `id`, moving it to its place in the order.
+    fn roll_initiative(&mut self, id: Uuid) {
+        let Some(combatant) = self.encounter.combatant_mut(id) else {
+            return;
+        };
+
+        let initiative = self.random.between(1..=20) as i32 + combatant.initiative_bonus;
+        self.encounter.set_initiative(id, Some(initiative));
+
+        self.sync_initiative_field(id);
+    }
+
+    /// Roll initiative for the creatures and start combat, unless the initiatives of some
+    /// characters are still to be entered.
+    fn start_combat(&mut self) {
+        self.encounter
+            .roll_initiative(&mut self.random, self.group_initiative);
+
+        let ids: Vec<_> = self
+            .encounter
+            .combatants()
+            .iter()
+            .map(|combatant| combatant.id)
+            .collect();
+
+        for id in ids {
+            self.sync_initiative_field(id);
+        }
+
+        self.initiative_entry = self.encounter.combatants().iter().any(|combatant| {
+            combatant.kind == CombatantKind::Character && combatant.initiative.is_none()
+        });
+
+        if !self.initiative_entry {
+            self.encounter.start();
+        }
+    }
+
+    /// Apply `apply` with the entered amount to every target.
+    fn apply_to_targets(&mut self, apply: impl Fn(&mut Combatant, u32)) {
+        let Some(amount) = self.amount.try_value() else {
+            return;
+        };
+        let amount = amount.unsigned_abs();
+
+        for id in &self.targets {
+            if let Some(combatant) = self.encounter.combatant_mut(*id) {
+                apply(combatant, amount);
+            }
+        }
+    }
+
+    /// Show the initiative of the combatant with `id` in its field, after it changed otherwise.
+    fn sync_initiative_field(&mut self, id: Uuid) {
+        let Some(combatant) = self.encounter.combatant_mut(id) else {
+            return;
+        };
+
+        if let Some(field) = self.initiatives.get_mut(&id) {
+            let initiative = combatant
+                .initiative
+                .map(|initiative| initiative.to_string());
+            field.set(initiative.unwrap_or_default());
+        }
+    }
+
+    fn heading(&self) -> Element<'a, Message> {
+        let title = components::text::view_title("Combat");
+
+        let sub_title = match self.encounter.active() {
+            Some(active) => components::text::view_sub_title(format!(
+                "Round {} · {}'s turn",
+                self.encounter.round(),
+                active.name
+            )),
+            None => components::text::view_sub_title("Combat has not started"),
+        };
+
+        column![title, sub_title]
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    fn add_section(&'a self) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "COMBATANTS",
+            "The creatures of the bestiary and the characters joining the fight.",
+        );
+
+        let creature =
+            components::select_field("CREATURE", &self.creatures, Message::CreatureSelected)
+                .placeholder("Select a creature");
+        let add_creature = widget::button(components::text::display("Add Creature"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                self.creatures
+                    .selected()
+                    .is_some()
+                    .then_some(Message::CreatureAddRequested),
+            );
+
+        let character_name = components::text_field(Some("CHARACTER"), &self.character_name)
+            .placeholder("Name")
+            .on_input(Message::CharacterNameChanged);
+        let character_bonus =
+            components::number_field(Some("INITIATIVE BONUS"), &self.character_bonus)
+                .placeholder("0")
+                .on_input(Message::CharacterBonusChanged);
+        let character_dexterity =
+            components::number_field(Some("DEXTERITY"), &self.character_dexterity)
+                .placeholder("10")
+                .on_input(Message::CharacterDexterityChanged);
+        let character_hit_points =
+            components::number_field(Some("HIT POINTS"), &self.character_hit_points)
+                .placeholder("10")
+                .on_input(Message::CharacterHitPointsChanged);
+        let add_character = widget::button(components::text::display("Add Character"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press(Message::CharacterAdded);
+
+        let form = column![
+            row![creature, add_creature]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End),
+            row![
+                character_name,
+                character_bonus,
+                character_dexterity,
+                character_hit_points,
+                add_character
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::End),
+        ]
+        .spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn hit_points_section(&'a self) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "HIT POINTS",
+            "Deal damage, heal or grant temporary hit points to every target at once.",
+        );
+
+        let amount = components::number_field(Some("AMOUNT"), &self.amount)
+            .placeholder("0")
+            .on_input(Message::AmountChanged);
+        let damage_type = components::select_field(
+            "DAMAGE TYPE",
+            &self.damage_type,
+            Message::DamageTypeSelected,
+        )
+        .placeholder("Untyped");
+        let untyped = widget::button(components::text::display("Untyped"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                self.damage_type
+                    .selected()
+                    .is_some()
+                    .then_some(Message::DamageTypeCleared),
+            );
+
+        let damage_source = components::select_field(
+            "DEALT BY",
+            &self.damage_source,
+            Message::DamageSourceSelected,
+        )
+        .placeholder("Whose turn it is");
+        let active = widget::button(components::text::display("Whose Turn"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                self.damage_source
+                    .selected()
+                    .is_some()
+                    .then_some(Message::DamageSourceCleared),
+            );
+
+        let can_apply = !self.targets.is_empty() && !self.amount.raw_value().is_empty();
+
+        let button = |label: &'a str, class: ButtonClass, message: Message| {
+            widget::button(components::text::display(label))
+                .class(class)
+                .padding(INPUT_PADDING)
+                .on_press_maybe(can_apply.then_some(message))
+        };
+
+        let actions = row![
+            button("Damage", ButtonClass::Danger, Message::DamageApplied),
+            button("Heal", ButtonClass::Success, Message::HealingApplied),
+            button(
+                "Temporary HP",
+                ButtonClass::Interaction,
+                Message::TemporaryHitPointsGranted
+            ),
+            widget::space::horizontal().width(Fill),
+            widget::button(components::text::display("Clear Targets"))
+                .class(ButtonClass::Ghost)
+                .padding(INPUT_PADDING)
+                .on_press_maybe((!self.targets.is_empty()).then_some(Message::TargetsCleared)),
+        ]
+        .spacing(BODY_SPACING);
+
+        let targets = match self.targets.len() {
+            0 => String::from("Select the targets among the combatants."),
+            1 => String::from("1 target selected."),
+            count => format!("{count} targets selected."),
+        };
+
+        let form = column![
+            row![amount, damage_type, untyped]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End),
+            row![damage_source, active]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End),
+            components::text::detail(targets),
+            actions,
+        ]
+        .spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn conditions_section(&'a self) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "CONDITIONS",
+            "Apply standard or custom conditions to every target, with when they end.",
+        );
+
+        let condition =
+            components::select_field("CONDITION", &self.condition, Message::ConditionSelected)
+                .placeholder("Select a condition");
+        let custom_condition = components::text_field(Some("CUSTOM"), &self.custom_condition)
+            .placeholder("Hexed")
+            .on_input(Message::CustomConditionChanged);
+
+        let duration =
+            components::select_field("DURATION", &self.duration, Message::DurationSelected);
+        let timing: Element<_> = match self.duration.selected() {
+            Some(DurationKind::Rounds) => components::number_field(Some("ROUNDS"), &self.rounds)
+                .placeholder("1")
+                .on_input(Message::RoundsChanged)
+                .into(),
+            Some(DurationKind::UntilEndOfTurn) => {
+                components::select_field("TURN OF", &self.turn_of, Message::TurnOfSelected)
+                    .placeholder("Select a combatant")
+                    .into()
+            }
+            _ => widget::space::horizontal().width(Fill).into(),
+        };
+
+        let concentration = components::select_field(
+            "CONCENTRATION",
+            &self.concentration,
+            Message::ConcentrationSelected,
+        )
+        .placeholder("Not linked");
+        let unlinked = widget::button(components::text::display("Unlink"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                self.concentration
+                    .selected()
+                    .is_some()
+                    .then_some(Message::ConcentrationCleared),
+            );
+
+        let save_ability =
+            components::select_field("SAVE", &self.save_ability, Message::SaveAbilitySelected)
+                .placeholder("No save");
+        let save_difficulty = components::number_field(Some("DC"), &self.save_difficulty)
+            .placeholder("13")
+            .on_input(Message::SaveDifficultyChanged);
+        let no_save = widget::button(components::text::display("No Save"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                self.save_ability
+                    .selected()
+                    .is_some()
+                    .then_some(Message::SaveCleared),
+            );
+
+        let has_condition =
+            self.condition.selected().is_some() || !self.custom_condition.value().trim().is_empty();
+        let apply = widget::button(components::text::display("Apply Condition"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                (has_condition && !self.targets.is_empty()).then_some(Message::ConditionApplied),
+            );
+
+        let form = column![
+            row![condition, custom_condition]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End),
+            row![duration, timing]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End),
+            row![save_ability, save_difficulty, no_save]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End),
+            row![concentration, unlinked]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End),
+            apply,
+        ]
+        .spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn spells_section(&'a self) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "SPELLS",
+            "Cast spells on the targets, timing their effects and who concentrates on them.",
+        );
+
+        let caster = components::select_field("CASTER", &self.caster, Message::CasterSelected)
+            .placeholder("Select a combatant");
+        let spell = components::select_field("SPELL", &self.spells, Message::SpellSelected)
+            .placeholder("Select a spell");
+        let cast = widget::button(components::text::display("Cast"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                (self.caster.selected().is_some() && self.spells.selected().is_some())
+                    .then_some(Message::SpellCastRequested),
+            );
+
+        let form = row![caster, spell, cast]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::End);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    /// The initiatives of the characters to enter before combat starts, as their players roll
+    /// them at the table.
+    fn initiative_entry(&'a self) -> Option<Element<'a, Message>> {
+        if !self.initiative_entry {
+            return None;
+        }
+
+        let prompt = components::text::body(
+            "The creatures rolled initiative, enter the initiative of each character.",
+        );
+
+        let mut characters = Column::new().spacing(BODY_SPACING);
+
+        for combatant in self
+            .encounter
+            .combatants()
+            .iter()
+            .filter(|combatant| combatant.kind == CombatantKind::Character)
+        {
+            let id = combatant.id;
+
+            let Some(field) = self.initiatives.get(&id) else {
+                continue;
+            };
+
+            let name = components::text::display(&combatant.name);
+            let initiative = components::number_field(None, field)
+                .placeholder("-")
+                .width(INITIATIVE_WIDTH)
+                .on_input(move |initiative| Message::InitiativeChanged(id, initiative));
+
+            let character = row![name, widget::space::horizontal().width(Fill), initiative]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::Center);
+
+            characters = characters.push(character);
+        }
+
+        let cancel = widget::button(components::text::display("Cancel"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press(Message::InitiativeEntryCancelled);
+        let begin = widget::button(components::text::display("Begin"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press(Message::InitiativeEntryConfirmed);
+
+        let actions =
+            row![widget::space::horizontal().width(Fill), cancel, begin].spacing(BODY_SPACING);
+
+        let content = column![prompt, characters, actions].spacing(BODY_SPACING);
+
+        let container = widget::container(content)
+            .class(ContainerClass::Info)
+            .padding(INPUT_PADDING)
+            .width(Fill);
+
+        Some(container.into())
+    }
+
+    /// The concentration saving throws to resolve, each kept or lost by hand.
+    fn concentration_checks(&'a self) -> Option<Element<'a, Message>> {
+        if self.concentration_checks.is_empty() {
+            return None;
+        }
+
+        let mut checks =
+            Column::with_capacity(self.concentration_checks.len()).spacing(BODY_SPACING);
+
+        for (index, check) in self.concentration_checks.iter().enumerate() {
+            let Some(combatant) = self
+                .encounter
+                .combatants()
+                .iter()
+                .find(|combatant| combatant.id == check.combatant)
+            else {
+                continue;
+            };
+            let Some(concentration) = &combatant.concentration else {
+                continue;
+            };
+
+            let prompt = components::text::body(format!(
+                "{} makes a {} saving throw to keep concentrating on {}.",
+                combatant.name, check.saving_throw, concentration.spell
+            ));
+
+            let kept = widget::button(components::text::display("Kept"))
+                .class(ButtonClass::Success)
+                .padding(INPUT_PADDING)
+                .on_press(Message::ConcentrationKept(index));
+            let lost = widget::button(components::text::display("Lost"))
+                .class(ButtonClass::Danger)
+                .padding(INPUT_PADDING)
+                .on_press(Message::ConcentrationLost(index));
+
+            let check = row![prompt, widget::space::horizontal().width(Fill), kept, lost]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::Center);
+
+            checks = checks.push(check);
+        }
+
+        let container = widget::container(checks)
+            .class(ContainerClass::Info)
+            .padding(INPUT_PADDING)
+            .width(Fill);
+
+        Some(container.into())
+    }
+
+    /// The conditions that expired and the saving throws due when the last turn ended.
+    fn reminders(&'a self) -> Option<Element<'a, Message>> {
+        if self.reminders.is_empty() {
+            return None;
+        }
+
+        let mut reminders = Column::with_capacity(self.reminders.len()).spacing(BODY_SPACING);
+
+        for reminder in &self.reminders {
+            reminders = reminders.push(components::text::body(reminder));
+        }
+
+        let dismiss = widget::button(components::text::display("Dismiss"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press(Message::RemindersDismissed);
+
+        let content = row![reminders, widget::space::horizontal().width(Fill), dismiss]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+        let container = widget::container(content)
+            .class(ContainerClass::Info)
+            .padding(INPUT_PADDING)
+            .width(Fill);
+
+        Some(container.into())
+    }
+
+    /// The outcome of the fight that just ended, with a way to take it into the session notes.
+    fn summary(&self) -> Option<Element<'a, Message>> {
+        let log = self.encounter.log();
+
+        if self.encounter.has_started() || log.is_empty() {
+            return None;
+        }
+
+        let summary = log.summary();
+
+        let heading = components::text::heading("Summary");
+        let overview = components::text::body(summary_overview(
+            summary.rounds,
+            summary.experience,
+            summary.experience_per_character,
+        ));
+
+        let copy = widget::button(components::text::display("Copy as Markdown"))
+            .class(ButtonClass::Outlined)
+            .padding(INPUT_PADDING)
+            .on_press(Message::SummaryCopied);
+
+        let header = row![
+            column![heading, overview].spacing(BODY_SPACING),
+            widget::space::horizontal().width(Fill),
+            copy
+        ]
+        .align_y(Alignment::Center);
+
+        let cell = |text: String| components::text::detail(text).width(Fill);
+
+        let mut table = column![row![
+            cell(String::from("Combatant")),
+            cell(String::from("Damage dealt")),
+            cell(String::from("Damage taken")),
+            cell(String::from("Healing received")),
+        ]]
+        .spacing(BODY_SPACING);
+
+        for participant in summary.participants {
+            table = table.push(row![
+                components::text::body(participant.name).width(Fill),
+                components::text::body(participant.damage_dealt.to_string()).width(Fill),
+                components::text::body(participant.damage_taken.to_string()).width(Fill),
+                components::text::body(participant.healing_received.to_string()).width(Fill),
+            ]);
+        }
+
+        let container = widget::container(column![header, table].spacing(SECTION_SPACING))
+            .class(ContainerClass::Surface)
+            .padding(INPUT_PADDING)
+            .width(Fill);
+
+        Some(container.into())
+    }
+
+    /// The events of the fight, the latest first.
+    fn log(&self) -> Element<'a, Message> {
+        let log = self.encounter.log();
+
+        let mut entries = Column::with_capacity(log.entries.len() + 1).spacing(BODY_SPACING);
+        entries = entries.push(components::text::heading("Log"));
+
+        if log.is_empty() {
+            entries = entries.push(components::text::detail("Nothing happened yet."));
+        }
+
+        for entry in log.entries.iter().rev() {
+            let entry = row![
+                components::text::detail(format!("R{}", entry.round)),
+                components::text::body(describe(log, &entry.event)),
+            ]
+            .spacing(BODY_SPACING);
+
+            entries = entries.push(entry);
+        }
+
+        widget::container(entries)
+            .class(ContainerClass::Surface)
+            .padding(INPUT_PADDING)
+            .width(LOG_WIDTH)
+            .into()
+    }
+
+    fn controls(&self) -> Element<'a, Message> {
+        let started = self.encounter.has_started();
+        let has_combatants = !self.encounter.combatants().is_empty();
+
+        let button = |label: &'a str, class: ButtonClass, message: Option<Message>| {
+            widget::button(components::text::display(label))
+                .class(class)
+                .padding(INPUT_PADDING)
+                .on_press_maybe(message)
+        };
+
+        let toggle_combat = if started {
+            button(
+                "End Combat",
+                ButtonClass::Danger,
+                Some(Message::CombatEnded),
+            )
+        } else {
+            button(
+                "Start Combat",
+                ButtonClass::Primary,
+                (has_combatants && !self.initiative_entry).then_some(Message::CombatStarted),
+            )
+        };
+
+        let group = components::toggle("Group Initiative", self.group_initiative)
+            .on_toggle(Message::GroupInitiativeToggled);
+
+        let roll_missing = button(
+            "Roll Missing Initiative",
+            ButtonClass::Interaction,
+            has_combatants.then_some(Message::MissingInitiativeRolled),
+        );
+        let sort = button(
+            "Sort by Initiative",
+            ButtonClass::Interaction,
+            has_combatants.then_some(Message::SortedByInitiative),
+        );
+        let previous = button(
+            "Previous Turn",
+            ButtonClass::Interaction,
+            started.then_some(Message::PreviousTurn),
+        );
+        let next = button(
+            "Next Turn",
+            ButtonClass::Primary,
+            started.then_some(Message::NextTurn),
+        );
+
+        row![
+            toggle_combat,
+            roll_missing,
+            sort,
+            group,
+            widget::space::horizontal().width(Fill),
+            previous,
+            next
+        ]
+        .spacing(BODY_SPACING)
+        .into()
+    }
+
+    fn combatants(&'a self) -> Element<'a, Message> {
+        let combatants = self.encounter.combatants();
+
+        if combatants.is_empty() {
+            return components::text::detail("Add creatures and characters to start a fight.")
+                .into();
+        }
+
+        let active = self.encounter.active().map(|combatant| combatant.id);
+
+        let mut rows = Column::with_capacity(combatants.len()).spacing(BODY_SPACING);
+
+        for (index, combatant) in combatants.iter().enumerate() {
+            let id = combatant.id;
+            let is_active = active == Some(id);
+
+            let initiative: Element<_> = match self.initiatives.get(&id) {
+                Some(field) => components::number_field(None, field)
+                    .placeholder("-")
+                    .width(INITIATIVE_WIDTH)
+                    .on_input(move |initiative| Message::InitiativeChanged(id, initiative))
+                    .into(),
+                None => widget::space().width(INITIATIVE_WIDTH).into(),
+            };
+
+            let is_lair = matches!(combatant.kind, CombatantKind::Lair(_));
+
+            // A lair always acts on initiative count 20, so there is nothing to roll.
+            let roll = widget::button(components::text::display("Roll"))
+                .class(ButtonClass::Ghost)
+                .padding(INPUT_PADDING)
+                .on_press_maybe((!is_lair).then_some(Message::InitiativeRolled(id)));
+
+            let name: Element<_> = match combatant.kind {
+                CombatantKind::Creature(creature_id) => {
+                    widget::button(components::text::display(&combatant.name))
+                        .class(ButtonClass::Ghost)
+                        .padding(INPUT_PADDING)
+                        .on_press(Message::OpenCreature(creature_id))
+                        .into()
+                }
+                CombatantKind::Character | CombatantKind::Lair(_) => {
+                    components::text::display(&combatant.name).into()
+                }
+            };
+
+            let kind = components::text::detail(match combatant.kind {
+                CombatantKind::Creature(_) => format!("Creature, {:+}", combatant.initiative_bonus),
+                CombatantKind::Character => {
+                    format!("Character, {:+}", combatant.initiative_bonus)
+                }
+                CombatantKind::Lair(_) => String::from("Lair actions, initiative count 20"),
+            });
+
+            let action = |label: &'a str, class: ButtonClass, message: Option<Message>| {
+                widget::button(components::text::display(label))
+                    .class(class)
+                    .padding(INPUT_PADDING)
+                    .on_press_maybe(message)
+            };
+
+            let can_delay = is_active && index + 1 < combatants.len();
+
+            let actions = row![
+                action(
+                    "Up",
+                    ButtonClass::Ghost,
+                    (index > 0).then_some(Message::MovedUp(id))
+                ),
+                action(
+                    "Down",
+                    ButtonClass::Ghost,
+                    (index + 1 < combatants.len()).then_some(Message::MovedDown(id))
+                ),
+                action(
+                    "Delay",
+                    ButtonClass::Interaction,
+                    can_delay.then_some(Message::Delayed(id))
+                ),
+                action("Remove", ButtonClass::Danger, Some(Message::Removed(id))),
+            ]
+            .spacing(BODY_SPACING);
+
+            let target = components::toggle("Target", self.targets.contains(&id))
+                .on_toggle(Message::TargetToggled(id));
+
+            let hit_points = combatant.hit_points;
+            let mut health = match hit_points.temporary {
+                0 => format!("HP {}/{}", hit_points.current, hit_points.maximum),
+                temporary => format!(
+                    "HP {}/{} +{temporary}",
+                    hit_points.current, hit_points.maximum
+                ),
+            };
+            let class = match combatant.health() {
+                Health::Healthy => TextClass::Normal,
+                Health::Bloodied => TextClass::Warning,
+                Health::Unconscious | Health::Dead => TextClass::Danger,
+            };
+            if combatant.health() != Health::Healthy {
+                health = format!("{health} · {}", combatant.health());
+            }
+            let health: Element<_> = if is_lair {
+                widget::space().into()
+            } else {
+                components::text::display(health).class(class).into()
+            };
+
+            let content = row![
+                target,
+                initiative,
+                roll,
+                name,
+                kind,
+                widget::space::horizontal().width(Fill),
+                health,
+                actions
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+            // The combatant whose turn it is stands out from the rest.
+            let class = if is_active {
+                ContainerClass::Interaction
+            } else {
+                ContainerClass::Surface
+            };
+
+            let mut content = column![content].spacing(BODY_SPACING);
+
+            if !is_lair {
+                content = content.push(Self::bookkeeping(combatant));
+            }
+
+            let has_effects = self
+                .encounter
+                .effects()
+                .iter()
+                .any(|effect| effect.caster == id || effect.targets.contains(&id));
+
+            if combatant.concentration.is_some() || has_effects || !combatant.conditions.is_empty()
+            {
+                let concentration = combatant.concentration.as_ref().map(|concentration| {
+                    badge(
+                        format!("Concentrating on {}", concentration.spell),
+                        Message::ConcentrationEnded(id),
+                    )
+                });
+                let conditions = combatant
+                    .conditions
+                    .iter()
+                    .map(|applied| Self::condition_badge(combatants, id, applied));
+
+                let effects = self
+                    .encounter
+                    .effects()
+                    .iter()
+                    .filter(|effect| effect.caster == id || effect.targets.contains(&id))
+                    .map(|effect| {
+                        badge(
+                            format!("{} · {}", effect.spell, time_left(effect.rounds)),
+                            Message::EffectEnded(effect.id),
+                        )
+                    });
+
+                let badges = concentration.into_iter().chain(effects).chain(conditions);
+
+                content = content.push(widget::Row::with_children(badges).spacing(BODY_SPACING));
+            }
+
+            let combatant = widget::container(content)
+                .class(class)
+                .padding(INPUT_PADDING)
+                .width(Fill);
+
+            rows = rows.push(combatant);
+        }
+
+        rows.into()
+    }
+
+    /// The reaction of `combatant`, with its legendary actions and resistances if it has any.
+    fn bookkeeping(combatant: &Combatant) -> Element<'a, Message> {
+        let id = combatant.id;
+
+        let reaction = components::toggle("Reaction", !combatant.reaction_used)
+            .on_toggle(Message::ReactionToggled(id));
+
+        let legendary_actions = combatant.legendary_actions.map(|uses| {
+            counter(
+                format!("Legendary actions {}/{}", uses.remaining, uses.maximum),
+                uses,
+                Message::LegendaryActionSpent(id),
+                Message::LegendaryActionRegained(id),
+            )
+        });
+
+        let legendary_resistances = combatant.legendary_resistances.map(|uses| {
+            counter(
+                format!(
+                    "Legendary resistances {}/{} per day",
+                    uses.remaining, uses.maximum
+                ),
+                uses,
+                Message::LegendaryResistanceSpent(id),
+                Message::LegendaryResistanceRegained(id),
+            )
+        });
+
+        row![reaction]
+            .push(legendary_actions)
+            .push(legendary_resistances)
+            .spacing(SECTION_SPACING)
+            .align_y(Alignment::Center)
+            .into()
+    }
+
+    /// A condition on the combatant with `id`, with how long it lasts, removed when pressed.
+    fn condition_badge(
+        combatants: &'a [Combatant],
+        id: Uuid,
+        applied: &'a AppliedCondition,
+    ) -> Element<'a, Message> {
+        let duration = match applied.duration {
+            ConditionDuration::Indefinite => None,
+            ConditionDuration::Rounds(1) => Some(String::from("1 round")),
+            ConditionDuration::Rounds(rounds) => Some(format!("{rounds} rounds")),
+            ConditionDuration::UntilEndOfTurn(turn_of) => {
+                let name = combatants
+                    .iter()
+                    .find(|combatant| combatant.id == turn_of)
+                    .map_or("", |combatant| combatant.name.as_str());
+
+                Some(format!("until end of {name}'s turn"))
+            }
+        };
+
+        let mut label = applied.condition.to_string();
+        if let Some(duration) = duration {
+            label = format!("{label} · {duration}");
+        }
+        if let Some(save) = applied.save {
+            label = format!("{label} · {save} save");
+        }
+
+        badge(label, Message::ConditionRemoved(id, applied.id))
+    }
+}
+
+impl Viewable for CombatTracker {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        "Combat"
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::CreatureSelected(creature) => self.creatures.set(creature),
+            Message::CreatureAddRequested => {
+                let Some(creature) = self.creatures.selected() else {
+                    return (Task::none(), None);
+                };
+
+                let task = Task::perform(
+                    load_creature(self.context.clone(), creature.id),
+                    Message::CreatureLoaded,
+                );
+
+                return (task, None);
+            }
+            Message::CharacterNameChanged(name) => self.character_name.set(name),
+            Message::CharacterBonusChanged(bonus) => self.character_bonus.set(bonus),
+            Message::CharacterDexterityChanged(dexterity) => {
+                self.character_dexterity.set(dexterity)
+            }
+            Message::CharacterHitPointsChanged(hit_points) => {
+                self.character_hit_points.set(hit_points);
+            }
+            Message::CharacterAdded => {
+                let name = self.character_name.try_value();
+                let bonus = self.character_bonus.try_value();
+                let dexterity = self.character_dexterity.try_value();
+                let hit_points = self.character_hit_points.try_value();
+
+                if let (Some(name), Some(bonus), Some(dexterity), Some(hit_points)) =
+                    (name, bonus, dexterity, hit_points)
+                {
+                    let mut combatant = Combatant::new(
+                        name,
+                        CombatantKind::Character,
+                        bonus,
+                        hit_points.unsigned_abs(),
+                    );
+                    combatant.dexterity = dexterity;
+                    self.add_combatant(combatant);
+
+                    self.character_name.set(String::new());
+                }
+            }
+            Message::InitiativeChanged(id, initiative) => {
+                let value = initiative.trim().parse().ok();
+
+                if let Some(field) = self.initiatives.get_mut(&id) {
+                    field.set(initiative);
+                }
+
+                // The order is only updated when sorting, so the row does not move while typing.
+                if let Some(combatant) = self.encounter.combatant_mut(id) {
+                    combatant.initiative = value;
+                }
+            }
+            Message::InitiativeRolled(id) => self.roll_initiative(id),
+            Message::MissingInitiativeRolled => {
+                let missing: Vec<_> = self
+                    .encounter
+                    .combatants()
+                    .iter()
+                    .filter(|combatant| combatant.initiative.is_none())
+                    .map(|combatant| combatant.id)
+                    .collect();
+
+                for id in missing {
+                    self.roll_initiative(id);
+                }
+            }
+            Message::SortedByInitiative => self.encounter.sort_by_initiative(),
+            Message::GroupInitiativeToggled => self.group_initiative = !self.group_initiative,
+            Message::CombatStarted => self.start_combat(),
+            Message::InitiativeEntryConfirmed => {
+                // Characters still without an initiative simply go last.
+                self.initiative_entry = false;
+                self.encounter.start();
+            }
+            Message::InitiativeEntryCancelled => self.initiative_entry = false,
+            Message::CombatEnded => {
+                let fought = self.encounter.has_started();
+                self.encounter.end();
+
+                // Only a fight that was actually run makes it into the chronicle.
+                if fought {
+                    let activity = NewActivity {
+                        kind: ActivityKind::Fought,
+                        entity: EntityKind::Combat,
+                        entity_id: self.combat_id,
+                        name: fight_name(self.encounter.log()),
+                    };
+
+                    let task = Task::perform(
+                        record_fight(self.context.clone(), activity),
+                        Message::FightRecorded,
+                    );
+
+                    return (task, None);
+                }
+            }
+            Message::NextTurn => {
+                if let Some(turn_end) = self.encounter.next_turn() {
+                    self.remind(turn_end);
+                }
+
+                // Effects that ended may have taken the concentration of their casters along.
+                self.sync_combatant_choices();
+            }
+            Message::PreviousTurn => self.encounter.previous_turn(),
+            Message::Delayed(id) => {
+                if self.encounter.delay(id) {
+                    self.sync_initiative_field(id);
+                }
+            }
+            Message::MovedUp(id) => self.encounter.move_up(id),
+            Message::MovedDown(id) => self.encounter.move_down(id),
+            Message::Removed(id) => {
+                let kind = self
+                    .encounter
+                    .combatants()
+                    .iter()
+                    .find(|combatant| combatant.id == id)
+                    .map(|combatant| combatant.kind);
+
+                self.encounter.remove(id);
+                self.initiatives.remove(&id);
+                self.targets.remove(&id);
+
+                // The lair leaves with the last creature of its stat block.
+                let lair = match kind {
+                    Some(CombatantKind::Creature(creature_id)) => {
+                        self.lair_without_creatures(creature_id)
+                    }
+                    _ => None,
+                };
+                if let Some(lair) = lair {
+                    self.encounter.remove(lair);
+                    self.initiatives.remove(&lair);
+                    self.targets.remove(&lair);
+                }
+
+                self.sync_combatant_choices();
+            }
+            Message::ReactionToggled(id) => {
+                if let Some(combatant) = self.encounter.combatant_mut(id) {
+                    combatant.reaction_used = !combatant.reaction_used;
+                }
+            }
+            Message::LegendaryActionSpent(id) => {
+                if let Some(uses) = self.legendary_actions_mut(id) {
+                    uses.spend();
+                }
+            }
+            Message::LegendaryActionRegained(id) => {
+                if let Some(uses) = self.legendary_actions_mut(id) {
+                    uses.regain();
+                }
+            }
+            Message::LegendaryResistanceSpent(id) => {
+                if let Some(uses) = self.legendary_resistances_mut(id) {
+                    uses.spend();
+                }
+            }
+            Message::LegendaryResistanceRegained(id) => {
+                if let Some(uses) = self.legendary_resistances_mut(id) {
+                    uses.regain();
+                }
+            }
+            Message::TargetToggled(id) => {
+                if !self.targets.remove(&id) {
+                    self.targets.insert(id);
+                }
+            }
+            Message::TargetsCleared => self.targets.clear(),
+            Message::AmountChanged(amount) => self.amount.set(amount),
+            Message::DamageTypeSelected(damage_type) => self.damage_type.set(damage_type),
+            Message::DamageTypeCleared => self.damage_type.clear(),
+            Message::DamageSourceSelected(source) => self.damage_source.set(source),
+            Message::DamageSourceCleared => self.damage_source.clear(),
+            Message::DamageApplied => {
+                let damage_type = self.damage_type.selected().copied();
+                let source = match self.damage_source.selected() {
+                    Some(source) => Some(source.id),
+                    None => self.encounter.active().map(|combatant| combatant.id),
+                };
+
+                if let Some(damage) = self.amount.try_value() {
+                    for id in &self.targets {
+                        let saving_throw =
+                            self.encounter
+                                .damage(*id, source, damage.unsigned_abs(), damage_type);
+
+                        if let Some(saving_throw) = saving_throw {
+                            self.concentration_checks.push(ConcentrationCheck {
+                                combatant: *id,
+                                saving_throw,
+                            });
+                        }
+                    }
+
+                    self.sync_combatant_choices();
+                }
+            }
+            Message::HealingApplied => {
+                if let Some(amount) = self.amount.try_value() {
+                    for id in &self.targets {
+                        self.encounter.heal(*id, amount.unsigned_abs());
+                    }
+                }
+            }
+            Message::TemporaryHitPointsGranted => {
+                self.apply_to_targets(|combatant, amount| {
+                    combatant.hit_points.grant_temporary(amount);
+                });
+            }
+            Message::ConditionSelected(condition) => self.condition.set(condition),
+            Message::CustomConditionChanged(name) => self.custom_condition.set(name),
+            Message::DurationSelected(duration) => self.duration.set(duration),
+            Message::RoundsChanged(rounds) => self.rounds.set(rounds),
+            Message::TurnOfSelected(combatant) => self.turn_of.set(combatant),
+            Message::SaveAbilitySelected(ability) => self.save_ability.set(ability),
+            Message::SaveCleared => self.save_ability.clear(),
+            Message::SaveDifficultyChanged(difficulty) => self.save_difficulty.set(difficulty),
+            Message::ConditionApplied => {
+                if let Some(condition) = self.entered_condition() {
+                    for id in &self.targets {
+                        self.encounter.apply_condition(*id, condition.clone());
+                    }
+
+                    self.custom_condition.set(String::new());
+                }
+            }
+            Message::ConditionRemoved(id, condition_id) => {
+                self.encounter.remove_condition(id, condition_id);
+            }
+            Message::ConcentrationSelected(concentration) => {
+                self.concentration.set(concentration);
+            }
+            Message::ConcentrationCleared => self.concentration.clear(),
+            Message::ConcentrationEnded(id) => {
+                self.encounter.break_concentration(id);
+                self.sync_combatant_choices();
+            }
+            Message::ConcentrationKept(index) => {
+                if index < self.concentration_checks.len() {
+                    self.concentration_checks.remove(index);
+                }
+            }
+            Message::ConcentrationLost(index) => {
+                if index < self.concentration_checks.len() {
+                    let check = self.concentration_checks.remove(index);
+
+                    self.encounter.break_concentration(check.combatant);
+                    self.sync_combatant_choices();
+                }
+            }
+            Message::CasterSelected(caster) => self.caster.set(caster),
+            Message::SpellSelected(spell) => self.spells.set(spell),
+            Message::SpellCastRequested => {
+                let (Some(caster), Some(spell)) = (self.caster.try_value(), self.spells.selected())
+                else {
+                    return (Task::none(), None);
+                };
+
+                let task =
+                    Task::perform(load_spell(self.context.clone(), spell.id), move |spell| {
+                        Message::SpellLoaded(caster.id, spell)
+                    });
+
+                return (task, None);
+            }
+            Message::SpellLoaded(caster, Ok(spell)) => self.cast(caster, *spell),
+            Message::SpellLoaded(_, Err(err)) => {
+                tracing::error!("failed to load spell for combat: {err:?}");
+            }
+            Message::SpellsLoaded(Ok(spells)) => {
+                let choices = spells.iter().map(|spell| Named {
+                    id: spell.id,
+                    name: spell.name.clone(),
+                });
+
+                // The selected spell is kept, even if it was since deleted.
+                let selected = self.spells.selected().cloned();
+                self.spells = SelectFieldState::new(choices, selected);
+            }
+            Message::SpellsLoaded(Err(err)) => {
+                tracing::error!("failed to load spells for combat: {err:?}");
+            }
+            Message::EffectEnded(id) => {
+                self.encounter.end_effect(id);
+                self.sync_combatant_choices();
+            }
+            Message::RemindersDismissed => self.reminders.clear(),
+            Message::SummaryCopied => {
+                let markdown = markdown(self.encounter.log());
+
+                return (iced::clipboard::write(markdown), None);
+            }
+            Message::CombatSaved(result) => {
+                if let Err(err) = result {
+                    tracing::error!("failed to autosave combat: {err:?}");
+
+                    // Retry on the next autosave.
+                    self.persisted = None;
+                }
+            }
+            Message::FightRecorded(Ok(())) => {
+                return (Task::none(), Some(Effect::Fought));
+            }
+            Message::FightRecorded(Err(err)) => {
+                tracing::error!("failed to record fight: {err:?}");
+            }
+            Message::OpenCreature(id) => {
+                let request = Request::CreatureDetail { id };
+
+                return (Task::none(), Some(Effect::OpenView(request)));
+            }
+            Message::CreaturesLoaded(Ok(creatures)) => {
+                let choices = creatures.iter().map(|creature| Named {
+                    id: creature.id,
+                    name: creature.name.clone(),
+                });
+
+                // The selected creature is kept, even if it was since deleted.
+                let selected = self.creatures.selected().cloned();
+                self.creatures = SelectFieldState::new(choices, selected);
+            }
+            Message::CreaturesLoaded(Err(err)) => {
+                tracing::error!("failed to load creatures for combat: {err:?}");
+            }
+            Message::CreatureLoaded(Ok(creature)) => self.add_creature(*creature),
+            Message::CreatureLoaded(Err(err)) => {
+                tracing::error!("failed to load creature for combat: {err:?}");
+            }
+            Message::EncounterLoaded(Ok(encounter)) => {
+                // Only the levels of the party are saved, so its characters are added by hand.
+                self.reminders.push(format!(
+                    "{} is ready, add the {} characters of its party.",
+                    encounter.name,
+                    encounter.party.len()
+                ));
+
+                let tasks = encounter.creatures.iter().map(|creature| {
+                    let count = creature.count;
+
+                    Task::perform(
+                        load_creature(self.context.clone(), creature.creature_id),
+                        move |result| Message::EncounterCreatureLoaded(count, result),
+                    )
+                });
+
+                return (Task::batch(tasks), None);
+            }
+            Message::EncounterLoaded(Err(err)) => {
+                tracing::error!("failed to load encounter for combat: {err:?}");
+            }
+            Message::EncounterCreatureLoaded(count, Ok(creature)) => {
+                for _ in 0..count {
+                    self.add_creature(creature.as_ref().clone());
+                }
+            }
+            Message::EncounterCreatureLoaded(_, Err(err)) => {
+                tracing::error!("failed to load creature of encounter for combat: {err:?}");
+            }
+        }
+
+        (Task::none(), None)
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let heading = self.heading();
+
+        let add = self.add_section();
+
+        let hit_points = self.hit_points_section();
+
+        let conditions = self.conditions_section();
+
+        let spells = self.spells_section();
+
+        let controls = self.controls();
+
+        let combatants = self.combatants();
+
+        let tracker = column![heading, add, hit_points, conditions, spells, controls]
+            .push(self.initiative_entry())
+            .push(self.concentration_checks())
+            .push(self.reminders())
+            .push(self.summary())
+            .push(combatants)
+            .spacing(SECTION_SPACING)
+            .width(Fill);
+
+        row![tracker, self.log()].spacing(SECTION_SPACING).into()
+    }
+}
+
+/// A badge on a combatant with `label`, which sends `on_remove` when pressed.
+fn badge<'a>(label: String, on_remove: Message) -> Element<'a, Message> {
+    let icon = components::icon(IconName::Close)
+        .class(SvgClass::Normal)
+        .size(IconSize::Small);
+    let content = row![components::text::detail(label), icon]
+        .spacing(BODY_SPACING)
+        .align_y(Alignment::Center);
+
+    widget::button(content)
+        .class(ButtonClass::Outlined)
+        .padding(INPUT_PADDING)
+        .on_press(on_remove)
+        .into()
+}
+
+/// A count of `uses` under `label`, with buttons to spend and regain one.
+fn counter<'a>(label: String, uses: Uses, spend: Message, regain: Message) -> Element<'a, Message> {
+    let button = |label: &'a str, message: Option<Message>| {
+        widget::button(components::text::display(label))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(message)
+    };
+
+    row![
+        components::text::detail(label),
+        button("Spend", (uses.remaining > 0).then_some(spend)),
+        button("Regain", (uses.remaining < uses.maximum).then_some(regain)),
+    ]
+    .spacing(BODY_SPACING)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// What happened in `event`, naming those involved as they appear in `log`.
+fn describe(log: &CombatLog, event: &CombatEvent) -> String {
+    match event {
+        CombatEvent::Started => String::from("Combat started"),
+        CombatEvent::TurnStarted { combatant } => format!("{}'s turn", log.name(*combatant)),
+        CombatEvent::Damaged {
+            target,
+            source,
+            amount,
+            damage_type,
+        } => {
+            let damage = match damage_type {
+                Some(damage_type) => {
+                    format!("{amount} {} damage", damage_type.to_string().to_lowercase())
+                }
+                None => format!("{amount} damage"),
+            };
+
+            match source.filter(|source| source != target) {
+                Some(source) => format!(
+                    "{} takes {damage} from {}",
+                    log.name(*target),
+                    log.name(source)
+                ),
+                None => format!("{} takes {damage}", log.name(*target)),
+            }
+        }
+        CombatEvent::Healed { target, amount } => {
+            format!("{} regains {amount} hit points", log.name(*target))
+        }
+        CombatEvent::ConditionApplied { target, condition } => {
+            format!("{} is {condition}", log.name(*target))
+        }
+        CombatEvent::ConditionEnded { target, condition } => {
+            format!("{} is no longer {condition}", log.name(*target))
+        }
+        CombatEvent::SpellCast { caster, spell } => {
+            format!("{} casts {spell}", log.name(*caster))
+        }
+        CombatEvent::Downed { combatant } => {
+            format!("{} falls unconscious", log.name(*combatant))
+        }
+        CombatEvent::Died { combatant } => format!("{} dies", log.name(*combatant)),
+        CombatEvent::Ended => String::from("Combat ended"),
+    }
+}
+
+/// The rounds a fight lasted and the experience points it awarded, like "3 rounds · 450 XP".
+fn summary_overview(rounds: u32, experience: u32, per_character: Option<u32>) -> String {
+    let rounds = match rounds {
+        1 => String::from("1 round"),
+        rounds => format!("{rounds} rounds"),
+    };
+
+    match per_character {
+        Some(per_character) => {
+            format!("{rounds} · {experience} XP, {per_character} XP per character")
+        }
+        None => format!("{rounds} · {experience} XP"),
+    }
+}
+
+/// The summary and log of a fight as Markdown, for the notes of the session.
+fn markdown(log: &CombatLog) -> String {
+    let summary = log.summary();
+
+    let mut markdown = String::from("# Combat\n\n");
+    markdown.push_str(&summary_overview(
+        summary.rounds,
+        summary.experience,
+        summary.experience_per_character,
+    ));
+    markdown.push_str("\n\n| Combatant | Damage dealt | Damage taken | Healing received |\n");
+    markdown.push_str("| --- | ---: | ---: | ---: |\n");
+
+    for participant in &summary.participants {
+        // A pipe in a name would otherwise end its cell early.
+        markdown.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            participant.name.replace('|', "\\|"),
+            participant.damage_dealt,
+            participant.damage_taken,
+            participant.healing_received
+        ));
+    }
+
+    markdown.push_str("\n## Log\n\n");
+
+    for entry in &log.entries {
+        markdown.push_str(&format!(
+            "- Round {}: {}\n",
+            entry.round,
+            describe(log, &entry.event)
+        ));
+    }
+
+    markdown
+}
+
+/// How a fight is named in the chronicle, by its length and the creatures taking part.
+fn fight_name(log: &CombatLog) -> String {
+    let rounds = log.summary().rounds;
+    let creatures = log
+        .participants
+        .iter()
+        .filter(|participant| matches!(participant.kind, CombatantKind::Creature(_)))
+        .count();
+
+    let rounds = match rounds {
+        1 => String::from("1 round"),
+        rounds => format!("{rounds} rounds"),
+    };
+
+    match creatures {
+        1 => format!("{rounds} against 1 creature"),
+        creatures => format!("{rounds} against {creatures} creatures"),
+    }
+}
+
+/// The time left of `rounds` of 6 seconds, like "2 min 3 rounds".
+fn time_left(rounds: u32) -> String {
+    let hours = rounds / 600;
+    let minutes = rounds % 600 / 10;
+    let rounds = rounds % 10;
+
+    let mut parts = Vec::with_capacity(3);
+
+    if hours > 0 {
+        parts.push(format!("{hours} h"));
+    }
+    if minutes > 0 {
+        parts.push(format!("{minutes} min"));
+    }
+    match rounds {
+        0 => {}
+        1 => parts.push(String::from("1 round")),
+        rounds => parts.push(format!("{rounds} rounds")),
+    }
+
+    parts.join(" ")
+}
+
+async fn save_combat<C: CombatsRepository>(ctx: C, combat: NewCombat) -> Result<(), Error> {
+    let repo = ctx.combats();
+    repo.save(combat).await
+}
+
+async fn record_fight<C: ActivitiesRepository>(ctx: C, activity: NewActivity) -> Result<(), Error> {
+    let repo = ctx.activities();
+    repo.record(activity).await
+}
+
+async fn delete_combat<C: CombatsRepository>(ctx: C, id: Uuid) {
+    let repo = ctx.combats();
+
+    if let Err(err) = repo.delete(id).await {
+        tracing::error!("failed to delete combat '{id}': {err:?}");
+    }
+}
+
+async fn load_creatures<C: CreaturesRepository>(ctx: C) -> Result<Box<[CreatureSummary]>, Error> {
+    let repo = ctx.creatures();
+    repo.list().await
+}
+
+async fn load_spells<C: SpellsRepository>(ctx: C) -> Result<Box<[SpellSummary]>, Error> {
+    let repo = ctx.spells();
+    repo.list().await
+}
+
+async fn load_spell<C: SpellsRepository>(ctx: C, id: Uuid) -> Result<Box<Spell>, Error> {
+    let repo = ctx.spells();
+    let record = repo.get(id).await?;
+
+    Spell::try_from(&record).map(Box::new)
+}
+
+async fn load_encounter<C: EncountersRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<Box<EncounterRecord>, Error> {
+    let repo = ctx.encounters();
+    repo.get(id).await.map(Box::new)
+}
+
+async fn load_creature<C: CreaturesRepository>(ctx: C, id: Uuid) -> Result<Box<Creature>, Error> {
+    let repo = ctx.creatures();
+    let record = repo.get(id).await?;
+
+    Creature::try_from(&record).map(Box::new)
+}
@@ -0,0 +1,65 @@
+use crate::context::Context;
+use storage::Error;
+use storage::repositories::creatures::CreaturesRepository;
+use types::Creature;
+
+use iced::Task;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum LoadMessage {
+    CreatureLoaded(Result<Box<Creature>, Error>),
+}
+
+pub struct Loader {
+    /// The number of tasks in total the loader must complete.
+    pub total: usize,
+
+    /// The number of tasks that have been completed.
+    pub progress: usize,
+
+    pub error: Option<Error>,
+
+    pub creature: Option<Creature>,
+}
+
+impl Loader {
+    pub fn new(context: Context, id: Uuid) -> (Self, Task<LoadMessage>) {
+        let tasks = vec![Task::perform(
+            load_creature(context, id),
+            LoadMessage::CreatureLoaded,
+        )];
+
+        let loader = Self {
+            total: tasks.len(),
+            progress: 0,
+            error: None,
+            creature: None,
+        };
+
+        (loader, Task::batch(tasks))
+    }
+
+    pub fn update(&mut self, message: LoadMessage) {
+        match message {
+            LoadMessage::CreatureLoaded(Ok(creature)) => self.creature = Some(*creature),
+            LoadMessage::CreatureLoaded(Err(err)) => {
+                tracing::error!("failed to load creature: {:?}", err);
+                self.error = Some(err);
+            }
+        }
+
+        self.progress += 1;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress == self.total
+    }
+}
+
+async fn load_creature<C: CreaturesRepository>(ctx: C, id: Uuid) -> Result<Box<Creature>, Error> {
+    let repo = ctx.creatures();
+    let record = repo.get(id).await?;
+
+    Creature::try_from(&record).map(Box::new)
+}
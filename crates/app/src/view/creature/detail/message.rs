@@ -0,0 +1,20 @@
+use crate::view::creature::detail::loader::LoadMessage;
+use crate::view::request::Request;
+use storage::Error;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    LoadMessage(LoadMessage),
+    EditRequested,
+    DeleteRequested,
+    Deleted(Result<(), Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    OpenView(Request),
+    LoadFailed(Error),
+
+    /// The creature was deleted.
+    Deleted,
+}
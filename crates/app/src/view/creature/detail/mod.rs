@@ -0,0 +1,392 @@
+mod loader;
+pub mod message;
+
+use crate::context::Context;
+use crate::status::Status;
+use crate::status::loading;
+use crate::view::Viewable;
+use crate::view::creature::ABILITY_LABELS;
+use crate::view::creature::detail::loader::Loader;
+use crate::view::creature::detail::message::Effect;
+use crate::view::creature::detail::message::Message;
+use crate::view::request::Request;
+use storage::Error;
+use storage::repositories::creatures::CreaturesRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
+use style::layout::SECTION_SPACING;
+use style::text::TextClass;
+use types::Ability;
+use types::Creature;
+use types::Defense;
+use types::Feature;
+use types::FormMode;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length;
+use iced::Length::Fill;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+use iced::widget::rule;
+use strum::VariantArray;
+use uuid::Uuid;
+
+/// The text size of the creature name atop the stat block.
+const NAME_SIZE: u32 = 32;
+
+/// The text size of the headers of the action sections.
+const HEADER_SIZE: u32 = 22;
+
+pub struct CreatureDetail {
+    id: Uuid,
+    context: Context,
+    status: Status<Loader, Creature>,
+}
+
+impl<'a> CreatureDetail {
+    pub fn new(id: Uuid, context: Context) -> (Self, Task<Message>) {
+        let (loader, task) = Loader::new(context.clone(), id);
+
+        let creature_detail = Self {
+            id,
+            context,
+            status: Status::Loading(Box::new(loader)),
+        };
+
+        (creature_detail, task.map(Message::LoadMessage))
+    }
+
+    /// The id of the creature being displayed.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Reload the creature.
+    pub fn reload(&mut self) -> Task<Message> {
+        let (loader, task) = Loader::new(self.context.clone(), self.id);
+        self.status = Status::Loading(Box::new(loader));
+
+        task.map(Message::LoadMessage)
+    }
+
+    fn heading(creature: &'a Creature) -> Element<'a, Message> {
+        let title = components::text::view_title(&creature.name);
+
+        let sub_title = components::text::view_sub_title(format!(
+            "Challenge {} · {}",
+            creature.challenge_rating,
+            creature
+                .source
+                .as_ref()
+                .map_or_else(|| String::from("No source"), ToString::to_string)
+        ));
+
+        let edit = widget::button(components::text::display("Edit"))
+            .class(ButtonClass::Interaction)
+            .padding(INPUT_PADDING)
+            .on_press(Message::EditRequested);
+
+        let delete = widget::button(components::text::display("Delete"))
+            .class(ButtonClass::Danger)
+            .padding(INPUT_PADDING)
+            .on_press(Message::DeleteRequested);
+
+        let actions = row![widget::space::horizontal().width(Fill), edit, delete]
+            .spacing(BODY_SPACING)
+            .width(Fill);
+
+        column![title, sub_title, actions]
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    /// The classic stat block, with the statistics and traits on the left and everything the
+    /// creature can do on its turn and outside of it on the right.
+    fn stat_block(creature: &'a Creature) -> Element<'a, Message> {
+        let name = widget::text(&creature.name)
+            .size(NAME_SIZE)
+            .font(fonts::heading::bold())
+            .class(TextClass::Primary);
+
+        let classification = widget::text(format!(
+            "{} {}, {}",
+            creature.size,
+            creature.kind.to_string().to_lowercase(),
+            creature.alignment.to_string().to_lowercase()
+        ))
+        .font(fonts::body::italic())
+        .class(TextClass::Normal);
+
+        let armor_class = match &creature.armor_description {
+            Some(description) => format!("{} ({description})", creature.armor_class),
+            None => creature.armor_class.to_string(),
+        };
+        let hit_points = format!("{} ({})", creature.hit_points(), creature.hit_dice);
+
+        let defenses = column![
+            statistic("Armor Class", armor_class),
+            statistic("Hit Points", hit_points),
+            statistic("Speed", creature.speeds.to_string()),
+        ]
+        .spacing(LABEL_SPACING);
+
+        let mut statistics = Column::new().spacing(LABEL_SPACING);
+
+        if !creature.saving_throws.is_empty() {
+            let saving_throws = creature
+                .saving_throws
+                .iter()
+                .map(|bonus| format!("{} {:+}", &bonus.target.as_ref()[..3], bonus.bonus))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            statistics = statistics.push(statistic("Saving Throws", saving_throws));
+        }
+
+        if !creature.skills.is_empty() {
+            let skills = creature
+                .skills
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            statistics = statistics.push(statistic("Skills", skills));
+        }
+
+        for (label, defense) in [
+            ("Damage Vulnerabilities", Defense::Vulnerability),
+            ("Damage Resistances", Defense::Resistance),
+            ("Damage Immunities", Defense::Immunity),
+        ] {
+            let damage_types = creature
+                .damage_defenses
+                .iter()
+                .filter(|damage_defense| damage_defense.defense == defense)
+                .map(|damage_defense| damage_defense.damage_type.as_ref().to_lowercase())
+                .collect::<Vec<_>>();
+
+            if !damage_types.is_empty() {
+                statistics = statistics.push(statistic(label, damage_types.join(", ")));
+            }
+        }
+
+        let languages = if creature.languages.is_empty() {
+            String::from("—")
+        } else {
+            creature.languages.join(", ")
+        };
+
+        let challenge = format!(
+            "{} ({} XP)",
+            creature.challenge_rating,
+            creature.challenge_rating.experience()
+        );
+
+        statistics = statistics
+            .push(statistic("Senses", creature.senses.to_string()))
+            .push(statistic("Languages", languages))
+            .push(statistic("Challenge", challenge));
+
+        if !creature.environments.is_empty() {
+            let environments = creature
+                .environments
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            statistics = statistics.push(statistic("Environments", environments));
+        }
+
+        let mut left = column![
+            name,
+            classification,
+            rule::horizontal(1),
+            defenses,
+            rule::horizontal(1),
+            Self::ability_table(creature),
+            rule::horizontal(1),
+            statistics,
+            rule::horizontal(1),
+        ]
+        .spacing(BODY_SPACING)
+        .width(Length::FillPortion(1));
+
+        for feature in &creature.traits {
+            left = left.push(feature_paragraph(feature));
+        }
+
+        let mut right = Column::new()
+            .spacing(BODY_SPACING)
+            .width(Length::FillPortion(1));
+
+        for (header, features) in [
+            ("Actions", &creature.actions),
+            ("Reactions", &creature.reactions),
+            ("Legendary Actions", &creature.legendary_actions),
+            ("Lair Actions", &creature.lair_actions),
+        ] {
+            if features.is_empty() {
+                continue;
+            }
+
+            let header = widget::text(header)
+                .size(HEADER_SIZE)
+                .font(fonts::heading::regular())
+                .class(TextClass::Primary);
+
+            right = right.push(column![header, rule::horizontal(1)].spacing(LABEL_SPACING));
+
+            for feature in features {
+                right = right.push(feature_paragraph(feature));
+            }
+        }
+
+        let stat_block = row![left, right].spacing(SECTION_SPACING);
+
+        widget::container(stat_block)
+            .padding(INPUT_PADDING)
+            .width(Fill)
+            .into()
+    }
+
+    /// The ability scores with their modifiers, side by side.
+    fn ability_table(creature: &'a Creature) -> Element<'a, Message> {
+        let abilities = Ability::VARIANTS
+            .iter()
+            .zip(ABILITY_LABELS)
+            .map(|(&ability, label)| {
+                let score = creature.abilities[ability];
+                let modifier = creature.abilities.modifier(ability);
+
+                let label = widget::text(label)
+                    .font(fonts::body::bold())
+                    .class(TextClass::Normal);
+                let score = components::text::body(format!("{score} ({modifier:+})"));
+
+                column![label, score]
+                    .align_x(Alignment::Center)
+                    .width(Fill)
+                    .into()
+            });
+
+        widget::Row::with_children(abilities).into()
+    }
+}
+
+impl Viewable for CreatureDetail {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        match &self.status {
+            Status::Loading(_) => "Creature",
+            Status::Ready(creature) => &creature.name,
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::LoadMessage(load_message) => {
+                let loader = loading!(self.status);
+
+                loader.update(load_message);
+
+                if let Some(err) = loader.error.take() {
+                    tracing::error!("failed to load creature detail: {:?}", err);
+
+                    return (Task::none(), Some(Effect::LoadFailed(err)));
+                }
+
+                if loader.is_done() {
+                    match loader.creature.take() {
+                        Some(creature) => self.status = Status::Ready(Box::new(creature)),
+                        None => {
+                            tracing::error!("failed to convert loader to creature");
+                        }
+                    }
+                }
+            }
+            Message::EditRequested => {
+                let request = Request::CreatureForm {
+                    mode: FormMode::Edit(self.id),
+                };
+
+                return (Task::none(), Some(Effect::OpenView(request)));
+            }
+            Message::DeleteRequested => {
+                let task = Task::perform(
+                    delete_creature(self.context.clone(), self.id),
+                    Message::Deleted,
+                );
+
+                return (task, None);
+            }
+            Message::Deleted(Ok(())) => {
+                return (Task::none(), Some(Effect::Deleted));
+            }
+            Message::Deleted(Err(err)) => {
+                tracing::error!("failed to delete creature: {err:?}");
+            }
+        }
+
+        (Task::none(), None)
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match &self.status {
+            Status::Loading(loader) => {
+                let start = 0.0;
+                let end = loader.total as f32;
+                let current = loader.progress as f32;
+
+                widget::progress_bar(start..=end, current).into()
+            }
+            Status::Ready(creature) => {
+                let heading = Self::heading(creature);
+
+                let stat_block = Self::stat_block(creature);
+
+                column![heading, stat_block]
+                    .align_x(Alignment::Center)
+                    .spacing(SECTION_SPACING)
+                    .into()
+            }
+        }
+    }
+}
+
+/// A statistic of the stat block, with its name in bold, like "**Armor Class** 13".
+fn statistic<'a, Message: 'a>(label: &'a str, value: String) -> Element<'a, Message> {
+    widget::rich_text([
+        widget::span::<(), _>(label).font(fonts::body::bold()),
+        widget::span(" "),
+        widget::span(value).font(fonts::body::regular()),
+    ])
+    .class(TextClass::Normal)
+    .into()
+}
+
+/// A trait, action, reaction, legendary or lair action, led by its name in bold italics.
+fn feature_paragraph<'a, Message: 'a>(feature: &'a Feature) -> Element<'a, Message> {
+    widget::rich_text([
+        widget::span::<(), _>(format!("{}. ", feature.name)).font(fonts::body::bold_italic()),
+        widget::span(&feature.description).font(fonts::body::regular()),
+    ])
+    .class(TextClass::Normal)
+    .into()
+}
+
+async fn delete_creature<C: CreaturesRepository>(ctx: C, id: Uuid) -> Result<(), Error> {
+    let repo = ctx.creatures();
+    repo.delete(id).await
+}
@@ -0,0 +1,89 @@
+use crate::context::Context;
+use storage::Error;
+use storage::repositories::creatures::CreaturesRepository;
+use storage::repositories::options::OptionsRepository;
+use storage::repositories::options::Variant;
+use types::Creature;
+use types::FormMode;
+
+use iced::Task;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum LoadMessage {
+    SourcesLoaded(Result<Box<[String]>, Error>),
+    CreatureLoaded(Result<Box<Creature>, Error>),
+}
+
+pub struct Loader {
+    /// The number of tasks in total the loader must complete.
+    pub total: usize,
+
+    /// The number of tasks that have been completed.
+    pub progress: usize,
+
+    pub error: Option<Error>,
+
+    /// The sources a creature can be picked from, shared with spells.
+    pub sources: Option<Box<[String]>>,
+
+    /// The creature being edited, only loaded in [`FormMode::Edit`].
+    pub creature: Option<Creature>,
+}
+
+impl Loader {
+    pub fn new(context: Context, mode: &FormMode) -> (Self, Task<LoadMessage>) {
+        let mut tasks = Vec::with_capacity(2);
+
+        let task = Task::perform(load_sources(context.clone()), LoadMessage::SourcesLoaded);
+        tasks.push(task);
+
+        if let FormMode::Edit(id) = mode {
+            let task = Task::perform(load_creature(context, *id), LoadMessage::CreatureLoaded);
+            tasks.push(task);
+        }
+
+        let loader = Self {
+            total: tasks.len(),
+            progress: 0,
+            error: None,
+            sources: None,
+            creature: None,
+        };
+
+        (loader, Task::batch(tasks))
+    }
+
+    pub fn update(&mut self, message: LoadMessage) {
+        match message {
+            LoadMessage::SourcesLoaded(Ok(sources)) => self.sources = Some(sources),
+            LoadMessage::SourcesLoaded(Err(err)) => {
+                tracing::error!("failed to load creature sources: {:?}", err);
+                self.error = Some(err);
+            }
+            LoadMessage::CreatureLoaded(Ok(creature)) => self.creature = Some(*creature),
+            LoadMessage::CreatureLoaded(Err(err)) => {
+                tracing::error!("failed to load creature: {:?}", err);
+                self.error = Some(err);
+            }
+        }
+
+        self.progress += 1;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress == self.total
+    }
+}
+
+async fn load_sources<C: OptionsRepository>(ctx: C) -> Result<Box<[String]>, Error> {
+    let repo = ctx.options();
+    repo.list_options(Variant::Source).await
+}
+
+async fn load_creature<C: CreaturesRepository>(ctx: C, id: Uuid) -> Result<Box<Creature>, Error> {
+    let repo = ctx.creatures();
+    let record = repo.get(id).await?;
+
+    Creature::try_from(&record).map(Box::new)
+}
@@ -0,0 +1,65 @@
+use crate::view::creature::form::loader::LoadMessage;
+use crate::view::creature::form::sheet::FeatureKind;
+use crate::view::creature::form::sheet::Movement;
+use crate::view::creature::form::sheet::Sense;
+use storage::Error;
+use types::Ability;
+use types::Alignment;
+use types::ChallengeRating;
+use types::CreatureType;
+use types::DamageType;
+use types::Environment;
+use types::Size;
+use types::Skill;
+
+use iced::widget::text_editor::Action;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    LoadMessage(LoadMessage),
+    NameChanged(String),
+    SizeSelected(Size),
+    KindSelected(CreatureType),
+    AlignmentSelected(Alignment),
+    ChallengeRatingSelected(ChallengeRating),
+    SourceSelected(String),
+    EnvironmentToggled(Environment),
+    ArmorClassChanged(String),
+    ArmorDescriptionChanged(String),
+    HitDiceChanged(String),
+    SpeedChanged(Movement, String),
+    HoverToggled,
+    AbilityScoreChanged(Ability, String),
+    InitiativeBonusChanged(String),
+    SavingThrowToggled(Ability),
+    SkillToggled(Skill),
+    DefenseCycled(DamageType),
+    SenseChanged(Sense, String),
+    LanguageChanged(String),
+    LanguageSubmitted,
+    LanguageRemoved(usize),
+    FeatureAdded(FeatureKind),
+    FeatureRemoved(FeatureKind, usize),
+    FeatureNameChanged(FeatureKind, usize, String),
+    FeatureDescriptionChanged(FeatureKind, usize, Action),
+    LegendaryActionCountChanged(String),
+    Submitted,
+    Saved(Result<Uuid, Error>),
+    DeleteRequested,
+    Deleted(Result<(), Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    LoadFailed(Error),
+
+    /// The creature was created or updated.
+    Saved,
+
+    /// The creature could not be saved, either due to invalid fields or a storage error.
+    SaveFailed,
+
+    /// The creature was deleted.
+    Deleted,
+}
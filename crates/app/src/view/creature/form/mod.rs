@@ -0,0 +1,700 @@
+mod loader;
+pub mod message;
+mod sheet;
+
+use crate::context::Context;
+use crate::status::Status;
+use crate::status::loading;
+use crate::status::ready;
+use crate::view::Viewable;
+use crate::view::creature::ABILITY_LABELS;
+use crate::view::creature::form::loader::Loader;
+use crate::view::creature::form::message::Effect;
+use crate::view::creature::form::message::Message;
+use crate::view::creature::form::sheet::FeatureKind;
+use crate::view::creature::form::sheet::Movement;
+use crate::view::creature::form::sheet::Sense;
+use crate::view::creature::form::sheet::Sheet;
+use storage::Error;
+use storage::models::creature::NewCreature;
+use storage::repositories::creatures::CreaturesRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
+use style::layout::SECTION_SPACING;
+use style::text::TextClass;
+use types::Ability;
+use types::DamageType;
+use types::Environment;
+use types::FormMode;
+use types::Skill;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length::Fill;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+use strum::VariantArray;
+use uuid::Uuid;
+
+/// The labels of the speed fields, in the order of [`Movement`].
+const MOVEMENT_LABELS: [&str; 5] = ["WALK", "BURROW", "CLIMB", "FLY", "SWIM"];
+
+/// The labels of the sense fields, in the order of [`Sense`].
+const SENSE_LABELS: [&str; 4] = ["BLINDSIGHT", "DARKVISION", "TREMORSENSE", "TRUESIGHT"];
+
+/// The number of skill toggles on each row.
+const SKILLS_PER_ROW: usize = 3;
+
+/// The number of environment toggles on each row.
+const ENVIRONMENTS_PER_ROW: usize = 4;
+
+/// The number of damage defense buttons on each row.
+const DEFENSES_PER_ROW: usize = 4;
+
+pub struct CreatureForm {
+    mode: FormMode,
+    context: Context,
+    status: Status<Loader, Sheet>,
+
+    /// Whether the sheet changed since the creature was last saved.
+    unsaved: bool,
+
+    /// Whether the creature is currently being saved, used to prevent duplicate submissions.
+    saving: bool,
+}
+
+impl<'a> CreatureForm {
+    pub fn new(mode: FormMode, context: Context) -> (Self, Task<Message>) {
+        let (loader, task) = Loader::new(context.clone(), &mode);
+
+        let form = Self {
+            mode,
+            context,
+            status: Status::Loading(Box::new(loader)),
+            unsaved: false,
+            saving: false,
+        };
+
+        (form, task.map(Message::LoadMessage))
+    }
+
+    /// The id of the creature being edited, if it has been saved.
+    pub fn id(&self) -> Option<Uuid> {
+        match self.mode {
+            FormMode::Create => None,
+            FormMode::Edit(id) => Some(id),
+        }
+    }
+
+    /// Construct the sheet once the loader is done.
+    fn finish_loading(&mut self) {
+        let Status::Loading(loader) = &mut self.status else {
+            return;
+        };
+
+        if !loader.is_done() {
+            return;
+        }
+
+        let sources = loader.sources.take().unwrap_or_default();
+
+        let sheet = match loader.creature.take() {
+            Some(creature) => Sheet::from_creature(creature, sources),
+            None => Sheet::new(sources),
+        };
+
+        self.status = Status::Ready(Box::new(sheet));
+    }
+
+    fn heading() -> Element<'a, Message> {
+        let title = components::text::view_title("Creature");
+
+        let sub_title = components::text::view_sub_title("The stat block of a monster or person");
+
+        column![title, sub_title]
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    fn identity_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "IDENTITY",
+            "What the creature is, and how dangerous it is to face.",
+        );
+
+        let name = components::text_field(Some("NAME"), &sheet.name)
+            .placeholder("Owlbear")
+            .on_input(Message::NameChanged);
+        let size = components::select_field("SIZE", &sheet.size, Message::SizeSelected)
+            .placeholder("Select a size");
+        let kind = components::select_field("TYPE", &sheet.kind, Message::KindSelected)
+            .placeholder("Select a type");
+        let alignment =
+            components::select_field("ALIGNMENT", &sheet.alignment, Message::AlignmentSelected)
+                .placeholder("Select an alignment");
+        let challenge_rating = components::select_field(
+            "CHALLENGE",
+            &sheet.challenge_rating,
+            Message::ChallengeRatingSelected,
+        )
+        .placeholder("Select a rating");
+
+        let experience = sheet.challenge_rating.selected().map(|rating| {
+            components::text::detail(format!(
+                "{} XP, proficiency bonus {:+}",
+                rating.experience(),
+                rating.proficiency_bonus()
+            ))
+        });
+
+        let source = components::select_field("SOURCE", &sheet.source, Message::SourceSelected)
+            .placeholder("Select a source");
+
+        let environments: Element<_> = {
+            let label = components::text::label("ENVIRONMENTS");
+
+            let toggles = Environment::VARIANTS
+                .iter()
+                .map(|environment| {
+                    components::toggle(
+                        environment.as_ref(),
+                        sheet.environments.contains(environment),
+                    )
+                    .width(Fill)
+                    .on_toggle(Message::EnvironmentToggled(*environment))
+                    .into()
+                })
+                .collect();
+            let grid = components::row::chunked(toggles, ENVIRONMENTS_PER_ROW);
+
+            column![label, grid].spacing(LABEL_SPACING).into()
+        };
+
+        let row1 = row![name, challenge_rating].spacing(BODY_SPACING);
+        let row2 = row![size, kind, alignment].spacing(BODY_SPACING);
+
+        let form = column![row1, experience, row2, source, environments].spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn defenses_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "DEFENSES",
+            "How hard the creature is to hit, and how much it takes to bring it down.",
+        );
+
+        let armor_class = components::number_field(Some("ARMOR CLASS"), &sheet.armor_class)
+            .placeholder("10")
+            .on_input(Message::ArmorClassChanged);
+        let armor_description =
+            components::text_field(Some("ARMOR DESCRIPTION"), &sheet.armor_description)
+                .placeholder("natural armor")
+                .on_input(Message::ArmorDescriptionChanged);
+        let hit_dice = components::text_field(Some("HIT DICE"), &sheet.hit_dice)
+            .placeholder("7d10 + 21")
+            .on_input(Message::HitDiceChanged);
+
+        let hit_points = match sheet.hit_points() {
+            Some(hit_points) => format!("{hit_points} hit points on average"),
+            None => String::from("Write the hit dice as a formula like 2d8 + 4."),
+        };
+
+        let defenses = {
+            let label = components::text::label("DAMAGE");
+            let hint = components::text::detail(
+                "Press a damage type to cycle between resistance, immunity and vulnerability.",
+            );
+
+            let buttons = DamageType::VARIANTS
+                .iter()
+                .map(|&damage_type| Self::defense_button(sheet, damage_type))
+                .collect();
+            let grid = components::row::chunked(buttons, DEFENSES_PER_ROW);
+
+            column![label, hint, grid].spacing(LABEL_SPACING)
+        };
+
+        let row1 = row![armor_class, armor_description, hit_dice].spacing(BODY_SPACING);
+
+        let form =
+            column![row1, components::text::detail(hit_points), defenses].spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn defense_button(sheet: &'a Sheet, damage_type: DamageType) -> Element<'a, Message> {
+        let (text, class) = match sheet.defenses.get(&damage_type) {
+            None => (String::from(damage_type.as_ref()), ButtonClass::Outlined),
+            Some(defense) => (format!("{damage_type}: {defense}"), ButtonClass::Primary),
+        };
+
+        widget::button(components::text::display(text))
+            .class(class)
+            .width(Fill)
+            .padding(INPUT_PADDING)
+            .on_press(Message::DefenseCycled(damage_type))
+            .into()
+    }
+
+    fn movement_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "MOVEMENT & SENSES",
+            "How the creature gets around, perceives the world and speaks to it.",
+        );
+
+        let speeds = Movement::VARIANTS
+            .iter()
+            .zip(MOVEMENT_LABELS)
+            .map(|(&movement, label)| {
+                components::number_field(Some(label), &sheet.speeds[movement as usize])
+                    .placeholder("ft.")
+                    .on_input(move |speed| Message::SpeedChanged(movement, speed))
+                    .into()
+            });
+        let hover = components::toggle("Hover", sheet.hover).on_toggle(Message::HoverToggled);
+
+        let senses = Sense::VARIANTS
+            .iter()
+            .zip(SENSE_LABELS)
+            .map(|(&sense, label)| {
+                components::number_field(Some(label), &sheet.senses[sense as usize])
+                    .placeholder("ft.")
+                    .on_input(move |range| Message::SenseChanged(sense, range))
+                    .into()
+            });
+        let passive_perception =
+            components::text::detail(format!("Passive Perception {}", sheet.passive_perception()));
+
+        let languages = components::multi_text_field(Some("LANGUAGES"), &sheet.languages)
+            .placeholder("Write a language")
+            .on_input(Message::LanguageChanged)
+            .on_submit(Message::LanguageSubmitted)
+            .on_remove(Message::LanguageRemoved);
+
+        let row1 = widget::Row::with_children(speeds).spacing(BODY_SPACING);
+        let row2 = widget::Row::with_children(senses).spacing(BODY_SPACING);
+
+        let form = column![row1, hover, row2, passive_perception, languages].spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn abilities_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "ABILITIES",
+            "The ability scores, initiative, and the saving throws it is proficient in.",
+        );
+
+        let abilities = Ability::VARIANTS
+            .iter()
+            .zip(ABILITY_LABELS)
+            .map(|(&ability, label)| {
+                let score =
+                    components::number_field(Some(label), &sheet.abilities[ability as usize])
+                        .placeholder("10")
+                        .on_input(move |score| Message::AbilityScoreChanged(ability, score));
+
+                let modifier = match sheet.modifier(ability) {
+                    Some(modifier) => format!("{modifier:+}"),
+                    None => String::from("-"),
+                };
+
+                let save = components::toggle("Save", sheet.saving_throws.contains(&ability))
+                    .on_toggle(Message::SavingThrowToggled(ability));
+                let save_bonus =
+                    components::text::detail(format!("{:+}", sheet.saving_throw_bonus(ability)));
+
+                column![
+                    score,
+                    components::text::detail(modifier),
+                    row![save, save_bonus]
+                        .spacing(LABEL_SPACING)
+                        .align_y(Alignment::Center)
+                ]
+                .spacing(LABEL_SPACING)
+                .width(Fill)
+                .into()
+            });
+
+        let initiative_bonus =
+            components::number_field(Some("INITIATIVE BONUS"), &sheet.initiative_bonus)
+                .placeholder("0")
+                .on_input(Message::InitiativeBonusChanged);
+        let initiative = match sheet.initiative() {
+            Some(initiative) => format!("Initiative {initiative:+} with the Dexterity modifier"),
+            None => String::from("Initiative adds the bonus to the Dexterity modifier."),
+        };
+
+        let form = column![
+            widget::Row::with_children(abilities).spacing(BODY_SPACING),
+            initiative_bonus,
+            components::text::detail(initiative),
+        ]
+        .spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn skills_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header =
+            components::form::section_header("SKILLS", "The skills the creature is proficient in.");
+
+        let skills = Skill::VARIANTS
+            .iter()
+            .map(|skill| {
+                let toggle = components::toggle(skill.as_ref(), sheet.skills.contains(skill))
+                    .width(Fill)
+                    .on_toggle(Message::SkillToggled(*skill));
+                let bonus = components::text::detail(format!("{:+}", sheet.skill_bonus(*skill)));
+
+                row![toggle, bonus]
+                    .spacing(LABEL_SPACING)
+                    .align_y(Alignment::Center)
+                    .width(Fill)
+                    .into()
+            })
+            .collect();
+
+        let form = components::row::chunked(skills, SKILLS_PER_ROW);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn features_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "FEATURES",
+            "The traits of the creature, and what it can do on its turn and those of others.",
+        );
+
+        let mut form = Column::with_capacity(FeatureKind::VARIANTS.len()).spacing(SECTION_SPACING);
+
+        for &kind in FeatureKind::VARIANTS {
+            form = form.push(Self::features(sheet, kind));
+        }
+
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn features(sheet: &'a Sheet, kind: FeatureKind) -> Element<'a, Message> {
+        let features = &sheet.features[kind as usize];
+
+        let add = widget::button(components::text::display(format!("Add {kind}")))
+            .class(ButtonClass::Ghost)
+            .on_press(Message::FeatureAdded(kind));
+
+        let heading = row![
+            components::text::heading(format!("{kind}s")),
+            widget::space::horizontal().width(Fill),
+            add
+        ]
+        .align_y(Alignment::Center);
+
+        let mut rows = Column::with_capacity(features.len()).spacing(BODY_SPACING);
+
+        for (index, feature) in features.iter().enumerate() {
+            let name = components::text_field(Some("NAME"), &feature.name)
+                .placeholder("Multiattack")
+                .on_input(move |name| Message::FeatureNameChanged(kind, index, name));
+            let remove = widget::button(components::text::display("Remove"))
+                .class(ButtonClass::Danger)
+                .padding(INPUT_PADDING)
+                .on_press(Message::FeatureRemoved(kind, index));
+            let description =
+                components::text_area_field("DESCRIPTION", &feature.description, move |action| {
+                    Message::FeatureDescriptionChanged(kind, index, action)
+                })
+                .height(100);
+
+            let name_row = row![name, remove]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::End);
+
+            rows = rows.push(column![name_row, description].spacing(LABEL_SPACING));
+        }
+
+        // Only legendary actions are limited to a number of uses each round.
+        let count = (kind == FeatureKind::LegendaryAction).then(|| {
+            components::number_field(
+                Some("LEGENDARY ACTIONS PER ROUND"),
+                &sheet.legendary_action_count,
+            )
+            .placeholder("3")
+            .on_input(Message::LegendaryActionCountChanged)
+        });
+
+        column![heading]
+            .push(count)
+            .push(rows)
+            .spacing(BODY_SPACING)
+            .width(Fill)
+            .into()
+    }
+
+    fn actions(&self, sheet: &'a Sheet) -> Element<'a, Message> {
+        let error = sheet
+            .error
+            .map(|error| components::text::detail(error).class(TextClass::Danger));
+
+        let delete = self.id().map(|_| {
+            widget::button(components::text::display("Delete"))
+                .class(ButtonClass::Danger)
+                .padding(INPUT_PADDING)
+                .on_press(Message::DeleteRequested)
+        });
+
+        let label = if self.saving {
+            "Saving..."
+        } else {
+            "Save creature"
+        };
+
+        let save = widget::button(components::text::display(label))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe((!self.saving).then_some(Message::Submitted));
+
+        row![delete, widget::space::horizontal().width(Fill), error, save]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center)
+            .width(Fill)
+            .into()
+    }
+}
+
+impl Viewable for CreatureForm {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        match self.mode {
+            FormMode::Create => "Create creature",
+            FormMode::Edit(_) => "Edit creature",
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::LoadMessage(load_message) => {
+                let loader = loading!(self.status);
+
+                loader.update(load_message);
+
+                if let Some(err) = loader.error.take() {
+                    tracing::error!("failed to load creature form: {:?}", err);
+
+                    return (Task::none(), Some(Effect::LoadFailed(err)));
+                }
+
+                self.finish_loading();
+
+                return (Task::none(), None);
+            }
+            Message::Submitted => {
+                if self.saving {
+                    return (Task::none(), None);
+                }
+
+                let id = self.id().unwrap_or_else(Uuid::new_v4);
+                let sheet = ready!(self.status);
+
+                let Some(new_creature) = sheet.try_new_creature(id) else {
+                    tracing::debug!("creature form has invalid fields");
+
+                    return (Task::none(), Some(Effect::SaveFailed));
+                };
+
+                self.saving = true;
+
+                let task = Task::perform(
+                    save_creature(self.context.clone(), self.mode.clone(), new_creature),
+                    Message::Saved,
+                );
+
+                return (task, None);
+            }
+            Message::Saved(Ok(id)) => {
+                self.saving = false;
+                self.unsaved = false;
+
+                // Saving a new creature turns the form into an edit of that creature.
+                self.mode = FormMode::Edit(id);
+
+                return (Task::none(), Some(Effect::Saved));
+            }
+            Message::Saved(Err(err)) => {
+                self.saving = false;
+
+                tracing::error!("failed to save creature: {err:?}");
+
+                return (Task::none(), Some(Effect::SaveFailed));
+            }
+            Message::DeleteRequested => {
+                let Some(id) = self.id() else {
+                    return (Task::none(), None);
+                };
+
+                let task =
+                    Task::perform(delete_creature(self.context.clone(), id), Message::Deleted);
+
+                return (task, None);
+            }
+            Message::Deleted(Ok(())) => return (Task::none(), Some(Effect::Deleted)),
+            Message::Deleted(Err(err)) => {
+                tracing::error!("failed to delete creature: {err:?}");
+            }
+            message => {
+                let sheet = ready!(self.status);
+
+                // Moving the cursor or selecting text in a description does not change it.
+                let changes = !matches!(
+                    &message,
+                    Message::FeatureDescriptionChanged(_, _, action) if !action.is_edit()
+                );
+
+                sheet.edit(message);
+                self.unsaved |= changes;
+            }
+        }
+
+        (Task::none(), None)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.unsaved
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match &self.status {
+            Status::Loading(loader) => {
+                let start = 0.0;
+                let end = loader.total as f32;
+                let current = loader.progress as f32;
+
+                widget::progress_bar(start..=end, current).into()
+            }
+            Status::Ready(sheet) => {
+                let heading = Self::heading();
+
+                let identity = Self::identity_section(sheet);
+
+                let defenses = Self::defenses_section(sheet);
+
+                let movement = Self::movement_section(sheet);
+
+                let abilities = Self::abilities_section(sheet);
+
+                let skills = Self::skills_section(sheet);
+
+                let features = Self::features_section(sheet);
+
+                let actions = self.actions(sheet);
+
+                column![
+                    heading, identity, defenses, movement, abilities, skills, features, actions
+                ]
+                .align_x(Alignment::Center)
+                .spacing(SECTION_SPACING)
+                .into()
+            }
+        }
+    }
+}
+
+impl Sheet {
+    /// Apply a message that edits a field of the sheet.
+    fn edit(&mut self, message: Message) {
+        match message {
+            Message::NameChanged(name) => self.name.set(name),
+            Message::SizeSelected(size) => self.size.set(size),
+            Message::KindSelected(kind) => self.kind.set(kind),
+            Message::AlignmentSelected(alignment) => self.alignment.set(alignment),
+            Message::ChallengeRatingSelected(rating) => self.challenge_rating.set(rating),
+            Message::SourceSelected(source) => self.source.set(source),
+            Message::EnvironmentToggled(environment) => {
+                if !self.environments.remove(&environment) {
+                    self.environments.insert(environment);
+                }
+            }
+            Message::ArmorClassChanged(armor_class) => self.armor_class.set(armor_class),
+            Message::ArmorDescriptionChanged(description) => {
+                self.armor_description.set(description)
+            }
+            Message::HitDiceChanged(hit_dice) => self.hit_dice.set(hit_dice),
+            Message::SpeedChanged(movement, speed) => self.speeds[movement as usize].set(speed),
+            Message::HoverToggled => self.hover = !self.hover,
+            Message::InitiativeBonusChanged(bonus) => self.initiative_bonus.set(bonus),
+            Message::AbilityScoreChanged(ability, score) => {
+                self.abilities[ability as usize].set(score)
+            }
+            Message::SavingThrowToggled(ability) => {
+                if !self.saving_throws.remove(&ability) {
+                    self.saving_throws.insert(ability);
+                }
+            }
+            Message::SkillToggled(skill) => {
+                if !self.skills.remove(&skill) {
+                    self.skills.insert(skill);
+                }
+            }
+            Message::DefenseCycled(damage_type) => self.cycle_defense(damage_type),
+            Message::SenseChanged(sense, range) => self.senses[sense as usize].set(range),
+            Message::LanguageChanged(language) => self.languages.set_value(language),
+            Message::LanguageSubmitted => self.languages.add_selection(),
+            Message::LanguageRemoved(index) => self.languages.remove_selection(index),
+            Message::FeatureAdded(kind) => self.add_feature(kind),
+            Message::FeatureRemoved(kind, index) => self.remove_feature(kind, index),
+            Message::FeatureNameChanged(kind, index, name) => {
+                if let Some(feature) = self.feature_mut(kind, index) {
+                    feature.name.set(name);
+                }
+            }
+            Message::FeatureDescriptionChanged(kind, index, action) => {
+                if let Some(feature) = self.feature_mut(kind, index) {
+                    feature.description.perform(action);
+                }
+            }
+            Message::LegendaryActionCountChanged(count) => self.legendary_action_count.set(count),
+            Message::LoadMessage(_)
+            | Message::Submitted
+            | Message::Saved(_)
+            | Message::DeleteRequested
+            | Message::Deleted(_) => {}
+        }
+    }
+}
+
+async fn save_creature<C: CreaturesRepository>(
+    ctx: C,
+    mode: FormMode,
+    new_creature: NewCreature,
+) -> Result<Uuid, Error> {
+    let id = new_creature.id;
+    let repo = ctx.creatures();
+
+    match mode {
+        FormMode::Create => repo.create(new_creature).await?,
+        FormMode::Edit(_) => repo.update(new_creature).await?,
+    }
+
+    Ok(id)
+}
+
+async fn delete_creature<C: CreaturesRepository>(ctx: C, id: Uuid) -> Result<(), Error> {
+    let repo = ctx.creatures();
+    repo.delete(id).await
+}
@@ -0,0 +1,518 @@
+use components::multi_text_field::MultiTextFieldState;
+use components::number_field::NumberFieldState;
+use components::select_field::SelectFieldState;
+use components::text_area_field::TextAreaFieldState;
+use components::text_field::TextFieldState;
+use storage::models::creature::NewCreature;
+use types::Ability;
+use types::AbilityScores;
+use types::Alignment;
+use types::Bonus;
+use types::ChallengeRating;
+use types::Choice;
+use types::Creature;
+use types::CreatureType;
+use types::DamageDefense;
+use types::DamageType;
+use types::Defense;
+use types::Dice;
+use types::Environment;
+use types::Feature;
+use types::Senses;
+use types::Size;
+use types::Skill;
+use types::Speeds;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use strum::Display;
+use strum::VariantArray;
+use uuid::Uuid;
+
+/// The scores an ability can have.
+const ABILITY_SCORES: RangeInclusive<i32> = 1..=30;
+
+/// The ways a creature can move, each with its own speed.
+#[derive(Debug, Display, VariantArray, Clone, Copy, PartialEq, Eq)]
+pub enum Movement {
+    Walk,
+    Burrow,
+    Climb,
+    Fly,
+    Swim,
+}
+
+/// The special senses of a creature, each with its own range.
+#[derive(Debug, Display, VariantArray, Clone, Copy, PartialEq, Eq)]
+pub enum Sense {
+    Blindsight,
+    Darkvision,
+    Tremorsense,
+    Truesight,
+}
+
+/// The kinds of features a stat block lists, each in its own section.
+#[derive(Debug, Display, VariantArray, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureKind {
+    Trait,
+    Action,
+    Reaction,
+    #[strum(to_string = "Legendary Action")]
+    LegendaryAction,
+    #[strum(to_string = "Lair Action")]
+    LairAction,
+}
+
+/// The written stat block of a creature.
+///
+/// Saving throw and skill bonuses follow from the ability scores and the proficiency bonus of the
+/// challenge rating, so only whether the creature is proficient in them is edited.
+pub struct Sheet {
+    pub name: TextFieldState,
+    pub size: SelectFieldState<Size>,
+    pub kind: SelectFieldState<CreatureType>,
+    pub alignment: SelectFieldState<Alignment>,
+    pub challenge_rating: SelectFieldState<ChallengeRating>,
+    pub source: SelectFieldState<String>,
+    pub environments: HashSet<Environment>,
+    pub armor_class: NumberFieldState,
+    pub armor_description: TextFieldState,
+    pub hit_dice: TextFieldState,
+
+    /// The speeds in feet, in the order of [`Movement`].
+    pub speeds: [NumberFieldState; 5],
+    pub hover: bool,
+
+    /// The ability scores, in the order of [`Ability`].
+    pub abilities: [NumberFieldState; 6],
+
+    /// The bonus to initiative on top of the Dexterity modifier.
+    pub initiative_bonus: NumberFieldState,
+    pub saving_throws: HashSet<Ability>,
+    pub skills: HashSet<Skill>,
+    pub defenses: HashMap<DamageType, Defense>,
+
+    /// The ranges of the special senses in feet, in the order of [`Sense`].
+    pub senses: [NumberFieldState; 4],
+    pub languages: MultiTextFieldState,
+
+    /// The features of each kind, in the order of [`FeatureKind`].
+    pub features: [Vec<FeatureFields>; 5],
+
+    /// The number of legendary actions the creature can take each round, if it has any.
+    pub legendary_action_count: NumberFieldState,
+
+    /// Why the creature could not be saved, if it failed validation.
+    pub error: Option<&'static str>,
+}
+
+/// The fields of a single trait, action, reaction, legendary or lair action.
+pub struct FeatureFields {
+    pub name: TextFieldState,
+    pub description: TextAreaFieldState,
+}
+
+impl FeatureFields {
+    fn new(feature: Feature) -> Self {
+        Self {
+            name: TextFieldState::new(feature.name).required(true),
+            description: TextAreaFieldState::new(feature.description).required(true),
+        }
+    }
+}
+
+impl Sheet {
+    /// A blank sheet, where the source is picked from `sources`.
+    pub fn new(sources: Box<[String]>) -> Self {
+        Self {
+            name: TextFieldState::default().required(true),
+            size: SelectFieldState::new(Size::VARIANTS.iter().copied(), Some(Size::Medium))
+                .required(true),
+            kind: SelectFieldState::new(CreatureType::VARIANTS.iter().copied(), None)
+                .required(true),
+            alignment: SelectFieldState::new(Alignment::VARIANTS.iter().copied(), None)
+                .required(true),
+            challenge_rating: SelectFieldState::new(ChallengeRating::all(), None).required(true),
+            source: SelectFieldState::new(sources, None),
+            environments: HashSet::new(),
+            armor_class: NumberFieldState::new(Some(10))
+                .required(true)
+                .non_negative(true),
+            armor_description: TextFieldState::default(),
+            hit_dice: TextFieldState::default().required(true),
+            speeds: [
+                NumberFieldState::new(Some(30))
+                    .required(true)
+                    .non_negative(true),
+                NumberFieldState::default().non_negative(true),
+                NumberFieldState::default().non_negative(true),
+                NumberFieldState::default().non_negative(true),
+                NumberFieldState::default().non_negative(true),
+            ],
+            hover: false,
+            abilities: std::array::from_fn(|_| {
+                NumberFieldState::new(Some(10))
+                    .required(true)
+                    .non_negative(true)
+            }),
+            initiative_bonus: NumberFieldState::new(Some(0)).required(true),
+            saving_throws: HashSet::new(),
+            skills: HashSet::new(),
+            defenses: HashMap::new(),
+            senses: std::array::from_fn(|_| NumberFieldState::default().non_negative(true)),
+            languages: MultiTextFieldState::default(),
+            features: std::array::from_fn(|_| Vec::new()),
+            legendary_action_count: NumberFieldState::new(Some(3))
+                .required(true)
+                .non_negative(true),
+            error: None,
+        }
+    }
+
+    pub fn from_creature(creature: Creature, sources: Box<[String]>) -> Self {
+        let mut sheet = Self::new(sources);
+
+        sheet.name.set(creature.name);
+        sheet.size.set(creature.size);
+        sheet.kind.set(creature.kind);
+        sheet.alignment.set(creature.alignment);
+        sheet.challenge_rating.set(creature.challenge_rating);
+        if let Some(source) = creature.source {
+            sheet.source.set(source.to_string());
+        }
+        sheet.environments = creature.environments.into_iter().collect();
+        sheet.armor_class.set(creature.armor_class.to_string());
+        sheet
+            .armor_description
+            .set(creature.armor_description.unwrap_or_default());
+        sheet.hit_dice.set(creature.hit_dice.to_string());
+
+        let speeds = creature.speeds;
+        for (movement, speed) in [
+            (Movement::Walk, Some(speeds.walk)),
+            (Movement::Burrow, speeds.burrow),
+            (Movement::Climb, speeds.climb),
+            (Movement::Fly, speeds.fly),
+            (Movement::Swim, speeds.swim),
+        ] {
+            if let Some(speed) = speed {
+                sheet.speeds[movement as usize].set(speed.to_string());
+            }
+        }
+        sheet.hover = speeds.hover;
+
+        for (field, score) in sheet.abilities.iter_mut().zip(creature.abilities.scores()) {
+            field.set(score.to_string());
+        }
+        sheet
+            .initiative_bonus
+            .set(creature.initiative_bonus.to_string());
+
+        sheet.saving_throws = creature
+            .saving_throws
+            .iter()
+            .map(|bonus| bonus.target)
+            .collect();
+        sheet.skills = creature.skills.iter().map(|bonus| bonus.target).collect();
+        sheet.defenses = creature
+            .damage_defenses
+            .iter()
+            .map(|defense| (defense.damage_type, defense.defense))
+            .collect();
+
+        let senses = creature.senses;
+        for (sense, range) in [
+            (Sense::Blindsight, senses.blindsight),
+            (Sense::Darkvision, senses.darkvision),
+            (Sense::Tremorsense, senses.tremorsense),
+            (Sense::Truesight, senses.truesight),
+        ] {
+            if let Some(range) = range {
+                sheet.senses[sense as usize].set(range.to_string());
+            }
+        }
+
+        sheet.languages.set_selections(creature.languages);
+
+        for (kind, features) in [
+            (FeatureKind::Trait, creature.traits),
+            (FeatureKind::Action, creature.actions),
+            (FeatureKind::Reaction, creature.reactions),
+            (FeatureKind::LegendaryAction, creature.legendary_actions),
+            (FeatureKind::LairAction, creature.lair_actions),
+        ] {
+            sheet.features[kind as usize] = features.into_iter().map(FeatureFields::new).collect();
+        }
+        sheet
+            .legendary_action_count
+            .set(creature.legendary_action_count.to_string());
+
+        sheet
+    }
+
+    /// Move the defense against `damage_type` to the next one, from none, to resistance, to
+    /// immunity, to vulnerability and back to none.
+    pub fn cycle_defense(&mut self, damage_type: DamageType) {
+        let next = match self.defenses.get(&damage_type) {
+            None => Some(Defense::Resistance),
+            Some(Defense::Resistance) => Some(Defense::Immunity),
+            Some(Defense::Immunity) => Some(Defense::Vulnerability),
+            Some(Defense::Vulnerability) => None,
+        };
+
+        match next {
+            Some(defense) => self.defenses.insert(damage_type, defense),
+            None => self.defenses.remove(&damage_type),
+        };
+    }
+
+    pub fn add_feature(&mut self, kind: FeatureKind) {
+        let feature = Feature {
+            name: String::new(),
+            description: String::new(),
+        };
+
+        self.features[kind as usize].push(FeatureFields::new(feature));
+    }
+
+    pub fn remove_feature(&mut self, kind: FeatureKind, index: usize) {
+        let features = &mut self.features[kind as usize];
+
+        if index < features.len() {
+            features.remove(index);
+        }
+    }
+
+    pub fn feature_mut(&mut self, kind: FeatureKind, index: usize) -> Option<&mut FeatureFields> {
+        self.features[kind as usize].get_mut(index)
+    }
+
+    /// The modifier of the written score of `ability`, if it is a valid score.
+    pub fn modifier(&self, ability: Ability) -> Option<i32> {
+        let score = parse_within(self.abilities[ability as usize].raw_value(), ABILITY_SCORES)?;
+
+        Some(Ability::modifier(score))
+    }
+
+    /// The bonus to initiative, the Dexterity modifier and the written bonus, if both are valid.
+    pub fn initiative(&self) -> Option<i32> {
+        let bonus = self.initiative_bonus.raw_value().parse::<i32>().ok()?;
+
+        Some(self.modifier(Ability::Dexterity)? + bonus)
+    }
+
+    /// The proficiency bonus of the selected challenge rating.
+    pub fn proficiency_bonus(&self) -> i32 {
+        self.challenge_rating
+            .selected()
+            .copied()
+            .unwrap_or(ChallengeRating::ZERO)
+            .proficiency_bonus()
+    }
+
+    /// The bonus to saving throws of `ability`, including proficiency if the creature has it.
+    pub fn saving_throw_bonus(&self, ability: Ability) -> i32 {
+        let proficiency = if self.saving_throws.contains(&ability) {
+            self.proficiency_bonus()
+        } else {
+            0
+        };
+
+        self.modifier(ability).unwrap_or_default() + proficiency
+    }
+
+    /// The bonus to checks of `skill`, including proficiency if the creature has it.
+    pub fn skill_bonus(&self, skill: Skill) -> i32 {
+        let proficiency = if self.skills.contains(&skill) {
+            self.proficiency_bonus()
+        } else {
+            0
+        };
+
+        self.modifier(skill.ability()).unwrap_or_default() + proficiency
+    }
+
+    /// The passive Perception, which follows from the Perception bonus.
+    pub fn passive_perception(&self) -> i32 {
+        10 + self.skill_bonus(Skill::Perception)
+    }
+
+    /// The average hit points of the written hit dice, if they are a valid formula.
+    pub fn hit_points(&self) -> Option<i32> {
+        self.hit_dice
+            .value()
+            .parse::<Dice>()
+            .ok()
+            .map(|dice| dice.average().max(1))
+    }
+
+    /// Validate every field and construct the creature with `id` from the sheet.
+    ///
+    /// Returns `None` if the sheet is invalid, in which case the errors are displayed.
+    pub fn try_new_creature(&mut self, id: Uuid) -> Option<NewCreature> {
+        // Every field is validated before returning, so all errors are shown at once.
+        let name = self.name.try_value();
+        let size = self.size.try_value();
+        let kind = self.kind.try_value();
+        let alignment = self.alignment.try_value();
+        let challenge_rating = self.challenge_rating.try_value();
+        let source = self.source.try_value();
+        let armor_class = self.armor_class.try_value();
+        let hit_dice = self.hit_dice.try_value();
+        let speeds = self.speeds.each_mut().map(NumberFieldState::try_value);
+        let abilities = self.abilities.each_mut().map(NumberFieldState::try_value);
+        let initiative_bonus = self.initiative_bonus.try_value();
+        let senses = self.senses.each_mut().map(NumberFieldState::try_value);
+        let languages = self.languages.try_value();
+        let legendary_action_count = self.legendary_action_count.try_value();
+        let features = self.features.each_mut().map(|features| {
+            features
+                .iter_mut()
+                .map(|feature| {
+                    let name = feature.name.try_value();
+                    let description = feature.description.try_value();
+
+                    Some(Feature {
+                        name: name?,
+                        description: description?,
+                    })
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let (name, size, kind, alignment, challenge_rating, armor_class, hit_dice, languages) = (
+            name?,
+            size?,
+            kind?,
+            alignment?,
+            challenge_rating?,
+            armor_class?,
+            hit_dice?,
+            languages?,
+        );
+        let [walk, burrow, climb, fly, swim] = speeds;
+        let [blindsight, darkvision, tremorsense, truesight] = senses;
+        let abilities = abilities
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .and_then(|abilities| <[i32; 6]>::try_from(abilities).ok())?;
+        let [traits, actions, reactions, legendary_actions, lair_actions] =
+            features.map(|features| features.into_iter().collect::<Option<Vec<_>>>());
+        let (traits, actions, reactions, legendary_actions, lair_actions) = (
+            traits?,
+            actions?,
+            reactions?,
+            legendary_actions?,
+            lair_actions?,
+        );
+
+        let hit_dice = hit_dice.parse::<Dice>();
+
+        self.error = if hit_dice.is_err() {
+            Some("The hit dice must be a formula like 2d8 + 4.")
+        } else if !abilities.iter().all(|score| ABILITY_SCORES.contains(score)) {
+            Some("Every ability score must be between 1 and 30.")
+        } else {
+            None
+        };
+
+        let Ok(hit_dice) = hit_dice else {
+            return None;
+        };
+
+        if self.error.is_some() {
+            return None;
+        }
+
+        let distance = |value: Option<i32>| value.and_then(|value| u32::try_from(value).ok());
+
+        let armor_description = self.armor_description.value().trim();
+        let armor_description =
+            (!armor_description.is_empty()).then(|| String::from(armor_description));
+
+        let saving_throws = Ability::VARIANTS
+            .iter()
+            .filter(|ability| self.saving_throws.contains(ability))
+            .map(|&ability| Bonus {
+                target: ability,
+                bonus: self.saving_throw_bonus(ability),
+            })
+            .collect();
+
+        let skills = Skill::VARIANTS
+            .iter()
+            .filter(|skill| self.skills.contains(skill))
+            .map(|&skill| Bonus {
+                target: skill,
+                bonus: self.skill_bonus(skill),
+            })
+            .collect();
+
+        let environments = Environment::VARIANTS
+            .iter()
+            .filter(|environment| self.environments.contains(environment))
+            .copied()
+            .collect();
+
+        let damage_defenses = DamageType::VARIANTS
+            .iter()
+            .filter_map(|&damage_type| {
+                let defense = *self.defenses.get(&damage_type)?;
+
+                Some(DamageDefense {
+                    damage_type,
+                    defense,
+                })
+            })
+            .collect();
+
+        let creature = Creature {
+            id,
+            name,
+            size,
+            kind,
+            alignment,
+            armor_class: u32::try_from(armor_class).ok()?,
+            armor_description,
+            hit_dice,
+            speeds: Speeds {
+                walk: distance(walk)?,
+                burrow: distance(burrow),
+                climb: distance(climb),
+                fly: distance(fly),
+                swim: distance(swim),
+                hover: self.hover && fly.is_some(),
+            },
+            abilities: AbilityScores::new(abilities),
+            initiative_bonus: initiative_bonus?,
+            saving_throws,
+            skills,
+            damage_defenses,
+            senses: Senses {
+                blindsight: distance(blindsight),
+                darkvision: distance(darkvision),
+                tremorsense: distance(tremorsense),
+                truesight: distance(truesight),
+                passive_perception: self.passive_perception(),
+            },
+            languages: languages.into_vec(),
+            challenge_rating,
+            environments,
+            source: source.as_deref().map(Choice::parse),
+            traits,
+            actions,
+            reactions,
+            legendary_actions,
+            legendary_action_count: u32::try_from(legendary_action_count?).ok()?,
+            lair_actions,
+        };
+
+        Some(NewCreature::from(&creature))
+    }
+}
+
+/// Parse a written number, if it is within `range`.
+fn parse_within(value: &str, range: RangeInclusive<i32>) -> Option<i32> {
+    value.parse().ok().filter(|value| range.contains(value))
+}
@@ -0,0 +1,28 @@
+use crate::view::request::Request;
+use storage::Error;
+use storage::records::creature::CreatureSummary;
+use types::ChallengeRating;
+use types::CreatureType;
+use types::Environment;
+use types::Size;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    OpenNewCreature,
+    OpenCreature(Uuid),
+    MinChallengeSelected(ChallengeRating),
+    MaxChallengeSelected(ChallengeRating),
+    KindSelected(CreatureType),
+    SizeSelected(Size),
+    EnvironmentSelected(Environment),
+    SourceSelected(String),
+    FiltersCleared,
+    CreaturesLoaded(Result<Box<[CreatureSummary]>, Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    OpenView(Request),
+}
@@ -0,0 +1,300 @@
+pub mod message;
+
+use crate::context::Context;
+use crate::view::Viewable;
+use crate::view::creature::list::message::Effect;
+use crate::view::creature::list::message::Message;
+use crate::view::request::Request;
+use components::select_field::SelectFieldState;
+use storage::Error;
+use storage::records::creature::CreatureSummary;
+use storage::repositories::creatures::CreaturesRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::SECTION_SPACING;
+use types::ChallengeRating;
+use types::CreatureType;
+use types::Environment;
+use types::FormMode;
+use types::Size;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+use std::collections::BTreeSet;
+use strum::VariantArray;
+
+/// The bestiary, listing every creature with filters for what kind of creature to look for.
+pub struct CreatureList {
+    context: Context,
+
+    /// The creatures of the list, `None` while they are loading.
+    creatures: Option<Box<[CreatureSummary]>>,
+
+    /// The lowest challenge rating of the listed creatures.
+    min_challenge: SelectFieldState<ChallengeRating>,
+
+    /// The highest challenge rating of the listed creatures.
+    max_challenge: SelectFieldState<ChallengeRating>,
+
+    kind: SelectFieldState<CreatureType>,
+    size: SelectFieldState<Size>,
+    environment: SelectFieldState<Environment>,
+
+    /// The source of the listed creatures, picked from the sources of the loaded creatures.
+    source: SelectFieldState<String>,
+}
+
+impl CreatureList {
+    pub fn new(context: Context) -> (Self, Task<Message>) {
+        let mut creature_list = Self {
+            context,
+            creatures: None,
+            min_challenge: SelectFieldState::new(ChallengeRating::all(), None),
+            max_challenge: SelectFieldState::new(ChallengeRating::all(), None),
+            kind: SelectFieldState::new(CreatureType::VARIANTS.iter().copied(), None),
+            size: SelectFieldState::new(Size::VARIANTS.iter().copied(), None),
+            environment: SelectFieldState::new(Environment::VARIANTS.iter().copied(), None),
+            source: SelectFieldState::new([], None),
+        };
+
+        let task = creature_list.reload();
+
+        (creature_list, task)
+    }
+
+    /// Reload the creatures of the list.
+    pub fn reload(&mut self) -> Task<Message> {
+        Task::perform(
+            load_creatures(self.context.clone()),
+            Message::CreaturesLoaded,
+        )
+    }
+
+    /// Clear every filter, listing all creatures again.
+    fn clear_filters(&mut self) {
+        self.min_challenge.clear();
+        self.max_challenge.clear();
+        self.kind.clear();
+        self.size.clear();
+        self.environment.clear();
+        self.source.clear();
+    }
+
+    /// The creatures matching every selected filter.
+    fn visible<'a>(&self, creatures: &'a [CreatureSummary]) -> Vec<&'a CreatureSummary> {
+        let min = self.min_challenge.selected();
+        let max = self.max_challenge.selected();
+        let kind = self.kind.selected().map(ToString::to_string);
+        let size = self.size.selected().map(ToString::to_string);
+        let environment = self.environment.selected().map(ToString::to_string);
+        let source = self.source.selected();
+
+        creatures
+            .iter()
+            .filter(|creature| {
+                if min.is_none() && max.is_none() {
+                    return true;
+                }
+
+                let Ok(rating) = creature.challenge_rating.parse::<ChallengeRating>() else {
+                    return false;
+                };
+
+                min.is_none_or(|min| rating >= *min) && max.is_none_or(|max| rating <= *max)
+            })
+            .filter(|creature| kind.as_ref().is_none_or(|kind| creature.kind == *kind))
+            .filter(|creature| size.as_ref().is_none_or(|size| creature.size == *size))
+            .filter(|creature| {
+                environment
+                    .as_ref()
+                    .is_none_or(|environment| creature.environments.contains(environment))
+            })
+            .filter(|creature| source.is_none_or(|source| creature.source.as_ref() == Some(source)))
+            .collect()
+    }
+}
+
+impl Viewable for CreatureList {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        "Bestiary"
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::OpenNewCreature => {
+                let request = Request::CreatureForm {
+                    mode: FormMode::Create,
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenCreature(id) => {
+                let request = Request::CreatureDetail { id };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::MinChallengeSelected(rating) => {
+                self.min_challenge.set(rating);
+
+                (Task::none(), None)
+            }
+            Message::MaxChallengeSelected(rating) => {
+                self.max_challenge.set(rating);
+
+                (Task::none(), None)
+            }
+            Message::KindSelected(kind) => {
+                self.kind.set(kind);
+
+                (Task::none(), None)
+            }
+            Message::SizeSelected(size) => {
+                self.size.set(size);
+
+                (Task::none(), None)
+            }
+            Message::EnvironmentSelected(environment) => {
+                self.environment.set(environment);
+
+                (Task::none(), None)
+            }
+            Message::SourceSelected(source) => {
+                self.source.set(source);
+
+                (Task::none(), None)
+            }
+            Message::FiltersCleared => {
+                self.clear_filters();
+
+                (Task::none(), None)
+            }
+            Message::CreaturesLoaded(Ok(creatures)) => {
+                let sources: BTreeSet<_> = creatures
+                    .iter()
+                    .filter_map(|creature| creature.source.clone())
+                    .collect();
+
+                // The selected source is kept, even if no creature of it remains.
+                let selected = self.source.selected().cloned();
+                self.source = SelectFieldState::new(sources, selected);
+                self.creatures = Some(creatures);
+
+                (Task::none(), None)
+            }
+            Message::CreaturesLoaded(Err(err)) => {
+                tracing::error!("failed to load creatures: {err:?}");
+
+                (Task::none(), None)
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let title = components::text::view_title("Bestiary");
+
+        let create_creature_button =
+            widget::button("Create New Creature").on_press(Message::OpenNewCreature);
+
+        let creatures: Element<_> = match &self.creatures {
+            None => components::text::detail("Loading creatures...").into(),
+            Some(creatures) if creatures.is_empty() => {
+                components::text::detail("No creatures have been catalogued yet.").into()
+            }
+            Some(creatures) => {
+                let visible = self.visible(creatures);
+
+                let mut rows = Column::with_capacity(visible.len()).spacing(BODY_SPACING);
+
+                if visible.is_empty() {
+                    rows = rows.push(components::text::detail(
+                        "No creatures match these filters.",
+                    ));
+                }
+
+                for creature in visible {
+                    let name = components::text::display(&creature.name);
+                    let classification = components::text::detail(format!(
+                        "{} {} · AC {} · CR {}",
+                        creature.size,
+                        creature.kind,
+                        creature.armor_class,
+                        creature.challenge_rating
+                    ));
+                    let content = row![
+                        name,
+                        widget::space::horizontal().width(Length::Fill),
+                        classification
+                    ]
+                    .align_y(Alignment::Center);
+
+                    let button = widget::button(content)
+                        .class(ButtonClass::Interaction)
+                        .padding(INPUT_PADDING)
+                        .width(Length::Fill)
+                        .on_press(Message::OpenCreature(creature.id));
+
+                    rows = rows.push(button);
+                }
+
+                rows.into()
+            }
+        };
+
+        let min_challenge = components::select_field(
+            "MIN CHALLENGE",
+            &self.min_challenge,
+            Message::MinChallengeSelected,
+        )
+        .placeholder("Any");
+        let max_challenge = components::select_field(
+            "MAX CHALLENGE",
+            &self.max_challenge,
+            Message::MaxChallengeSelected,
+        )
+        .placeholder("Any");
+        let kind =
+            components::select_field("TYPE", &self.kind, Message::KindSelected).placeholder("Any");
+        let size =
+            components::select_field("SIZE", &self.size, Message::SizeSelected).placeholder("Any");
+        let environment = components::select_field(
+            "ENVIRONMENT",
+            &self.environment,
+            Message::EnvironmentSelected,
+        )
+        .placeholder("Any");
+        let source = components::select_field("SOURCE", &self.source, Message::SourceSelected)
+            .placeholder("Any");
+
+        let clear_filters_button =
+            widget::button("Clear Filters").on_press(Message::FiltersCleared);
+
+        let controls = column![
+            row![min_challenge, max_challenge, kind].spacing(BODY_SPACING),
+            row![size, environment, source].spacing(BODY_SPACING),
+            clear_filters_button,
+        ]
+        .spacing(BODY_SPACING);
+
+        column![title, create_creature_button, controls, creatures]
+            .spacing(SECTION_SPACING)
+            .into()
+    }
+}
+
+async fn load_creatures<C: CreaturesRepository>(ctx: C) -> Result<Box<[CreatureSummary]>, Error> {
+    let repo = ctx.creatures();
+    repo.list().await
+}
@@ -0,0 +1,6 @@
+pub mod detail;
+pub mod form;
+pub mod list;
+
+/// The abbreviated names of the abilities, in the order of [`types::Ability`].
+const ABILITY_LABELS: [&str; 6] = ["STR", "DEX", "CON", "INT", "WIS", "CHA"];
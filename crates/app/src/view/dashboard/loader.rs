@@ -0,0 +1,106 @@
+use crate::context::Context;
+use storage::Error;
+use storage::repositories::creatures::CreaturesRepository;
+use storage::repositories::encounters::EncountersRepository;
+use storage::repositories::spellbooks::SpellbooksRepository;
+use storage::repositories::spells::SpellsRepository;
+
+use iced::Task;
+
+#[derive(Debug, Clone)]
+#[expect(
+    clippy::enum_variant_names,
+    reason = "every variant is the result of a load task"
+)]
+pub enum LoadMessage {
+    SpellsCounted(Result<u32, Error>),
+    SpellbooksCounted(Result<u32, Error>),
+    CreaturesCounted(Result<u32, Error>),
+    EncountersCounted(Result<u32, Error>),
+}
+
+/// Counts the entries of every archive, which is done once every count is in.
+pub struct Loader {
+    /// The load this loader is for, as the dashboard may reload before every count is in.
+    pub generation: u64,
+
+    pub error: Option<Error>,
+
+    pub spells: Option<u32>,
+    pub spellbooks: Option<u32>,
+    pub creatures: Option<u32>,
+    pub encounters: Option<u32>,
+}
+
+impl Loader {
+    pub fn new(context: Context, generation: u64) -> (Self, Task<LoadMessage>) {
+        let tasks = vec![
+            Task::perform(count_spells(context.clone()), LoadMessage::SpellsCounted),
+            Task::perform(
+                count_spellbooks(context.clone()),
+                LoadMessage::SpellbooksCounted,
+            ),
+            Task::perform(
+                count_creatures(context.clone()),
+                LoadMessage::CreaturesCounted,
+            ),
+            Task::perform(count_encounters(context), LoadMessage::EncountersCounted),
+        ];
+
+        let loader = Self {
+            generation,
+            error: None,
+            spells: None,
+            spellbooks: None,
+            creatures: None,
+            encounters: None,
+        };
+
+        (loader, Task::batch(tasks))
+    }
+
+    pub fn update(&mut self, message: LoadMessage) {
+        match message {
+            LoadMessage::SpellsCounted(Ok(count)) => self.spells = Some(count),
+            LoadMessage::SpellsCounted(Err(err)) => {
+                tracing::error!("failed to count spells: {:?}", err);
+                self.error = Some(err);
+            }
+            LoadMessage::SpellbooksCounted(Ok(count)) => self.spellbooks = Some(count),
+            LoadMessage::SpellbooksCounted(Err(err)) => {
+                tracing::error!("failed to count spellbooks: {:?}", err);
+                self.error = Some(err);
+            }
+            LoadMessage::CreaturesCounted(Ok(count)) => self.creatures = Some(count),
+            LoadMessage::CreaturesCounted(Err(err)) => {
+                tracing::error!("failed to count creatures: {:?}", err);
+                self.error = Some(err);
+            }
+            LoadMessage::EncountersCounted(Ok(count)) => self.encounters = Some(count),
+            LoadMessage::EncountersCounted(Err(err)) => {
+                tracing::error!("failed to count encounters: {:?}", err);
+                self.error = Some(err);
+            }
+        }
+    }
+}
+
+async fn count_spells<C: SpellsRepository>(ctx: C) -> Result<u32, Error> {
+    let repo = ctx.spells();
+    repo.count().await
+}
+
+async fn count_spellbooks<C: SpellbooksRepository>(ctx: C) -> Result<u32, Error> {
+    let repo = ctx.spellbooks();
+    repo.count().await
+}
+
+async fn count_creatures<C: CreaturesRepository>(ctx: C) -> Result<u32, Error> {
+    let repo = ctx.creatures();
+    repo.count().await
+}
+
+async fn count_encounters<C: EncountersRepository>(ctx: C) -> Result<u32, Error> {
+    let repo = ctx.encounters();
+    repo.count().await
+}
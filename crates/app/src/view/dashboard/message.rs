@@ -1,9 +1,29 @@
+use crate::view::dashboard::loader::LoadMessage;
 use crate::view::request::Request;
+use storage::Error;
+use storage::records::activity::ActivityRecord;
+use storage::records::spell::SpellSummary;
+
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum Message {
+    /// A count of the load with the given generation.
+    CountsLoaded(u64, LoadMessage),
     OpenSpells,
     OpenNewSpell,
+    OpenSpellbooks,
+    OpenNewSpellbook,
+    OpenBestiary,
+    OpenNewCreature,
+    OpenEncounters,
+    OpenNewEncounter,
+    OpenCombat,
+    OpenSpell(Uuid),
+    OpenView(Request),
+    FavouritesLoaded(Result<Box<[SpellSummary]>, Error>),
+    RecentsLoaded(Result<Box<[SpellSummary]>, Error>),
+    ActivitiesLoaded(Result<Box<[ActivityRecord]>, Error>),
 }
 
 #[derive(Debug, Clone)]
@@ -1,11 +1,22 @@
+mod loader;
 pub mod message;
 
+use crate::context::Context;
+use crate::status::Status;
 use crate::view::Viewable;
+use crate::view::dashboard::loader::Loader;
 use crate::view::request::Request;
 use components::icon::IconName;
 use components::icon::IconSize;
 use message::Effect;
 use message::Message;
+use storage::Error;
+use storage::records::activity::ActivityKind;
+use storage::records::activity::ActivityRecord;
+use storage::records::activity::EntityKind;
+use storage::records::spell::SpellSummary;
+use storage::repositories::activities::ActivitiesRepository;
+use storage::repositories::bookmarks::BookmarksRepository;
 use style::button::ButtonClass;
 use style::container::ContainerClass;
 use style::layout::BODY_SPACING;
@@ -25,21 +36,132 @@ use iced::widget::row;
 
 const ITEM_HEIGHT: u32 = 75;
 
-const CREATIONS_PER_ROW: usize = 4;
+const CREATIONS_PER_ROW: usize = 5;
 
-const ARCHIVES_PER_ROW: usize = 3;
+const ARCHIVES_PER_ROW: usize = 4;
 
-const CREATIONS: [(&str, IconName, Message); 1] =
-    [("New Spell", IconName::WandSparkles, Message::OpenNewSpell)];
+const SPELLS_PER_ROW: usize = 3;
 
-const ARCHIVES: [(&str, IconName, Message); 1] =
-    [("Spells", IconName::Library, Message::OpenSpells)];
+/// The number of recently opened spells shown.
+const RECENTS_SHOWN: u32 = 12;
 
-pub struct Dashboard;
+/// The number of latest activities shown in the chronicle.
+const ACTIVITIES_SHOWN: u32 = 20;
+
+const CREATIONS: [(&str, IconName, Message); 5] = [
+    ("New Spell", IconName::WandSparkles, Message::OpenNewSpell),
+    ("New Spellbook", IconName::Spell, Message::OpenNewSpellbook),
+    ("New Creature", IconName::Monster, Message::OpenNewCreature),
+    ("New Encounter", IconName::Plus, Message::OpenNewEncounter),
+    ("Combat", IconName::Sparkle, Message::OpenCombat),
+];
+
+/// The archives, in the same order as the counts of [`Counts::entries`].
+const ARCHIVES: [(&str, IconName, Message); 4] = [
+    ("Spells", IconName::Library, Message::OpenSpells),
+    ("Spellbooks", IconName::Directory, Message::OpenSpellbooks),
+    ("Bestiary", IconName::Monster, Message::OpenBestiary),
+    ("Encounters", IconName::Clipboard, Message::OpenEncounters),
+];
+
+pub struct Dashboard {
+    context: Context,
+
+    /// The number of entries in each archive.
+    counts: Status<Loader, Counts>,
+
+    /// The generation of the latest load, the messages of older loads are ignored.
+    generation: u64,
+
+    /// The favourite spells, `None` while they are loading.
+    favourites: Option<Box<[SpellSummary]>>,
+
+    /// The recently opened spells, `None` while they are loading.
+    recents: Option<Box<[SpellSummary]>>,
+
+    /// The latest activities, newest first, `None` while they are loading.
+    activities: Option<Box<[ActivityRecord]>>,
+}
+
+struct Counts {
+    spells: u32,
+    spellbooks: u32,
+    creatures: u32,
+    encounters: u32,
+}
+
+impl Counts {
+    fn from_loader(loader: &Loader) -> Option<Self> {
+        let counts = Self {
+            spells: loader.spells?,
+            spellbooks: loader.spellbooks?,
+            creatures: loader.creatures?,
+            encounters: loader.encounters?,
+        };
+
+        Some(counts)
+    }
+
+    /// The number of entries of each of the [`ARCHIVES`].
+    fn entries(&self) -> [u32; 4] {
+        [
+            self.spells,
+            self.spellbooks,
+            self.creatures,
+            self.encounters,
+        ]
+    }
+}
 
 impl Dashboard {
-    pub fn new() -> Self {
-        Self
+    pub fn new(context: Context) -> (Self, Task<Message>) {
+        let (loader, task) = Loader::new(context.clone(), 0);
+
+        let dashboard = Self {
+            context,
+            counts: Status::Loading(Box::new(loader)),
+            generation: 0,
+            favourites: None,
+            recents: None,
+            activities: None,
+        };
+
+        let task = Task::batch([
+            task.map(|message| Message::CountsLoaded(0, message)),
+            dashboard.load(),
+        ]);
+
+        (dashboard, task)
+    }
+
+    /// Reload the archive counts, the favourite and recently opened spells, and the latest
+    /// activities.
+    pub fn reload(&mut self) -> Task<Message> {
+        self.generation += 1;
+        let generation = self.generation;
+
+        let (loader, task) = Loader::new(self.context.clone(), generation);
+        self.counts = Status::Loading(Box::new(loader));
+
+        Task::batch([
+            task.map(move |message| Message::CountsLoaded(generation, message)),
+            self.load(),
+        ])
+    }
+
+    /// Load the favourite and recently opened spells, and the latest activities.
+    fn load(&self) -> Task<Message> {
+        Task::batch([
+            Task::perform(
+                load_activities(self.context.clone()),
+                Message::ActivitiesLoaded,
+            ),
+            Task::perform(
+                load_favourites(self.context.clone()),
+                Message::FavouritesLoaded,
+            ),
+            Task::perform(load_recents(self.context.clone()), Message::RecentsLoaded),
+        ])
     }
 }
 
@@ -54,6 +176,23 @@ impl Viewable for Dashboard {
 
     fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
         match message {
+            Message::CountsLoaded(generation, load_message) => {
+                let Some(loader) = self.counts.as_loading_mut() else {
+                    return (Task::none(), None);
+                };
+
+                if loader.generation != generation {
+                    return (Task::none(), None);
+                }
+
+                loader.update(load_message);
+
+                if let Some(counts) = Counts::from_loader(loader) {
+                    self.counts = Status::Ready(Box::new(counts));
+                }
+
+                (Task::none(), None)
+            }
             Message::OpenSpells => {
                 let request = Request::SpellList;
                 let effect = Effect::OpenView(request);
@@ -68,6 +207,91 @@ impl Viewable for Dashboard {
 
                 (Task::none(), Some(effect))
             }
+            Message::OpenSpellbooks => {
+                let request = Request::SpellbookList;
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenNewSpellbook => {
+                let request = Request::SpellbookEditor {
+                    mode: FormMode::Create,
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenBestiary => {
+                let request = Request::CreatureList;
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenNewCreature => {
+                let request = Request::CreatureForm {
+                    mode: FormMode::Create,
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenEncounters => {
+                let request = Request::EncounterList;
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenNewEncounter => {
+                let request = Request::EncounterBuilder {
+                    mode: FormMode::Create,
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenCombat => {
+                let request = Request::CombatTracker { encounter: None };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenSpell(id) => {
+                let request = Request::SpellDetail { id };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenView(request) => (Task::none(), Some(Effect::OpenView(request))),
+            Message::FavouritesLoaded(Ok(favourites)) => {
+                self.favourites = Some(favourites);
+
+                (Task::none(), None)
+            }
+            Message::FavouritesLoaded(Err(err)) => {
+                tracing::error!("failed to load favourite spells: {err:?}");
+
+                (Task::none(), None)
+            }
+            Message::RecentsLoaded(Ok(recents)) => {
+                self.recents = Some(recents);
+
+                (Task::none(), None)
+            }
+            Message::RecentsLoaded(Err(err)) => {
+                tracing::error!("failed to load recent spells: {err:?}");
+
+                (Task::none(), None)
+            }
+            Message::ActivitiesLoaded(Ok(activities)) => {
+                self.activities = Some(activities);
+
+                (Task::none(), None)
+            }
+            Message::ActivitiesLoaded(Err(err)) => {
+                tracing::error!("failed to load activities: {err:?}");
+
+                (Task::none(), None)
+            }
         }
     }
 
@@ -100,9 +324,20 @@ impl Viewable for Dashboard {
                 "The ancient records of your currated collection.",
             );
 
+            let entries = match &self.counts {
+                Status::Loading(loader) if loader.error.is_some() => {
+                    std::array::from_fn(|_| String::from("Unavailable"))
+                }
+                Status::Loading(_) => std::array::from_fn(|_| String::from("Counting...")),
+                Status::Ready(counts) => counts.entries().map(|count| match count {
+                    1 => String::from("1 entry"),
+                    count => format!("{count} entries"),
+                }),
+            };
+
             let mut buttons = Vec::with_capacity(ARCHIVES.len());
-            for (text, icon, message) in ARCHIVES.into_iter() {
-                let button = archive_card(text, icon, message);
+            for ((text, icon, message), entries) in ARCHIVES.into_iter().zip(entries) {
+                let button = archive_card(text, icon, message, entries);
 
                 buttons.push(button);
             }
@@ -116,14 +351,41 @@ impl Viewable for Dashboard {
             column![header, archives].spacing(BODY_SPACING)
         };
 
+        let favourites = {
+            let header =
+                components::form::section_header("FAVOURITES", "The spells kept close at hand.");
+
+            let favourites = spell_cards(
+                self.favourites.as_deref(),
+                "Mark a spell as favourite to keep it here.",
+            );
+
+            column![header, favourites].spacing(BODY_SPACING)
+        };
+
+        let recents = {
+            let header =
+                components::form::section_header("RECENT", "The spells most recently consulted.");
+
+            let recents = spell_cards(self.recents.as_deref(), "No spells have been opened yet.");
+
+            column![header, recents].spacing(BODY_SPACING)
+        };
+
         let chronicles = {
-            components::form::section_header(
+            let header = components::form::section_header(
                 "CHRONICLE",
                 "Witness the unfolding echoes of past decisions.",
-            )
+            );
+
+            let chronicle = chronicle(self.activities.as_deref());
+
+            column![header, chronicle]
+                .spacing(BODY_SPACING)
+                .width(Length::FillPortion(1))
         };
 
-        let column = column![creations, archives]
+        let column = column![creations, archives, favourites, recents]
             .spacing(SECTION_SPACING)
             .width(Length::FillPortion(2));
 
@@ -155,11 +417,15 @@ fn creation_card<'a>(text: &'a str, icon: IconName, message: Message) -> Element
         .into()
 }
 
-fn archive_card<'a>(text: &'a str, icon: IconName, message: Message) -> Element<'a, Message> {
+fn archive_card<'a>(
+    text: &'a str,
+    icon: IconName,
+    message: Message,
+    entries: String,
+) -> Element<'a, Message> {
     let label = {
         let label = components::text::display(text);
-        let entries_text = format!("{} entries", 5);
-        let entries = components::text::detail(entries_text);
+        let entries = components::text::detail(entries);
 
         column![
             widget::space().height(Length::Fill),
@@ -186,3 +452,124 @@ fn archive_card<'a>(text: &'a str, icon: IconName, message: Message) -> Element<
         .on_press(message)
         .into()
 }
+
+/// A grid of buttons opening `spells`, or `empty` if there are none.
+fn spell_cards<'a>(spells: Option<&'a [SpellSummary]>, empty: &'a str) -> Element<'a, Message> {
+    let content: Element<_> = match spells {
+        None => components::text::detail("Loading spells...").into(),
+        Some([]) => components::text::detail(empty).into(),
+        Some(spells) => {
+            let mut buttons = Vec::with_capacity(spells.len());
+            for spell in spells {
+                buttons.push(spell_card(spell));
+            }
+
+            components::row::chunked(buttons, SPELLS_PER_ROW)
+        }
+    };
+
+    widget::container(content)
+        .class(ContainerClass::Surface)
+        .padding(INPUT_PADDING)
+        .width(Length::Fill)
+        .into()
+}
+
+fn spell_card(spell: &SpellSummary) -> Element<'_, Message> {
+    let name = components::text::display(&spell.name);
+    let classification = components::text::detail(format!("{} {}", spell.level, spell.school));
+
+    let column = column![name, classification].spacing(LABEL_SPACING);
+
+    widget::button(column)
+        .class(ButtonClass::Interaction)
+        .width(Length::FillPortion(1))
+        .padding(INPUT_PADDING)
+        .on_press(Message::OpenSpell(spell.id))
+        .into()
+}
+
+/// The latest `activities`, each opening the view of what it is about if it still exists.
+fn chronicle(activities: Option<&[ActivityRecord]>) -> Element<'_, Message> {
+    let content: Element<_> = match activities {
+        None => components::text::detail("Loading the chronicle...").into(),
+        Some([]) => components::text::detail("Nothing has happened yet.").into(),
+        Some(activities) => {
+            let mut column = widget::Column::with_capacity(activities.len()).spacing(LABEL_SPACING);
+            for (index, activity) in activities.iter().enumerate() {
+                let request = activity_request(activity, &activities[..index]);
+                column = column.push(activity_card(activity, request));
+            }
+
+            column.into()
+        }
+    };
+
+    widget::container(content)
+        .class(ContainerClass::Surface)
+        .padding(INPUT_PADDING)
+        .width(Length::Fill)
+        .into()
+}
+
+fn activity_card(activity: &ActivityRecord, request: Option<Request>) -> Element<'_, Message> {
+    let kind = components::text::label(format!(
+        "{} {}",
+        activity.kind,
+        activity.entity.to_string().to_lowercase()
+    ));
+    let name = components::text::display(&activity.name);
+    let occurred_at = components::text::detail(&activity.occurred_at);
+
+    let column = column![kind, name, occurred_at].spacing(LABEL_SPACING);
+
+    widget::button(column)
+        .class(ButtonClass::Interaction)
+        .width(Length::Fill)
+        .padding(INPUT_PADDING)
+        .on_press_maybe(request.map(Message::OpenView))
+        .into()
+}
+
+/// The view to open for `activity`, `None` if there is nothing left to open.
+///
+/// The `newer` activities are the ones that happened after, newest first, which tell whether
+/// what the activity is about has since been deleted.
+fn activity_request(activity: &ActivityRecord, newer: &[ActivityRecord]) -> Option<Request> {
+    let latest = newer
+        .iter()
+        .find(|newer| newer.entity_id == activity.entity_id)
+        .unwrap_or(activity);
+
+    if latest.kind == ActivityKind::Deleted {
+        return None;
+    }
+
+    let id = activity.entity_id;
+
+    match activity.entity {
+        EntityKind::Spell => Some(Request::SpellDetail { id }),
+        EntityKind::Spellbook => Some(Request::SpellbookTracker { id }),
+        EntityKind::Creature => Some(Request::CreatureDetail { id }),
+        EntityKind::Encounter => Some(Request::EncounterBuilder {
+            mode: FormMode::Edit(id),
+        }),
+        // A fight that ended has no view of its own.
+        EntityKind::Combat => None,
+    }
+}
+
+async fn load_activities<C: ActivitiesRepository>(ctx: C) -> Result<Box<[ActivityRecord]>, Error> {
+    let repo = ctx.activities();
+    repo.latest(ACTIVITIES_SHOWN).await
+}
+
+async fn load_favourites<C: BookmarksRepository>(ctx: C) -> Result<Box<[SpellSummary]>, Error> {
+    let repo = ctx.bookmarks();
+    repo.favourites().await
+}
+
+async fn load_recents<C: BookmarksRepository>(ctx: C) -> Result<Box<[SpellSummary]>, Error> {
+    let repo = ctx.bookmarks();
+    repo.recents(RECENTS_SHOWN).await
+}
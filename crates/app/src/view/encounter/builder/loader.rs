@@ -0,0 +1,88 @@
+use crate::context::Context;
+use storage::Error;
+use storage::records::creature::CreatureSummary;
+use storage::records::encounter::EncounterRecord;
+use storage::repositories::creatures::CreaturesRepository;
+use storage::repositories::encounters::EncountersRepository;
+use types::FormMode;
+
+use iced::Task;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum LoadMessage {
+    EncounterLoaded(Result<Box<EncounterRecord>, Error>),
+    CreaturesLoaded(Result<Box<[CreatureSummary]>, Error>),
+}
+
+pub struct Loader {
+    /// The number of tasks in total the loader must complete.
+    pub total: usize,
+
+    /// The number of tasks that have been completed.
+    pub progress: usize,
+
+    pub error: Option<Error>,
+
+    /// The encounter being edited, only loaded in [`FormMode::Edit`].
+    pub encounter: Option<EncounterRecord>,
+
+    /// The creatures of the bestiary to pick from.
+    pub creatures: Option<Box<[CreatureSummary]>>,
+}
+
+impl Loader {
+    pub fn new(context: Context, mode: &FormMode) -> (Self, Task<LoadMessage>) {
+        let mut tasks = Vec::with_capacity(2);
+
+        tasks.push(Task::perform(
+            load_creatures(context.clone()),
+            LoadMessage::CreaturesLoaded,
+        ));
+
+        if let FormMode::Edit(id) = mode {
+            let task = Task::perform(load_encounter(context, *id), LoadMessage::EncounterLoaded);
+            tasks.push(task);
+        }
+
+        let loader = Self {
+            total: tasks.len(),
+            progress: 0,
+            error: None,
+            encounter: None,
+            creatures: None,
+        };
+
+        (loader, Task::batch(tasks))
+    }
+
+    pub fn update(&mut self, message: LoadMessage) {
+        match message {
+            LoadMessage::EncounterLoaded(Ok(encounter)) => self.encounter = Some(*encounter),
+            LoadMessage::CreaturesLoaded(Ok(creatures)) => self.creatures = Some(creatures),
+            LoadMessage::EncounterLoaded(Err(err)) | LoadMessage::CreaturesLoaded(Err(err)) => {
+                tracing::error!("failed to load encounter builder: {:?}", err);
+                self.error = Some(err);
+            }
+        }
+
+        self.progress += 1;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress == self.total
+    }
+}
+
+async fn load_encounter<C: EncountersRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<Box<EncounterRecord>, Error> {
+    let repo = ctx.encounters();
+    repo.get(id).await.map(Box::new)
+}
+
+async fn load_creatures<C: CreaturesRepository>(ctx: C) -> Result<Box<[CreatureSummary]>, Error> {
+    let repo = ctx.creatures();
+    repo.list().await
+}
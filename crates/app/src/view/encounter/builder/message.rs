@@ -0,0 +1,55 @@
+use crate::view::encounter::builder::loader::LoadMessage;
+use crate::view::request::Request;
+use storage::Error;
+use types::ChallengeRating;
+use types::Difficulty;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    LoadMessage(LoadMessage),
+    NameChanged(String),
+    CharacterAdded,
+    CharacterRemoved(usize),
+    LevelChanged(usize, String),
+    SearchChanged(String),
+    CreatureAdded(Uuid),
+    CountIncreased(Uuid),
+    CountDecreased(Uuid),
+    CreatureRemoved(Uuid),
+    DifficultySelected(Difficulty),
+    EnvironmentSelected(String),
+    EnvironmentCleared,
+    KindSelected(String),
+    KindCleared,
+    MinChallengeSelected(ChallengeRating),
+    MinChallengeCleared,
+    MaxChallengeSelected(ChallengeRating),
+    MaxChallengeCleared,
+    SeedChanged(String),
+    SeedRolled,
+    Generated,
+    Rerolled(Uuid),
+    LaunchRequested,
+    Submitted,
+    Saved(Result<Uuid, Error>),
+    DeleteRequested,
+    Deleted(Result<(), Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    LoadFailed(Error),
+
+    OpenView(Request),
+
+    /// The encounter was created or updated.
+    Saved,
+
+    /// The encounter could not be saved, either due to invalid fields or a storage error.
+    SaveFailed,
+
+    /// The encounter was deleted.
+    Deleted,
+}
@@ -0,0 +1,766 @@
+mod loader;
+pub mod message;
+mod sheet;
+
+use crate::context::Context;
+use crate::status::Status;
+use crate::status::loading;
+use crate::status::ready;
+use crate::view::Viewable;
+use crate::view::encounter::builder::loader::Loader;
+use crate::view::encounter::builder::message::Effect;
+use crate::view::encounter::builder::message::Message;
+use crate::view::encounter::builder::sheet::Sheet;
+use crate::view::request::Request;
+use storage::Error;
+use storage::models::encounter::NewEncounter;
+use storage::repositories::encounters::EncountersRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
+use style::layout::SECTION_SPACING;
+use style::text::TextClass;
+use types::Difficulty;
+use types::FormMode;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length::Fill;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+use uuid::Uuid;
+
+/// The width of the level field of each character.
+const LEVEL_WIDTH: u32 = 80;
+
+pub struct EncounterBuilder {
+    mode: FormMode,
+    context: Context,
+    status: Status<Loader, Sheet>,
+
+    /// Whether the sheet changed since the encounter was last saved.
+    unsaved: bool,
+
+    /// Whether the encounter is currently being saved, used to prevent duplicate submissions.
+    saving: bool,
+}
+
+impl<'a> EncounterBuilder {
+    pub fn new(mode: FormMode, context: Context) -> (Self, Task<Message>) {
+        let (loader, task) = Loader::new(context.clone(), &mode);
+
+        let builder = Self {
+            mode,
+            context,
+            status: Status::Loading(Box::new(loader)),
+            unsaved: false,
+            saving: false,
+        };
+
+        (builder, task.map(Message::LoadMessage))
+    }
+
+    /// The id of the encounter being built, if it has been saved.
+    pub fn id(&self) -> Option<Uuid> {
+        match self.mode {
+            FormMode::Create => None,
+            FormMode::Edit(id) => Some(id),
+        }
+    }
+
+    fn heading() -> Element<'a, Message> {
+        let title = components::text::view_title("Encounter");
+
+        let sub_title = components::text::view_sub_title("A fight planned for a party");
+
+        column![title, sub_title]
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    fn encounter_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header("ENCOUNTER", "The name of the encounter.");
+
+        let name = components::text_field(Some("NAME"), &sheet.name)
+            .placeholder("Goblin Ambush")
+            .on_input(Message::NameChanged);
+
+        let body = components::form::section_body(name);
+
+        row![header, body].into()
+    }
+
+    fn party_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "PARTY",
+            "The levels of the characters the encounter is planned for.",
+        );
+
+        let mut characters = Column::with_capacity(sheet.party.len()).spacing(LABEL_SPACING);
+
+        for (index, level) in sheet.party.iter().enumerate() {
+            let label = components::text::display(format!("Character {}", index + 1));
+            let level = components::number_field(None, level)
+                .placeholder("1")
+                .width(LEVEL_WIDTH)
+                .on_input(move |level| Message::LevelChanged(index, level));
+            let remove = widget::button(components::text::display("Remove"))
+                .class(ButtonClass::Danger)
+                .on_press(Message::CharacterRemoved(index));
+
+            let character = row![
+                label,
+                widget::space::horizontal().width(Fill),
+                level,
+                remove
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+            characters = characters.push(character);
+        }
+
+        let add = widget::button(components::text::display("Add character"))
+            .class(ButtonClass::Secondary)
+            .padding(INPUT_PADDING)
+            .on_press(Message::CharacterAdded);
+
+        let form = column![characters, add].spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn generate_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "GENERATE",
+            "Fill the encounter with random creatures of the bestiary that make for a fight of the \
+             difficulty. The same seed generates the same encounter.",
+        );
+
+        let difficulty =
+            components::select_field("DIFFICULTY", &sheet.difficulty, Message::DifficultySelected)
+                .placeholder("Select a difficulty");
+        let seed = components::number_field(Some("SEED"), &sheet.seed)
+            .placeholder("0")
+            .on_input(Message::SeedChanged);
+
+        let environment = components::select_field(
+            "ENVIRONMENT",
+            &sheet.environment,
+            Message::EnvironmentSelected,
+        )
+        .placeholder("Any environment");
+        let any_environment = widget::button(components::text::display("Any"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                sheet
+                    .environment
+                    .selected()
+                    .is_some()
+                    .then_some(Message::EnvironmentCleared),
+            );
+        let kind = components::select_field("TYPE", &sheet.kind, Message::KindSelected)
+            .placeholder("Any type");
+        let any_kind = widget::button(components::text::display("Any"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                sheet
+                    .kind
+                    .selected()
+                    .is_some()
+                    .then_some(Message::KindCleared),
+            );
+
+        let min_challenge = components::select_field(
+            "MIN CHALLENGE",
+            &sheet.min_challenge,
+            Message::MinChallengeSelected,
+        )
+        .placeholder("Any");
+        let any_min = widget::button(components::text::display("Any"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                sheet
+                    .min_challenge
+                    .selected()
+                    .is_some()
+                    .then_some(Message::MinChallengeCleared),
+            );
+        let max_challenge = components::select_field(
+            "MAX CHALLENGE",
+            &sheet.max_challenge,
+            Message::MaxChallengeSelected,
+        )
+        .placeholder("Any");
+        let any_max = widget::button(components::text::display("Any"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(
+                sheet
+                    .max_challenge
+                    .selected()
+                    .is_some()
+                    .then_some(Message::MaxChallengeCleared),
+            );
+
+        let new_seed = widget::button(components::text::display("New seed"))
+            .class(ButtonClass::Secondary)
+            .padding(INPUT_PADDING)
+            .on_press(Message::SeedRolled);
+        let generate = widget::button(components::text::display("Generate"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press(Message::Generated);
+
+        let row1 = row![difficulty, seed]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::End);
+        let row2 = row![environment, any_environment, kind, any_kind]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::End);
+        let row3 = row![min_challenge, any_min, max_challenge, any_max]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::End);
+        let row4 =
+            row![widget::space::horizontal().width(Fill), new_seed, generate].spacing(BODY_SPACING);
+
+        let form = column![row1, row2, row3, row4].spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn creatures_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "CREATURES",
+            "The creatures the party faces, picked from the bestiary.",
+        );
+
+        let library = Self::library(sheet);
+        let entries = Self::entries(sheet);
+
+        let form = row![library, entries].spacing(SECTION_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn library(sheet: &'a Sheet) -> Element<'a, Message> {
+        let heading = components::text::heading("Bestiary");
+
+        let search = components::text_field(None, &sheet.search)
+            .placeholder("Search creatures")
+            .on_input(Message::SearchChanged);
+
+        let mut rows = Column::new().spacing(LABEL_SPACING);
+
+        for creature in sheet.matches() {
+            let name = components::text::display(&creature.name);
+            let challenge_rating =
+                components::text::detail(format!("CR {}", creature.challenge_rating));
+            let add = widget::button(components::text::display("Add"))
+                .class(ButtonClass::Ghost)
+                .on_press(Message::CreatureAdded(creature.id));
+
+            let creature_row = row![
+                name,
+                challenge_rating,
+                widget::space::horizontal().width(Fill),
+                add
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+            rows = rows.push(creature_row);
+        }
+
+        let creatures: Element<_> = if sheet.library.is_empty() {
+            components::text::detail("The bestiary has no creatures yet.").into()
+        } else {
+            rows.into()
+        };
+
+        column![heading, search, creatures]
+            .spacing(BODY_SPACING)
+            .width(Fill)
+            .into()
+    }
+
+    fn entries(sheet: &'a Sheet) -> Element<'a, Message> {
+        let count: u32 = sheet.entries.iter().map(|entry| entry.count).sum();
+
+        let heading = row![
+            components::text::heading("Encounter"),
+            widget::space::horizontal().width(Fill),
+            components::text::detail(format!("{count} creatures"))
+        ]
+        .align_y(Alignment::Center);
+
+        if sheet.entries.is_empty() {
+            let empty = components::text::detail("No creatures have been added yet.");
+
+            return column![heading, empty]
+                .spacing(BODY_SPACING)
+                .width(Fill)
+                .into();
+        }
+
+        let mut rows = Column::with_capacity(sheet.entries.len()).spacing(LABEL_SPACING);
+
+        for entry in &sheet.entries {
+            let name = components::text::display(&entry.name);
+            let challenge_rating =
+                components::text::detail(format!("CR {}", entry.challenge_rating));
+            let label = column![name, challenge_rating].spacing(LABEL_SPACING);
+
+            let decrease = widget::button(components::text::display("-"))
+                .class(ButtonClass::Ghost)
+                .on_press_maybe(
+                    (entry.count > 1).then_some(Message::CountDecreased(entry.creature_id)),
+                );
+            let count = components::text::display(format!("× {}", entry.count));
+            let increase = widget::button(components::text::display("+"))
+                .class(ButtonClass::Ghost)
+                .on_press(Message::CountIncreased(entry.creature_id));
+            let reroll = widget::button(components::text::display("Reroll"))
+                .class(ButtonClass::Ghost)
+                .on_press(Message::Rerolled(entry.creature_id));
+            let remove = widget::button(components::text::display("Remove"))
+                .class(ButtonClass::Danger)
+                .on_press(Message::CreatureRemoved(entry.creature_id));
+
+            let entry_row = row![
+                label,
+                widget::space::horizontal().width(Fill),
+                decrease,
+                count,
+                increase,
+                reroll,
+                remove
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+            rows = rows.push(entry_row);
+        }
+
+        column![heading, rows]
+            .spacing(BODY_SPACING)
+            .width(Fill)
+            .into()
+    }
+
+    fn difficulty_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "DIFFICULTY",
+            "The XP of the creatures, multiplied for their number, weighed against the thresholds \
+             of the party.",
+        );
+
+        let rating = sheet.rating();
+
+        let stat = |label: &'a str, value: String| {
+            column![
+                components::text::detail(label),
+                components::text::display(value)
+            ]
+            .spacing(LABEL_SPACING)
+            .width(Fill)
+        };
+
+        let budget = row![
+            stat("XP", rating.experience.to_string()),
+            stat("MULTIPLIER", format!("× {}", rating.multiplier)),
+            stat("ADJUSTED XP", rating.adjusted_experience.to_string()),
+            stat("DIFFICULTY", rating.difficulty.to_string()),
+        ]
+        .spacing(BODY_SPACING);
+
+        let thresholds = [
+            (Difficulty::Easy, rating.thresholds.easy),
+            (Difficulty::Medium, rating.thresholds.medium),
+            (Difficulty::Hard, rating.thresholds.hard),
+            (Difficulty::Deadly, rating.thresholds.deadly),
+        ]
+        .into_iter()
+        .map(|(difficulty, threshold)| {
+            // The threshold the encounter reached stands out from the others.
+            let class = if difficulty == rating.difficulty {
+                TextClass::Primary
+            } else {
+                TextClass::Dimmed
+            };
+
+            column![
+                components::text::detail(difficulty.to_string().to_uppercase()).class(class),
+                components::text::display(format!("{threshold} XP")).class(class)
+            ]
+            .spacing(LABEL_SPACING)
+            .width(Fill)
+            .into()
+        });
+
+        let thresholds = widget::Row::with_children(thresholds).spacing(BODY_SPACING);
+
+        let form = column![budget, thresholds].spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn actions(&self, sheet: &'a Sheet) -> Element<'a, Message> {
+        let error = sheet
+            .error
+            .map(|error| components::text::detail(error).class(TextClass::Danger));
+
+        let delete = self.id().map(|_| {
+            widget::button(components::text::display("Delete"))
+                .class(ButtonClass::Danger)
+                .padding(INPUT_PADDING)
+                .on_press(Message::DeleteRequested)
+        });
+
+        // Only what is saved can be run, so unsaved changes must be saved first.
+        let launch = self.id().map(|_| {
+            widget::button(components::text::display("Run in combat"))
+                .class(ButtonClass::Secondary)
+                .padding(INPUT_PADDING)
+                .on_press_maybe((!self.unsaved).then_some(Message::LaunchRequested))
+        });
+
+        let label = if self.saving {
+            "Saving..."
+        } else {
+            "Save encounter"
+        };
+
+        let save = widget::button(components::text::display(label))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe((!self.saving).then_some(Message::Submitted));
+
+        row![
+            delete,
+            widget::space::horizontal().width(Fill),
+            error,
+            launch,
+            save
+        ]
+        .spacing(BODY_SPACING)
+        .align_y(Alignment::Center)
+        .width(Fill)
+        .into()
+    }
+}
+
+impl Viewable for EncounterBuilder {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        match self.mode {
+            FormMode::Create => "Create encounter",
+            FormMode::Edit(_) => "Edit encounter",
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::LoadMessage(load_message) => {
+                let loader = loading!(self.status);
+
+                loader.update(load_message);
+
+                if let Some(err) = loader.error.take() {
+                    tracing::error!("failed to load encounter builder: {:?}", err);
+
+                    return (Task::none(), Some(Effect::LoadFailed(err)));
+                }
+
+                if loader.is_done() {
+                    let library = loader.creatures.take().unwrap_or_default();
+
+                    let sheet = match loader.encounter.take() {
+                        Some(encounter) => Sheet::from_record(encounter, library),
+                        None => Sheet::new(library),
+                    };
+
+                    self.status = Status::Ready(Box::new(sheet));
+                }
+            }
+            Message::NameChanged(name) => {
+                let sheet = ready!(self.status);
+
+                sheet.name.set(name);
+                self.unsaved = true;
+            }
+            Message::CharacterAdded => {
+                let sheet = ready!(self.status);
+
+                sheet.add_character();
+                self.unsaved = true;
+            }
+            Message::CharacterRemoved(index) => {
+                let sheet = ready!(self.status);
+
+                sheet.remove_character(index);
+                self.unsaved = true;
+            }
+            Message::LevelChanged(index, level) => {
+                let sheet = ready!(self.status);
+
+                if let Some(character) = sheet.party.get_mut(index) {
+                    character.set(level);
+                    self.unsaved = true;
+                }
+            }
+            Message::SearchChanged(search) => {
+                let sheet = ready!(self.status);
+
+                sheet.search.set(search);
+            }
+            Message::CreatureAdded(creature_id) => {
+                let sheet = ready!(self.status);
+
+                let creature = sheet
+                    .library
+                    .iter()
+                    .find(|creature| creature.id == creature_id)
+                    .cloned();
+
+                if let Some(creature) = creature {
+                    sheet.add(&creature);
+                    self.unsaved = true;
+                }
+            }
+            Message::CountIncreased(creature_id) => {
+                let sheet = ready!(self.status);
+
+                if let Some(entry) = sheet.entry_mut(creature_id) {
+                    entry.count += 1;
+                    self.unsaved = true;
+                }
+            }
+            Message::CountDecreased(creature_id) => {
+                let sheet = ready!(self.status);
+
+                if let Some(entry) = sheet.entry_mut(creature_id)
+                    && entry.count > 1
+                {
+                    entry.count -= 1;
+                    self.unsaved = true;
+                }
+            }
+            Message::CreatureRemoved(creature_id) => {
+                let sheet = ready!(self.status);
+
+                sheet.remove(creature_id);
+                self.unsaved = true;
+            }
+            Message::DifficultySelected(difficulty) => {
+                let sheet = ready!(self.status);
+
+                sheet.difficulty.set(difficulty);
+            }
+            Message::EnvironmentSelected(environment) => {
+                let sheet = ready!(self.status);
+
+                sheet.environment.set(environment);
+            }
+            Message::EnvironmentCleared => {
+                let sheet = ready!(self.status);
+
+                sheet.environment.clear();
+            }
+            Message::KindSelected(kind) => {
+                let sheet = ready!(self.status);
+
+                sheet.kind.set(kind);
+            }
+            Message::KindCleared => {
+                let sheet = ready!(self.status);
+
+                sheet.kind.clear();
+            }
+            Message::MinChallengeSelected(challenge_rating) => {
+                let sheet = ready!(self.status);
+
+                sheet.min_challenge.set(challenge_rating);
+            }
+            Message::MinChallengeCleared => {
+                let sheet = ready!(self.status);
+
+                sheet.min_challenge.clear();
+            }
+            Message::MaxChallengeSelected(challenge_rating) => {
+                let sheet = ready!(self.status);
+
+                sheet.max_challenge.set(challenge_rating);
+            }
+            Message::MaxChallengeCleared => {
+                let sheet = ready!(self.status);
+
+                sheet.max_challenge.clear();
+            }
+            Message::SeedChanged(seed) => {
+                let sheet = ready!(self.status);
+
+                sheet.seed.set(seed);
+            }
+            Message::SeedRolled => {
+                let sheet = ready!(self.status);
+
+                sheet.new_seed();
+            }
+            Message::Generated => {
+                let sheet = ready!(self.status);
+
+                if sheet.generate() {
+                    self.unsaved = true;
+                }
+            }
+            Message::Rerolled(creature_id) => {
+                let sheet = ready!(self.status);
+
+                if sheet.reroll(creature_id) {
+                    self.unsaved = true;
+                }
+            }
+            Message::LaunchRequested => {
+                let Some(id) = self.id() else {
+                    return (Task::none(), None);
+                };
+
+                let request = Request::CombatTracker {
+                    encounter: Some(id),
+                };
+
+                return (Task::none(), Some(Effect::OpenView(request)));
+            }
+            Message::Submitted => {
+                if self.saving {
+                    return (Task::none(), None);
+                }
+
+                let id = self.id().unwrap_or_else(Uuid::new_v4);
+                let sheet = ready!(self.status);
+
+                let Some(new_encounter) = sheet.try_new_encounter(id) else {
+                    tracing::debug!("encounter builder has invalid fields");
+
+                    return (Task::none(), Some(Effect::SaveFailed));
+                };
+
+                self.saving = true;
+
+                let task = Task::perform(
+                    save_encounter(self.context.clone(), self.mode.clone(), new_encounter),
+                    Message::Saved,
+                );
+
+                return (task, None);
+            }
+            Message::Saved(Ok(id)) => {
+                self.saving = false;
+                self.unsaved = false;
+
+                // Saving a new encounter turns the builder into an edit of that encounter.
+                self.mode = FormMode::Edit(id);
+
+                return (Task::none(), Some(Effect::Saved));
+            }
+            Message::Saved(Err(err)) => {
+                self.saving = false;
+
+                tracing::error!("failed to save encounter: {err:?}");
+
+                return (Task::none(), Some(Effect::SaveFailed));
+            }
+            Message::DeleteRequested => {
+                let Some(id) = self.id() else {
+                    return (Task::none(), None);
+                };
+
+                let task =
+                    Task::perform(delete_encounter(self.context.clone(), id), Message::Deleted);
+
+                return (task, None);
+            }
+            Message::Deleted(Ok(())) => return (Task::none(), Some(Effect::Deleted)),
+            Message::Deleted(Err(err)) => {
+                tracing::error!("failed to delete encounter: {err:?}");
+            }
+        }
+
+        (Task::none(), None)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.unsaved
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match &self.status {
+            Status::Loading(loader) => {
+                let start = 0.0;
+                let end = loader.total as f32;
+                let current = loader.progress as f32;
+
+                widget::progress_bar(start..=end, current).into()
+            }
+            Status::Ready(sheet) => {
+                let heading = Self::heading();
+
+                let encounter = Self::encounter_section(sheet);
+
+                let party = Self::party_section(sheet);
+
+                let generate = Self::generate_section(sheet);
+
+                let creatures = Self::creatures_section(sheet);
+
+                let difficulty = Self::difficulty_section(sheet);
+
+                let actions = self.actions(sheet);
+
+                column![
+                    heading, encounter, party, generate, creatures, difficulty, actions
+                ]
+                .align_x(Alignment::Center)
+                .spacing(SECTION_SPACING)
+                .into()
+            }
+        }
+    }
+}
+
+async fn save_encounter<C: EncountersRepository>(
+    ctx: C,
+    mode: FormMode,
+    new_encounter: NewEncounter,
+) -> Result<Uuid, Error> {
+    let id = new_encounter.id;
+    let repo = ctx.encounters();
+
+    match mode {
+        FormMode::Create => repo.create(new_encounter).await?,
+        FormMode::Edit(_) => repo.update(new_encounter).await?,
+    }
+
+    Ok(id)
+}
+
+async fn delete_encounter<C: EncountersRepository>(ctx: C, id: Uuid) -> Result<(), Error> {
+    let repo = ctx.encounters();
+    repo.delete(id).await
+}
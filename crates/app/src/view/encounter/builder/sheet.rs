@@ -0,0 +1,425 @@
+use components::number_field::NumberFieldState;
+use components::select_field::SelectFieldState;
+use components::text_field::TextFieldState;
+use storage::models::encounter::NewEncounter;
+use storage::models::encounter::NewEncounterCreature;
+use storage::records::creature::CreatureSummary;
+use storage::records::encounter::EncounterRecord;
+use types::Candidate;
+use types::ChallengeRating;
+use types::Difficulty;
+use types::EncounterGenerator;
+use types::EncounterRating;
+use types::EncounterSlot;
+use types::Random;
+
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use uuid::Uuid;
+
+/// The levels a character can have.
+const LEVELS: RangeInclusive<i32> = 1..=20;
+
+/// The number of characters a new encounter is planned for.
+const DEFAULT_PARTY_SIZE: usize = 4;
+
+/// The difficulties an encounter can be generated for.
+const DIFFICULTIES: [Difficulty; 4] = [
+    Difficulty::Easy,
+    Difficulty::Medium,
+    Difficulty::Hard,
+    Difficulty::Deadly,
+];
+
+/// The party an encounter is planned for, along with its creatures.
+pub struct Sheet {
+    pub name: TextFieldState,
+
+    /// The level of each character of the party.
+    pub party: Vec<NumberFieldState>,
+
+    /// The creatures of the encounter, ordered by name.
+    pub entries: Vec<Entry>,
+
+    /// The creatures of the bestiary to pick from.
+    pub library: Box<[CreatureSummary]>,
+
+    /// Filters the library by name.
+    pub search: TextFieldState,
+
+    /// The difficulty to generate the encounter for.
+    pub difficulty: SelectFieldState<Difficulty>,
+
+    /// The environment the generated creatures live in, any if none is selected.
+    pub environment: SelectFieldState<String>,
+
+    /// The type of the generated creatures, any if none is selected.
+    pub kind: SelectFieldState<String>,
+
+    /// The lowest challenge rating of the generated creatures, any if none is selected.
+    pub min_challenge: SelectFieldState<ChallengeRating>,
+
+    /// The highest challenge rating of the generated creatures, any if none is selected.
+    pub max_challenge: SelectFieldState<ChallengeRating>,
+
+    /// The seed the encounter is generated from, so the same encounter can be generated again.
+    pub seed: NumberFieldState,
+
+    /// Picks the creatures, continuing from the seed as slots are rerolled.
+    random: Random,
+
+    /// Why the encounter could not be saved, if it failed validation.
+    pub error: Option<&'static str>,
+}
+
+/// A creature of the encounter, with how many of it take part.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub creature_id: Uuid,
+    pub name: String,
+    pub challenge_rating: String,
+    pub count: u32,
+}
+
+impl Sheet {
+    pub fn new(library: Box<[CreatureSummary]>) -> Self {
+        let environments: BTreeSet<_> = library
+            .iter()
+            .flat_map(|creature| creature.environments.iter().cloned())
+            .collect();
+        let kinds: BTreeSet<_> = library
+            .iter()
+            .map(|creature| creature.kind.clone())
+            .collect();
+
+        let mut sheet = Self {
+            name: TextFieldState::default().required(true),
+            party: (0..DEFAULT_PARTY_SIZE).map(|_| character(1)).collect(),
+            entries: Vec::new(),
+            library,
+            search: TextFieldState::default(),
+            difficulty: SelectFieldState::new(DIFFICULTIES, Some(Difficulty::Medium))
+                .required(true),
+            environment: SelectFieldState::new(environments, None),
+            kind: SelectFieldState::new(kinds, None),
+            min_challenge: SelectFieldState::new(ChallengeRating::all(), None),
+            max_challenge: SelectFieldState::new(ChallengeRating::all(), None),
+            seed: NumberFieldState::default()
+                .required(true)
+                .non_negative(true),
+            random: Random::new(0),
+            error: None,
+        };
+        sheet.new_seed();
+
+        sheet
+    }
+
+    pub fn from_record(record: EncounterRecord, library: Box<[CreatureSummary]>) -> Self {
+        let mut sheet = Self::new(library);
+
+        sheet.name.set(record.name);
+        sheet.party = record
+            .party
+            .iter()
+            .map(|&level| character(i32::from(level)))
+            .collect();
+        sheet.entries = record
+            .creatures
+            .into_iter()
+            .map(|creature| Entry {
+                creature_id: creature.creature_id,
+                name: creature.name,
+                challenge_rating: creature.challenge_rating,
+                count: creature.count,
+            })
+            .collect();
+
+        sheet
+    }
+
+    pub fn add_character(&mut self) {
+        // New characters join at the level of the last one, as parties tend to share a level.
+        let level = self
+            .party
+            .last()
+            .and_then(|level| parse_level(level.raw_value()))
+            .unwrap_or(1);
+
+        self.party.push(character(level));
+    }
+
+    pub fn remove_character(&mut self, index: usize) {
+        if index < self.party.len() {
+            self.party.remove(index);
+        }
+    }
+
+    /// Add one of the `creature` to the encounter, adding its entry if it has none yet.
+    pub fn add(&mut self, creature: &CreatureSummary) {
+        if let Some(entry) = self.entry_mut(creature.id) {
+            entry.count += 1;
+            return;
+        }
+
+        self.entries.push(Entry {
+            creature_id: creature.id,
+            name: creature.name.clone(),
+            challenge_rating: creature.challenge_rating.clone(),
+            count: 1,
+        });
+        self.sort_entries();
+    }
+
+    pub fn remove(&mut self, creature_id: Uuid) {
+        self.entries
+            .retain(|entry| entry.creature_id != creature_id);
+    }
+
+    pub fn entry_mut(&mut self, creature_id: Uuid) -> Option<&mut Entry> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.creature_id == creature_id)
+    }
+
+    /// The creatures of the library matching the search.
+    pub fn matches(&self) -> impl Iterator<Item = &CreatureSummary> {
+        let search = self.search.value().trim().to_lowercase();
+
+        self.library
+            .iter()
+            .filter(move |creature| creature.name.to_lowercase().contains(&search))
+    }
+
+    /// Enter a fresh seed to generate the encounter from.
+    pub fn new_seed(&mut self) {
+        // The seed is entered like any number, so it is kept within what can be entered.
+        let seed = Random::seed() % i32::MAX as u64;
+
+        self.seed.set(seed.to_string());
+    }
+
+    /// Replace the creatures of the encounter with random ones of the bestiary that make for a
+    /// fight of the selected difficulty, generated from the seed.
+    ///
+    /// Returns whether the encounter was generated, otherwise the error is displayed.
+    pub fn generate(&mut self) -> bool {
+        let difficulty = self.difficulty.try_value();
+        let seed = self.seed.try_value();
+
+        let (Some(difficulty), Some(seed)) = (difficulty, seed) else {
+            return false;
+        };
+
+        let levels = self.levels();
+        let candidates = self.candidates();
+
+        self.error = if levels.is_empty() {
+            Some("The party must have at least one character.")
+        } else if candidates.is_empty() {
+            Some(
+                "No creatures of the bestiary live in the environment, are of the type and are \
+                 within the challenge ratings.",
+            )
+        } else {
+            None
+        };
+
+        if self.error.is_some() {
+            return false;
+        }
+
+        self.random = Random::new(u64::try_from(seed).unwrap_or_default());
+
+        let generator = EncounterGenerator::new(&levels, difficulty, &candidates);
+        let slots = generator.generate(&mut self.random);
+
+        self.entries = slots.iter().filter_map(|slot| self.entry(slot)).collect();
+        self.sort_entries();
+
+        true
+    }
+
+    /// Replace the creature with `creature_id` with another random one of the bestiary, keeping
+    /// the others as they are.
+    ///
+    /// Returns whether the creature was replaced.
+    pub fn reroll(&mut self, creature_id: Uuid) -> bool {
+        let Some(index) = self
+            .entries
+            .iter()
+            .position(|entry| entry.creature_id == creature_id)
+        else {
+            return false;
+        };
+        let Some(difficulty) = self.difficulty.try_value() else {
+            return false;
+        };
+
+        let levels = self.levels();
+        let candidates = self.candidates();
+
+        let slots: Vec<_> = self
+            .entries
+            .iter()
+            .map(|entry| EncounterSlot {
+                creature: Candidate {
+                    id: entry.creature_id,
+                    challenge_rating: entry
+                        .challenge_rating
+                        .parse()
+                        .unwrap_or(ChallengeRating::ZERO),
+                },
+                count: entry.count,
+            })
+            .collect();
+
+        let generator = EncounterGenerator::new(&levels, difficulty, &candidates);
+        let rerolled = generator.reroll(&slots, index, &mut self.random);
+
+        let Some(entry) = self.entry(&rerolled[index]) else {
+            return false;
+        };
+
+        let changed = entry.creature_id != creature_id;
+        self.entries[index] = entry;
+        self.sort_entries();
+
+        changed
+    }
+
+    /// The creatures of the library the encounter can be generated from, living in the selected
+    /// environment, of the selected type and within the selected challenge ratings.
+    fn candidates(&self) -> Vec<Candidate> {
+        let environment = self.environment.selected();
+        let kind = self.kind.selected();
+        let min = self.min_challenge.selected();
+        let max = self.max_challenge.selected();
+
+        self.library
+            .iter()
+            .filter(|creature| {
+                environment.is_none_or(|environment| creature.environments.contains(environment))
+            })
+            .filter(|creature| kind.is_none_or(|kind| creature.kind == *kind))
+            .filter_map(|creature| {
+                let challenge_rating = creature.challenge_rating.parse().ok()?;
+
+                let within = min.is_none_or(|min| challenge_rating >= *min)
+                    && max.is_none_or(|max| challenge_rating <= *max);
+
+                within.then_some(Candidate {
+                    id: creature.id,
+                    challenge_rating,
+                })
+            })
+            .collect()
+    }
+
+    /// The entry of the library creature of `slot`, if it is still in the library.
+    fn entry(&self, slot: &EncounterSlot) -> Option<Entry> {
+        let creature = self
+            .library
+            .iter()
+            .find(|creature| creature.id == slot.creature.id)?;
+
+        Some(Entry {
+            creature_id: creature.id,
+            name: creature.name.clone(),
+            challenge_rating: creature.challenge_rating.clone(),
+            count: slot.count,
+        })
+    }
+
+    fn sort_entries(&mut self) {
+        self.entries
+            .sort_by_cached_key(|entry| entry.name.to_lowercase());
+    }
+
+    /// The levels of the characters of the party, skipping those that are not valid yet.
+    pub fn levels(&self) -> Vec<u8> {
+        self.party
+            .iter()
+            .filter_map(|level| parse_level(level.raw_value()))
+            .filter_map(|level| u8::try_from(level).ok())
+            .collect()
+    }
+
+    /// Rate the encounter against the party as it currently stands.
+    pub fn rating(&self) -> EncounterRating {
+        let creatures = self.entries.iter().filter_map(|entry| {
+            match entry.challenge_rating.parse::<ChallengeRating>() {
+                Ok(challenge_rating) => Some((challenge_rating, entry.count)),
+                Err(err) => {
+                    tracing::warn!("skipping '{}' in encounter rating: {err}", entry.name);
+                    None
+                }
+            }
+        });
+
+        EncounterRating::new(&self.levels(), creatures)
+    }
+
+    /// Validate every field and construct the encounter with `id` from the sheet.
+    ///
+    /// Returns `None` if the sheet is invalid, in which case the errors are displayed.
+    pub fn try_new_encounter(&mut self, id: Uuid) -> Option<NewEncounter> {
+        // Every field is validated before returning, so all errors are shown at once.
+        let name = self.name.try_value();
+        let levels: Vec<_> = self
+            .party
+            .iter_mut()
+            .map(|level| level.try_value())
+            .collect();
+
+        let name = name?;
+        let levels: Vec<_> = levels.into_iter().collect::<Option<_>>()?;
+
+        self.error = if self.party.is_empty() {
+            Some("The party must have at least one character.")
+        } else if levels.iter().any(|level| !LEVELS.contains(level)) {
+            Some("The level of every character must be between 1 and 20.")
+        } else if self.entries.is_empty() {
+            Some("The encounter must have at least one creature.")
+        } else {
+            None
+        };
+
+        if self.error.is_some() {
+            return None;
+        }
+
+        let party = levels
+            .into_iter()
+            .map(u8::try_from)
+            .collect::<Result<_, _>>()
+            .ok()?;
+
+        let creatures = self
+            .entries
+            .iter()
+            .map(|entry| NewEncounterCreature {
+                creature_id: entry.creature_id,
+                count: entry.count,
+            })
+            .collect();
+
+        Some(NewEncounter {
+            id,
+            name,
+            party,
+            creatures,
+        })
+    }
+}
+
+fn character(level: i32) -> NumberFieldState {
+    NumberFieldState::new(Some(level))
+        .required(true)
+        .non_negative(true)
+}
+
+/// Parse a written level, if it is one a character can have.
+fn parse_level(value: &str) -> Option<i32> {
+    value.parse().ok().filter(|level| LEVELS.contains(level))
+}
@@ -0,0 +1,18 @@
+use crate::view::request::Request;
+use storage::Error;
+use storage::records::encounter::EncounterSummary;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    OpenNewEncounter,
+    OpenEncounter(Uuid),
+    OpenCombat(Uuid),
+    EncountersLoaded(Result<Box<[EncounterSummary]>, Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    OpenView(Request),
+}
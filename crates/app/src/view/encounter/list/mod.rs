@@ -0,0 +1,158 @@
+pub mod message;
+
+use crate::context::Context;
+use crate::view::Viewable;
+use crate::view::encounter::list::message::Effect;
+use crate::view::encounter::list::message::Message;
+use crate::view::request::Request;
+use storage::Error;
+use storage::records::encounter::EncounterSummary;
+use storage::repositories::encounters::EncountersRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::SECTION_SPACING;
+use types::FormMode;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+
+pub struct EncounterList {
+    context: Context,
+
+    /// The encounters of the list, `None` while they are loading.
+    encounters: Option<Box<[EncounterSummary]>>,
+}
+
+impl EncounterList {
+    pub fn new(context: Context) -> (Self, Task<Message>) {
+        let mut encounter_list = Self {
+            context,
+            encounters: None,
+        };
+
+        let task = encounter_list.reload();
+
+        (encounter_list, task)
+    }
+
+    /// Reload the encounters of the list.
+    pub fn reload(&mut self) -> Task<Message> {
+        Task::perform(
+            load_encounters(self.context.clone()),
+            Message::EncountersLoaded,
+        )
+    }
+}
+
+impl Viewable for EncounterList {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        "Encounters"
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::OpenNewEncounter => {
+                let request = Request::EncounterBuilder {
+                    mode: FormMode::Create,
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenEncounter(id) => {
+                let request = Request::EncounterBuilder {
+                    mode: FormMode::Edit(id),
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenCombat(id) => {
+                let request = Request::CombatTracker {
+                    encounter: Some(id),
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::EncountersLoaded(Ok(encounters)) => {
+                self.encounters = Some(encounters);
+
+                (Task::none(), None)
+            }
+            Message::EncountersLoaded(Err(err)) => {
+                tracing::error!("failed to load encounters: {err:?}");
+
+                (Task::none(), None)
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let title = components::text::view_title("Encounters");
+
+        let create_encounter_button =
+            widget::button("Create New Encounter").on_press(Message::OpenNewEncounter);
+
+        let encounters: Element<_> = match &self.encounters {
+            None => components::text::detail("Loading encounters...").into(),
+            Some(encounters) if encounters.is_empty() => {
+                components::text::detail("No encounters have been planned yet.").into()
+            }
+            Some(encounters) => {
+                let mut rows = Column::with_capacity(encounters.len()).spacing(BODY_SPACING);
+
+                for encounter in encounters {
+                    let name = components::text::display(&encounter.name);
+                    let composition = components::text::detail(format!(
+                        "{} creatures · {} characters",
+                        encounter.creature_count, encounter.party_size
+                    ));
+                    let content = row![
+                        name,
+                        widget::space::horizontal().width(Length::Fill),
+                        composition
+                    ]
+                    .align_y(Alignment::Center);
+
+                    let button = widget::button(content)
+                        .class(ButtonClass::Interaction)
+                        .padding(INPUT_PADDING)
+                        .width(Length::Fill)
+                        .on_press(Message::OpenEncounter(encounter.id));
+
+                    let launch = widget::button(components::text::display("Run"))
+                        .class(ButtonClass::Primary)
+                        .padding(INPUT_PADDING)
+                        .on_press(Message::OpenCombat(encounter.id));
+
+                    rows = rows.push(row![button, launch].spacing(BODY_SPACING));
+                }
+
+                rows.into()
+            }
+        };
+
+        column![title, create_encounter_button, encounters]
+            .spacing(SECTION_SPACING)
+            .into()
+    }
+}
+
+async fn load_encounters<C: EncountersRepository>(
+    ctx: C,
+) -> Result<Box<[EncounterSummary]>, Error> {
+    let repo = ctx.encounters();
+    repo.list().await
+}
@@ -1,15 +1,40 @@
+pub mod combat;
+pub mod creature;
 pub mod dashboard;
+pub mod encounter;
 pub mod request;
 pub mod spell;
+pub mod spellbook;
 
+use crate::view::combat::tracker::CombatTracker;
+use crate::view::creature::detail::CreatureDetail;
+use crate::view::creature::form::CreatureForm;
+use crate::view::creature::list::CreatureList;
 use crate::view::dashboard::Dashboard;
+use crate::view::encounter::builder::EncounterBuilder;
+use crate::view::encounter::list::EncounterList;
+use crate::view::spell::detail::SpellDetail;
 use crate::view::spell::form::SpellForm;
 use crate::view::spell::list::SpellList;
+use crate::view::spellbook::editor::SpellbookEditor;
+use crate::view::spellbook::list::SpellbookList;
+use crate::view::spellbook::tracker::SpellbookTracker;
+use combat::tracker::message::Message as CombatTrackerMessage;
+use creature::detail::message::Message as CreatureDetailMessage;
+use creature::form::message::Message as CreatureFormMessage;
+use creature::list::message::Message as CreatureListMessage;
 use dashboard::message::Message as DashboardMessage;
+use encounter::builder::message::Message as EncounterBuilderMessage;
+use encounter::list::message::Message as EncounterListMessage;
+use spell::detail::message::Message as SpellDetailMessage;
 use spell::form::message::Message as SpellFormMessage;
 use spell::list::message::Message as SpellListMessage;
+use spellbook::editor::message::Message as SpellbookEditorMessage;
+use spellbook::list::message::Message as SpellbookListMessage;
+use spellbook::tracker::message::Message as SpellbookTrackerMessage;
 use widgets::Element;
 
+use iced::Subscription;
 use iced::Task;
 use std::fmt::Debug;
 use std::sync::atomic::AtomicU64;
@@ -17,9 +42,19 @@ use std::sync::atomic::Ordering;
 
 #[derive(Debug, Clone)]
 pub enum ViewMessage {
+    CombatTracker(CombatTrackerMessage),
+    CreatureDetail(CreatureDetailMessage),
+    CreatureForm(CreatureFormMessage),
+    CreatureList(CreatureListMessage),
     Dashboard(DashboardMessage),
+    EncounterBuilder(EncounterBuilderMessage),
+    EncounterList(EncounterListMessage),
+    SpellDetail(SpellDetailMessage),
     SpellForm(SpellFormMessage),
     SpellList(SpellListMessage),
+    SpellbookEditor(SpellbookEditorMessage),
+    SpellbookList(SpellbookListMessage),
+    SpellbookTracker(SpellbookTrackerMessage),
 }
 
 static NEXT_ID: AtomicU64 = AtomicU64::new(0);
@@ -52,23 +87,96 @@ pub trait Viewable {
     /// caller to manage and either handle, or further emit up the chain until a caller can.
     fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>);
 
+    /// Whether `Self` has unsaved changes, which would be lost by closing it.
+    fn is_dirty(&self) -> bool {
+        false
+    }
+
+    /// Listens to external events, like keyboard shortcuts, while `Self` is the active view.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
     /// Generate the view of `Self`.
     fn view(&self) -> Element<'_, Self::Message>;
 }
 
 /// The views of the application.
 pub enum View {
+    CombatTracker(Box<CombatTracker>),
+    CreatureDetail(Box<CreatureDetail>),
+    CreatureForm(Box<CreatureForm>),
+    CreatureList(Box<CreatureList>),
     Dashboard(Dashboard),
+    EncounterBuilder(Box<EncounterBuilder>),
+    EncounterList(Box<EncounterList>),
+    SpellDetail(Box<SpellDetail>),
     SpellForm(Box<SpellForm>),
     SpellList(Box<SpellList>),
+    SpellbookEditor(Box<SpellbookEditor>),
+    SpellbookList(Box<SpellbookList>),
+    SpellbookTracker(Box<SpellbookTracker>),
 }
 
 impl View {
     pub fn title(&self) -> &str {
         match self {
+            View::CombatTracker(combat_tracker) => combat_tracker.title(),
+            View::CreatureDetail(creature_detail) => creature_detail.title(),
+            View::CreatureForm(creature_form) => creature_form.title(),
+            View::CreatureList(creature_list) => creature_list.title(),
             View::Dashboard(dashboard) => dashboard.title(),
+            View::EncounterBuilder(encounter_builder) => encounter_builder.title(),
+            View::EncounterList(encounter_list) => encounter_list.title(),
+            View::SpellDetail(spell_detail) => spell_detail.title(),
             View::SpellForm(spell_form) => spell_form.title(),
             View::SpellList(spell_list) => spell_list.title(),
+            View::SpellbookEditor(spellbook_editor) => spellbook_editor.title(),
+            View::SpellbookList(spellbook_list) => spellbook_list.title(),
+            View::SpellbookTracker(spellbook_tracker) => spellbook_tracker.title(),
+        }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        match self {
+            View::CombatTracker(combat_tracker) => combat_tracker.is_dirty(),
+            View::CreatureDetail(creature_detail) => creature_detail.is_dirty(),
+            View::CreatureForm(creature_form) => creature_form.is_dirty(),
+            View::CreatureList(creature_list) => creature_list.is_dirty(),
+            View::Dashboard(dashboard) => dashboard.is_dirty(),
+            View::EncounterBuilder(encounter_builder) => encounter_builder.is_dirty(),
+            View::EncounterList(encounter_list) => encounter_list.is_dirty(),
+            View::SpellDetail(spell_detail) => spell_detail.is_dirty(),
+            View::SpellForm(spell_form) => spell_form.is_dirty(),
+            View::SpellList(spell_list) => spell_list.is_dirty(),
+            View::SpellbookEditor(spellbook_editor) => spellbook_editor.is_dirty(),
+            View::SpellbookList(spellbook_list) => spellbook_list.is_dirty(),
+            View::SpellbookTracker(spellbook_tracker) => spellbook_tracker.is_dirty(),
+        }
+    }
+
+    /// The message that saves the unsaved changes of the view, if it can be saved.
+    pub fn save_message(&self) -> Option<ViewMessage> {
+        match self {
+            View::CreatureForm(_) => {
+                Some(ViewMessage::CreatureForm(CreatureFormMessage::Submitted))
+            }
+            View::EncounterBuilder(_) => Some(ViewMessage::EncounterBuilder(
+                EncounterBuilderMessage::Submitted,
+            )),
+            View::SpellForm(_) => Some(ViewMessage::SpellForm(SpellFormMessage::Submitted)),
+            View::SpellbookEditor(_) => Some(ViewMessage::SpellbookEditor(
+                SpellbookEditorMessage::Submitted,
+            )),
+            View::CombatTracker(_)
+            | View::CreatureDetail(_)
+            | View::CreatureList(_)
+            | View::Dashboard(_)
+            | View::EncounterList(_)
+            | View::SpellDetail(_)
+            | View::SpellList(_)
+            | View::SpellbookList(_)
+            | View::SpellbookTracker(_) => None,
         }
     }
 }
@@ -1,10 +1,39 @@
 use types::FormMode;
 
+use uuid::Uuid;
+
 /// A request to open a new view.
 ///
 /// This allows the application to intercept and redirect to existing non-unique tabs.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Request {
-    SpellForm { mode: FormMode },
+    /// The fight, bringing in the creatures of the saved encounter if there is one.
+    CombatTracker {
+        encounter: Option<Uuid>,
+    },
+    CreatureDetail {
+        id: Uuid,
+    },
+    CreatureForm {
+        mode: FormMode,
+    },
+    CreatureList,
+    EncounterBuilder {
+        mode: FormMode,
+    },
+    EncounterList,
+    SpellForm {
+        mode: FormMode,
+    },
+    SpellDetail {
+        id: Uuid,
+    },
     SpellList,
+    SpellbookEditor {
+        mode: FormMode,
+    },
+    SpellbookList,
+    SpellbookTracker {
+        id: Uuid,
+    },
 }
@@ -0,0 +1,113 @@
+use crate::context::Context;
+use storage::Error;
+use storage::records::ImageRecord;
+use storage::records::spell::SpellRecord;
+use storage::records::spell::SpellRevision;
+use storage::repositories::bookmarks::BookmarksRepository;
+use storage::repositories::spells::SpellsRepository;
+
+use iced::Task;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+#[expect(
+    clippy::enum_variant_names,
+    reason = "every variant is the result of a load task"
+)]
+pub enum LoadMessage {
+    SpellLoaded(Result<(Box<SpellRecord>, Box<[ImageRecord]>), Error>),
+    RevisionsLoaded(Result<Box<[SpellRevision]>, Error>),
+    FavouriteLoaded(Result<bool, Error>),
+}
+
+pub struct Loader {
+    /// The number of tasks in total the loader must complete.
+    pub total: usize,
+
+    /// The number of tasks that have been completed.
+    pub progress: usize,
+
+    pub error: Option<Error>,
+
+    pub spell: Option<SpellRecord>,
+    pub images: Option<Box<[ImageRecord]>>,
+    pub revisions: Option<Box<[SpellRevision]>>,
+    pub favourite: Option<bool>,
+}
+
+impl Loader {
+    pub fn new(context: Context, id: Uuid) -> (Self, Task<LoadMessage>) {
+        let tasks = vec![
+            Task::perform(load_spell(context.clone(), id), LoadMessage::SpellLoaded),
+            Task::perform(
+                load_revisions(context.clone(), id),
+                LoadMessage::RevisionsLoaded,
+            ),
+            Task::perform(load_favourite(context, id), LoadMessage::FavouriteLoaded),
+        ];
+
+        let loader = Self {
+            total: tasks.len(),
+            progress: 0,
+            error: None,
+            spell: None,
+            images: None,
+            revisions: None,
+            favourite: None,
+        };
+
+        (loader, Task::batch(tasks))
+    }
+
+    pub fn update(&mut self, message: LoadMessage) {
+        match message {
+            LoadMessage::SpellLoaded(Ok((spell, images))) => {
+                self.spell = Some(*spell);
+                self.images = Some(images);
+            }
+            LoadMessage::SpellLoaded(Err(err)) => {
+                tracing::error!("failed to load spell: {:?}", err);
+                self.error = Some(err);
+            }
+            LoadMessage::RevisionsLoaded(Ok(revisions)) => self.revisions = Some(revisions),
+            LoadMessage::RevisionsLoaded(Err(err)) => {
+                tracing::error!("failed to load spell revisions: {:?}", err);
+                self.error = Some(err);
+            }
+            LoadMessage::FavouriteLoaded(Ok(favourite)) => self.favourite = Some(favourite),
+            LoadMessage::FavouriteLoaded(Err(err)) => {
+                tracing::error!("failed to load spell favourite: {:?}", err);
+                self.error = Some(err);
+            }
+        }
+
+        self.progress += 1;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress == self.total
+    }
+}
+
+async fn load_spell<C: SpellsRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<(Box<SpellRecord>, Box<[ImageRecord]>), Error> {
+    let spell = ctx.spells().get(id).await?;
+    let images = ctx.spells().images(id).await?;
+
+    Ok((Box::new(spell), images))
+}
+
+async fn load_revisions<C: SpellsRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<Box<[SpellRevision]>, Error> {
+    let repo = ctx.spells();
+    repo.revisions(id).await
+}
+
+async fn load_favourite<C: BookmarksRepository>(ctx: C, id: Uuid) -> Result<bool, Error> {
+    let repo = ctx.bookmarks();
+    repo.is_favourite(id).await
+}
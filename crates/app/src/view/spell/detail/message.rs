@@ -0,0 +1,45 @@
+use crate::players::Handout;
+use crate::view::request::Request;
+use crate::view::spell::detail::loader::LoadMessage;
+use storage::Error;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    LoadMessage(LoadMessage),
+    EditRequested,
+    DeleteRequested,
+    DeleteCancelled,
+    DeleteConfirmed,
+    Deleted(Result<(), Error>),
+    RevisionSelected(usize),
+    RestoreRequested(Uuid),
+    Restored(Result<(), Error>),
+    FavouriteToggled,
+    FavouriteSaved(Result<bool, Error>),
+    Viewed(Result<(), Error>),
+    ShownToPlayers,
+    ImageShown(usize),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    OpenView(Request),
+    LoadFailed(Error),
+
+    /// The spell was deleted.
+    Deleted,
+
+    /// The spell was restored to an earlier revision.
+    Restored,
+
+    /// The spell was recorded as recently opened.
+    Viewed,
+
+    /// The spell was marked or unmarked as favourite.
+    FavouriteChanged,
+
+    /// The spell card or one of its images should be shown in the player window.
+    ShowToPlayers(Handout),
+}
@@ -0,0 +1,617 @@
+mod loader;
+pub mod message;
+
+use crate::context::Context;
+use crate::players::Handout;
+use crate::status::Status;
+use crate::status::loading;
+use crate::status::ready;
+use crate::view::Viewable;
+use crate::view::request::Request;
+use crate::view::spell::detail::loader::Loader;
+use crate::view::spell::detail::message::Effect;
+use crate::view::spell::detail::message::Message;
+use storage::Error;
+use storage::records::spell::RevisionKind;
+use storage::records::spell::SpellRecord;
+use storage::records::spell::SpellRevision;
+use storage::repositories::bookmarks::BookmarksRepository;
+use storage::repositories::spells::SpellsRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
+use style::layout::SECTION_SPACING;
+use style::text::TextClass;
+use types::FormMode;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::ContentFit;
+use iced::Length;
+use iced::Length::Fill;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::image::Handle;
+use iced::widget::row;
+use uuid::Uuid;
+
+/// The number of images shown per row.
+const IMAGES_PER_ROW: usize = 3;
+
+/// The height of each image.
+const IMAGE_HEIGHT: u32 = 200;
+
+pub struct SpellDetail {
+    id: Uuid,
+    context: Context,
+    status: Status<Loader, Details>,
+
+    /// Whether the deletion of the spell awaits confirmation.
+    confirming_delete: bool,
+}
+
+struct Details {
+    spell: SpellRecord,
+    images: Box<[Handle]>,
+
+    /// The revisions of the spell, newest first.
+    revisions: Box<[SpellRevision]>,
+
+    /// The index of the revision currently inspected in the history panel.
+    selected_revision: Option<usize>,
+
+    favourite: bool,
+}
+
+impl Details {
+    fn from_loader(loader: &mut Loader) -> Option<Self> {
+        let spell = loader.spell.take()?;
+        let images = loader.images.take()?;
+        let revisions = loader.revisions.take()?;
+        let favourite = loader.favourite.take()?;
+
+        let images = images
+            .into_iter()
+            .map(|image| Handle::from_bytes(image.bytes))
+            .collect();
+
+        let details = Self {
+            spell,
+            images,
+            revisions,
+            selected_revision: None,
+            favourite,
+        };
+
+        Some(details)
+    }
+}
+
+impl<'a> SpellDetail {
+    pub fn new(id: Uuid, context: Context) -> (Self, Task<Message>) {
+        let (loader, task) = Loader::new(context.clone(), id);
+
+        // Opening the spell counts as viewing it, while reloading it does not.
+        let viewed = Task::perform(record_view(context.clone(), id), Message::Viewed);
+
+        let spell_detail = Self {
+            id,
+            context,
+            status: Status::Loading(Box::new(loader)),
+            confirming_delete: false,
+        };
+
+        (
+            spell_detail,
+            Task::batch([task.map(Message::LoadMessage), viewed]),
+        )
+    }
+
+    /// The id of the spell being displayed.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Reload the spell, its images and its revisions.
+    pub fn reload(&mut self) -> Task<Message> {
+        let (loader, task) = Loader::new(self.context.clone(), self.id);
+        self.status = Status::Loading(Box::new(loader));
+
+        task.map(Message::LoadMessage)
+    }
+
+    fn heading(&'a self, details: &'a Details) -> Element<'a, Message> {
+        let title = components::text::view_title(&details.spell.name);
+
+        let sub_title = components::text::view_sub_title(format!(
+            "{} {}",
+            details.spell.level, details.spell.school
+        ));
+
+        let favourite_label = if details.favourite {
+            "Unfavourite"
+        } else {
+            "Favourite"
+        };
+        let favourite = widget::button(components::text::display(favourite_label))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press(Message::FavouriteToggled);
+
+        let show = widget::button(components::text::display("Show to Players"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press(Message::ShownToPlayers);
+
+        let edit = widget::button(components::text::display("Edit"))
+            .class(ButtonClass::Interaction)
+            .padding(INPUT_PADDING)
+            .on_press(Message::EditRequested);
+
+        let delete = widget::button(components::text::display("Delete"))
+            .class(ButtonClass::Danger)
+            .padding(INPUT_PADDING)
+            .on_press(Message::DeleteRequested);
+
+        let actions = if self.confirming_delete {
+            let cancel = widget::button(components::text::display("Cancel"))
+                .class(ButtonClass::Ghost)
+                .padding(INPUT_PADDING)
+                .on_press(Message::DeleteCancelled);
+
+            let confirm = widget::button(components::text::display("Delete"))
+                .class(ButtonClass::Danger)
+                .padding(INPUT_PADDING)
+                .on_press(Message::DeleteConfirmed);
+
+            row![
+                widget::space::horizontal().width(Fill),
+                components::text::detail(
+                    "Delete this spell? It can be restored from the spell list."
+                ),
+                cancel,
+                confirm
+            ]
+        } else {
+            row![
+                widget::space::horizontal().width(Fill),
+                favourite,
+                show,
+                edit,
+                delete
+            ]
+        }
+        .spacing(BODY_SPACING)
+        .align_y(Alignment::Center)
+        .width(Fill);
+
+        column![title, sub_title, actions]
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    fn properties_section(spell: &'a SpellRecord) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "PROPERTIES",
+            "The mechanics and requirements of casting the spell.",
+        );
+
+        let mut components = Vec::with_capacity(3);
+        if spell.verbal {
+            components.push(String::from("V"));
+        }
+        if spell.somatic {
+            components.push(String::from("S"));
+        }
+        if spell.material {
+            let materials = spell
+                .materials
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            if materials.is_empty() {
+                components.push(String::from("M"));
+            } else {
+                components.push(format!("M ({materials})"));
+            }
+        }
+
+        let mut properties = Vec::with_capacity(2);
+        if spell.ritual {
+            properties.push("Ritual");
+        }
+        if spell.concentration {
+            properties.push("Concentration");
+        }
+
+        let form = column![
+            row![
+                property("CASTING TIME", spell.casting_time.clone()),
+                property("DURATION", spell.duration.clone()),
+            ]
+            .spacing(BODY_SPACING),
+            row![
+                property("RANGE", spell.range.clone()),
+                property("AREA", spell.area.clone()),
+                property("SHAPE", spell.shape.to_string()),
+            ]
+            .spacing(BODY_SPACING),
+            row![
+                property("COMPONENTS", components.join(", ")),
+                property("PROPERTIES", properties.join(", ")),
+            ]
+            .spacing(BODY_SPACING),
+            row![
+                property("CLASSES", spell.classes.join(", ")),
+                property("SOURCE", spell.source.clone().unwrap_or_default()),
+            ]
+            .spacing(BODY_SPACING),
+            row![
+                property("ALIASES", spell.aliases.join(", ")),
+                property("TAGS", spell.tags.join(", ")),
+            ]
+            .spacing(BODY_SPACING),
+        ]
+        .spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn description_section(details: &'a Details) -> Element<'a, Message> {
+        let spell = &details.spell;
+
+        let header = components::form::section_header(
+            "DESCRIPTION",
+            "The effect of the spell, and its power scaling with greater mastery.",
+        );
+
+        let mut form = Column::new().spacing(BODY_SPACING);
+
+        form = form.push(paragraph("DESCRIPTION", &spell.description));
+
+        if let Some(at_higher_levels) = &spell.at_higher_levels {
+            form = form.push(paragraph("AT HIGHER LEVELS", at_higher_levels));
+        }
+
+        if let Some(flavor_text) = &spell.flavor_text {
+            let mut quote = column![components::text::view_sub_title(flavor_text)];
+
+            if let Some(attribution) = &spell.attribution {
+                quote = quote.push(components::text::detail(format!("— {attribution}")));
+            }
+
+            form = form.push(quote.spacing(LABEL_SPACING));
+        }
+
+        if !details.images.is_empty() {
+            let images: Vec<Element<_>> = details
+                .images
+                .iter()
+                .enumerate()
+                .map(|(index, handle)| {
+                    let image = widget::image(handle.clone())
+                        .content_fit(ContentFit::Cover)
+                        .width(Fill)
+                        .height(IMAGE_HEIGHT)
+                        .border_radius(2);
+
+                    widget::button(image)
+                        .class(ButtonClass::Ghost)
+                        .padding(0)
+                        .width(Length::FillPortion(1))
+                        .on_press(Message::ImageShown(index))
+                        .into()
+                })
+                .collect();
+
+            form = form.push(components::row::chunked(images, IMAGES_PER_ROW));
+            form = form.push(components::text::detail(
+                "Click an image to show it to the players.",
+            ));
+        }
+
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn history_section(details: &'a Details) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "HISTORY",
+            "Every change inscribed upon the spell, and the means to undo it.",
+        );
+
+        let mut revisions = Column::with_capacity(details.revisions.len()).spacing(LABEL_SPACING);
+        for (index, revision) in details.revisions.iter().enumerate() {
+            let class = if details.selected_revision == Some(index) {
+                ButtonClass::Primary
+            } else {
+                ButtonClass::Interaction
+            };
+
+            let content = column![
+                components::text::display(revision.kind.to_string()),
+                components::text::detail(format!("{} · {}", revision.created_at, revision.author)),
+            ];
+
+            let button = widget::button(content)
+                .class(class)
+                .padding(INPUT_PADDING)
+                .width(Fill)
+                .on_press(Message::RevisionSelected(index));
+
+            revisions = revisions.push(button);
+        }
+
+        let changes: Element<_> = match details.selected_revision {
+            None => components::text::detail("Select a revision to inspect its changes.").into(),
+            Some(index) => Self::revision_changes(details, index),
+        };
+
+        let form = row![
+            widget::container(revisions).width(Length::FillPortion(1)),
+            widget::container(changes).width(Length::FillPortion(2)),
+        ]
+        .spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn revision_changes(details: &'a Details, index: usize) -> Element<'a, Message> {
+        let Some(revision) = details.revisions.get(index) else {
+            return widget::space().into();
+        };
+
+        // The revisions are newest first, so the preceding revision is the next one.
+        let previous = details
+            .revisions
+            .get(index + 1)
+            .map(|revision| &revision.snapshot);
+
+        let mut column = Column::new().spacing(BODY_SPACING);
+
+        if revision.kind == RevisionKind::Deleted {
+            column = column.push(components::text::detail("The spell was deleted."));
+        } else {
+            let changes = revision.snapshot.changes(previous);
+
+            if changes.is_empty() {
+                column = column.push(components::text::detail("No fields were changed."));
+            }
+
+            for change in changes {
+                let before = components::text::body(change.before).class(TextClass::Danger);
+                let after = components::text::body(change.after).class(TextClass::Success);
+
+                let change = column![components::text::label(change.field), before, after]
+                    .spacing(LABEL_SPACING);
+
+                column = column.push(change);
+            }
+        }
+
+        // The newest revision is the current state of the spell, so there is nothing to restore.
+        let on_restore = (index > 0).then_some(Message::RestoreRequested(revision.id));
+        let restore = widget::button(components::text::display("Restore this version"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(on_restore);
+
+        column.push(restore).into()
+    }
+}
+
+impl Viewable for SpellDetail {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        match &self.status {
+            Status::Loading(_) => "Spell",
+            Status::Ready(details) => &details.spell.name,
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::LoadMessage(load_message) => {
+                let loader = loading!(self.status);
+
+                loader.update(load_message);
+
+                if let Some(err) = loader.error.take() {
+                    tracing::error!("failed to load spell detail: {:?}", err);
+
+                    return (Task::none(), Some(Effect::LoadFailed(err)));
+                }
+
+                if loader.is_done() {
+                    match Details::from_loader(loader) {
+                        Some(details) => self.status = Status::Ready(Box::new(details)),
+                        None => {
+                            tracing::error!("failed to convert loader to details");
+                        }
+                    }
+                }
+            }
+            Message::EditRequested => {
+                let request = Request::SpellForm {
+                    mode: FormMode::Edit(self.id),
+                };
+
+                return (Task::none(), Some(Effect::OpenView(request)));
+            }
+            Message::DeleteRequested => {
+                self.confirming_delete = true;
+            }
+            Message::DeleteCancelled => {
+                self.confirming_delete = false;
+            }
+            Message::DeleteConfirmed => {
+                self.confirming_delete = false;
+
+                let task = Task::perform(
+                    delete_spell(self.context.clone(), self.id),
+                    Message::Deleted,
+                );
+
+                return (task, None);
+            }
+            Message::Deleted(Ok(())) => {
+                return (Task::none(), Some(Effect::Deleted));
+            }
+            Message::Deleted(Err(err)) => {
+                tracing::error!("failed to delete spell: {err:?}");
+            }
+            Message::RevisionSelected(index) => {
+                let details = ready!(self.status);
+
+                details.selected_revision = Some(index);
+            }
+            Message::RestoreRequested(revision_id) => {
+                let task = Task::perform(
+                    restore_spell(self.context.clone(), revision_id),
+                    Message::Restored,
+                );
+
+                return (task, None);
+            }
+            Message::Restored(Ok(())) => {
+                return (self.reload(), Some(Effect::Restored));
+            }
+            Message::Restored(Err(err)) => {
+                tracing::error!("failed to restore spell revision: {err:?}");
+            }
+            Message::FavouriteToggled => {
+                let details = ready!(self.status);
+
+                let task = Task::perform(
+                    set_favourite(self.context.clone(), self.id, !details.favourite),
+                    Message::FavouriteSaved,
+                );
+
+                return (task, None);
+            }
+            Message::FavouriteSaved(Ok(favourite)) => {
+                let details = ready!(self.status);
+
+                details.favourite = favourite;
+
+                return (Task::none(), Some(Effect::FavouriteChanged));
+            }
+            Message::FavouriteSaved(Err(err)) => {
+                tracing::error!("failed to save spell favourite: {err:?}");
+            }
+            Message::Viewed(Ok(())) => {
+                return (Task::none(), Some(Effect::Viewed));
+            }
+            Message::Viewed(Err(err)) => {
+                tracing::error!("failed to record spell view: {err:?}");
+            }
+            Message::ShownToPlayers => {
+                let details = ready!(self.status);
+
+                let handout = Handout::Spell(Box::new(details.spell.clone()));
+
+                return (Task::none(), Some(Effect::ShowToPlayers(handout)));
+            }
+            Message::ImageShown(index) => {
+                let details = ready!(self.status);
+
+                let Some(handle) = details.images.get(index) else {
+                    tracing::error!("could not find image at index {index}");
+
+                    return (Task::none(), None);
+                };
+
+                let handout = Handout::Image(handle.clone());
+
+                return (Task::none(), Some(Effect::ShowToPlayers(handout)));
+            }
+        }
+
+        (Task::none(), None)
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match &self.status {
+            Status::Loading(loader) => {
+                let start = 0.0;
+                let end = loader.total as f32;
+                let current = loader.progress as f32;
+
+                widget::progress_bar(start..=end, current).into()
+            }
+            Status::Ready(details) => {
+                let heading = self.heading(details);
+
+                let properties = Self::properties_section(&details.spell);
+
+                let description = Self::description_section(details);
+
+                let history = Self::history_section(details);
+
+                column![heading, properties, description, history]
+                    .align_x(Alignment::Center)
+                    .spacing(SECTION_SPACING)
+                    .into()
+            }
+        }
+    }
+}
+
+/// A labeled value of a spell.
+fn property<'a, Message: 'a>(label: &'a str, value: String) -> Element<'a, Message> {
+    let value = if value.is_empty() {
+        components::text::detail("None")
+    } else {
+        components::text::body(value)
+    };
+
+    column![components::text::label(label), value]
+        .spacing(LABEL_SPACING)
+        .width(Fill)
+        .into()
+}
+
+/// A labeled paragraph of text.
+fn paragraph<'a, Message: 'a>(label: &'a str, text: &'a str) -> Element<'a, Message> {
+    column![components::text::label(label), components::text::body(text)]
+        .spacing(LABEL_SPACING)
+        .into()
+}
+
+async fn delete_spell<C: SpellsRepository>(ctx: C, id: Uuid) -> Result<(), Error> {
+    let repo = ctx.spells();
+    repo.delete(id).await
+}
+
+async fn restore_spell<C: SpellsRepository>(ctx: C, revision_id: Uuid) -> Result<(), Error> {
+    let repo = ctx.spells();
+    repo.restore(revision_id).await
+}
+
+async fn set_favourite<C: BookmarksRepository>(
+    ctx: C,
+    id: Uuid,
+    favourite: bool,
+) -> Result<bool, Error> {
+    let repo = ctx.bookmarks();
+    repo.set_favourite(id, favourite).await?;
+
+    Ok(favourite)
+}
+
+async fn record_view<C: BookmarksRepository>(ctx: C, id: Uuid) -> Result<(), Error> {
+    let repo = ctx.bookmarks();
+    repo.record_view(id).await
+}
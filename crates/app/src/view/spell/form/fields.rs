@@ -1,15 +1,31 @@
 use crate::view::spell::form::Loader;
+use crate::view::spell::form::history::Field;
+use crate::view::spell::form::history::FieldValue;
+use crate::view::spell::form::history::MaterialValue;
 use components::image_field::state::ImageFieldState;
 use components::multi_text_field::MultiTextFieldState;
 use components::number_field::NumberFieldState;
 use components::select_field::SelectFieldState;
 use components::text_area_field::TextAreaFieldState;
 use components::text_field::TextFieldState;
+use storage::models::NewImage;
+use storage::models::spell::NewSpell;
+use storage::models::spell::NewSpellMaterial;
+use storage::models::spell::NewSpellShape;
+use storage::records::ImageRecord;
+use storage::records::draft::SpellDraftData;
+use storage::records::draft::SpellDraftMaterial;
+use storage::records::spell::SpellRecord;
+use storage::records::spell::SpellShapeRecord;
 use types::Class;
+use types::Distance;
 use types::SPELLCASTING_CLASSES;
 use types::ShapeKind;
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use strum::VariantArray;
+use uuid::Uuid;
 
 pub struct Fields {
     pub name: TextFieldState,
@@ -27,8 +43,8 @@ pub struct Fields {
     pub material: bool,
     pub materials: Vec<SpellMaterialInput>,
     pub duration: SelectFieldState<String>,
-    pub range: SelectFieldState<String>,
-    pub area: SelectFieldState<String>,
+    pub range: DistanceInput,
+    pub area: DistanceInput,
     pub shape_kind: SelectFieldState<ShapeKind>,
     pub shape: SpellShapeInput,
     pub description: TextAreaFieldState,
@@ -48,7 +64,7 @@ impl Fields {
         let areas = loader.areas.take()?;
         let sources = loader.sources.take()?;
 
-        let fields = Self {
+        let mut fields = Self {
             name: TextFieldState::default().required(true),
             aliases: MultiTextFieldState::default().normalize(false),
             school: SelectFieldState::new(schools, None).required(true),
@@ -64,8 +80,8 @@ impl Fields {
             material: false,
             materials: Vec::new(),
             duration: SelectFieldState::new(durations, None).required(true),
-            range: SelectFieldState::new(ranges, None).required(true),
-            area: SelectFieldState::new(areas, None).required(true),
+            range: DistanceInput::new(ranges),
+            area: DistanceInput::new(areas),
             shape_kind: SelectFieldState::new(ShapeKind::VARIANTS.iter().copied(), None)
                 .required(true),
             shape: SpellShapeInput::NoShape,
@@ -76,8 +92,363 @@ impl Fields {
             images: ImageFieldState::default(),
         };
 
+        // A draft holds unsaved work, so it takes precedence over the stored spell.
+        if let Some(draft) = loader.draft.take() {
+            fields.fill_draft(draft.data, draft.images);
+        } else if let Some(spell) = loader.spell.take() {
+            let images = loader.images.take().unwrap_or_default();
+            fields.fill(spell, images);
+        }
+
         Some(fields)
     }
+
+    /// Fill the fields with the values of an existing `spell`.
+    fn fill(&mut self, spell: SpellRecord, images: Box<[ImageRecord]>) {
+        self.name.set(spell.name);
+        self.aliases.set_selections(spell.aliases.into_vec());
+        self.school.set(spell.school);
+        self.level.set(spell.level);
+        if let Some(source) = spell.source {
+            self.source.set(source);
+        }
+        self.classes = parse_classes(&spell.classes);
+        self.tags.set_selections(spell.tags.into_vec());
+        self.casting_time.set(spell.casting_time);
+        self.ritual = spell.ritual;
+        self.concentration = spell.concentration;
+        self.verbal = spell.verbal;
+        self.somatic = spell.somatic;
+        self.material = spell.material;
+        self.materials = spell
+            .materials
+            .iter()
+            .map(|material| SpellMaterialInput {
+                material: TextFieldState::new(material.material.clone()),
+                worth: TextFieldState::new(material.worth.clone().unwrap_or_default()),
+                consumed: material.consumed,
+            })
+            .collect();
+        // Materials always keep a trailing empty input to write the next material in.
+        if self.material {
+            self.materials.push(SpellMaterialInput::default());
+        }
+        self.duration.set(spell.duration);
+        self.range.set(spell.range);
+        self.area.set(spell.area);
+        self.shape_kind.set(shape_kind(spell.shape));
+        self.shape = SpellShapeInput::from(spell.shape);
+        self.description = TextAreaFieldState::new(spell.description).required(true);
+        self.at_higher_levels = TextAreaFieldState::new(spell.at_higher_levels.unwrap_or_default());
+        self.flavor_text = TextAreaFieldState::new(spell.flavor_text.unwrap_or_default());
+        self.attribution.set(spell.attribution.unwrap_or_default());
+        self.images =
+            ImageFieldState::with_ids(images.into_iter().map(|image| (image.id, image.bytes)));
+    }
+
+    /// Fill the fields with the values of an autosaved draft.
+    fn fill_draft(&mut self, data: SpellDraftData, images: Box<[ImageRecord]>) {
+        let shape_kind = data.shape_kind.and_then(|kind| match kind.parse() {
+            Ok(kind) => Some(kind),
+            Err(err) => {
+                tracing::error!("failed to parse shape kind '{kind}': {err}");
+                None
+            }
+        });
+
+        self.name.set(data.name);
+        self.aliases.set_selections(data.aliases.into_vec());
+        restore_select(&mut self.school, data.school);
+        restore_select(&mut self.level, data.level);
+        restore_select(&mut self.source, data.source);
+        self.classes = parse_classes(&data.classes);
+        self.tags.set_selections(data.tags.into_vec());
+        restore_select(&mut self.casting_time, data.casting_time);
+        self.ritual = data.ritual;
+        self.concentration = data.concentration;
+        self.verbal = data.verbal;
+        self.somatic = data.somatic;
+        self.material = data.material;
+        self.materials = data
+            .materials
+            .into_iter()
+            .map(|material| SpellMaterialInput {
+                material: TextFieldState::new(material.material),
+                worth: TextFieldState::new(material.worth),
+                consumed: material.consumed,
+            })
+            .collect();
+        restore_select(&mut self.duration, data.duration);
+        if let Some(range) = data.range {
+            self.range.set(range);
+        }
+        if let Some(area) = data.area {
+            self.area.set(area);
+        }
+        restore_select(&mut self.shape_kind, shape_kind);
+        self.shape = SpellShapeInput::from(shape_kind.unwrap_or(ShapeKind::NoShape));
+        self.shape.set_dimensions(data.shape_dimensions.into_vec());
+        self.description.set(data.description);
+        self.at_higher_levels.set(data.at_higher_levels);
+        self.flavor_text.set(data.flavor_text);
+        self.attribution.set(data.attribution);
+        self.images =
+            ImageFieldState::with_ids(images.into_iter().map(|image| (image.id, image.bytes)));
+    }
+
+    /// The values of the fields as they are currently written, regardless of their validity.
+    pub fn draft_data(&self) -> SpellDraftData {
+        SpellDraftData {
+            name: String::from(self.name.value()),
+            aliases: self.aliases.selections().into(),
+            school: self.school.selected().cloned(),
+            level: self.level.selected().cloned(),
+            source: self.source.selected().cloned(),
+            classes: self.classes.iter().map(ToString::to_string).collect(),
+            tags: self.tags.selections().into(),
+            casting_time: self.casting_time.selected().cloned(),
+            ritual: self.ritual,
+            concentration: self.concentration,
+            verbal: self.verbal,
+            somatic: self.somatic,
+            material: self.material,
+            materials: self
+                .materials
+                .iter()
+                .map(|input| SpellDraftMaterial {
+                    material: String::from(input.material.value()),
+                    worth: String::from(input.worth.value()),
+                    consumed: input.consumed,
+                })
+                .collect(),
+            duration: self.duration.selected().cloned(),
+            range: self.range.draft_value(),
+            area: self.area.draft_value(),
+            shape_kind: self.shape_kind.selected().map(ToString::to_string),
+            shape_dimensions: self.shape.dimensions().into_boxed_slice(),
+            description: self.description.text(),
+            at_higher_levels: self.at_higher_levels.text(),
+            flavor_text: self.flavor_text.text(),
+            attribution: String::from(self.attribution.value()),
+        }
+    }
+
+    /// Validate every field and construct the spell with `id` from the fields.
+    ///
+    /// Returns `None` if any field is invalid, in which case the invalid fields display their errors.
+    pub fn try_new_spell(&mut self, id: Uuid) -> Option<NewSpell> {
+        // Every field is validated before returning, so all errors are shown at once.
+        let name = self.name.try_value();
+        let aliases = self.aliases.try_value();
+        let school = self.school.try_value();
+        let level = self.level.try_value();
+        let source = self.source.try_value();
+        let tags = self.tags.try_value();
+        let casting_time = self.casting_time.try_value();
+        let duration = self.duration.try_value();
+        let range = self.range.try_value();
+        let area = self.area.try_value();
+        let shape_kind = self.shape_kind.try_value();
+        let shape = self.shape.try_value();
+        let description = self.description.try_value();
+        let at_higher_levels = self.at_higher_levels.try_value();
+        let flavor_text = self.flavor_text.try_value();
+        let attribution = self.attribution.try_value();
+
+        let materials = if self.material {
+            self.materials
+                .iter_mut()
+                .filter_map(|input| {
+                    let material = input.material.try_value()?;
+
+                    Some(NewSpellMaterial {
+                        material,
+                        worth: input.worth.try_value(),
+                        consumed: input.consumed,
+                    })
+                })
+                .collect()
+        } else {
+            Box::default()
+        };
+
+        let images = self
+            .images
+            .images()
+            .into_iter()
+            .map(|(id, bytes)| NewImage { id, bytes })
+            .collect();
+
+        shape_kind?;
+
+        let new_spell = NewSpell {
+            id,
+            name: name?,
+            aliases: aliases?,
+            school: school?,
+            level: level?,
+            source,
+            classes: self.classes.clone().into_boxed_slice(),
+            tags: tags?,
+            casting_time: casting_time?,
+            ritual: self.ritual,
+            concentration: self.concentration,
+            verbal: self.verbal,
+            somatic: self.somatic,
+            material: self.material,
+            materials,
+            duration: duration?,
+            range: range?,
+            area: area?,
+            shape: shape?,
+            description: description?,
+            at_higher_levels,
+            flavor_text,
+            attribution,
+            images,
+        };
+
+        Some(new_spell)
+    }
+
+    /// Take a snapshot of the current value of `field`.
+    pub fn value(&self, field: Field) -> FieldValue {
+        let select =
+            |state: &SelectFieldState<String>| FieldValue::Select(state.selected().cloned());
+        let multi_text = |state: &MultiTextFieldState| FieldValue::MultiText {
+            input: String::from(state.value()),
+            selections: state.selections().to_vec(),
+        };
+
+        match field {
+            Field::Name => FieldValue::Text(String::from(self.name.value())),
+            Field::Aliases => multi_text(&self.aliases),
+            Field::School => select(&self.school),
+            Field::Level => select(&self.level),
+            Field::Source => select(&self.source),
+            Field::Classes => FieldValue::Classes(self.classes.clone()),
+            Field::Tags => multi_text(&self.tags),
+            Field::CastingTime => select(&self.casting_time),
+            Field::Ritual => FieldValue::Toggle(self.ritual),
+            Field::Concentration => FieldValue::Toggle(self.concentration),
+            Field::Verbal => FieldValue::Toggle(self.verbal),
+            Field::Somatic => FieldValue::Toggle(self.somatic),
+            Field::Materials => FieldValue::Materials {
+                enabled: self.material,
+                materials: self
+                    .materials
+                    .iter()
+                    .map(|input| MaterialValue {
+                        material: String::from(input.material.value()),
+                        worth: String::from(input.worth.value()),
+                        consumed: input.consumed,
+                    })
+                    .collect(),
+            },
+            Field::Duration => select(&self.duration),
+            Field::Range => self.range.value(),
+            Field::Area => self.area.value(),
+            Field::Shape => FieldValue::Shape {
+                kind: self.shape_kind.selected().copied(),
+                dimensions: self.shape.dimensions(),
+            },
+            Field::Description => FieldValue::Text(self.description.text()),
+            Field::AtHigherLevels => FieldValue::Text(self.at_higher_levels.text()),
+            Field::FlavorText => FieldValue::Text(self.flavor_text.text()),
+            Field::Attribution => FieldValue::Text(String::from(self.attribution.value())),
+            Field::Images => FieldValue::Images(self.images.clone()),
+        }
+    }
+
+    /// Restore `field` to a previous snapshot of its `value`.
+    pub fn restore(&mut self, field: Field, value: FieldValue) {
+        match (field, value) {
+            (Field::Name, FieldValue::Text(name)) => self.name.set(name),
+            (Field::Aliases, FieldValue::MultiText { input, selections }) => {
+                self.aliases.set_value(input);
+                self.aliases.set_selections(selections);
+            }
+            (Field::School, FieldValue::Select(school)) => restore_select(&mut self.school, school),
+            (Field::Level, FieldValue::Select(level)) => restore_select(&mut self.level, level),
+            (Field::Source, FieldValue::Select(source)) => restore_select(&mut self.source, source),
+            (Field::Classes, FieldValue::Classes(classes)) => self.classes = classes,
+            (Field::Tags, FieldValue::MultiText { input, selections }) => {
+                self.tags.set_value(input);
+                self.tags.set_selections(selections);
+            }
+            (Field::CastingTime, FieldValue::Select(casting_time)) => {
+                restore_select(&mut self.casting_time, casting_time);
+            }
+            (Field::Ritual, FieldValue::Toggle(ritual)) => self.ritual = ritual,
+            (Field::Concentration, FieldValue::Toggle(concentration)) => {
+                self.concentration = concentration;
+            }
+            (Field::Verbal, FieldValue::Toggle(verbal)) => self.verbal = verbal,
+            (Field::Somatic, FieldValue::Toggle(somatic)) => self.somatic = somatic,
+            (Field::Materials, FieldValue::Materials { enabled, materials }) => {
+                self.material = enabled;
+                self.materials = materials
+                    .into_iter()
+                    .map(|value| SpellMaterialInput {
+                        material: TextFieldState::new(value.material),
+                        worth: TextFieldState::new(value.worth),
+                        consumed: value.consumed,
+                    })
+                    .collect();
+            }
+            (Field::Duration, FieldValue::Select(duration)) => {
+                restore_select(&mut self.duration, duration);
+            }
+            (Field::Range, FieldValue::Distance { kind, amount }) => {
+                self.range.restore(kind, amount);
+            }
+            (Field::Area, FieldValue::Distance { kind, amount }) => {
+                self.area.restore(kind, amount);
+            }
+            (Field::Shape, FieldValue::Shape { kind, dimensions }) => {
+                restore_select(&mut self.shape_kind, kind);
+                self.shape = SpellShapeInput::from(kind.unwrap_or(ShapeKind::NoShape));
+                self.shape.set_dimensions(dimensions);
+            }
+            (Field::Description, FieldValue::Text(description)) => {
+                self.description.set(description);
+            }
+            (Field::AtHigherLevels, FieldValue::Text(at_higher_levels)) => {
+                self.at_higher_levels.set(at_higher_levels);
+            }
+            (Field::FlavorText, FieldValue::Text(flavor_text)) => {
+                self.flavor_text.set(flavor_text);
+            }
+            (Field::Attribution, FieldValue::Text(attribution)) => {
+                self.attribution.set(attribution);
+            }
+            (Field::Images, FieldValue::Images(images)) => self.images = images,
+            (field, value) => {
+                tracing::error!("cannot restore field '{field:?}' to value '{value:?}'");
+            }
+        }
+    }
+}
+
+/// Parse stored `classes`, skipping those that are not known classes.
+fn parse_classes(classes: &[String]) -> Vec<Class> {
+    classes
+        .iter()
+        .filter_map(|class| match class.parse() {
+            Ok(class) => Some(class),
+            Err(err) => {
+                tracing::error!("failed to parse spell class '{class}': {err}");
+                None
+            }
+        })
+        .collect()
+}
+
+fn restore_select<Value: Clone>(state: &mut SelectFieldState<Value>, value: Option<Value>) {
+    match value {
+        Some(value) => state.set(value),
+        None => state.clear(),
+    }
 }
 
 #[derive(Debug, Default)]
@@ -94,6 +465,119 @@ impl SpellMaterialInput {
     }
 }
 
+/// The kind of distance chosen in a [`DistanceInput`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DistanceKind {
+    /// An option that is not a measured distance, like "Self" or "Single target".
+    Preset(String),
+    Feet,
+    Miles,
+}
+
+impl Display for DistanceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceKind::Preset(preset) => f.write_str(preset),
+            DistanceKind::Feet => f.write_str("Feet"),
+            DistanceKind::Miles => f.write_str("Miles"),
+        }
+    }
+}
+
+/// An input for a distance, which is either a preset option or an amount of feet or miles.
+#[derive(Debug)]
+pub struct DistanceInput {
+    pub kind: SelectFieldState<DistanceKind>,
+    pub amount: NumberFieldState,
+}
+
+impl DistanceInput {
+    /// Create the input from the stored `options`.
+    ///
+    /// Measured options like "30 ft." are left out, as they are written with an amount instead.
+    fn new(options: Box<[String]>) -> Self {
+        let kinds = options
+            .into_iter()
+            .filter(|option| !option.parse().is_ok_and(Distance::is_measured))
+            .map(DistanceKind::Preset)
+            .chain([DistanceKind::Feet, DistanceKind::Miles]);
+
+        Self {
+            kind: SelectFieldState::new(kinds, None).required(true),
+            amount: NumberFieldState::new(None)
+                .required(true)
+                .non_negative(true),
+        }
+    }
+
+    /// Whether the chosen kind is measured in an amount of feet or miles.
+    pub fn is_measured(&self) -> bool {
+        matches!(
+            self.kind.selected(),
+            Some(DistanceKind::Feet | DistanceKind::Miles)
+        )
+    }
+
+    /// Set the input to a stored `value`.
+    pub fn set(&mut self, value: String) {
+        let amount = |amount: u32| {
+            NumberFieldState::new(i32::try_from(amount).ok())
+                .required(true)
+                .non_negative(true)
+        };
+
+        match value.parse() {
+            Ok(Distance::Feet(feet)) => {
+                self.kind.set(DistanceKind::Feet);
+                self.amount = amount(feet);
+            }
+            Ok(Distance::Miles(miles)) => {
+                self.kind.set(DistanceKind::Miles);
+                self.amount = amount(miles);
+            }
+            _ => self.kind.set(DistanceKind::Preset(value)),
+        }
+    }
+
+    /// Get the value of the distance as it is stored, if it is valid, otherwise None.
+    pub fn try_value(&mut self) -> Option<String> {
+        let distance = match self.kind.try_value()? {
+            DistanceKind::Preset(preset) => return Some(preset),
+            DistanceKind::Feet => Distance::Feet(self.try_amount()?),
+            DistanceKind::Miles => Distance::Miles(self.try_amount()?),
+        };
+
+        Some(distance.to_string())
+    }
+
+    fn try_amount(&mut self) -> Option<u32> {
+        u32::try_from(self.amount.try_value()?).ok()
+    }
+
+    /// The value of the distance as it is currently written, if it is complete.
+    fn draft_value(&self) -> Option<String> {
+        let amount = self.amount.raw_value().parse().ok();
+
+        match self.kind.selected()? {
+            DistanceKind::Preset(preset) => Some(preset.clone()),
+            DistanceKind::Feet => amount.map(|feet| Distance::Feet(feet).to_string()),
+            DistanceKind::Miles => amount.map(|miles| Distance::Miles(miles).to_string()),
+        }
+    }
+
+    fn value(&self) -> FieldValue {
+        FieldValue::Distance {
+            kind: self.kind.selected().cloned(),
+            amount: String::from(self.amount.raw_value()),
+        }
+    }
+
+    fn restore(&mut self, kind: Option<DistanceKind>, amount: String) {
+        restore_select(&mut self.kind, kind);
+        self.amount.set(amount);
+    }
+}
+
 pub enum SpellShapeInput {
     NoShape,
     Cone {
@@ -115,6 +599,75 @@ pub enum SpellShapeInput {
     },
 }
 
+impl SpellShapeInput {
+    /// Get the value of the shape, if all of its dimensions are valid, otherwise None.
+    pub fn try_value(&mut self) -> Option<NewSpellShape> {
+        match self {
+            SpellShapeInput::NoShape => Some(NewSpellShape::NoShape),
+            SpellShapeInput::Cone { length } => Some(NewSpellShape::Cone {
+                length: length.try_value()?,
+            }),
+            SpellShapeInput::Cube { length } => Some(NewSpellShape::Cube {
+                length: length.try_value()?,
+            }),
+            SpellShapeInput::Cylinder { radius, height } => {
+                let (radius, height) = (radius.try_value(), height.try_value());
+
+                Some(NewSpellShape::Cylinder {
+                    radius: radius?,
+                    height: height?,
+                })
+            }
+            SpellShapeInput::Line { width, length } => {
+                let (width, length) = (width.try_value(), length.try_value());
+
+                Some(NewSpellShape::Line {
+                    width: width?,
+                    length: length?,
+                })
+            }
+            SpellShapeInput::Sphere { radius } => Some(NewSpellShape::Sphere {
+                radius: radius.try_value()?,
+            }),
+        }
+    }
+
+    /// The dimensions of the shape as written in their inputs, in declaration order.
+    fn dimensions(&self) -> Vec<String> {
+        self.inputs()
+            .into_iter()
+            .map(|input| String::from(input.raw_value()))
+            .collect()
+    }
+
+    /// Write `dimensions` into the inputs of the shape, in declaration order.
+    fn set_dimensions(&mut self, dimensions: Vec<String>) {
+        for (input, dimension) in self.inputs_mut().into_iter().zip(dimensions) {
+            input.set(dimension);
+        }
+    }
+
+    fn inputs(&self) -> Vec<&NumberFieldState> {
+        match self {
+            SpellShapeInput::NoShape => Vec::new(),
+            SpellShapeInput::Cone { length } | SpellShapeInput::Cube { length } => vec![length],
+            SpellShapeInput::Cylinder { radius, height } => vec![radius, height],
+            SpellShapeInput::Line { width, length } => vec![width, length],
+            SpellShapeInput::Sphere { radius } => vec![radius],
+        }
+    }
+
+    fn inputs_mut(&mut self) -> Vec<&mut NumberFieldState> {
+        match self {
+            SpellShapeInput::NoShape => Vec::new(),
+            SpellShapeInput::Cone { length } | SpellShapeInput::Cube { length } => vec![length],
+            SpellShapeInput::Cylinder { radius, height } => vec![radius, height],
+            SpellShapeInput::Line { width, length } => vec![width, length],
+            SpellShapeInput::Sphere { radius } => vec![radius],
+        }
+    }
+}
+
 impl From<ShapeKind> for SpellShapeInput {
     fn from(kind: ShapeKind) -> Self {
         let input = NumberFieldState::new(None).required(true);
@@ -134,3 +687,41 @@ impl From<ShapeKind> for SpellShapeInput {
         }
     }
 }
+
+impl From<SpellShapeRecord> for SpellShapeInput {
+    fn from(shape: SpellShapeRecord) -> Self {
+        let input = |value| NumberFieldState::new(Some(value)).required(true);
+        match shape {
+            SpellShapeRecord::NoShape => Self::NoShape,
+            SpellShapeRecord::Cone { length } => Self::Cone {
+                length: input(length),
+            },
+            SpellShapeRecord::Cube { length } => Self::Cube {
+                length: input(length),
+            },
+            SpellShapeRecord::Cylinder { radius, height } => Self::Cylinder {
+                radius: input(radius),
+                height: input(height),
+            },
+            SpellShapeRecord::Line { width, length } => Self::Line {
+                width: input(width),
+                length: input(length),
+            },
+            SpellShapeRecord::Sphere { radius } => Self::Sphere {
+                radius: input(radius),
+            },
+        }
+    }
+}
+
+/// The kind of a stored `shape`.
+fn shape_kind(shape: SpellShapeRecord) -> ShapeKind {
+    match shape {
+        SpellShapeRecord::NoShape => ShapeKind::NoShape,
+        SpellShapeRecord::Cone { .. } => ShapeKind::Cone,
+        SpellShapeRecord::Cube { .. } => ShapeKind::Cube,
+        SpellShapeRecord::Cylinder { .. } => ShapeKind::Cylinder,
+        SpellShapeRecord::Line { .. } => ShapeKind::Line,
+        SpellShapeRecord::Sphere { .. } => ShapeKind::Sphere,
+    }
+}
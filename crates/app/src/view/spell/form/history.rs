@@ -0,0 +1,511 @@
+use crate::view::spell::form::fields::DistanceKind;
+use components::image_field::state::ImageFieldState;
+use types::Class;
+use types::ShapeKind;
+
+use std::collections::VecDeque;
+use std::time::Duration;
+use std::time::Instant;
+
+/// The maximum number of edits that can be undone.
+const MAX_EDITS: usize = 200;
+
+/// How long typing can pause before the following keystrokes start a new edit.
+const TYPING_PAUSE: Duration = Duration::from_secs(1);
+
+/// A field of the spell form that can be edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Name,
+    Aliases,
+    School,
+    Level,
+    Source,
+    Classes,
+    Tags,
+    CastingTime,
+    Ritual,
+    Concentration,
+    Verbal,
+    Somatic,
+    Materials,
+    Duration,
+    Range,
+    Area,
+    Shape,
+    Description,
+    AtHigherLevels,
+    FlavorText,
+    Attribution,
+    Images,
+}
+
+impl Field {
+    /// Whether consecutive edits of the field are merged into a single edit.
+    ///
+    /// This is the case for fields that are typed in, so undoing reverts a burst of typing rather
+    /// than a single character.
+    fn is_typed(self) -> bool {
+        matches!(
+            self,
+            Field::Name
+                | Field::Aliases
+                | Field::Tags
+                | Field::Materials
+                | Field::Range
+                | Field::Area
+                | Field::Shape
+                | Field::Description
+                | Field::AtHigherLevels
+                | Field::FlavorText
+                | Field::Attribution
+        )
+    }
+}
+
+/// A snapshot of the value of a single [`Field`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Text(String),
+    MultiText {
+        input: String,
+        selections: Vec<String>,
+    },
+    Select(Option<String>),
+    Toggle(bool),
+    Classes(Vec<Class>),
+    Materials {
+        enabled: bool,
+        materials: Vec<MaterialValue>,
+    },
+    Distance {
+        kind: Option<DistanceKind>,
+        amount: String,
+    },
+    Shape {
+        kind: Option<ShapeKind>,
+        dimensions: Vec<String>,
+    },
+    /// The images share their bytes with the form, so taking a snapshot does not copy them.
+    Images(ImageFieldState),
+}
+
+impl FieldValue {
+    /// Whether the change from `self` to `after` is a single keystroke of typing or deleting.
+    ///
+    /// Clearing a field, replacing a selection and pasting are not keystrokes. Neither is any
+    /// change to the value other than its text, like picking a kind, toggling or removing a row.
+    fn is_keystroke(&self, after: &FieldValue) -> bool {
+        match (self, after) {
+            (FieldValue::Text(before), FieldValue::Text(after)) => is_keystroke(before, after),
+            (
+                FieldValue::MultiText {
+                    input: before,
+                    selections: selections_before,
+                },
+                FieldValue::MultiText {
+                    input: after,
+                    selections: selections_after,
+                },
+            ) => selections_before == selections_after && is_keystroke(before, after),
+            (
+                FieldValue::Materials {
+                    enabled: enabled_before,
+                    materials: before,
+                },
+                FieldValue::Materials {
+                    enabled: enabled_after,
+                    materials: after,
+                },
+            ) => {
+                let same_rows = enabled_before == enabled_after
+                    && before.len() == after.len()
+                    && before
+                        .iter()
+                        .zip(after)
+                        .all(|(before, after)| before.consumed == after.consumed);
+
+                same_rows
+                    && is_single_keystroke(before.iter().zip(after).flat_map(|(before, after)| {
+                        [
+                            (before.material.as_str(), after.material.as_str()),
+                            (before.worth.as_str(), after.worth.as_str()),
+                        ]
+                    }))
+            }
+            (
+                FieldValue::Distance {
+                    kind: kind_before,
+                    amount: before,
+                },
+                FieldValue::Distance {
+                    kind: kind_after,
+                    amount: after,
+                },
+            ) => kind_before == kind_after && is_keystroke(before, after),
+            (
+                FieldValue::Shape {
+                    kind: kind_before,
+                    dimensions: before,
+                },
+                FieldValue::Shape {
+                    kind: kind_after,
+                    dimensions: after,
+                },
+            ) => {
+                kind_before == kind_after
+                    && before.len() == after.len()
+                    && is_single_keystroke(
+                        before
+                            .iter()
+                            .zip(after)
+                            .map(|(before, after)| (before.as_str(), after.as_str())),
+                    )
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Whether exactly one of the `inputs`, as pairs of text before and after, changed, and by a
+/// single keystroke.
+fn is_single_keystroke<'a>(inputs: impl Iterator<Item = (&'a str, &'a str)>) -> bool {
+    let mut changed = inputs.filter(|(before, after)| before != after);
+
+    match (changed.next(), changed.next()) {
+        (Some((before, after)), None) => is_keystroke(before, after),
+        _ => false,
+    }
+}
+
+/// Whether the text went from `before` to `after` by typing or deleting a single character.
+fn is_keystroke(before: &str, after: &str) -> bool {
+    if after.is_empty() {
+        return false;
+    }
+
+    let before: Vec<char> = before.chars().collect();
+    let after: Vec<char> = after.chars().collect();
+
+    let prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(before, after)| before == after)
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(before, after)| before == after)
+        .count();
+
+    let removed = before.len() - prefix - suffix;
+    let inserted = after.len() - prefix - suffix;
+
+    removed + inserted == 1
+}
+
+/// A snapshot of a single spell material input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialValue {
+    pub material: String,
+    pub worth: String,
+    pub consumed: bool,
+}
+
+/// A single reversible edit of a field, from its value `before` to its value `after`.
+#[derive(Debug)]
+struct Edit {
+    field: Field,
+    before: FieldValue,
+    after: FieldValue,
+}
+
+/// The undo and redo history of the spell form.
+#[derive(Debug, Default)]
+pub struct History {
+    undo: VecDeque<Edit>,
+    redo: Vec<Edit>,
+
+    /// Whether the latest edit may still absorb the following edit of the same field.
+    ///
+    /// Stepping through the history closes it, so typing after an undo starts a new edit.
+    open: bool,
+
+    /// When the latest edit was recorded or last absorbed an edit.
+    edited_at: Option<Instant>,
+}
+
+impl History {
+    /// Record an edit of `field` from `before` to `after`.
+    ///
+    /// Edits that do not change the value are ignored, and any edit clears the redo history.
+    /// Returns whether the edit was recorded.
+    pub fn record(&mut self, field: Field, before: FieldValue, after: FieldValue) -> bool {
+        self.record_at(field, before, after, Instant::now())
+    }
+
+    /// Record an edit of `field` from `before` to `after`, made at `now`.
+    ///
+    /// Keystrokes in the same field are merged into the latest edit, until typing pauses.
+    fn record_at(
+        &mut self,
+        field: Field,
+        before: FieldValue,
+        after: FieldValue,
+        now: Instant,
+    ) -> bool {
+        if before == after {
+            return false;
+        }
+
+        self.redo.clear();
+
+        let keystroke = field.is_typed() && before.is_keystroke(&after);
+        let paused = self
+            .edited_at
+            .is_none_or(|edited_at| now.duration_since(edited_at) >= TYPING_PAUSE);
+        self.edited_at = Some(now);
+
+        if self.open
+            && keystroke
+            && !paused
+            && let Some(last) = self.undo.back_mut()
+            && last.field == field
+        {
+            last.after = after;
+
+            return true;
+        }
+
+        if self.undo.len() == MAX_EDITS {
+            self.undo.pop_front();
+        }
+
+        self.undo.push_back(Edit {
+            field,
+            before,
+            after,
+        });
+
+        // Clearing, replacing a selection and pasting stay an edit of their own.
+        self.open = keystroke;
+
+        true
+    }
+
+    /// Step back one edit, returning the field and the value it must be restored to.
+    pub fn undo(&mut self) -> Option<(Field, FieldValue)> {
+        let edit = self.undo.pop_back()?;
+        let restored = (edit.field, edit.before.clone());
+
+        self.redo.push(edit);
+        self.open = false;
+
+        Some(restored)
+    }
+
+    /// Step forward one undone edit, returning the field and the value it must be restored to.
+    pub fn redo(&mut self) -> Option<(Field, FieldValue)> {
+        let edit = self.redo.pop()?;
+        let restored = (edit.field, edit.after.clone());
+
+        self.undo.push_back(edit);
+        self.open = false;
+
+        Some(restored)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> FieldValue {
+        FieldValue::Text(String::from(value))
+    }
+
+    /// Type `typed` into the name field one character at a time, starting at `now`.
+    fn type_name(history: &mut History, before: &str, typed: &str, now: Instant) -> String {
+        let mut value = String::from(before);
+
+        for character in typed.chars() {
+            let previous = value.clone();
+            value.push(character);
+
+            history.record_at(Field::Name, text(&previous), text(&value), now);
+        }
+
+        value
+    }
+
+    #[test]
+    fn typing_is_undone_at_once() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        type_name(&mut history, "", "Fireball", now);
+
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn pause_starts_new_edit() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        let value = type_name(&mut history, "", "Fire", now);
+        type_name(&mut history, &value, "ball", now + TYPING_PAUSE);
+
+        assert_eq!(history.undo(), Some((Field::Name, text("Fire"))));
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+    }
+
+    #[test]
+    fn clearing_is_edit_of_its_own() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        let value = type_name(&mut history, "", "Fireball", now);
+        history.record_at(Field::Name, text(&value), text(""), now);
+        type_name(&mut history, "", "Ice", now);
+
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+        assert_eq!(history.undo(), Some((Field::Name, text("Fireball"))));
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+    }
+
+    #[test]
+    fn replacing_selection_is_edit_of_its_own() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        let value = type_name(&mut history, "", "Fireball", now);
+        history.record_at(Field::Name, text(&value), text("I"), now);
+
+        assert_eq!(history.undo(), Some((Field::Name, text("Fireball"))));
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+    }
+
+    #[test]
+    fn deleting_merges_with_typing() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        let value = type_name(&mut history, "", "Fireballs", now);
+        history.record_at(Field::Name, text(&value), text("Fireball"), now);
+
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+    }
+
+    #[test]
+    fn other_field_starts_new_edit() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        type_name(&mut history, "", "Fireball", now);
+        history.record_at(Field::Description, text(""), text("B"), now);
+        history.record_at(Field::Description, text("B"), text("Bo"), now);
+
+        assert_eq!(history.undo(), Some((Field::Description, text(""))));
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+    }
+
+    #[test]
+    fn typing_after_undo_clears_redo() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        type_name(&mut history, "", "Fireball", now);
+        history.undo();
+
+        assert!(history.can_redo());
+        assert_eq!(history.redo(), Some((Field::Name, text("Fireball"))));
+
+        history.undo();
+        type_name(&mut history, "", "Ice", now);
+
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some((Field::Name, text(""))));
+    }
+
+    #[test]
+    fn changing_distance_kind_is_edit_of_its_own() {
+        let mut history = History::default();
+        let now = Instant::now();
+
+        let distance = |kind: DistanceKind, amount: &str| FieldValue::Distance {
+            kind: Some(kind),
+            amount: String::from(amount),
+        };
+
+        history.record_at(
+            Field::Range,
+            distance(DistanceKind::Feet, ""),
+            distance(DistanceKind::Feet, "3"),
+            now,
+        );
+        history.record_at(
+            Field::Range,
+            distance(DistanceKind::Feet, "3"),
+            distance(DistanceKind::Feet, "30"),
+            now,
+        );
+        history.record_at(
+            Field::Range,
+            distance(DistanceKind::Feet, "30"),
+            distance(DistanceKind::Miles, "30"),
+            now,
+        );
+
+        assert_eq!(
+            history.undo(),
+            Some((Field::Range, distance(DistanceKind::Feet, "30")))
+        );
+        assert_eq!(
+            history.undo(),
+            Some((Field::Range, distance(DistanceKind::Feet, "")))
+        );
+    }
+
+    #[test]
+    fn unchanged_value_is_ignored() {
+        let mut history = History::default();
+
+        assert!(!history.record(
+            Field::Ritual,
+            FieldValue::Toggle(true),
+            FieldValue::Toggle(true)
+        ));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn oldest_edits_are_dropped() {
+        let mut history = History::default();
+
+        for index in 0..=MAX_EDITS {
+            history.record(
+                Field::Ritual,
+                FieldValue::Toggle(index % 2 == 0),
+                FieldValue::Toggle(index % 2 == 1),
+            );
+        }
+
+        let mut undone = 0;
+        while history.undo().is_some() {
+            undone += 1;
+        }
+
+        assert_eq!(undone, MAX_EDITS);
+    }
+}
@@ -1,13 +1,20 @@
 use crate::context::Context;
 use storage::Error;
+use storage::records::ImageRecord;
+use storage::records::draft::SpellDraftRecord;
+use storage::records::spell::SpellRecord;
 use storage::repositories::options::OptionsRepository;
 use storage::repositories::options::Variant;
+use storage::repositories::spells::SpellsRepository;
+use types::FormMode;
 
 use iced::Task;
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum LoadMessage {
     OptionsLoaded(Variant, Result<Box<[String]>, Error>),
+    SpellLoaded(Result<(Box<SpellRecord>, Box<[ImageRecord]>), Error>),
 }
 
 pub struct Loader {
@@ -26,10 +33,24 @@ pub struct Loader {
     pub ranges: Option<Box<[String]>>,
     pub areas: Option<Box<[String]>>,
     pub sources: Option<Box<[String]>>,
+
+    /// The spell being edited, only loaded in [`FormMode::Edit`].
+    pub spell: Option<SpellRecord>,
+
+    /// The images of the spell being edited, only loaded in [`FormMode::Edit`].
+    pub images: Option<Box<[ImageRecord]>>,
+
+    /// The draft the form is restored from, if any.
+    pub draft: Option<SpellDraftRecord>,
 }
 
 impl Loader {
-    pub fn new(context: Context) -> (Self, Task<LoadMessage>) {
+    /// Load the form in `mode`, restoring the fields from `draft` if there is one.
+    pub fn new(
+        context: Context,
+        mode: &FormMode,
+        draft: Option<SpellDraftRecord>,
+    ) -> (Self, Task<LoadMessage>) {
         let variants = &[
             Variant::School,
             Variant::Level,
@@ -40,7 +61,7 @@ impl Loader {
             Variant::Source,
         ];
 
-        let mut tasks = Vec::with_capacity(variants.len());
+        let mut tasks = Vec::with_capacity(variants.len() + 1);
         for variant in variants {
             let task = Task::perform(load_options(context.clone(), *variant), |result| {
                 LoadMessage::OptionsLoaded(*variant, result)
@@ -49,6 +70,14 @@ impl Loader {
             tasks.push(task);
         }
 
+        // The draft replaces the stored spell, which is therefore not loaded.
+        if let FormMode::Edit(id) = mode
+            && draft.is_none()
+        {
+            let task = Task::perform(load_spell(context.clone(), *id), LoadMessage::SpellLoaded);
+            tasks.push(task);
+        }
+
         let loader = Self {
             total: tasks.len(),
             progress: 0,
@@ -60,6 +89,9 @@ impl Loader {
             ranges: None,
             areas: None,
             sources: None,
+            spell: None,
+            images: None,
+            draft,
         };
 
         (loader, Task::batch(tasks))
@@ -80,6 +112,14 @@ impl Loader {
                 tracing::error!("failed to load options for '{:?}': {:?}", variant, err);
                 self.error = Some(err);
             }
+            LoadMessage::SpellLoaded(Ok((spell, images))) => {
+                self.spell = Some(*spell);
+                self.images = Some(images);
+            }
+            LoadMessage::SpellLoaded(Err(err)) => {
+                tracing::error!("failed to load spell: {:?}", err);
+                self.error = Some(err);
+            }
         }
 
         self.progress += 1;
@@ -97,3 +137,13 @@ async fn load_options<C: OptionsRepository>(
     let repo = ctx.options();
     repo.list_options(variant).await
 }
+
+async fn load_spell<C: SpellsRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<(Box<SpellRecord>, Box<[ImageRecord]>), Error> {
+    let spell = ctx.spells().get(id).await?;
+    let images = ctx.spells().images(id).await?;
+
+    Ok((Box::new(spell), images))
+}
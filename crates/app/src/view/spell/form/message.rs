@@ -1,3 +1,5 @@
+use crate::view::spell::form::fields::DistanceKind;
+use crate::view::spell::form::history::Field;
 use crate::view::spell::form::loader::LoadMessage;
 use components::image_field::error::ImageError;
 use storage::Error;
@@ -6,6 +8,7 @@ use types::ShapeKind;
 
 use iced::widget::text_editor::Action;
 use std::path::PathBuf;
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum Message {
@@ -31,8 +34,10 @@ pub enum Message {
     MaterialWorthChanged(usize, String),
     MaterialConsumed(usize),
     DurationSelected(String),
-    RangeSelected(String),
-    AreaSelected(String),
+    RangeKindSelected(DistanceKind),
+    RangeAmountChanged(String),
+    AreaKindSelected(DistanceKind),
+    AreaAmountChanged(String),
     ShapeKindSelected(ShapeKind),
     ShapeLengthChanged(String),
     ShapeRadiusChanged(String),
@@ -48,9 +53,65 @@ pub enum Message {
     ImagePickerOpened,
     ImageFileSelected(Option<PathBuf>),
     ImageFileLoaded(Result<Box<[u8]>, ImageError>),
+    Undo,
+    Redo,
+    DraftSaved(Result<(), Error>),
+    Submitted,
+    Saved(Result<Uuid, Error>),
+}
+
+impl Message {
+    /// The field edited by the message, if any.
+    pub fn field(&self) -> Option<Field> {
+        let field = match self {
+            Message::NameChanged(_) => Field::Name,
+            Message::AliasChanged(_) | Message::AliasSubmitted | Message::AliasRemoved(_) => {
+                Field::Aliases
+            }
+            Message::SchoolSelected(_) => Field::School,
+            Message::LevelSelected(_) => Field::Level,
+            Message::SourceSelected(_) => Field::Source,
+            Message::ClassToggled(_) => Field::Classes,
+            Message::TagChanged(_) | Message::TagSubmitted | Message::TagRemoved(_) => Field::Tags,
+            Message::CastingTimeSelected(_) => Field::CastingTime,
+            Message::RitualToggled => Field::Ritual,
+            Message::ConcentrationToggled => Field::Concentration,
+            Message::VerbalToggled => Field::Verbal,
+            Message::SomaticToggled => Field::Somatic,
+            Message::MaterialToggled
+            | Message::MaterialChanged(..)
+            | Message::MaterialWorthChanged(..)
+            | Message::MaterialConsumed(_) => Field::Materials,
+            Message::DurationSelected(_) => Field::Duration,
+            Message::RangeKindSelected(_) | Message::RangeAmountChanged(_) => Field::Range,
+            Message::AreaKindSelected(_) | Message::AreaAmountChanged(_) => Field::Area,
+            Message::ShapeKindSelected(_)
+            | Message::ShapeLengthChanged(_)
+            | Message::ShapeRadiusChanged(_)
+            | Message::ShapeHeightChanged(_)
+            | Message::ShapeWidthChanged(_) => Field::Shape,
+            // Only edits change the text, other actions merely move the cursor or selection.
+            Message::DescriptionChanged(action) if action.is_edit() => Field::Description,
+            Message::AtHigherLevelsChanged(action) if action.is_edit() => Field::AtHigherLevels,
+            Message::FlavorTextChanged(action) if action.is_edit() => Field::FlavorText,
+            Message::AttributionChanged(_) => Field::Attribution,
+            Message::ImageLoaded(Ok(_))
+            | Message::ImageFileLoaded(Ok(_))
+            | Message::ImageRemoved(_) => Field::Images,
+            _ => return None,
+        };
+
+        Some(field)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum Effect {
     LoadFailed(Error),
+
+    /// The spell with the given id was created or updated.
+    Saved(Uuid),
+
+    /// The spell could not be saved, either due to invalid fields or a storage error.
+    SaveFailed,
 }
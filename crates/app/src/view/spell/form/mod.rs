@@ -1,4 +1,5 @@
 mod fields;
+mod history;
 mod loader;
 pub mod message;
 
@@ -7,14 +8,27 @@ use crate::status::Status;
 use crate::status::loading;
 use crate::status::ready;
 use crate::view::Viewable;
+use crate::view::spell::form::fields::DistanceInput;
+use crate::view::spell::form::fields::DistanceKind;
 use crate::view::spell::form::fields::Fields;
 use crate::view::spell::form::fields::SpellMaterialInput;
 use crate::view::spell::form::fields::SpellShapeInput;
+use crate::view::spell::form::history::History;
 use crate::view::spell::form::loader::Loader;
 use crate::view::spell::form::message::Effect;
 use crate::view::spell::form::message::Message;
+use components::image_field::state::ImageFieldState;
 use components::label::Label;
+use storage::Error;
+use storage::models::NewImage;
+use storage::models::draft::NewSpellDraft;
+use storage::models::spell::NewSpell;
+use storage::records::draft::SpellDraftRecord;
+use storage::repositories::spell_drafts::SpellDraftsRepository;
+use storage::repositories::spells::SpellsRepository;
+use style::button::ButtonClass;
 use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
 use style::layout::LABEL_SPACING;
 use style::layout::SECTION_SPACING;
 use types::FormMode;
@@ -22,33 +36,151 @@ use types::SPELLCASTING_CLASSES;
 use widgets::Element;
 
 use iced::Alignment;
+use iced::Event;
 use iced::Length;
 use iced::Length::Fill;
+use iced::Subscription;
 use iced::Task;
+use iced::event;
+use iced::keyboard;
+use iced::keyboard::Key;
 use iced::widget;
 use iced::widget::Column;
 use iced::widget::Row;
 use iced::widget::column;
 use iced::widget::row;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::sync::OwnedMutexGuard;
+use uuid::Uuid;
 
 pub struct SpellForm {
     mode: FormMode,
+    context: Context,
     status: Status<Loader, Fields>,
+    history: History,
+
+    /// The id the fields are autosaved under, which is kept when restoring a draft.
+    draft_id: Uuid,
+
+    /// Whether the fields changed since they were last autosaved.
+    draft_changed: bool,
+
+    /// The images as they were last autosaved, as they are only written again once changed.
+    draft_images: Option<ImageFieldState>,
+
+    /// Held while a draft is being written, so the draft is only discarded after it is written.
+    draft_lock: Arc<Mutex<()>>,
+
+    /// Whether the fields changed since the spell was last saved.
+    unsaved: bool,
+
+    /// Whether the spell is currently being saved, used to prevent duplicate submissions.
+    saving: bool,
 }
 
 impl<'a> SpellForm {
     pub fn new(mode: FormMode, context: Context) -> (Self, Task<Message>) {
-        let (loader, tasks) = Loader::new(context);
+        Self::with_draft(mode, context, None)
+    }
+
+    /// Create a form that restores the unsaved work of `draft`.
+    pub fn from_draft(draft: SpellDraftRecord, context: Context) -> (Self, Task<Message>) {
+        let mode = match draft.spell_id {
+            Some(id) => FormMode::Edit(id),
+            None => FormMode::Create,
+        };
+
+        Self::with_draft(mode, context, Some(draft))
+    }
+
+    fn with_draft(
+        mode: FormMode,
+        context: Context,
+        draft: Option<SpellDraftRecord>,
+    ) -> (Self, Task<Message>) {
+        let draft_id = draft.as_ref().map_or_else(Uuid::new_v4, |draft| draft.id);
+
+        // A restored draft is unsaved work by definition.
+        let unsaved = draft.is_some();
+        let (loader, tasks) = Loader::new(context.clone(), &mode, draft);
         let mapped_tasks = tasks.map(Message::LoadMessage);
 
         let spell_form = Self {
             mode,
+            context,
             status: Status::Loading(Box::new(loader)),
+            history: History::default(),
+            draft_id,
+            draft_changed: false,
+            draft_images: None,
+            draft_lock: Arc::default(),
+            unsaved,
+            saving: false,
         };
 
         (spell_form, mapped_tasks)
     }
 
+    /// Autosave the fields as a draft, if they changed since they were last autosaved.
+    pub fn autosave(&mut self) -> Task<Message> {
+        if !self.draft_changed || self.saving {
+            return Task::none();
+        }
+
+        let Some(fields) = self.status.as_ready() else {
+            return Task::none();
+        };
+
+        // The changes wait for the next autosave while the previous draft is still being written.
+        let Ok(guard) = Arc::clone(&self.draft_lock).try_lock_owned() else {
+            return Task::none();
+        };
+
+        let spell_id = match self.mode {
+            FormMode::Create => None,
+            FormMode::Edit(id) => Some(id),
+        };
+
+        let images = (self.draft_images.as_ref() != Some(&fields.images)).then(|| {
+            fields
+                .images
+                .images()
+                .into_iter()
+                .map(|(id, bytes)| NewImage { id, bytes })
+                .collect()
+        });
+
+        let draft = NewSpellDraft {
+            id: self.draft_id,
+            spell_id,
+            data: fields.draft_data(),
+            images,
+        };
+
+        self.draft_changed = false;
+        self.draft_images = Some(fields.images.clone());
+
+        Task::perform(
+            save_draft(self.context.clone(), draft, guard),
+            Message::DraftSaved,
+        )
+    }
+
+    /// Discard the autosaved draft of the form, once its work is either saved or abandoned.
+    ///
+    /// A draft that is still being written is discarded once it is written.
+    pub fn discard_draft(&self) -> Task<Message> {
+        let draft_lock = Arc::clone(&self.draft_lock);
+
+        Task::future(delete_draft(
+            self.context.clone(),
+            self.draft_id,
+            draft_lock,
+        ))
+        .discard()
+    }
+
     fn heading(_fields: &'a Fields) -> Element<'a, Message> {
         let title = components::text::view_title("Spell Forge");
 
@@ -213,11 +345,21 @@ impl<'a> SpellForm {
             components::select_field("DURATION", &fields.duration, Message::DurationSelected)
                 .placeholder("Select a duration");
 
-        let range = components::select_field("RANGE", &fields.range, Message::RangeSelected)
-            .placeholder("Select a range");
+        let range = distance_field(
+            "RANGE",
+            "Select a range",
+            &fields.range,
+            Message::RangeKindSelected,
+            Message::RangeAmountChanged,
+        );
 
-        let area = components::select_field("AREA", &fields.area, Message::AreaSelected)
-            .placeholder("Select an area");
+        let area = distance_field(
+            "AREA",
+            "Select an area",
+            &fields.area,
+            Message::AreaKindSelected,
+            Message::AreaAmountChanged,
+        );
 
         let shape: Element<_> = {
             let kind =
@@ -329,21 +471,33 @@ impl<'a> SpellForm {
 
         row![header, body].into()
     }
-}
 
-impl Viewable for SpellForm {
-    type Message = Message;
+    fn actions(history: &History, saving: bool) -> Element<'a, Message> {
+        let undo = widget::button(components::text::display("Undo"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(history.can_undo().then_some(Message::Undo));
 
-    type Effect = Effect;
+        let redo = widget::button(components::text::display("Redo"))
+            .class(ButtonClass::Ghost)
+            .padding(INPUT_PADDING)
+            .on_press_maybe(history.can_redo().then_some(Message::Redo));
 
-    fn title(&self) -> &str {
-        match self.mode {
-            FormMode::Create => "Create spell",
-            FormMode::Edit(_) => "Edit spell",
-        }
+        let label = if saving { "Saving..." } else { "Save spell" };
+
+        let save = widget::button(components::text::display(label))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe((!saving).then_some(Message::Submitted));
+
+        row![undo, redo, widget::space::horizontal().width(Fill), save]
+            .spacing(BODY_SPACING)
+            .width(Fill)
+            .into()
     }
 
-    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+    /// Apply `message` to the form, without recording it in the history.
+    fn apply(&mut self, message: Message) -> (Task<Message>, Option<Effect>) {
         match message {
             Message::LoadMessage(load_message) => {
                 let loader = loading!(self.status);
@@ -508,20 +662,31 @@ impl Viewable for SpellForm {
 
                 fields.duration.set(duration);
             }
-            Message::RangeSelected(range) => {
+            Message::RangeKindSelected(kind) => {
+                let fields = ready!(self.status);
+
+                fields.range.kind.set(kind);
+            }
+            Message::RangeAmountChanged(amount) => {
                 let fields = ready!(self.status);
 
-                fields.range.set(range);
+                fields.range.amount.set(amount);
             }
-            Message::AreaSelected(area) => {
+            Message::AreaKindSelected(kind) => {
                 let fields = ready!(self.status);
 
-                fields.area.set(area);
+                fields.area.kind.set(kind);
+            }
+            Message::AreaAmountChanged(amount) => {
+                let fields = ready!(self.status);
+
+                fields.area.amount.set(amount);
             }
             Message::ShapeKindSelected(shape_kind) => {
                 let fields = ready!(self.status);
 
                 fields.shape_kind.set(shape_kind);
+                fields.shape = SpellShapeInput::from(shape_kind);
             }
             Message::ShapeLengthChanged(new_length) => {
                 let fields = ready!(self.status);
@@ -625,10 +790,143 @@ impl Viewable for SpellForm {
             Message::ImageFileLoaded(Err(err)) => {
                 tracing::error!("{err}");
             }
+            Message::Undo => {
+                let fields = ready!(self.status);
+
+                if let Some((field, value)) = self.history.undo() {
+                    fields.restore(field, value);
+                    self.draft_changed = true;
+                    self.unsaved = true;
+                }
+            }
+            Message::Redo => {
+                let fields = ready!(self.status);
+
+                if let Some((field, value)) = self.history.redo() {
+                    fields.restore(field, value);
+                    self.draft_changed = true;
+                    self.unsaved = true;
+                }
+            }
+            Message::DraftSaved(Ok(())) => {}
+            Message::DraftSaved(Err(err)) => {
+                tracing::error!("failed to autosave spell draft: {err:?}");
+
+                // Retry on the next autosave, images included.
+                self.draft_changed = true;
+                self.draft_images = None;
+            }
+            Message::Submitted => {
+                if self.saving {
+                    return (Task::none(), None);
+                }
+
+                let fields = ready!(self.status);
+
+                let id = match self.mode {
+                    FormMode::Create => Uuid::new_v4(),
+                    FormMode::Edit(id) => id,
+                };
+
+                let Some(new_spell) = fields.try_new_spell(id) else {
+                    tracing::debug!("spell form has invalid fields");
+
+                    return (Task::none(), Some(Effect::SaveFailed));
+                };
+
+                self.saving = true;
+
+                let task = Task::perform(
+                    save_spell(self.context.clone(), self.mode.clone(), new_spell),
+                    Message::Saved,
+                );
+
+                return (task, None);
+            }
+            Message::Saved(Ok(id)) => {
+                self.saving = false;
+                self.unsaved = false;
+
+                // Saving a new spell turns the form into an edit of that spell.
+                self.mode = FormMode::Edit(id);
+
+                return (Task::none(), Some(Effect::Saved(id)));
+            }
+            Message::Saved(Err(err)) => {
+                self.saving = false;
+
+                tracing::error!("failed to save spell: {err:?}");
+
+                return (Task::none(), Some(Effect::SaveFailed));
+            }
         }
 
         (Task::none(), None)
     }
+}
+
+impl Viewable for SpellForm {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        match self.mode {
+            FormMode::Create => "Create spell",
+            FormMode::Edit(_) => "Edit spell",
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        // Edits are recorded by comparing the edited field before and after applying the message.
+        let edit = message.field().and_then(|field| {
+            let fields = self.status.as_ready()?;
+
+            Some((field, fields.value(field)))
+        });
+
+        let output = self.apply(message);
+
+        if let Some((field, before)) = edit
+            && let Some(fields) = self.status.as_ready()
+        {
+            let recorded = self.history.record(field, before, fields.value(field));
+            self.draft_changed |= recorded;
+            self.unsaved |= recorded;
+        }
+
+        output
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.unsaved
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        if self.status.as_ready().is_none() {
+            return Subscription::none();
+        }
+
+        // Text inputs capture key presses, so every event is listened to rather than only ignored ones.
+        event::listen_with(|event, _status, _window| {
+            let Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event else {
+                return None;
+            };
+
+            match key.as_ref() {
+                Key::Character(character)
+                    if modifiers.command() && character.eq_ignore_ascii_case("z") =>
+                {
+                    if modifiers.shift() {
+                        Some(Message::Redo)
+                    } else {
+                        Some(Message::Undo)
+                    }
+                }
+                _ => None,
+            }
+        })
+    }
 
     fn view(&self) -> Element<'_, Self::Message> {
         match &self.status {
@@ -650,7 +948,9 @@ impl Viewable for SpellForm {
 
                 let narrative = Self::narrative(fields);
 
-                let view = column![heading, identity, casting, effect, narrative]
+                let actions = Self::actions(&self.history, self.saving);
+
+                let view = column![heading, identity, casting, effect, narrative, actions]
                     .align_x(Alignment::Center)
                     .spacing(SECTION_SPACING);
 
@@ -660,6 +960,67 @@ impl Viewable for SpellForm {
     }
 }
 
+async fn save_spell<C: SpellsRepository>(
+    ctx: C,
+    mode: FormMode,
+    new_spell: NewSpell,
+) -> Result<Uuid, Error> {
+    let id = new_spell.id;
+    let repo = ctx.spells();
+
+    match mode {
+        FormMode::Create => repo.create(new_spell).await?,
+        FormMode::Edit(_) => repo.update(new_spell).await?,
+    }
+
+    Ok(id)
+}
+
+/// Save the `draft`, releasing the `_guard` of the draft lock once it is written.
+async fn save_draft<C: SpellDraftsRepository>(
+    ctx: C,
+    draft: NewSpellDraft,
+    _guard: OwnedMutexGuard<()>,
+) -> Result<(), Error> {
+    let repo = ctx.spell_drafts();
+    repo.save(draft).await
+}
+
+async fn delete_draft<C: SpellDraftsRepository>(ctx: C, id: Uuid, draft_lock: Arc<Mutex<()>>) {
+    let _guard = draft_lock.lock().await;
+
+    let repo = ctx.spell_drafts();
+
+    if let Err(err) = repo.delete(id).await {
+        tracing::error!("failed to delete spell draft '{id}': {err:?}");
+    }
+}
+
 fn fill_space<'a, Message: 'a>() -> Element<'a, Message> {
     widget::space().width(Length::Fill).into()
 }
+
+/// A select of the distance kind, followed by its amount if the distance is measured.
+fn distance_field<'a>(
+    label: &'a str,
+    placeholder: &'a str,
+    input: &'a DistanceInput,
+    on_kind: fn(DistanceKind) -> Message,
+    on_amount: fn(String) -> Message,
+) -> Element<'a, Message> {
+    let kind = components::select_field(label, &input.kind, on_kind).placeholder(placeholder);
+
+    if !input.is_measured() {
+        return kind.into();
+    }
+
+    let amount = components::number_field(None, &input.amount)
+        .placeholder("Amount")
+        .width(Length::Fixed(90.0))
+        .on_input(on_amount);
+
+    row![kind, amount]
+        .spacing(LABEL_SPACING)
+        .align_y(Alignment::End)
+        .into()
+}
@@ -1,11 +1,31 @@
 use crate::view::request::Request;
+use crate::view::spell::list::SpellSort;
+use storage::Error;
+use storage::records::spell::SpellRevision;
+use storage::records::spell::SpellSummary;
+
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub enum Message {
     OpenNewSpell,
+    OpenSpell(Uuid),
+    SortSelected(SpellSort),
+    MinRangeChanged(String),
+    MaxRangeChanged(String),
+    FavouritesOnlyToggled,
+    DeletedOnlyToggled,
+    SpellsLoaded(Result<Box<[SpellSummary]>, Error>),
+    FavouritesLoaded(Result<Box<[SpellSummary]>, Error>),
+    DeletedLoaded(Result<Box<[SpellRevision]>, Error>),
+    RestoreRequested(Uuid),
+    Restored(Result<Uuid, Error>),
 }
 
 #[derive(Debug, Clone)]
 pub enum Effect {
     OpenView(Request),
+
+    /// The deleted spell with the id was restored.
+    Restored(Uuid),
 }
@@ -1,21 +1,193 @@
 pub mod message;
 
+use crate::context::Context;
 use crate::view::Viewable;
 use crate::view::request::Request;
 use crate::view::spell::list::message::Effect;
 use crate::view::spell::list::message::Message;
+use components::number_field::NumberFieldState;
+use components::select_field::SelectFieldState;
+use storage::Error;
+use storage::records::spell::SpellRevision;
+use storage::records::spell::SpellSummary;
+use storage::repositories::bookmarks::BookmarksRepository;
+use storage::repositories::spells::SpellsRepository;
+use style::button::ButtonClass;
+use style::container::ContainerClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::SECTION_SPACING;
+use types::Area;
+use types::Choice;
+use types::Distance;
 use types::FormMode;
 use widgets::Element;
 
+use iced::Alignment;
+use iced::Length;
 use iced::Task;
 use iced::widget;
+use iced::widget::Column;
 use iced::widget::column;
+use iced::widget::row;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use strum::VariantArray;
+use uuid::Uuid;
 
-pub struct SpellList {}
+pub struct SpellList {
+    context: Context,
+
+    /// The spells of the list, `None` while they are loading.
+    spells: Option<Box<[SpellSummary]>>,
+
+    sort: SelectFieldState<SpellSort>,
+
+    /// The shortest range in feet of the listed spells.
+    min_range: NumberFieldState,
+
+    /// The longest range in feet of the listed spells.
+    max_range: NumberFieldState,
+
+    /// The ids of the favourite spells.
+    favourites: HashSet<Uuid>,
+
+    /// Whether only the favourite spells are listed.
+    favourites_only: bool,
+
+    /// The revisions the deleted spells were deleted in, newest first.
+    deleted: Box<[SpellRevision]>,
+
+    /// Whether the deleted spells are listed instead of the spells.
+    deleted_only: bool,
+}
+
+/// The order of the spells in the list.
+#[derive(Debug, Clone, Copy, PartialEq, VariantArray)]
+pub enum SpellSort {
+    Name,
+    Range,
+    Area,
+}
+
+impl Display for SpellSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let sort = match self {
+            SpellSort::Name => "Name",
+            SpellSort::Range => "Range",
+            SpellSort::Area => "Area",
+        };
+
+        write!(f, "{sort}")
+    }
+}
 
 impl SpellList {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(context: Context) -> (Self, Task<Message>) {
+        let mut spell_list = Self {
+            context,
+            spells: None,
+            sort: SelectFieldState::new(SpellSort::VARIANTS.iter().copied(), Some(SpellSort::Name)),
+            min_range: NumberFieldState::new(None).non_negative(true),
+            max_range: NumberFieldState::new(None).non_negative(true),
+            favourites: HashSet::new(),
+            favourites_only: false,
+            deleted: Box::default(),
+            deleted_only: false,
+        };
+
+        let task = spell_list.reload();
+
+        (spell_list, task)
+    }
+
+    /// Reload the spells of the list, which of them are favourites, and the deleted spells.
+    pub fn reload(&mut self) -> Task<Message> {
+        Task::batch([
+            Task::perform(load_spells(self.context.clone()), Message::SpellsLoaded),
+            Task::perform(
+                load_favourites(self.context.clone()),
+                Message::FavouritesLoaded,
+            ),
+            Task::perform(load_deleted(self.context.clone()), Message::DeletedLoaded),
+        ])
+    }
+
+    /// The spells within the range filter, in the selected order.
+    fn visible<'a>(&self, spells: &'a [SpellSummary]) -> Vec<&'a SpellSummary> {
+        let bound = |state: &NumberFieldState| state.raw_value().parse::<u32>().ok();
+        let (min, max) = (bound(&self.min_range), bound(&self.max_range));
+
+        let mut visible: Vec<_> = spells
+            .iter()
+            .filter(|spell| !self.favourites_only || self.favourites.contains(&spell.id))
+            .filter(|spell| {
+                if min.is_none() && max.is_none() {
+                    return true;
+                }
+
+                // Spells without a distance as range cannot be compared, and unbounded ranges
+                // like sight are longer than any bound.
+                let Ok(range) = Distance::parse_written(&spell.range) else {
+                    return false;
+                };
+                let feet = range.feet().unwrap_or(u32::MAX);
+
+                min.is_none_or(|min| feet >= min) && max.is_none_or(|max| feet <= max)
+            })
+            .collect();
+
+        // The spells are loaded ordered by name, which the stable sort keeps among equal values.
+        match self.sort.selected() {
+            None | Some(SpellSort::Name) => {}
+            Some(SpellSort::Range) => {
+                visible.sort_by_cached_key(|spell| Choice::<Distance>::parse(&spell.range));
+            }
+            Some(SpellSort::Area) => {
+                visible.sort_by_cached_key(|spell| Choice::<Area>::parse(&spell.area));
+            }
+        }
+
+        visible
+    }
+
+    /// The deleted spells, each with the means to restore it.
+    fn deleted_view(&self) -> Element<'_, Message> {
+        if self.deleted.is_empty() {
+            return components::text::detail("No spells have been deleted.").into();
+        }
+
+        let mut rows = Column::with_capacity(self.deleted.len()).spacing(BODY_SPACING);
+        for revision in &self.deleted {
+            let name = components::text::display(&revision.snapshot.name);
+            let deleted = components::text::detail(format!(
+                "Deleted {} · {}",
+                revision.created_at, revision.author
+            ));
+            let restore = widget::button(components::text::display("Restore"))
+                .class(ButtonClass::Primary)
+                .padding(INPUT_PADDING)
+                .on_press(Message::RestoreRequested(revision.id));
+
+            let content = row![
+                name,
+                widget::space::horizontal().width(Length::Fill),
+                deleted,
+                restore
+            ]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center);
+
+            rows = rows.push(
+                widget::container(content)
+                    .class(ContainerClass::Surface)
+                    .padding(INPUT_PADDING)
+                    .width(Length::Fill),
+            );
+        }
+
+        rows.into()
     }
 }
 
@@ -38,6 +210,91 @@ impl Viewable for SpellList {
 
                 (Task::none(), Some(effect))
             }
+            Message::OpenSpell(id) => {
+                let request = Request::SpellDetail { id };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::SortSelected(sort) => {
+                self.sort.set(sort);
+
+                (Task::none(), None)
+            }
+            Message::MinRangeChanged(min_range) => {
+                self.min_range.set(min_range);
+
+                (Task::none(), None)
+            }
+            Message::MaxRangeChanged(max_range) => {
+                self.max_range.set(max_range);
+
+                (Task::none(), None)
+            }
+            Message::FavouritesOnlyToggled => {
+                self.favourites_only = !self.favourites_only;
+
+                (Task::none(), None)
+            }
+            Message::DeletedOnlyToggled => {
+                self.deleted_only = !self.deleted_only;
+
+                (Task::none(), None)
+            }
+            Message::SpellsLoaded(Ok(spells)) => {
+                self.spells = Some(spells);
+
+                (Task::none(), None)
+            }
+            Message::SpellsLoaded(Err(err)) => {
+                tracing::error!("failed to load spells: {err:?}");
+
+                (Task::none(), None)
+            }
+            Message::FavouritesLoaded(Ok(favourites)) => {
+                self.favourites = favourites.iter().map(|spell| spell.id).collect();
+
+                (Task::none(), None)
+            }
+            Message::FavouritesLoaded(Err(err)) => {
+                tracing::error!("failed to load favourite spells: {err:?}");
+
+                (Task::none(), None)
+            }
+            Message::DeletedLoaded(Ok(deleted)) => {
+                self.deleted = deleted;
+
+                (Task::none(), None)
+            }
+            Message::DeletedLoaded(Err(err)) => {
+                tracing::error!("failed to load deleted spells: {err:?}");
+
+                (Task::none(), None)
+            }
+            Message::RestoreRequested(revision_id) => {
+                let Some(revision) = self
+                    .deleted
+                    .iter()
+                    .find(|revision| revision.id == revision_id)
+                else {
+                    tracing::error!("could not find deleted spell of revision '{revision_id}'");
+
+                    return (Task::none(), None);
+                };
+
+                let task = Task::perform(
+                    restore_spell(self.context.clone(), revision.id, revision.spell_id),
+                    Message::Restored,
+                );
+
+                (task, None)
+            }
+            Message::Restored(Ok(id)) => (Task::none(), Some(Effect::Restored(id))),
+            Message::Restored(Err(err)) => {
+                tracing::error!("failed to restore deleted spell: {err:?}");
+
+                (Task::none(), None)
+            }
         }
     }
 
@@ -47,6 +304,92 @@ impl Viewable for SpellList {
         let create_spell_button =
             widget::button("Create New Spell").on_press(Message::OpenNewSpell);
 
-        column![title, create_spell_button].into()
+        let spells: Element<_> = match &self.spells {
+            _ if self.deleted_only => self.deleted_view(),
+            None => components::text::detail("Loading spells...").into(),
+            Some(spells) if spells.is_empty() => {
+                components::text::detail("No spells have been inscribed yet.").into()
+            }
+            Some(spells) => {
+                let visible = self.visible(spells);
+
+                let mut rows = Column::with_capacity(visible.len()).spacing(BODY_SPACING);
+
+                if visible.is_empty() {
+                    rows = rows.push(components::text::detail("No spells match these filters."));
+                }
+
+                for spell in visible {
+                    let name = components::text::display(&spell.name);
+                    let classification = components::text::detail(format!(
+                        "{} {} · {}",
+                        spell.level, spell.school, spell.range
+                    ));
+                    let content = row![
+                        name,
+                        widget::space::horizontal().width(Length::Fill),
+                        classification
+                    ]
+                    .align_y(Alignment::Center);
+
+                    let button = widget::button(content)
+                        .class(ButtonClass::Interaction)
+                        .padding(INPUT_PADDING)
+                        .width(Length::Fill)
+                        .on_press(Message::OpenSpell(spell.id));
+
+                    rows = rows.push(button);
+                }
+
+                rows.into()
+            }
+        };
+
+        let sort = components::select_field("SORT BY", &self.sort, Message::SortSelected);
+        let min_range = components::number_field(Some("MIN RANGE (FT.)"), &self.min_range)
+            .placeholder("Any")
+            .on_input(Message::MinRangeChanged);
+        let max_range = components::number_field(Some("MAX RANGE (FT.)"), &self.max_range)
+            .placeholder("Any")
+            .on_input(Message::MaxRangeChanged);
+
+        let favourites_only = components::toggle("Favourites only", self.favourites_only)
+            .on_toggle(Message::FavouritesOnlyToggled);
+        let deleted_only = components::toggle("Deleted spells", self.deleted_only)
+            .on_toggle(Message::DeletedOnlyToggled);
+
+        let controls = row![sort, min_range, max_range, favourites_only, deleted_only]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::End);
+
+        column![title, create_spell_button, controls, spells]
+            .spacing(SECTION_SPACING)
+            .into()
     }
 }
+
+async fn load_spells<C: SpellsRepository>(ctx: C) -> Result<Box<[SpellSummary]>, Error> {
+    let repo = ctx.spells();
+    repo.list().await
+}
+
+async fn load_favourites<C: BookmarksRepository>(ctx: C) -> Result<Box<[SpellSummary]>, Error> {
+    let repo = ctx.bookmarks();
+    repo.favourites().await
+}
+
+async fn load_deleted<C: SpellsRepository>(ctx: C) -> Result<Box<[SpellRevision]>, Error> {
+    let repo = ctx.spells();
+    repo.deleted().await
+}
+
+async fn restore_spell<C: SpellsRepository>(
+    ctx: C,
+    revision_id: Uuid,
+    spell_id: Uuid,
+) -> Result<Uuid, Error> {
+    let repo = ctx.spells();
+    repo.restore(revision_id).await?;
+
+    Ok(spell_id)
+}
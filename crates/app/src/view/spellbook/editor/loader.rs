@@ -0,0 +1,70 @@
+use crate::context::Context;
+use storage::Error;
+use storage::records::spellbook::SpellbookRecord;
+use storage::repositories::spellbooks::SpellbooksRepository;
+use types::FormMode;
+
+use iced::Task;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum LoadMessage {
+    SpellbookLoaded(Result<Box<SpellbookRecord>, Error>),
+}
+
+pub struct Loader {
+    /// The number of tasks in total the loader must complete.
+    pub total: usize,
+
+    /// The number of tasks that have been completed.
+    pub progress: usize,
+
+    pub error: Option<Error>,
+
+    /// The spellbook being edited, only loaded in [`FormMode::Edit`].
+    pub spellbook: Option<SpellbookRecord>,
+}
+
+impl Loader {
+    pub fn new(context: Context, mode: &FormMode) -> (Self, Task<LoadMessage>) {
+        let mut tasks = Vec::with_capacity(1);
+
+        if let FormMode::Edit(id) = mode {
+            let task = Task::perform(load_spellbook(context, *id), LoadMessage::SpellbookLoaded);
+            tasks.push(task);
+        }
+
+        let loader = Self {
+            total: tasks.len(),
+            progress: 0,
+            error: None,
+            spellbook: None,
+        };
+
+        (loader, Task::batch(tasks))
+    }
+
+    pub fn update(&mut self, message: LoadMessage) {
+        match message {
+            LoadMessage::SpellbookLoaded(Ok(spellbook)) => self.spellbook = Some(*spellbook),
+            LoadMessage::SpellbookLoaded(Err(err)) => {
+                tracing::error!("failed to load spellbook: {:?}", err);
+                self.error = Some(err);
+            }
+        }
+
+        self.progress += 1;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress == self.total
+    }
+}
+
+async fn load_spellbook<C: SpellbooksRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<Box<SpellbookRecord>, Error> {
+    let repo = ctx.spellbooks();
+    repo.get(id).await.map(Box::new)
+}
@@ -0,0 +1,40 @@
+use crate::view::spellbook::editor::loader::LoadMessage;
+use storage::Error;
+use storage::records::spell::SpellSummary;
+use types::Ability;
+use types::Class;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    LoadMessage(LoadMessage),
+    OwnerChanged(String),
+    ClassSelected(Class),
+    LevelChanged(String),
+    AbilitySelected(Ability),
+    AbilityScoreChanged(String),
+    LibraryLoaded(Class, Result<Box<[SpellSummary]>, Error>),
+    SpellAdded(Uuid),
+    SpellRemoved(Uuid),
+    PreparedToggled(Uuid),
+    AlwaysPreparedToggled(Uuid),
+    Submitted,
+    Saved(Result<Uuid, Error>),
+    DeleteRequested,
+    Deleted(Result<(), Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    LoadFailed(Error),
+
+    /// The spellbook was created or updated.
+    Saved,
+
+    /// The spellbook could not be saved, either due to invalid fields or a storage error.
+    SaveFailed,
+
+    /// The spellbook was deleted.
+    Deleted,
+}
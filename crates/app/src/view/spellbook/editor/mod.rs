@@ -0,0 +1,539 @@
+mod loader;
+pub mod message;
+mod sheet;
+
+use crate::context::Context;
+use crate::status::Status;
+use crate::status::loading;
+use crate::status::ready;
+use crate::view::Viewable;
+use crate::view::spellbook::editor::loader::Loader;
+use crate::view::spellbook::editor::message::Effect;
+use crate::view::spellbook::editor::message::Message;
+use crate::view::spellbook::editor::sheet::Sheet;
+use storage::Error;
+use storage::models::spellbook::NewSpellbook;
+use storage::records::spell::SpellSummary;
+use storage::repositories::spellbooks::SpellbooksRepository;
+use storage::repositories::spells::SpellsRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
+use style::layout::SECTION_SPACING;
+use style::text::TextClass;
+use types::Class;
+use types::FormMode;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length::Fill;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+use uuid::Uuid;
+
+pub struct SpellbookEditor {
+    mode: FormMode,
+    context: Context,
+    status: Status<Loader, Sheet>,
+
+    /// Whether the sheet changed since the spellbook was last saved.
+    unsaved: bool,
+
+    /// Whether the spellbook is currently being saved, used to prevent duplicate submissions.
+    saving: bool,
+}
+
+impl<'a> SpellbookEditor {
+    pub fn new(mode: FormMode, context: Context) -> (Self, Task<Message>) {
+        let (loader, task) = Loader::new(context.clone(), &mode);
+
+        let mut editor = Self {
+            mode,
+            context,
+            status: Status::Loading(Box::new(loader)),
+            unsaved: false,
+            saving: false,
+        };
+
+        // A new spellbook has nothing to load, so the sheet is ready right away.
+        let ready_task = editor.finish_loading();
+
+        (
+            editor,
+            Task::batch([task.map(Message::LoadMessage), ready_task]),
+        )
+    }
+
+    /// The id of the spellbook being edited, if it has been saved.
+    pub fn id(&self) -> Option<Uuid> {
+        match self.mode {
+            FormMode::Create => None,
+            FormMode::Edit(id) => Some(id),
+        }
+    }
+
+    /// Construct the sheet once the loader is done, loading the library of its class.
+    fn finish_loading(&mut self) -> Task<Message> {
+        let Status::Loading(loader) = &mut self.status else {
+            return Task::none();
+        };
+
+        if !loader.is_done() {
+            return Task::none();
+        }
+
+        let sheet = match loader.spellbook.take() {
+            Some(spellbook) => Sheet::from_record(spellbook),
+            None => Sheet::new(),
+        };
+        let class = sheet.class.selected().copied();
+
+        self.status = Status::Ready(Box::new(sheet));
+
+        match class {
+            Some(class) => self.load_library(class),
+            None => Task::none(),
+        }
+    }
+
+    fn load_library(&self, class: Class) -> Task<Message> {
+        Task::perform(load_library(self.context.clone(), class), move |result| {
+            Message::LibraryLoaded(class, result)
+        })
+    }
+
+    fn heading() -> Element<'a, Message> {
+        let title = components::text::view_title("Spellbook");
+
+        let sub_title = components::text::view_sub_title("The repertoire of a single caster");
+
+        column![title, sub_title]
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    fn character_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "CHARACTER",
+            "The caster the spellbook belongs to, which determines how many spells can be prepared.",
+        );
+
+        let owner = components::text_field(Some("OWNER"), &sheet.owner)
+            .placeholder("Elminster")
+            .on_input(Message::OwnerChanged);
+        let class = components::select_field("CLASS", &sheet.class, Message::ClassSelected)
+            .placeholder("Select a class");
+
+        let level = components::number_field(Some("LEVEL"), &sheet.level)
+            .placeholder("1")
+            .on_input(Message::LevelChanged);
+        let ability = components::select_field(
+            "SPELLCASTING ABILITY",
+            &sheet.ability,
+            Message::AbilitySelected,
+        )
+        .placeholder("Select an ability");
+        let ability_score = components::number_field(Some("ABILITY SCORE"), &sheet.ability_score)
+            .placeholder("10")
+            .on_input(Message::AbilityScoreChanged);
+
+        let row1 = row![owner, class].spacing(BODY_SPACING);
+        let row2 = row![level, ability, ability_score].spacing(BODY_SPACING);
+
+        let form = column![row1, row2].spacing(BODY_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn spells_section(sheet: &'a Sheet) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "SPELLS",
+            "The spells written in the spellbook, picked from those available to the class.",
+        );
+
+        let library = Self::library(sheet);
+        let entries = Self::entries(sheet);
+
+        let form = row![library, entries].spacing(SECTION_SPACING);
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn library(sheet: &'a Sheet) -> Element<'a, Message> {
+        let heading = components::text::heading("Library");
+
+        let spells: Element<_> = match (&sheet.library, sheet.class.selected()) {
+            (_, None) => components::text::detail("Select a class to pick its spells.").into(),
+            (None, Some(_)) => components::text::detail("Loading spells...").into(),
+            (Some(library), Some(class)) => {
+                let available: Vec<_> = library
+                    .iter()
+                    .filter(|spell| !sheet.contains(spell.id))
+                    .collect();
+
+                if available.is_empty() {
+                    components::text::detail(format!("No more {class} spells to add.")).into()
+                } else {
+                    let mut rows = Column::with_capacity(available.len()).spacing(LABEL_SPACING);
+
+                    for spell in available {
+                        let name = components::text::display(&spell.name);
+                        let level = components::text::detail(&spell.level);
+                        let add = widget::button(components::text::display("Add"))
+                            .class(ButtonClass::Ghost)
+                            .on_press(Message::SpellAdded(spell.id));
+
+                        let spell_row =
+                            row![name, level, widget::space::horizontal().width(Fill), add]
+                                .spacing(BODY_SPACING)
+                                .align_y(Alignment::Center);
+
+                        rows = rows.push(spell_row);
+                    }
+
+                    rows.into()
+                }
+            }
+        };
+
+        column![heading, spells]
+            .spacing(BODY_SPACING)
+            .width(Fill)
+            .into()
+    }
+
+    fn entries(sheet: &'a Sheet) -> Element<'a, Message> {
+        let count = match sheet.prepared_limit() {
+            Some(limit) => format!("{} / {limit} prepared", sheet.prepared_count()),
+            None => format!("{} prepared", sheet.prepared_count()),
+        };
+
+        let heading = row![
+            components::text::heading("Spellbook"),
+            widget::space::horizontal().width(Fill),
+            components::text::detail(count)
+        ]
+        .align_y(Alignment::Center);
+
+        if sheet.entries.is_empty() {
+            let empty = components::text::detail("No spells have been written yet.");
+
+            return column![heading, empty]
+                .spacing(BODY_SPACING)
+                .width(Fill)
+                .into();
+        }
+
+        let can_prepare = sheet.can_prepare();
+
+        let mut rows = Column::with_capacity(sheet.entries.len()).spacing(LABEL_SPACING);
+
+        for entry in &sheet.entries {
+            let name = components::text::display(&entry.name);
+            let level = components::text::detail(format!("{} {}", entry.level, entry.school));
+            let label = column![name, level].spacing(LABEL_SPACING);
+
+            let mut entry_row = row![label, widget::space::horizontal().width(Fill)]
+                .spacing(BODY_SPACING)
+                .align_y(Alignment::Center);
+
+            if !entry.is_cantrip() {
+                // Always prepared spells do not count towards the limit, so they can always be
+                // prepared, as can spells being unprepared.
+                let can_toggle = entry.prepared || entry.always_prepared || can_prepare;
+
+                let prepared =
+                    components::toggle("Prepared", entry.prepared || entry.always_prepared)
+                        .on_toggle_maybe(
+                            (can_toggle && !entry.always_prepared)
+                                .then_some(Message::PreparedToggled(entry.spell_id)),
+                        );
+                let always_prepared = components::toggle("Always", entry.always_prepared)
+                    .on_toggle(Message::AlwaysPreparedToggled(entry.spell_id));
+
+                entry_row = entry_row.push(prepared).push(always_prepared);
+            }
+
+            let remove = widget::button(components::text::display("Remove"))
+                .class(ButtonClass::Danger)
+                .on_press(Message::SpellRemoved(entry.spell_id));
+
+            rows = rows.push(entry_row.push(remove));
+        }
+
+        column![heading, rows]
+            .spacing(BODY_SPACING)
+            .width(Fill)
+            .into()
+    }
+
+    fn actions(&self, sheet: &'a Sheet) -> Element<'a, Message> {
+        let error = sheet
+            .error
+            .map(|error| components::text::detail(error).class(TextClass::Danger));
+
+        let delete = self.id().map(|_| {
+            widget::button(components::text::display("Delete"))
+                .class(ButtonClass::Danger)
+                .padding(INPUT_PADDING)
+                .on_press(Message::DeleteRequested)
+        });
+
+        let label = if self.saving {
+            "Saving..."
+        } else {
+            "Save spellbook"
+        };
+
+        let save = widget::button(components::text::display(label))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press_maybe((!self.saving).then_some(Message::Submitted));
+
+        row![delete, widget::space::horizontal().width(Fill), error, save]
+            .spacing(BODY_SPACING)
+            .align_y(Alignment::Center)
+            .width(Fill)
+            .into()
+    }
+}
+
+impl Viewable for SpellbookEditor {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        match self.mode {
+            FormMode::Create => "Create spellbook",
+            FormMode::Edit(_) => "Edit spellbook",
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::LoadMessage(load_message) => {
+                let loader = loading!(self.status);
+
+                loader.update(load_message);
+
+                if let Some(err) = loader.error.take() {
+                    tracing::error!("failed to load spellbook editor: {:?}", err);
+
+                    return (Task::none(), Some(Effect::LoadFailed(err)));
+                }
+
+                return (self.finish_loading(), None);
+            }
+            Message::OwnerChanged(owner) => {
+                let sheet = ready!(self.status);
+
+                sheet.owner.set(owner);
+                self.unsaved = true;
+            }
+            Message::ClassSelected(class) => {
+                let sheet = ready!(self.status);
+
+                if sheet.class.selected() == Some(&class) {
+                    return (Task::none(), None);
+                }
+
+                sheet.select_class(class);
+                sheet.library = None;
+                self.unsaved = true;
+
+                return (self.load_library(class), None);
+            }
+            Message::LevelChanged(level) => {
+                let sheet = ready!(self.status);
+
+                sheet.level.set(level);
+                self.unsaved = true;
+            }
+            Message::AbilitySelected(ability) => {
+                let sheet = ready!(self.status);
+
+                sheet.ability.set(ability);
+                self.unsaved = true;
+            }
+            Message::AbilityScoreChanged(ability_score) => {
+                let sheet = ready!(self.status);
+
+                sheet.ability_score.set(ability_score);
+                self.unsaved = true;
+            }
+            Message::LibraryLoaded(class, Ok(library)) => {
+                let sheet = ready!(self.status);
+
+                // The class may have changed again while the library was loading.
+                if sheet.class.selected() == Some(&class) {
+                    sheet.library = Some(library);
+                }
+            }
+            Message::LibraryLoaded(class, Err(err)) => {
+                tracing::error!("failed to load spells of class '{class}': {err:?}");
+            }
+            Message::SpellAdded(spell_id) => {
+                let sheet = ready!(self.status);
+
+                let spell = sheet
+                    .library
+                    .as_ref()
+                    .and_then(|library| library.iter().find(|spell| spell.id == spell_id))
+                    .cloned();
+
+                if let Some(spell) = spell {
+                    sheet.add(&spell);
+                    self.unsaved = true;
+                }
+            }
+            Message::SpellRemoved(spell_id) => {
+                let sheet = ready!(self.status);
+
+                sheet.remove(spell_id);
+                self.unsaved = true;
+            }
+            Message::PreparedToggled(spell_id) => {
+                let sheet = ready!(self.status);
+
+                let can_prepare = sheet.can_prepare();
+
+                if let Some(entry) = sheet.entry_mut(spell_id)
+                    && (entry.prepared || can_prepare)
+                {
+                    entry.prepared = !entry.prepared;
+                    self.unsaved = true;
+                }
+            }
+            Message::AlwaysPreparedToggled(spell_id) => {
+                let sheet = ready!(self.status);
+
+                if let Some(entry) = sheet.entry_mut(spell_id) {
+                    entry.always_prepared = !entry.always_prepared;
+                    self.unsaved = true;
+                }
+            }
+            Message::Submitted => {
+                if self.saving {
+                    return (Task::none(), None);
+                }
+
+                let id = self.id().unwrap_or_else(Uuid::new_v4);
+                let sheet = ready!(self.status);
+
+                let Some(new_spellbook) = sheet.try_new_spellbook(id) else {
+                    tracing::debug!("spellbook editor has invalid fields");
+
+                    return (Task::none(), Some(Effect::SaveFailed));
+                };
+
+                self.saving = true;
+
+                let task = Task::perform(
+                    save_spellbook(self.context.clone(), self.mode.clone(), new_spellbook),
+                    Message::Saved,
+                );
+
+                return (task, None);
+            }
+            Message::Saved(Ok(id)) => {
+                self.saving = false;
+                self.unsaved = false;
+
+                // Saving a new spellbook turns the editor into an edit of that spellbook.
+                self.mode = FormMode::Edit(id);
+
+                return (Task::none(), Some(Effect::Saved));
+            }
+            Message::Saved(Err(err)) => {
+                self.saving = false;
+
+                tracing::error!("failed to save spellbook: {err:?}");
+
+                return (Task::none(), Some(Effect::SaveFailed));
+            }
+            Message::DeleteRequested => {
+                let Some(id) = self.id() else {
+                    return (Task::none(), None);
+                };
+
+                let task =
+                    Task::perform(delete_spellbook(self.context.clone(), id), Message::Deleted);
+
+                return (task, None);
+            }
+            Message::Deleted(Ok(())) => return (Task::none(), Some(Effect::Deleted)),
+            Message::Deleted(Err(err)) => {
+                tracing::error!("failed to delete spellbook: {err:?}");
+            }
+        }
+
+        (Task::none(), None)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.unsaved
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match &self.status {
+            Status::Loading(loader) => {
+                let start = 0.0;
+                let end = loader.total as f32;
+                let current = loader.progress as f32;
+
+                widget::progress_bar(start..=end, current).into()
+            }
+            Status::Ready(sheet) => {
+                let heading = Self::heading();
+
+                let character = Self::character_section(sheet);
+
+                let spells = Self::spells_section(sheet);
+
+                let actions = self.actions(sheet);
+
+                column![heading, character, spells, actions]
+                    .align_x(Alignment::Center)
+                    .spacing(SECTION_SPACING)
+                    .into()
+            }
+        }
+    }
+}
+
+async fn load_library<C: SpellsRepository>(
+    ctx: C,
+    class: Class,
+) -> Result<Box<[SpellSummary]>, Error> {
+    let repo = ctx.spells();
+    repo.list_for_class(class).await
+}
+
+async fn save_spellbook<C: SpellbooksRepository>(
+    ctx: C,
+    mode: FormMode,
+    new_spellbook: NewSpellbook,
+) -> Result<Uuid, Error> {
+    let id = new_spellbook.id;
+    let repo = ctx.spellbooks();
+
+    match mode {
+        FormMode::Create => repo.create(new_spellbook).await?,
+        FormMode::Edit(_) => repo.update(new_spellbook).await?,
+    }
+
+    Ok(id)
+}
+
+async fn delete_spellbook<C: SpellbooksRepository>(ctx: C, id: Uuid) -> Result<(), Error> {
+    let repo = ctx.spellbooks();
+    repo.delete(id).await
+}
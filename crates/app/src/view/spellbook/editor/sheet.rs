@@ -0,0 +1,247 @@
+use components::number_field::NumberFieldState;
+use components::select_field::SelectFieldState;
+use components::text_field::TextFieldState;
+use storage::models::spellbook::NewSpellbook;
+use storage::models::spellbook::NewSpellbookEntry;
+use storage::records::spell::SpellSummary;
+use storage::records::spellbook::SpellbookRecord;
+use types::Ability;
+use types::Choice;
+use types::Class;
+use types::Level;
+use types::SPELLCASTING_CLASSES;
+
+use std::ops::RangeInclusive;
+use strum::VariantArray;
+use uuid::Uuid;
+
+/// The levels a character can have.
+const LEVELS: RangeInclusive<i32> = 1..=20;
+
+/// The scores an ability can have.
+const ABILITY_SCORES: RangeInclusive<i32> = 1..=30;
+
+/// The character sheet a spellbook is written for, along with its spells.
+pub struct Sheet {
+    pub owner: TextFieldState,
+    pub class: SelectFieldState<Class>,
+    pub level: NumberFieldState,
+    pub ability: SelectFieldState<Ability>,
+    pub ability_score: NumberFieldState,
+
+    /// The spells of the spellbook, ordered by level and name.
+    pub entries: Vec<Entry>,
+
+    /// The spells available to the selected class, `None` while they are loading.
+    pub library: Option<Box<[SpellSummary]>>,
+
+    /// Why the spellbook could not be saved, if it failed validation.
+    pub error: Option<&'static str>,
+}
+
+/// A spell written in the spellbook.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub spell_id: Uuid,
+    pub name: String,
+    pub school: String,
+    pub level: String,
+    pub prepared: bool,
+    pub always_prepared: bool,
+}
+
+impl Entry {
+    /// Cantrips are always at hand, so they are never prepared.
+    pub fn is_cantrip(&self) -> bool {
+        Choice::parse(&self.level) == Choice::BuiltIn(Level::Cantrip)
+    }
+
+    /// Whether the entry counts towards the prepared limit.
+    fn counts_as_prepared(&self) -> bool {
+        self.prepared && !self.always_prepared && !self.is_cantrip()
+    }
+}
+
+impl Sheet {
+    pub fn new() -> Self {
+        Self {
+            owner: TextFieldState::default().required(true),
+            class: SelectFieldState::new(SPELLCASTING_CLASSES, None).required(true),
+            level: NumberFieldState::new(Some(1))
+                .required(true)
+                .non_negative(true),
+            ability: SelectFieldState::new(Ability::VARIANTS.iter().copied(), None).required(true),
+            ability_score: NumberFieldState::new(Some(10))
+                .required(true)
+                .non_negative(true),
+            entries: Vec::new(),
+            library: None,
+            error: None,
+        }
+    }
+
+    pub fn from_record(record: SpellbookRecord) -> Self {
+        let mut sheet = Self::new();
+
+        sheet.owner.set(record.owner);
+        match record.class.parse() {
+            Ok(class) => sheet.class.set(class),
+            Err(err) => {
+                tracing::error!("failed to parse spellbook class '{}': {err}", record.class)
+            }
+        }
+        sheet.level.set(record.level.to_string());
+        match record.ability.parse() {
+            Ok(ability) => sheet.ability.set(ability),
+            Err(err) => tracing::error!("failed to parse ability '{}': {err}", record.ability),
+        }
+        sheet.ability_score.set(record.ability_score.to_string());
+        sheet.entries = record
+            .spells
+            .into_iter()
+            .map(|entry| Entry {
+                spell_id: entry.spell_id,
+                name: entry.name,
+                school: entry.school,
+                level: entry.level,
+                prepared: entry.prepared,
+                always_prepared: entry.always_prepared,
+            })
+            .collect();
+        sheet.sort_entries();
+
+        sheet
+    }
+
+    /// Select the `class`, defaulting the ability to the spellcasting ability of the class.
+    pub fn select_class(&mut self, class: Class) {
+        self.class.set(class);
+
+        if let Some(ability) = class.spellcasting_ability() {
+            self.ability.set(ability);
+        }
+    }
+
+    /// Write the `spell` into the spellbook, unless it is already written in it.
+    pub fn add(&mut self, spell: &SpellSummary) {
+        if self.contains(spell.id) {
+            return;
+        }
+
+        self.entries.push(Entry {
+            spell_id: spell.id,
+            name: spell.name.clone(),
+            school: spell.school.clone(),
+            level: spell.level.clone(),
+            prepared: false,
+            always_prepared: false,
+        });
+        self.sort_entries();
+    }
+
+    pub fn remove(&mut self, spell_id: Uuid) {
+        self.entries.retain(|entry| entry.spell_id != spell_id);
+    }
+
+    pub fn contains(&self, spell_id: Uuid) -> bool {
+        self.entries.iter().any(|entry| entry.spell_id == spell_id)
+    }
+
+    pub fn entry_mut(&mut self, spell_id: Uuid) -> Option<&mut Entry> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.spell_id == spell_id)
+    }
+
+    /// The number of prepared spells that count towards the prepared limit.
+    pub fn prepared_count(&self) -> u32 {
+        let count = self
+            .entries
+            .iter()
+            .filter(|entry| entry.counts_as_prepared())
+            .count();
+
+        u32::try_from(count).unwrap_or(u32::MAX)
+    }
+
+    /// The number of spells that can be prepared, if the class, level and ability score are valid.
+    pub fn prepared_limit(&self) -> Option<u32> {
+        let class = self.class.selected()?;
+        let level = parse_within(self.level.raw_value(), LEVELS)?;
+        let score = parse_within(self.ability_score.raw_value(), ABILITY_SCORES)?;
+
+        Some(class.prepared_limit(u8::try_from(level).ok()?, Ability::modifier(score)))
+    }
+
+    /// Whether another spell can be prepared without exceeding the prepared limit.
+    pub fn can_prepare(&self) -> bool {
+        self.prepared_limit()
+            .is_none_or(|limit| self.prepared_count() < limit)
+    }
+
+    /// Validate every field and construct the spellbook with `id` from the sheet.
+    ///
+    /// Returns `None` if the sheet is invalid, in which case the errors are displayed.
+    pub fn try_new_spellbook(&mut self, id: Uuid) -> Option<NewSpellbook> {
+        // Every field is validated before returning, so all errors are shown at once.
+        let owner = self.owner.try_value();
+        let class = self.class.try_value();
+        let level = self.level.try_value();
+        let ability = self.ability.try_value();
+        let ability_score = self.ability_score.try_value();
+
+        let (owner, class, level, ability, ability_score) =
+            (owner?, class?, level?, ability?, ability_score?);
+
+        self.error = if !LEVELS.contains(&level) {
+            Some("The level must be between 1 and 20.")
+        } else if !ABILITY_SCORES.contains(&ability_score) {
+            Some("The ability score must be between 1 and 30.")
+        } else if self
+            .prepared_limit()
+            .is_some_and(|limit| self.prepared_count() > limit)
+        {
+            Some("More spells are prepared than the character can prepare.")
+        } else {
+            None
+        };
+
+        if self.error.is_some() {
+            return None;
+        }
+
+        let spells = self
+            .entries
+            .iter()
+            .map(|entry| NewSpellbookEntry {
+                spell_id: entry.spell_id,
+                prepared: entry.prepared,
+                always_prepared: entry.always_prepared,
+            })
+            .collect();
+
+        Some(NewSpellbook {
+            id,
+            owner,
+            class,
+            level: u8::try_from(level).ok()?,
+            ability,
+            ability_score,
+            spells,
+        })
+    }
+
+    fn sort_entries(&mut self) {
+        self.entries.sort_by_cached_key(|entry| {
+            (
+                Choice::<Level>::parse(&entry.level),
+                entry.name.to_lowercase(),
+            )
+        });
+    }
+}
+
+/// Parse a written number, if it is within `range`.
+fn parse_within(value: &str, range: RangeInclusive<i32>) -> Option<i32> {
+    value.parse().ok().filter(|value| range.contains(value))
+}
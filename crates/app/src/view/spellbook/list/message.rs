@@ -0,0 +1,18 @@
+use crate::view::request::Request;
+use storage::Error;
+use storage::records::spellbook::SpellbookSummary;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    OpenNewSpellbook,
+    OpenSpellbook(Uuid),
+    OpenTracker(Uuid),
+    SpellbooksLoaded(Result<Box<[SpellbookSummary]>, Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    OpenView(Request),
+}
@@ -0,0 +1,156 @@
+pub mod message;
+
+use crate::context::Context;
+use crate::view::Viewable;
+use crate::view::request::Request;
+use crate::view::spellbook::list::message::Effect;
+use crate::view::spellbook::list::message::Message;
+use storage::Error;
+use storage::records::spellbook::SpellbookSummary;
+use storage::repositories::spellbooks::SpellbooksRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::SECTION_SPACING;
+use types::FormMode;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::column;
+use iced::widget::row;
+
+pub struct SpellbookList {
+    context: Context,
+
+    /// The spellbooks of the list, `None` while they are loading.
+    spellbooks: Option<Box<[SpellbookSummary]>>,
+}
+
+impl SpellbookList {
+    pub fn new(context: Context) -> (Self, Task<Message>) {
+        let mut spellbook_list = Self {
+            context,
+            spellbooks: None,
+        };
+
+        let task = spellbook_list.reload();
+
+        (spellbook_list, task)
+    }
+
+    /// Reload the spellbooks of the list.
+    pub fn reload(&mut self) -> Task<Message> {
+        Task::perform(
+            load_spellbooks(self.context.clone()),
+            Message::SpellbooksLoaded,
+        )
+    }
+}
+
+impl Viewable for SpellbookList {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        "Spellbooks"
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::OpenNewSpellbook => {
+                let request = Request::SpellbookEditor {
+                    mode: FormMode::Create,
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenSpellbook(id) => {
+                let request = Request::SpellbookEditor {
+                    mode: FormMode::Edit(id),
+                };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::OpenTracker(id) => {
+                let request = Request::SpellbookTracker { id };
+                let effect = Effect::OpenView(request);
+
+                (Task::none(), Some(effect))
+            }
+            Message::SpellbooksLoaded(Ok(spellbooks)) => {
+                self.spellbooks = Some(spellbooks);
+
+                (Task::none(), None)
+            }
+            Message::SpellbooksLoaded(Err(err)) => {
+                tracing::error!("failed to load spellbooks: {err:?}");
+
+                (Task::none(), None)
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        let title = components::text::view_title("Spellbooks");
+
+        let create_spellbook_button =
+            widget::button("Create New Spellbook").on_press(Message::OpenNewSpellbook);
+
+        let spellbooks: Element<_> = match &self.spellbooks {
+            None => components::text::detail("Loading spellbooks...").into(),
+            Some(spellbooks) if spellbooks.is_empty() => {
+                components::text::detail("No spellbooks have been bound yet.").into()
+            }
+            Some(spellbooks) => {
+                let mut rows = Column::with_capacity(spellbooks.len()).spacing(BODY_SPACING);
+
+                for spellbook in spellbooks {
+                    let owner = components::text::display(&spellbook.owner);
+                    let classification = components::text::detail(format!(
+                        "Level {} {} · {} spells",
+                        spellbook.level, spellbook.class, spellbook.spell_count
+                    ));
+                    let content = row![
+                        owner,
+                        widget::space::horizontal().width(Length::Fill),
+                        classification
+                    ]
+                    .align_y(Alignment::Center);
+
+                    let button = widget::button(content)
+                        .class(ButtonClass::Interaction)
+                        .padding(INPUT_PADDING)
+                        .width(Length::Fill)
+                        .on_press(Message::OpenSpellbook(spellbook.id));
+
+                    let track = widget::button(components::text::display("Track"))
+                        .class(ButtonClass::Primary)
+                        .padding(INPUT_PADDING)
+                        .on_press(Message::OpenTracker(spellbook.id));
+
+                    rows = rows.push(row![button, track].spacing(BODY_SPACING));
+                }
+
+                rows.into()
+            }
+        };
+
+        column![title, create_spellbook_button, spellbooks]
+            .spacing(SECTION_SPACING)
+            .into()
+    }
+}
+
+async fn load_spellbooks<C: SpellbooksRepository>(
+    ctx: C,
+) -> Result<Box<[SpellbookSummary]>, Error> {
+    let repo = ctx.spellbooks();
+    repo.list().await
+}
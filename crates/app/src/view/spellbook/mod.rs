@@ -0,0 +1,3 @@
+pub mod editor;
+pub mod list;
+pub mod tracker;
@@ -0,0 +1,86 @@
+use crate::context::Context;
+use storage::Error;
+use storage::records::spellbook::SpellbookRecord;
+use storage::records::spellbook::SpellbookTrackerRecord;
+use storage::repositories::spellbooks::SpellbooksRepository;
+
+use iced::Task;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum LoadMessage {
+    SpellbookLoaded(Result<Box<SpellbookRecord>, Error>),
+    TrackerLoaded(Result<SpellbookTrackerRecord, Error>),
+}
+
+pub struct Loader {
+    /// The number of tasks in total the loader must complete.
+    pub total: usize,
+
+    /// The number of tasks that have been completed.
+    pub progress: usize,
+
+    pub error: Option<Error>,
+
+    pub spellbook: Option<SpellbookRecord>,
+    pub tracker: Option<SpellbookTrackerRecord>,
+}
+
+impl Loader {
+    pub fn new(context: Context, id: Uuid) -> (Self, Task<LoadMessage>) {
+        let tasks = vec![
+            Task::perform(
+                load_spellbook(context.clone(), id),
+                LoadMessage::SpellbookLoaded,
+            ),
+            Task::perform(load_tracker(context, id), LoadMessage::TrackerLoaded),
+        ];
+
+        let loader = Self {
+            total: tasks.len(),
+            progress: 0,
+            error: None,
+            spellbook: None,
+            tracker: None,
+        };
+
+        (loader, Task::batch(tasks))
+    }
+
+    pub fn update(&mut self, message: LoadMessage) {
+        match message {
+            LoadMessage::SpellbookLoaded(Ok(spellbook)) => self.spellbook = Some(*spellbook),
+            LoadMessage::SpellbookLoaded(Err(err)) => {
+                tracing::error!("failed to load spellbook: {:?}", err);
+                self.error = Some(err);
+            }
+            LoadMessage::TrackerLoaded(Ok(tracker)) => self.tracker = Some(tracker),
+            LoadMessage::TrackerLoaded(Err(err)) => {
+                tracing::error!("failed to load spellbook tracker: {:?}", err);
+                self.error = Some(err);
+            }
+        }
+
+        self.progress += 1;
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.progress == self.total
+    }
+}
+
+async fn load_spellbook<C: SpellbooksRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<Box<SpellbookRecord>, Error> {
+    let repo = ctx.spellbooks();
+    repo.get(id).await.map(Box::new)
+}
+
+async fn load_tracker<C: SpellbooksRepository>(
+    ctx: C,
+    id: Uuid,
+) -> Result<SpellbookTrackerRecord, Error> {
+    let repo = ctx.spellbooks();
+    repo.tracker(id).await
+}
@@ -0,0 +1,24 @@
+use crate::view::spellbook::tracker::loader::LoadMessage;
+use storage::Error;
+use types::Rest;
+use types::Slot;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    LoadMessage(LoadMessage),
+
+    /// Cast the spell with the given id, expending the slot if it is not a cantrip.
+    Cast(Uuid, Option<Slot>),
+    SlotExpended(Slot),
+    SlotRegained(Slot),
+    ConcentrationEnded,
+    Rested(Rest),
+    Saved(Result<(), Error>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Effect {
+    LoadFailed(Error),
+}
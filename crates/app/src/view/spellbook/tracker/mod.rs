@@ -0,0 +1,464 @@
+mod loader;
+pub mod message;
+
+use crate::context::Context;
+use crate::status::Status;
+use crate::status::loading;
+use crate::status::ready;
+use crate::view::Viewable;
+use crate::view::spellbook::tracker::loader::Loader;
+use crate::view::spellbook::tracker::message::Effect;
+use crate::view::spellbook::tracker::message::Message;
+use storage::Error;
+use storage::models::spellbook::NewSpellbookTracker;
+use storage::records::spellbook::SpellbookEntryRecord;
+use storage::records::spellbook::SpellbookRecord;
+use storage::repositories::spellbooks::SpellbooksRepository;
+use style::button::ButtonClass;
+use style::layout::BODY_SPACING;
+use style::layout::INPUT_PADDING;
+use style::layout::LABEL_SPACING;
+use style::layout::SECTION_SPACING;
+use types::Choice;
+use types::Class;
+use types::Level;
+use types::MAX_SLOT_LEVEL;
+use types::Rest;
+use types::Slot;
+use types::SpellSlots;
+use widgets::Element;
+
+use iced::Alignment;
+use iced::Length::Fill;
+use iced::Task;
+use iced::widget;
+use iced::widget::Column;
+use iced::widget::Row;
+use iced::widget::column;
+use iced::widget::row;
+use uuid::Uuid;
+
+pub struct SpellbookTracker {
+    id: Uuid,
+    context: Context,
+    status: Status<Loader, Tracker>,
+}
+
+/// The resources of the owner of a spellbook.
+struct Tracker {
+    spellbook: SpellbookRecord,
+    slots: SpellSlots,
+
+    /// The spell being concentrated on.
+    concentration: Option<Uuid>,
+}
+
+impl Tracker {
+    fn from_loader(loader: &mut Loader) -> Option<Self> {
+        let spellbook = loader.spellbook.take()?;
+        let tracker = loader.tracker.take()?;
+
+        // Spellbooks are only saved with valid classes, so this only fails on a corrupt database.
+        let class = spellbook.class.parse::<Class>().ok()?;
+
+        let slots = SpellSlots::new(class, spellbook.level)
+            .with_expended(tracker.expended_slots, tracker.pact_slots_expended);
+
+        let tracker = Self {
+            spellbook,
+            slots,
+            concentration: tracker.concentration_spell_id,
+        };
+
+        Some(tracker)
+    }
+
+    /// The spells that are at hand, which are the cantrips and the prepared spells.
+    fn castable_spells(&self) -> impl Iterator<Item = &SpellbookEntryRecord> {
+        self.spellbook.spells.iter().filter(|entry| {
+            entry.prepared || entry.always_prepared || spell_level(entry) == Some(0)
+        })
+    }
+
+    fn spell(&self, id: Uuid) -> Option<&SpellbookEntryRecord> {
+        self.spellbook
+            .spells
+            .iter()
+            .find(|entry| entry.spell_id == id)
+    }
+
+    fn new_tracker(&self) -> NewSpellbookTracker {
+        NewSpellbookTracker {
+            spellbook_id: self.spellbook.id,
+            expended_slots: self.slots.expended(),
+            pact_slots_expended: self.slots.pact_expended(),
+            concentration_spell_id: self.concentration,
+        }
+    }
+}
+
+impl<'a> SpellbookTracker {
+    pub fn new(id: Uuid, context: Context) -> (Self, Task<Message>) {
+        let (loader, task) = Loader::new(context.clone(), id);
+
+        let tracker = Self {
+            id,
+            context,
+            status: Status::Loading(Box::new(loader)),
+        };
+
+        (tracker, task.map(Message::LoadMessage))
+    }
+
+    /// The id of the tracked spellbook.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Reload the spellbook and its tracker, after the spellbook changed.
+    pub fn reload(&mut self) -> Task<Message> {
+        let (loader, task) = Loader::new(self.context.clone(), self.id);
+        self.status = Status::Loading(Box::new(loader));
+
+        task.map(Message::LoadMessage)
+    }
+
+    fn heading(tracker: &'a Tracker) -> Element<'a, Message> {
+        let title = components::text::view_title(&tracker.spellbook.owner);
+
+        let sub_title = components::text::view_sub_title(format!(
+            "Level {} {}",
+            tracker.spellbook.level, tracker.spellbook.class
+        ));
+
+        let short_rest = widget::button(components::text::display("Short Rest"))
+            .class(ButtonClass::Interaction)
+            .padding(INPUT_PADDING)
+            .on_press(Message::Rested(Rest::Short));
+
+        let long_rest = widget::button(components::text::display("Long Rest"))
+            .class(ButtonClass::Primary)
+            .padding(INPUT_PADDING)
+            .on_press(Message::Rested(Rest::Long));
+
+        let actions = row![
+            widget::space::horizontal().width(Fill),
+            short_rest,
+            long_rest
+        ]
+        .spacing(BODY_SPACING)
+        .width(Fill);
+
+        column![title, sub_title, actions]
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .into()
+    }
+
+    fn slots_section(tracker: &'a Tracker) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "SLOTS",
+            "The spell slots left until the next rest. Pact slots return on a short rest.",
+        );
+
+        let mut form = Column::new().spacing(BODY_SPACING);
+
+        for level in 1..=MAX_SLOT_LEVEL {
+            let maximum = tracker.slots.maximum(level);
+            if maximum == 0 {
+                continue;
+            }
+
+            let available = tracker.slots.available(level);
+            form = form.push(slot_row(
+                format!("LEVEL {level}"),
+                Slot::Spell(level),
+                available,
+                maximum,
+            ));
+        }
+
+        if let Some(pact) = tracker.slots.pact() {
+            form = form.push(slot_row(
+                format!("PACT · LEVEL {}", pact.level),
+                Slot::Pact,
+                tracker.slots.pact_available(),
+                pact.slots,
+            ));
+        }
+
+        if tracker.slots.maximum(1) == 0 && tracker.slots.pact().is_none() {
+            form = form.push(components::text::detail(
+                "The class has no spell slots at this level.",
+            ));
+        }
+
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn concentration_section(tracker: &'a Tracker) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "CONCENTRATION",
+            "Only a single spell can be concentrated on, casting another ends it.",
+        );
+
+        let spell = tracker.concentration.and_then(|id| tracker.spell(id));
+
+        let form: Element<_> = match spell {
+            None => components::text::detail("Not concentrating on a spell.").into(),
+            Some(spell) => {
+                let end = widget::button(components::text::display("End"))
+                    .class(ButtonClass::Danger)
+                    .padding(INPUT_PADDING)
+                    .on_press(Message::ConcentrationEnded);
+
+                row![
+                    components::text::body(&spell.name),
+                    widget::space::horizontal().width(Fill),
+                    end
+                ]
+                .align_y(Alignment::Center)
+                .into()
+            }
+        };
+
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+
+    fn spells_section(tracker: &'a Tracker) -> Element<'a, Message> {
+        let header = components::form::section_header(
+            "CAST",
+            "The cantrips and prepared spells, cast with one of the remaining slots.",
+        );
+
+        let mut form = Column::new().spacing(BODY_SPACING);
+
+        for entry in tracker.castable_spells() {
+            let mut details = format!("{} {}", entry.level, entry.school);
+            if entry.concentration {
+                details.push_str(" · Concentration");
+            }
+
+            let label = column![
+                components::text::display(&entry.name),
+                components::text::detail(details)
+            ]
+            .spacing(LABEL_SPACING);
+
+            let mut casts = Row::new().spacing(LABEL_SPACING);
+
+            match spell_level(entry) {
+                Some(0) => {
+                    casts = casts.push(cast_button("Cast", entry.spell_id, None));
+                }
+                Some(level) => {
+                    let slots = tracker.slots.castable(level);
+
+                    if slots.is_empty() {
+                        casts = casts.push(components::text::detail("No slots left"));
+                    }
+
+                    for slot in slots {
+                        casts =
+                            casts.push(cast_button(slot.to_string(), entry.spell_id, Some(slot)));
+                    }
+                }
+                None => {
+                    casts = casts.push(components::text::detail("Custom level"));
+                }
+            }
+
+            let row = row![label, widget::space::horizontal().width(Fill), casts]
+                .align_y(Alignment::Center);
+
+            form = form.push(row);
+        }
+
+        if tracker.castable_spells().next().is_none() {
+            form = form.push(components::text::detail(
+                "Prepare spells in the spellbook to cast them here.",
+            ));
+        }
+
+        let body = components::form::section_body(form);
+
+        row![header, body].into()
+    }
+}
+
+impl Viewable for SpellbookTracker {
+    type Message = Message;
+
+    type Effect = Effect;
+
+    fn title(&self) -> &str {
+        match &self.status {
+            Status::Loading(_) => "Tracker",
+            Status::Ready(tracker) => &tracker.spellbook.owner,
+        }
+    }
+
+    fn update(&mut self, message: Self::Message) -> (Task<Self::Message>, Option<Self::Effect>) {
+        match message {
+            Message::LoadMessage(load_message) => {
+                let loader = loading!(self.status);
+
+                loader.update(load_message);
+
+                if let Some(err) = loader.error.take() {
+                    tracing::error!("failed to load spellbook tracker: {:?}", err);
+
+                    return (Task::none(), Some(Effect::LoadFailed(err)));
+                }
+
+                if loader.is_done() {
+                    match Tracker::from_loader(loader) {
+                        Some(tracker) => self.status = Status::Ready(Box::new(tracker)),
+                        None => {
+                            tracing::error!("failed to convert loader to tracker");
+
+                            return (Task::none(), Some(Effect::LoadFailed(Error::Decode)));
+                        }
+                    }
+                }
+            }
+            Message::Cast(spell_id, slot) => {
+                let tracker = ready!(self.status);
+
+                if let Some(slot) = slot
+                    && !tracker.slots.expend(slot)
+                {
+                    tracing::warn!("no '{slot}' slot left to cast spell '{spell_id}'");
+
+                    return (Task::none(), None);
+                }
+
+                // Casting a concentration spell ends the concentration on any other spell.
+                if tracker
+                    .spell(spell_id)
+                    .is_some_and(|spell| spell.concentration)
+                {
+                    tracker.concentration = Some(spell_id);
+                }
+
+                return (save(self.context.clone(), tracker), None);
+            }
+            Message::SlotExpended(slot) => {
+                let tracker = ready!(self.status);
+
+                if tracker.slots.expend(slot) {
+                    return (save(self.context.clone(), tracker), None);
+                }
+            }
+            Message::SlotRegained(slot) => {
+                let tracker = ready!(self.status);
+
+                tracker.slots.regain(slot);
+
+                return (save(self.context.clone(), tracker), None);
+            }
+            Message::ConcentrationEnded => {
+                let tracker = ready!(self.status);
+
+                tracker.concentration = None;
+
+                return (save(self.context.clone(), tracker), None);
+            }
+            Message::Rested(rest) => {
+                let tracker = ready!(self.status);
+
+                tracker.slots.rest(rest);
+
+                return (save(self.context.clone(), tracker), None);
+            }
+            Message::Saved(Ok(())) => {}
+            Message::Saved(Err(err)) => {
+                tracing::error!("failed to save spellbook tracker: {err:?}");
+            }
+        }
+
+        (Task::none(), None)
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match &self.status {
+            Status::Loading(loader) => {
+                let start = 0.0;
+                let end = loader.total as f32;
+                let current = loader.progress as f32;
+
+                widget::progress_bar(start..=end, current).into()
+            }
+            Status::Ready(tracker) => {
+                let heading = Self::heading(tracker);
+
+                let slots = Self::slots_section(tracker);
+
+                let concentration = Self::concentration_section(tracker);
+
+                let spells = Self::spells_section(tracker);
+
+                column![heading, slots, concentration, spells]
+                    .align_x(Alignment::Center)
+                    .spacing(SECTION_SPACING)
+                    .into()
+            }
+        }
+    }
+}
+
+/// The level of the spell of `entry`, or `None` if it has a custom level.
+fn spell_level(entry: &SpellbookEntryRecord) -> Option<u8> {
+    match Choice::<Level>::parse(&entry.level) {
+        Choice::BuiltIn(level) => Some(level as u8),
+        Choice::Custom(_) => None,
+    }
+}
+
+/// The remaining slots of a single kind, with buttons to correct them by hand.
+fn slot_row<'a>(label: String, slot: Slot, available: u32, maximum: u32) -> Element<'a, Message> {
+    let label = components::text::label(label).width(Fill);
+    let count = components::text::body(format!("{available} / {maximum}"));
+
+    let expend = widget::button(components::text::display("−"))
+        .class(ButtonClass::Ghost)
+        .on_press_maybe((available > 0).then_some(Message::SlotExpended(slot)));
+
+    let regain = widget::button(components::text::display("+"))
+        .class(ButtonClass::Ghost)
+        .on_press_maybe((available < maximum).then_some(Message::SlotRegained(slot)));
+
+    row![label, expend, count, regain]
+        .spacing(BODY_SPACING)
+        .align_y(Alignment::Center)
+        .into()
+}
+
+fn cast_button<'a>(
+    label: impl Into<String>,
+    spell_id: Uuid,
+    slot: Option<Slot>,
+) -> Element<'a, Message> {
+    widget::button(components::text::body(label.into()))
+        .class(ButtonClass::Interaction)
+        .padding(INPUT_PADDING)
+        .on_press(Message::Cast(spell_id, slot))
+        .into()
+}
+
+/// Persist `tracker` after it changed.
+fn save(context: Context, tracker: &Tracker) -> Task<Message> {
+    Task::perform(save_tracker(context, tracker.new_tracker()), Message::Saved)
+}
+
+async fn save_tracker<C: SpellbooksRepository>(
+    ctx: C,
+    tracker: NewSpellbookTracker,
+) -> Result<(), Error> {
+    let repo = ctx.spellbooks();
+    repo.save_tracker(tracker).await
+}
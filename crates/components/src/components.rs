@@ -1,3 +1,4 @@
+pub mod dialog;
 pub mod form;
 pub mod icon;
 pub mod image_field;
@@ -11,6 +12,7 @@ pub mod text_area_field;
 pub mod text_field;
 pub mod toggle;
 
+pub use dialog::dialog;
 pub use icon::icon;
 pub use image_field::image_field;
 pub use multi_text_field::multi_text_field;
@@ -0,0 +1,24 @@
+use style::container::ContainerClass;
+use style::layout::INPUT_PADDING;
+use widgets::Element;
+
+use iced::widget;
+use iced::widget::stack;
+
+/// The maximum width of a dialog.
+const DIALOG_WIDTH: u32 = 500;
+
+/// Display `content` in a dialog on top of `base`, blocking any interaction with `base`.
+pub fn dialog<'a, Message: 'a>(
+    base: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    let dialog = widget::container(content)
+        .class(ContainerClass::Surface)
+        .padding(INPUT_PADDING)
+        .max_width(DIALOG_WIDTH);
+
+    let backdrop = widget::center(dialog).class(ContainerClass::Backdrop);
+
+    stack![base.into(), widget::opaque(backdrop)].into()
+}
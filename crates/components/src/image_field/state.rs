@@ -1,30 +1,48 @@
 use iced::widget::image::Handle;
+use std::sync::Arc;
 use uuid::Uuid;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(super) struct Image {
     pub(super) handle: Handle,
     id: Uuid,
-    bytes: Box<[u8]>,
+    bytes: Arc<[u8]>,
 }
 
 impl Image {
     fn new(bytes: Box<[u8]>) -> Self {
+        Self::with_id(Uuid::new_v4(), bytes)
+    }
+
+    fn with_id(id: Uuid, bytes: Box<[u8]>) -> Self {
         let handle = Handle::from_bytes(bytes.clone());
 
         Self {
             handle,
-            id: Uuid::new_v4(),
-            bytes,
+            id,
+            bytes: Arc::from(bytes),
         }
     }
 }
 
-#[derive(Debug, Default)]
+/// The images of an image field.
+///
+/// Cloning the state shares the bytes of the images rather than copying them.
+#[derive(Debug, Clone, Default)]
 pub struct ImageFieldState {
     pub(super) images: Vec<Image>,
 }
 
+/// Images never change once added, so states with the same images in the same order are equal.
+impl PartialEq for ImageFieldState {
+    fn eq(&self, other: &Self) -> bool {
+        self.images
+            .iter()
+            .map(|image| image.id)
+            .eq(other.images.iter().map(|image| image.id))
+    }
+}
+
 impl ImageFieldState {
     pub fn new(images: impl IntoIterator<Item = impl Into<Box<[u8]>>>) -> Self {
         let collected_images = images.into_iter().map(|i| Image::new(i.into())).collect();
@@ -34,6 +52,18 @@ impl ImageFieldState {
         }
     }
 
+    /// Create the state from images that already have an identity, like previously stored images.
+    pub fn with_ids(images: impl IntoIterator<Item = (Uuid, Box<[u8]>)>) -> Self {
+        let collected_images = images
+            .into_iter()
+            .map(|(id, bytes)| Image::with_id(id, bytes))
+            .collect();
+
+        Self {
+            images: collected_images,
+        }
+    }
+
     pub fn add(&mut self, bytes: Box<[u8]>) {
         let image = Image::new(bytes);
         self.images.push(image);
@@ -46,7 +76,7 @@ impl ImageFieldState {
     pub fn images(&self) -> Box<[(Uuid, Box<[u8]>)]> {
         self.images
             .iter()
-            .map(|image| (image.id, image.bytes.clone()))
+            .map(|image| (image.id, Box::from(&*image.bytes)))
             .collect::<Vec<_>>()
             .into_boxed_slice()
     }
@@ -57,6 +57,12 @@ impl MultiTextFieldState {
         }
     }
 
+    /// Replace the collection of selected values.
+    pub fn set_selections(&mut self, selections: Vec<String>) {
+        self.selections = selections;
+        self.error = None;
+    }
+
     pub fn remove_selection(&mut self, index: usize) {
         self.selections.remove(index);
     }
@@ -80,11 +86,13 @@ impl MultiTextFieldState {
         self.required
     }
 
-    pub(super) fn value(&self) -> &str {
+    /// The value of the current input.
+    pub fn value(&self) -> &str {
         &self.value
     }
 
-    pub(super) fn selections(&self) -> &[String] {
+    /// The collection of selected values.
+    pub fn selections(&self) -> &[String] {
         &self.selections
     }
 
@@ -5,6 +5,9 @@ pub struct NumberFieldState {
     raw: String,
     parsed: Option<i32>,
     required: bool,
+
+    /// Whether negative numbers are rejected as they are typed.
+    non_negative: bool,
     error: Option<&'static str>,
 }
 
@@ -16,6 +19,7 @@ impl NumberFieldState {
             raw,
             parsed: value,
             required: false,
+            non_negative: false,
             error: None,
         }
     }
@@ -25,11 +29,16 @@ impl NumberFieldState {
         self
     }
 
+    pub fn non_negative(mut self, non_negative: bool) -> Self {
+        self.non_negative = non_negative;
+        self
+    }
+
     pub fn set(&mut self, value: String) {
         self.error = None;
 
         // Allow negative characters.
-        if &value == "-" {
+        if &value == "-" && !self.non_negative {
             self.raw = value;
 
             return;
@@ -44,7 +53,9 @@ impl NumberFieldState {
         }
 
         // Input is parsed, we assign both raw and parsed.
-        if let Ok(parsed) = value.parse() {
+        if let Ok(parsed) = value.parse::<i32>()
+            && (parsed >= 0 || !self.non_negative)
+        {
             self.raw = value;
             self.parsed = Some(parsed);
         }
@@ -69,7 +80,8 @@ impl NumberFieldState {
         self.required
     }
 
-    pub(super) fn raw_value(&self) -> &str {
+    /// The value as written in the input, which may not be a valid number yet.
+    pub fn raw_value(&self) -> &str {
         &self.raw
     }
 
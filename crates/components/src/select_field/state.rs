@@ -33,6 +33,12 @@ where
         self.selected = Some(value);
     }
 
+    /// Clear the selected value.
+    pub fn clear(&mut self) {
+        self.error = None;
+        self.selected = None;
+    }
+
     /// Get the value of the state, if it is valid, otherwise None.
     ///
     /// This method is most useful for "final" extraction on form submit.
@@ -48,7 +54,8 @@ where
         self.selected.clone()
     }
 
-    pub(super) fn selected(&self) -> Option<&Value> {
+    /// The currently selected value, if any.
+    pub fn selected(&self) -> Option<&Value> {
         self.selected.as_ref()
     }
 
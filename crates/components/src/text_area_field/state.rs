@@ -2,6 +2,7 @@ use crate::REQUIRED_ERROR_STR;
 
 use iced::widget::text_editor::Action;
 use iced::widget::text_editor::Content;
+use iced::widget::text_editor::Motion;
 
 #[derive(Debug, Default)]
 pub struct TextAreaFieldState {
@@ -29,6 +30,18 @@ impl TextAreaFieldState {
         self.content.perform(action);
     }
 
+    /// Replace the text of the `TextArea`, moving the cursor to the end of it.
+    pub fn set(&mut self, value: String) {
+        self.error = None;
+        self.content = Content::with_text(&value);
+        self.content.perform(Action::Move(Motion::DocumentEnd));
+    }
+
+    /// The current text of the `TextArea`.
+    pub fn text(&self) -> String {
+        self.content.text()
+    }
+
     /// Get the value of the state, if validation succeds, otherwise None.
     ///
     /// This method is most useful for "final" extraction on form submit.
@@ -31,6 +31,12 @@ impl<'a, Message> Toggle<'a, Message> {
         self
     }
 
+    /// Set the message of toggling, which disables the toggle if it is `None`.
+    pub fn on_toggle_maybe(mut self, on_toggle: Option<Message>) -> Self {
+        self.on_toggle = on_toggle;
+        self
+    }
+
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
         self
@@ -1,7 +1,7 @@
 use app::Application;
 
 use iced::Settings;
-use iced::application;
+use iced::daemon;
 use tracing::debug;
 
 fn main() -> iced::Result {
@@ -25,8 +25,10 @@ fn main() -> iced::Result {
     debug!("default text size: {} pixels", settings.default_text_size.0);
     debug!("     loaded fonts: {}", settings.fonts.len());
 
-    application(Application::new, Application::update, Application::view)
+    daemon(Application::new, Application::update, Application::view)
         .settings(settings)
+        .title(Application::title)
+        .subscription(Application::subscription)
         .theme(Application::theme)
         .run()
 }
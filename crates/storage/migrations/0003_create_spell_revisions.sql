@@ -0,0 +1,27 @@
+-- Every create, update, delete and restore of a spell is recorded as a revision,
+-- containing a full JSON snapshot of the spell as it was after the change.
+--
+-- Snapshots are stored whole rather than as diffs, since spells are small and
+-- it makes restoring any given revision a matter of re-inserting its snapshot.
+--
+-- Timestamps, here and in the tables that follow, are stored as UTC text in the format
+-- 'YYYY-MM-DD HH:MM:SS', so they sort chronologically as text.
+CREATE TABLE spell_revisions (
+    id         BLOB PRIMARY KEY,
+
+    -- Not a foreign key, as a deleted spell is restored from its revisions.
+    spell_id   BLOB NOT NULL,
+
+    kind       TEXT NOT NULL CHECK (kind IN ('created', 'updated', 'deleted', 'restored')),
+
+    -- The name of the user who made the change.
+    author     TEXT NOT NULL,
+
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now')),
+
+    -- For a 'deleted' revision, this is the snapshot of the spell just before it was deleted.
+    snapshot   TEXT NOT NULL
+);
+
+-- The revisions are always queried for a single spell at a time.
+CREATE INDEX idx_spell_revisions_spell_id ON spell_revisions(spell_id);
@@ -0,0 +1,24 @@
+-- The autosaved content of open spell forms, so unsaved work survives a crash or closing the app.
+--
+-- The content is inline JSON, since a draft is an incomplete and unvalidated spell
+-- that is only ever read back as a whole to restore a form.
+CREATE TABLE spell_drafts (
+    id         BLOB PRIMARY KEY,
+
+    -- The spell being edited by the form, or NULL if the form creates a new spell.
+    -- Once the spell is deleted, the draft is kept and restores as a new spell.
+    spell_id   BLOB REFERENCES spells(id) ON DELETE SET NULL,
+
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now')),
+
+    data       TEXT NOT NULL
+);
+
+-- Unlike spell images, draft images are stored in the database rather than as files,
+-- since drafts are short-lived and discarded as soon as the form is saved.
+CREATE TABLE spell_draft_images (
+    id       BLOB    NOT NULL,
+    draft_id BLOB    NOT NULL REFERENCES spell_drafts(id) ON DELETE CASCADE,
+    bytes    BLOB    NOT NULL,
+    PRIMARY KEY (draft_id, id)
+);
@@ -0,0 +1,18 @@
+-- A spellbook is the collection of spells a single character can cast,
+-- along with what is needed to determine how many of them can be prepared.
+CREATE TABLE spellbooks (
+    id            BLOB    PRIMARY KEY,
+    owner         TEXT    NOT NULL,
+    class         TEXT    NOT NULL,
+    level         INTEGER NOT NULL CHECK (level BETWEEN 1 AND 20),
+    ability       TEXT    NOT NULL,
+    ability_score INTEGER NOT NULL CHECK (ability_score BETWEEN 1 AND 30)
+);
+
+CREATE TABLE spellbook_spells (
+    spellbook_id    BLOB    NOT NULL REFERENCES spellbooks(id) ON DELETE CASCADE,
+    spell_id        BLOB    NOT NULL REFERENCES spells(id)     ON DELETE CASCADE,
+    prepared        INTEGER NOT NULL CHECK (prepared        IN (0, 1)) DEFAULT 0,
+    always_prepared INTEGER NOT NULL CHECK (always_prepared IN (0, 1)) DEFAULT 0,
+    PRIMARY KEY (spellbook_id, spell_id)
+);
@@ -0,0 +1,10 @@
+-- Spells marked as favourite, to be reached quickly during play.
+CREATE TABLE favourite_spells (
+    spell_id BLOB PRIMARY KEY REFERENCES spells(id) ON DELETE CASCADE
+);
+
+-- The spells that were opened most recently, pruned to a fixed number of entries.
+CREATE TABLE recent_spells (
+    spell_id  BLOB PRIMARY KEY REFERENCES spells(id) ON DELETE CASCADE,
+    viewed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now'))
+);
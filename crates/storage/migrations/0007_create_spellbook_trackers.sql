@@ -0,0 +1,17 @@
+-- The resources the owner of a spellbook has spent since they last rested.
+--
+-- Only what was spent is stored, as the available slots follow from the class and level.
+CREATE TABLE spellbook_trackers (
+    spellbook_id           BLOB    PRIMARY KEY REFERENCES spellbooks(id) ON DELETE CASCADE,
+    pact_slots_expended    INTEGER NOT NULL CHECK (pact_slots_expended >= 0) DEFAULT 0,
+
+    -- The spell being concentrated on, or NULL if there is none.
+    concentration_spell_id BLOB    REFERENCES spells(id) ON DELETE SET NULL
+);
+
+CREATE TABLE spellbook_expended_slots (
+    spellbook_id BLOB    NOT NULL REFERENCES spellbook_trackers(spellbook_id) ON DELETE CASCADE,
+    level        INTEGER NOT NULL CHECK (level BETWEEN 1 AND 9),
+    expended     INTEGER NOT NULL CHECK (expended > 0),
+    PRIMARY KEY (spellbook_id, level)
+);
@@ -0,0 +1,56 @@
+-- The core table for creatures, holding the parts of their stat blocks that are filtered on.
+CREATE TABLE creatures (
+    id                BLOB    PRIMARY KEY,
+    name              TEXT    NOT NULL,
+    size              TEXT    NOT NULL,
+    type              TEXT    NOT NULL,
+    alignment         TEXT    NOT NULL,
+    armor_class       INTEGER NOT NULL CHECK (armor_class >= 0),
+    armor_description TEXT,
+
+    -- A dice formula like '2d8 + 4', from which the average hit points follow.
+    hit_dice          TEXT    NOT NULL,
+
+    -- These are inline JSON, as they are only ever shown as part of the creature itself.
+    speeds            TEXT    NOT NULL,
+    saving_throws     TEXT    NOT NULL,
+    skills            TEXT    NOT NULL,
+    senses            TEXT    NOT NULL,
+
+    strength          INTEGER NOT NULL CHECK (strength     BETWEEN 1 AND 30),
+    dexterity         INTEGER NOT NULL CHECK (dexterity    BETWEEN 1 AND 30),
+    constitution      INTEGER NOT NULL CHECK (constitution BETWEEN 1 AND 30),
+    intelligence      INTEGER NOT NULL CHECK (intelligence BETWEEN 1 AND 30),
+    wisdom            INTEGER NOT NULL CHECK (wisdom       BETWEEN 1 AND 30),
+    charisma          INTEGER NOT NULL CHECK (charisma     BETWEEN 1 AND 30),
+
+    -- Written as in stat blocks, like '1/4' or '5'.
+    challenge_rating  TEXT    NOT NULL
+);
+
+-- The damage types a creature is vulnerable, resistant or immune to.
+CREATE TABLE creature_damage_defenses (
+    creature_id BLOB NOT NULL REFERENCES creatures(id) ON DELETE CASCADE,
+    damage_type TEXT NOT NULL,
+    defense     TEXT NOT NULL CHECK (defense IN ('Vulnerability', 'Resistance', 'Immunity')),
+    PRIMARY KEY (creature_id, damage_type)
+);
+
+-- The languages of a creature, in the order they are written in the stat block.
+CREATE TABLE creature_languages (
+    creature_id BLOB NOT NULL REFERENCES creatures(id) ON DELETE CASCADE,
+    language    TEXT NOT NULL,
+    PRIMARY KEY (creature_id, language)
+);
+
+-- The traits, actions, reactions and legendary actions of a creature.
+CREATE TABLE creature_features (
+    creature_id BLOB    NOT NULL REFERENCES creatures(id) ON DELETE CASCADE,
+    kind        TEXT    NOT NULL CHECK (kind IN ('trait', 'action', 'reaction', 'legendary_action')),
+
+    -- The position of the feature among the features of the same kind.
+    position    INTEGER NOT NULL,
+    name        TEXT    NOT NULL,
+    description TEXT    NOT NULL,
+    PRIMARY KEY (creature_id, kind, position)
+);
@@ -0,0 +1,9 @@
+-- The source book of a creature, written like the sources of spells.
+ALTER TABLE creatures ADD COLUMN source TEXT;
+
+-- The environments a creature is encountered in, used to filter the bestiary.
+CREATE TABLE creature_environments (
+    creature_id BLOB NOT NULL REFERENCES creatures(id) ON DELETE CASCADE,
+    environment TEXT NOT NULL,
+    PRIMARY KEY (creature_id, environment)
+);
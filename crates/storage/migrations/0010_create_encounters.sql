@@ -0,0 +1,20 @@
+-- An encounter prepared ahead of a session, pitting creatures of the bestiary against a party.
+CREATE TABLE encounters (
+    id   BLOB PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+-- The characters of the party the encounter is built for, of which only the level matters.
+CREATE TABLE encounter_characters (
+    encounter_id BLOB    NOT NULL REFERENCES encounters(id) ON DELETE CASCADE,
+    position     INTEGER NOT NULL CHECK (position >= 0),
+    level        INTEGER NOT NULL CHECK (level BETWEEN 1 AND 20),
+    PRIMARY KEY (encounter_id, position)
+);
+
+CREATE TABLE encounter_creatures (
+    encounter_id BLOB    NOT NULL REFERENCES encounters(id) ON DELETE CASCADE,
+    creature_id  BLOB    NOT NULL REFERENCES creatures(id)  ON DELETE CASCADE,
+    count        INTEGER NOT NULL CHECK (count > 0),
+    PRIMARY KEY (encounter_id, creature_id)
+);
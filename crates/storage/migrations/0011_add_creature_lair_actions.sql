@@ -0,0 +1,22 @@
+-- Lair actions become a kind of feature, which SQLite can only add to the check by rebuilding
+-- the table.
+CREATE TABLE creature_features_new (
+    creature_id BLOB    NOT NULL REFERENCES creatures(id) ON DELETE CASCADE,
+    kind        TEXT    NOT NULL CHECK (
+        kind IN ('trait', 'action', 'reaction', 'legendary_action', 'lair_action')
+    ),
+
+    -- The position of the feature among the features of the same kind.
+    position    INTEGER NOT NULL,
+    name        TEXT    NOT NULL,
+    description TEXT    NOT NULL,
+    PRIMARY KEY (creature_id, kind, position)
+);
+
+INSERT INTO creature_features_new (creature_id, kind, position, name, description)
+SELECT creature_id, kind, position, name, description
+FROM creature_features;
+
+DROP TABLE creature_features;
+
+ALTER TABLE creature_features_new RENAME TO creature_features;
@@ -0,0 +1,11 @@
+-- The combats left unfinished in the combat tracker, so a fight can be resumed in a later session.
+--
+-- The state is inline JSON, since it changes with every action taken in the fight
+-- and is only ever read back as a whole to restore the tracker.
+CREATE TABLE combats (
+    id         BLOB PRIMARY KEY,
+
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now')),
+
+    data       TEXT NOT NULL
+);
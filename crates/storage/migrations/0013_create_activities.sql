@@ -0,0 +1,15 @@
+-- The activity feed of the dashboard: what was created, changed or deleted, and the fights run.
+--
+-- The entity id is not a foreign key, as the activity must outlive the deletion of the entity.
+-- The name is kept as it was at the time, so the activity still reads the same
+-- once the entity is renamed or deleted.
+CREATE TABLE activities (
+    id          BLOB PRIMARY KEY,
+
+    kind        TEXT NOT NULL CHECK (kind IN ('created', 'updated', 'deleted', 'restored', 'fought')),
+    entity      TEXT NOT NULL CHECK (entity IN ('spell', 'spellbook', 'creature', 'encounter', 'combat')),
+    entity_id   BLOB NOT NULL,
+    name        TEXT NOT NULL,
+
+    occurred_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%S', 'now'))
+);
@@ -0,0 +1,3 @@
+-- The number of legendary actions a creature can take each round, which is only used if it has
+-- any. Most legendary creatures take three.
+ALTER TABLE creatures ADD COLUMN legendary_action_count INTEGER NOT NULL DEFAULT 3;
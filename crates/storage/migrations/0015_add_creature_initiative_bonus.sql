@@ -0,0 +1,2 @@
+-- The bonus a creature adds to its initiative on top of its Dexterity modifier.
+ALTER TABLE creatures ADD COLUMN initiative_bonus INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,114 @@
+use crate::Error;
+use crate::clients::local::Local;
+use crate::models::activity::NewActivity;
+use crate::records::activity::ActivityKind;
+use crate::records::activity::ActivityRecord;
+use crate::records::activity::EntityKind;
+use crate::repositories::activities::Activities;
+use crate::repositories::activities::ActivitiesRepository;
+
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+/// The number of activities that are kept.
+const ACTIVITIES_KEPT: i64 = 200;
+
+impl ActivitiesRepository for Local {
+    fn activities(&self) -> &dyn Activities {
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Activities for Local {
+    async fn record(&self, activity: NewActivity) -> Result<(), Error> {
+        let mut connection = self.pool.acquire().await?;
+
+        insert_activity(&mut connection, &activity).await
+    }
+
+    async fn latest(&self, limit: u32) -> Result<Box<[ActivityRecord]>, Error> {
+        // The rowid breaks ties between activities within the same second.
+        let query = r#"
+            SELECT id, kind, entity, entity_id, name, occurred_at
+            FROM activities
+            ORDER BY occurred_at DESC, rowid DESC
+            LIMIT $1;
+        "#;
+
+        let rows: Vec<ActivityRecord> = sqlx::query_as(query)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        tracing::debug!("fetched {} activities", rows.len());
+
+        Ok(rows.into_boxed_slice())
+    }
+}
+
+/// Record that `kind` happened to the `entity` with `entity_id` and `name`.
+pub(super) async fn record_activity(
+    connection: &mut SqliteConnection,
+    kind: ActivityKind,
+    entity: EntityKind,
+    entity_id: Uuid,
+    name: String,
+) -> Result<(), Error> {
+    let activity = NewActivity {
+        kind,
+        entity,
+        entity_id,
+        name,
+    };
+
+    insert_activity(connection, &activity).await
+}
+
+/// Insert `activity`, pruning the oldest activities beyond the ones kept.
+async fn insert_activity(
+    connection: &mut SqliteConnection,
+    activity: &NewActivity,
+) -> Result<(), Error> {
+    let insert_query = r#"
+        INSERT INTO activities (
+            id, kind, entity, entity_id, name
+        ) VALUES ($1,$2,$3,$4,$5);
+    "#;
+    let insert_result = sqlx::query(insert_query)
+        .bind(Uuid::new_v4())
+        .bind(activity.kind)
+        .bind(activity.entity)
+        .bind(activity.entity_id)
+        .bind(&activity.name)
+        .execute(&mut *connection)
+        .await;
+
+    if let Err(err) = insert_result {
+        tracing::error!("failed to insert activity in activities table: {err}");
+        return Err(Error::Query);
+    }
+
+    let prune_query = r#"
+        DELETE FROM activities
+        WHERE rowid NOT IN (
+            SELECT rowid
+            FROM activities
+            ORDER BY occurred_at DESC, rowid DESC
+            LIMIT $1
+        );
+    "#;
+    sqlx::query(prune_query)
+        .bind(ACTIVITIES_KEPT)
+        .execute(&mut *connection)
+        .await?;
+
+    tracing::debug!(
+        "recorded activity: {} {} '{}'",
+        activity.kind,
+        activity.entity,
+        activity.entity_id
+    );
+
+    Ok(())
+}
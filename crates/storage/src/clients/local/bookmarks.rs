@@ -0,0 +1,143 @@
+use crate::Error;
+use crate::clients::local::Local;
+use crate::clients::local::spells::SummaryRow;
+use crate::records::spell::SpellSummary;
+use crate::repositories::bookmarks::Bookmarks;
+use crate::repositories::bookmarks::BookmarksRepository;
+
+use uuid::Uuid;
+
+/// The number of recently opened spells that are kept.
+const RECENTS_KEPT: i64 = 50;
+
+impl BookmarksRepository for Local {
+    fn bookmarks(&self) -> &dyn Bookmarks {
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Bookmarks for Local {
+    async fn set_favourite(&self, spell_id: Uuid, favourite: bool) -> Result<(), Error> {
+        let query = if favourite {
+            r#"
+                INSERT INTO favourite_spells (spell_id)
+                VALUES ($1)
+                ON CONFLICT (spell_id) DO NOTHING;
+            "#
+        } else {
+            r#"
+                DELETE FROM favourite_spells
+                WHERE spell_id = $1;
+            "#
+        };
+
+        sqlx::query(query)
+            .bind(spell_id)
+            .execute(&self.pool)
+            .await?;
+
+        tracing::debug!("set favourite of spell '{spell_id}' to {favourite}");
+
+        Ok(())
+    }
+
+    async fn is_favourite(&self, spell_id: Uuid) -> Result<bool, Error> {
+        let query = r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM favourite_spells
+                WHERE spell_id = $1
+            );
+        "#;
+
+        let favourite: bool = sqlx::query_scalar(query)
+            .bind(spell_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(favourite)
+    }
+
+    async fn favourites(&self) -> Result<Box<[SpellSummary]>, Error> {
+        let query = r#"
+            SELECT s.id, s.name, s.school, s.level, s.range, s.area
+            FROM favourite_spells f
+            JOIN spells s ON s.id = f.spell_id
+            ORDER BY s.name COLLATE NOCASE;
+        "#;
+
+        let rows: Vec<SummaryRow> = sqlx::query_as(query).fetch_all(&self.pool).await?;
+
+        tracing::debug!("fetched {} favourite spells", rows.len());
+
+        Ok(rows.into_iter().map(SpellSummary::from).collect())
+    }
+
+    async fn record_view(&self, spell_id: Uuid) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for recording spell view");
+            return Err(Error::Connection);
+        };
+
+        // The entry is reinserted rather than updated, so its rowid orders it after every other
+        // entry, even among spells opened within the same second.
+        let delete_query = r#"
+            DELETE FROM recent_spells
+            WHERE spell_id = $1;
+        "#;
+        sqlx::query(delete_query)
+            .bind(spell_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let insert_query = r#"
+            INSERT INTO recent_spells (spell_id)
+            VALUES ($1);
+        "#;
+        sqlx::query(insert_query)
+            .bind(spell_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let prune_query = r#"
+            DELETE FROM recent_spells
+            WHERE rowid NOT IN (
+                SELECT rowid
+                FROM recent_spells
+                ORDER BY viewed_at DESC, rowid DESC
+                LIMIT $1
+            );
+        "#;
+        sqlx::query(prune_query)
+            .bind(RECENTS_KEPT)
+            .execute(&mut *transaction)
+            .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spell view transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        Ok(())
+    }
+
+    async fn recents(&self, limit: u32) -> Result<Box<[SpellSummary]>, Error> {
+        let query = r#"
+            SELECT s.id, s.name, s.school, s.level, s.range, s.area
+            FROM recent_spells r
+            JOIN spells s ON s.id = r.spell_id
+            ORDER BY r.viewed_at DESC, r.rowid DESC
+            LIMIT $1;
+        "#;
+
+        let rows: Vec<SummaryRow> = sqlx::query_as(query)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        tracing::debug!("fetched {} recent spells", rows.len());
+
+        Ok(rows.into_iter().map(SpellSummary::from).collect())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Error;
+use crate::clients::local::Local;
+use crate::models::combat::NewCombat;
+use crate::records::combat::CombatRecord;
+use crate::repositories::combats::Combats;
+use crate::repositories::combats::CombatsRepository;
+
+use uuid::Uuid;
+
+#[derive(sqlx::FromRow)]
+struct CombatRow {
+    id: Uuid,
+    updated_at: String,
+    data: String,
+}
+
+impl CombatsRepository for Local {
+    fn combats(&self) -> &dyn Combats {
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Combats for Local {
+    async fn save(&self, combat: NewCombat) -> Result<(), Error> {
+        let Ok(data) = serde_json::to_string(&combat.data) else {
+            tracing::error!("failed to JSON serialize combat '{}'", combat.id);
+            return Err(Error::Decode);
+        };
+
+        let query = r#"
+            INSERT INTO combats (
+                id, data
+            ) VALUES ($1,$2)
+            ON CONFLICT (id)
+            DO UPDATE SET
+                updated_at = strftime('%Y-%m-%d %H:%M:%S', 'now'),
+                data = excluded.data;
+        "#;
+        sqlx::query(query)
+            .bind(combat.id)
+            .bind(data)
+            .execute(&self.pool)
+            .await?;
+
+        tracing::debug!("saved combat '{}'", combat.id);
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = r#"
+            DELETE FROM combats
+            WHERE id = $1;
+        "#;
+
+        sqlx::query(query).bind(id).execute(&self.pool).await?;
+
+        tracing::debug!("deleted combat '{id}'");
+
+        Ok(())
+    }
+
+    async fn list(&self) -> Result<Box<[CombatRecord]>, Error> {
+        let query = r#"
+            SELECT id, updated_at, data
+            FROM combats
+            ORDER BY updated_at, rowid;
+        "#;
+
+        let rows: Vec<CombatRow> = sqlx::query_as(query).fetch_all(&self.pool).await?;
+
+        // A combat that no longer decodes, like one saved by another version of the
+        // application, is left out rather than keeping every other combat from resuming.
+        let mut combats = Vec::with_capacity(rows.len());
+        for row in rows {
+            let Ok(data) = serde_json::from_str(&row.data) else {
+                tracing::error!(
+                    "failed to JSON deserialize combat '{}', skipping it",
+                    row.id
+                );
+                continue;
+            };
+
+            combats.push(CombatRecord {
+                id: row.id,
+                updated_at: row.updated_at,
+                data,
+            });
+        }
+
+        tracing::debug!("fetched {} combats", combats.len());
+
+        Ok(combats.into_boxed_slice())
+    }
+}
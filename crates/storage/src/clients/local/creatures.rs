@@ -0,0 +1,600 @@
+use crate::Error;
+use crate::clients::local::Local;
+use crate::clients::local::activities::record_activity;
+use crate::models::creature::NewCreature;
+use crate::records::activity::ActivityKind;
+use crate::records::activity::EntityKind;
+use crate::records::creature::CreatureRecord;
+use crate::records::creature::CreatureSummary;
+use crate::records::creature::DamageDefenseRecord;
+use crate::records::creature::FeatureRecord;
+use crate::repositories::creatures::Creatures;
+use crate::repositories::creatures::CreaturesRepository;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use sqlx::SqliteConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// The kinds of features in the `creature_features` table.
+const TRAIT: &str = "trait";
+const ACTION: &str = "action";
+const REACTION: &str = "reaction";
+const LEGENDARY_ACTION: &str = "legendary_action";
+const LAIR_ACTION: &str = "lair_action";
+
+#[derive(sqlx::FromRow)]
+struct CreatureRow {
+    id: Uuid,
+    name: String,
+    size: String,
+    #[sqlx(rename = "type")]
+    kind: String,
+    alignment: String,
+    armor_class: u32,
+    armor_description: Option<String>,
+    hit_dice: String,
+    speeds: String,
+    saving_throws: String,
+    skills: String,
+    senses: String,
+    strength: i32,
+    dexterity: i32,
+    constitution: i32,
+    intelligence: i32,
+    wisdom: i32,
+    charisma: i32,
+    challenge_rating: String,
+    source: Option<String>,
+    legendary_action_count: u32,
+    initiative_bonus: i32,
+}
+
+#[derive(sqlx::FromRow)]
+struct FeatureRow {
+    kind: String,
+    name: String,
+    description: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct SummaryRow {
+    id: Uuid,
+    name: String,
+    size: String,
+    #[sqlx(rename = "type")]
+    kind: String,
+    armor_class: u32,
+    hit_dice: String,
+    challenge_rating: String,
+    source: Option<String>,
+}
+
+impl CreaturesRepository for Local {
+    fn creatures(&self) -> &dyn Creatures {
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Creatures for Local {
+    async fn create(&self, new_creature: NewCreature) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for creating creature");
+            return Err(Error::Connection);
+        };
+
+        insert_creature(&mut transaction, &new_creature).await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Created,
+            EntityKind::Creature,
+            new_creature.id,
+            new_creature.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit creature creation transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("created creature '{}'", new_creature.id);
+
+        Ok(())
+    }
+
+    async fn update(&self, creature: NewCreature) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for updating creature");
+            return Err(Error::Connection);
+        };
+
+        if !update_creature(&mut transaction, &creature).await? {
+            tracing::error!(
+                "failed to update creature '{}', as it does not exist",
+                creature.id
+            );
+            return Err(Error::NotFound);
+        }
+
+        record_activity(
+            &mut transaction,
+            ActivityKind::Updated,
+            EntityKind::Creature,
+            creature.id,
+            creature.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit creature update transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("updated creature '{}'", creature.id);
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for deleting creature");
+            return Err(Error::Connection);
+        };
+
+        let name_query = r#"
+            SELECT name
+            FROM creatures
+            WHERE id = $1;
+        "#;
+        let name: Option<String> = sqlx::query_scalar(name_query)
+            .bind(id)
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+        let Some(name) = name else {
+            return Err(Error::NotFound);
+        };
+
+        delete_creature(&mut transaction, id).await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Deleted,
+            EntityKind::Creature,
+            id,
+            name,
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit creature deletion transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("deleted creature '{id}'");
+
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<CreatureRecord, Error> {
+        let creature_query = r#"
+            SELECT id, name, size, type, alignment, armor_class, armor_description, hit_dice,
+                speeds, saving_throws, skills, senses,
+                strength, dexterity, constitution, intelligence, wisdom, charisma,
+                challenge_rating, source, legendary_action_count, initiative_bonus
+            FROM creatures
+            WHERE id = $1;
+        "#;
+
+        let row: CreatureRow = sqlx::query_as(creature_query)
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        let defenses_query = r#"
+            SELECT damage_type, defense
+            FROM creature_damage_defenses
+            WHERE creature_id = $1
+            ORDER BY rowid;
+        "#;
+
+        let defenses: Vec<(String, String)> = sqlx::query_as(defenses_query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let languages_query = r#"
+            SELECT language
+            FROM creature_languages
+            WHERE creature_id = $1
+            ORDER BY rowid;
+        "#;
+
+        let languages: Vec<String> = sqlx::query_scalar(languages_query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let environments_query = r#"
+            SELECT environment
+            FROM creature_environments
+            WHERE creature_id = $1
+            ORDER BY rowid;
+        "#;
+
+        let environments: Vec<String> = sqlx::query_scalar(environments_query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let features_query = r#"
+            SELECT kind, name, description
+            FROM creature_features
+            WHERE creature_id = $1
+            ORDER BY position;
+        "#;
+
+        let features: Vec<FeatureRow> = sqlx::query_as(features_query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let features_of = |kind: &str| -> Box<[FeatureRecord]> {
+            features
+                .iter()
+                .filter(|feature| feature.kind == kind)
+                .map(|feature| FeatureRecord {
+                    name: feature.name.clone(),
+                    description: feature.description.clone(),
+                })
+                .collect()
+        };
+
+        Ok(CreatureRecord {
+            id: row.id,
+            name: row.name,
+            size: row.size,
+            kind: row.kind,
+            alignment: row.alignment,
+            armor_class: row.armor_class,
+            armor_description: row.armor_description,
+            hit_dice: row.hit_dice,
+            speeds: from_json("speeds", &row.speeds)?,
+            abilities: [
+                row.strength,
+                row.dexterity,
+                row.constitution,
+                row.intelligence,
+                row.wisdom,
+                row.charisma,
+            ],
+            initiative_bonus: row.initiative_bonus,
+            saving_throws: from_json("saving throws", &row.saving_throws)?,
+            skills: from_json("skills", &row.skills)?,
+            damage_defenses: defenses
+                .into_iter()
+                .map(|(damage_type, defense)| DamageDefenseRecord {
+                    damage_type,
+                    defense,
+                })
+                .collect(),
+            senses: from_json("senses", &row.senses)?,
+            languages: languages.into(),
+            challenge_rating: row.challenge_rating,
+            environments: environments.into(),
+            source: row.source,
+            traits: features_of(TRAIT),
+            actions: features_of(ACTION),
+            reactions: features_of(REACTION),
+            legendary_actions: features_of(LEGENDARY_ACTION),
+            legendary_action_count: row.legendary_action_count,
+            lair_actions: features_of(LAIR_ACTION),
+        })
+    }
+
+    async fn list(&self) -> Result<Box<[CreatureSummary]>, Error> {
+        let query = r#"
+            SELECT id, name, size, type, armor_class, hit_dice, challenge_rating, source
+            FROM creatures
+            ORDER BY name COLLATE NOCASE;
+        "#;
+
+        let rows: Vec<SummaryRow> = sqlx::query_as(query).fetch_all(&self.pool).await?;
+
+        let environments_query = r#"
+            SELECT creature_id, environment
+            FROM creature_environments
+            ORDER BY rowid;
+        "#;
+
+        let environment_rows: Vec<(Uuid, String)> = sqlx::query_as(environments_query)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut environments: HashMap<Uuid, Vec<String>> = HashMap::new();
+        for (creature_id, environment) in environment_rows {
+            environments
+                .entry(creature_id)
+                .or_default()
+                .push(environment);
+        }
+
+        tracing::debug!("fetched {} creatures", rows.len());
+
+        let summaries = rows
+            .into_iter()
+            .map(|row| CreatureSummary {
+                id: row.id,
+                name: row.name,
+                size: row.size,
+                kind: row.kind,
+                armor_class: row.armor_class,
+                hit_dice: row.hit_dice,
+                challenge_rating: row.challenge_rating,
+                environments: environments.remove(&row.id).unwrap_or_default().into(),
+                source: row.source,
+            })
+            .collect();
+
+        Ok(summaries)
+    }
+
+    async fn count(&self) -> Result<u32, Error> {
+        let query = r#"
+            SELECT COUNT(*)
+            FROM creatures;
+        "#;
+
+        let count: u32 = sqlx::query_scalar(query).fetch_one(&self.pool).await?;
+
+        tracing::debug!("counted {count} creatures");
+
+        Ok(count)
+    }
+}
+
+/// Insert `creature` into the creatures table and its related tables.
+async fn insert_creature(
+    connection: &mut SqliteConnection,
+    creature: &NewCreature,
+) -> Result<(), Error> {
+    let [
+        strength,
+        dexterity,
+        constitution,
+        intelligence,
+        wisdom,
+        charisma,
+    ] = creature.abilities;
+
+    let insert_creature_query = r#"
+        INSERT INTO creatures (
+            id, name, size, type, alignment, armor_class, armor_description, hit_dice,
+            speeds, saving_throws, skills, senses,
+            strength, dexterity, constitution, intelligence, wisdom, charisma,
+            challenge_rating, source, legendary_action_count, initiative_bonus
+        ) VALUES (
+            $1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22
+        );
+    "#;
+    sqlx::query(insert_creature_query)
+        .bind(creature.id)
+        .bind(&creature.name)
+        .bind(&creature.size)
+        .bind(&creature.kind)
+        .bind(&creature.alignment)
+        .bind(creature.armor_class)
+        .bind(&creature.armor_description)
+        .bind(&creature.hit_dice)
+        .bind(to_json("speeds", &creature.speeds)?)
+        .bind(to_json("saving throws", &creature.saving_throws)?)
+        .bind(to_json("skills", &creature.skills)?)
+        .bind(to_json("senses", &creature.senses)?)
+        .bind(strength)
+        .bind(dexterity)
+        .bind(constitution)
+        .bind(intelligence)
+        .bind(wisdom)
+        .bind(charisma)
+        .bind(&creature.challenge_rating)
+        .bind(&creature.source)
+        .bind(creature.legendary_action_count)
+        .bind(creature.initiative_bonus)
+        .execute(&mut *connection)
+        .await?;
+
+    insert_relations(connection, creature).await
+}
+
+/// Update the creature of `creature` in place, returning whether it existed.
+///
+/// The rows of the related tables are replaced, as they have no identity of their own.
+async fn update_creature(
+    connection: &mut SqliteConnection,
+    creature: &NewCreature,
+) -> Result<bool, Error> {
+    let [
+        strength,
+        dexterity,
+        constitution,
+        intelligence,
+        wisdom,
+        charisma,
+    ] = creature.abilities;
+
+    let update_creature_query = r#"
+        UPDATE creatures
+        SET
+            name = $2,
+            size = $3,
+            type = $4,
+            alignment = $5,
+            armor_class = $6,
+            armor_description = $7,
+            hit_dice = $8,
+            speeds = $9,
+            saving_throws = $10,
+            skills = $11,
+            senses = $12,
+            strength = $13,
+            dexterity = $14,
+            constitution = $15,
+            intelligence = $16,
+            wisdom = $17,
+            charisma = $18,
+            challenge_rating = $19,
+            source = $20,
+            legendary_action_count = $21,
+            initiative_bonus = $22
+        WHERE id = $1;
+    "#;
+    let result = sqlx::query(update_creature_query)
+        .bind(creature.id)
+        .bind(&creature.name)
+        .bind(&creature.size)
+        .bind(&creature.kind)
+        .bind(&creature.alignment)
+        .bind(creature.armor_class)
+        .bind(&creature.armor_description)
+        .bind(&creature.hit_dice)
+        .bind(to_json("speeds", &creature.speeds)?)
+        .bind(to_json("saving throws", &creature.saving_throws)?)
+        .bind(to_json("skills", &creature.skills)?)
+        .bind(to_json("senses", &creature.senses)?)
+        .bind(strength)
+        .bind(dexterity)
+        .bind(constitution)
+        .bind(intelligence)
+        .bind(wisdom)
+        .bind(charisma)
+        .bind(&creature.challenge_rating)
+        .bind(&creature.source)
+        .bind(creature.legendary_action_count)
+        .bind(creature.initiative_bonus)
+        .execute(&mut *connection)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    let delete_relation_queries = [
+        "DELETE FROM creature_damage_defenses WHERE creature_id = $1;",
+        "DELETE FROM creature_languages WHERE creature_id = $1;",
+        "DELETE FROM creature_environments WHERE creature_id = $1;",
+        "DELETE FROM creature_features WHERE creature_id = $1;",
+    ];
+    for delete_relation_query in delete_relation_queries {
+        sqlx::query(delete_relation_query)
+            .bind(creature.id)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    insert_relations(connection, creature).await?;
+
+    Ok(true)
+}
+
+/// Insert the defenses, languages, environments and features of `creature` into their tables.
+async fn insert_relations(
+    connection: &mut SqliteConnection,
+    creature: &NewCreature,
+) -> Result<(), Error> {
+    for defense in &creature.damage_defenses {
+        let insert_defense_query = r#"
+            INSERT INTO creature_damage_defenses (
+                creature_id, damage_type, defense
+            ) VALUES ($1,$2,$3);
+        "#;
+        sqlx::query(insert_defense_query)
+            .bind(creature.id)
+            .bind(&defense.damage_type)
+            .bind(&defense.defense)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    for language in &creature.languages {
+        let insert_language_query = r#"
+            INSERT INTO creature_languages (
+                creature_id, language
+            ) VALUES ($1,$2);
+        "#;
+        sqlx::query(insert_language_query)
+            .bind(creature.id)
+            .bind(language)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    for environment in &creature.environments {
+        let insert_environment_query = r#"
+            INSERT INTO creature_environments (
+                creature_id, environment
+            ) VALUES ($1,$2);
+        "#;
+        sqlx::query(insert_environment_query)
+            .bind(creature.id)
+            .bind(environment)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    for (kind, features) in [
+        (TRAIT, &creature.traits),
+        (ACTION, &creature.actions),
+        (REACTION, &creature.reactions),
+        (LEGENDARY_ACTION, &creature.legendary_actions),
+        (LAIR_ACTION, &creature.lair_actions),
+    ] {
+        for (position, feature) in (0u32..).zip(features) {
+            let insert_feature_query = r#"
+                INSERT INTO creature_features (
+                    creature_id, kind, position, name, description
+                ) VALUES ($1,$2,$3,$4,$5);
+            "#;
+            sqlx::query(insert_feature_query)
+                .bind(creature.id)
+                .bind(kind)
+                .bind(position)
+                .bind(&feature.name)
+                .bind(&feature.description)
+                .execute(&mut *connection)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete the creature with `id`, returning whether it existed.
+async fn delete_creature(connection: &mut SqliteConnection, id: Uuid) -> Result<bool, Error> {
+    let query = r#"
+        DELETE FROM creatures
+        WHERE id = $1;
+    "#;
+
+    let result = sqlx::query(query)
+        .bind(id)
+        .execute(&mut *connection)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+fn to_json<T: Serialize + ?Sized>(field: &str, value: &T) -> Result<String, Error> {
+    serde_json::to_string(value).map_err(|err| {
+        tracing::error!("failed to JSON serialize creature {field}: {err}");
+        Error::Decode
+    })
+}
+
+fn from_json<T: DeserializeOwned>(field: &str, value: &str) -> Result<T, Error> {
+    serde_json::from_str(value).map_err(|err| {
+        tracing::error!("failed to JSON deserialize creature {field}: {err}");
+        Error::Decode
+    })
+}
@@ -0,0 +1,342 @@
+use crate::Error;
+use crate::clients::local::Local;
+use crate::clients::local::activities::record_activity;
+use crate::models::encounter::NewEncounter;
+use crate::records::activity::ActivityKind;
+use crate::records::activity::EntityKind;
+use crate::records::encounter::EncounterCreatureRecord;
+use crate::records::encounter::EncounterRecord;
+use crate::records::encounter::EncounterSummary;
+use crate::repositories::encounters::Encounters;
+use crate::repositories::encounters::EncountersRepository;
+
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+#[derive(sqlx::FromRow)]
+struct EncounterCreatureRow {
+    creature_id: Uuid,
+    name: String,
+    challenge_rating: String,
+    count: u32,
+}
+
+#[derive(sqlx::FromRow)]
+struct SummaryRow {
+    id: Uuid,
+    name: String,
+    party_size: u32,
+    creature_count: u32,
+}
+
+impl EncountersRepository for Local {
+    fn encounters(&self) -> &dyn Encounters {
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Encounters for Local {
+    async fn create(&self, new_encounter: NewEncounter) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for creating encounter");
+            return Err(Error::Connection);
+        };
+
+        insert_encounter(&mut transaction, &new_encounter).await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Created,
+            EntityKind::Encounter,
+            new_encounter.id,
+            new_encounter.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit encounter creation transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("created encounter '{}'", new_encounter.id);
+
+        Ok(())
+    }
+
+    async fn update(&self, encounter: NewEncounter) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for updating encounter");
+            return Err(Error::Connection);
+        };
+
+        if !update_encounter(&mut transaction, &encounter).await? {
+            tracing::error!(
+                "failed to update encounter '{}', as it does not exist",
+                encounter.id
+            );
+            return Err(Error::NotFound);
+        }
+
+        record_activity(
+            &mut transaction,
+            ActivityKind::Updated,
+            EntityKind::Encounter,
+            encounter.id,
+            encounter.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit encounter update transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("updated encounter '{}'", encounter.id);
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for deleting encounter");
+            return Err(Error::Connection);
+        };
+
+        let name_query = r#"
+            SELECT name
+            FROM encounters
+            WHERE id = $1;
+        "#;
+        let name: Option<String> = sqlx::query_scalar(name_query)
+            .bind(id)
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+        let Some(name) = name else {
+            return Err(Error::NotFound);
+        };
+
+        delete_encounter(&mut transaction, id).await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Deleted,
+            EntityKind::Encounter,
+            id,
+            name,
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit encounter deletion transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("deleted encounter '{id}'");
+
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<EncounterRecord, Error> {
+        let encounter_query = r#"
+            SELECT id, name
+            FROM encounters
+            WHERE id = $1;
+        "#;
+
+        let (id, name): (Uuid, String) = sqlx::query_as(encounter_query)
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        let party_query = r#"
+            SELECT level
+            FROM encounter_characters
+            WHERE encounter_id = $1
+            ORDER BY position;
+        "#;
+
+        let party: Vec<(u8,)> = sqlx::query_as(party_query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let creatures_query = r#"
+            SELECT e.creature_id, c.name, c.challenge_rating, e.count
+            FROM encounter_creatures e
+            JOIN creatures c ON c.id = e.creature_id
+            WHERE e.encounter_id = $1
+            ORDER BY c.name COLLATE NOCASE;
+        "#;
+
+        let creatures: Vec<EncounterCreatureRow> = sqlx::query_as(creatures_query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let creatures = creatures
+            .into_iter()
+            .map(|row| EncounterCreatureRecord {
+                creature_id: row.creature_id,
+                name: row.name,
+                challenge_rating: row.challenge_rating,
+                count: row.count,
+            })
+            .collect();
+
+        Ok(EncounterRecord {
+            id,
+            name,
+            party: party.into_iter().map(|(level,)| level).collect(),
+            creatures,
+        })
+    }
+
+    async fn list(&self) -> Result<Box<[EncounterSummary]>, Error> {
+        let query = r#"
+            SELECT e.id, e.name,
+                (SELECT COUNT(*) FROM encounter_characters p WHERE p.encounter_id = e.id)
+                    AS party_size,
+                (SELECT COALESCE(SUM(x.count), 0)
+                    FROM encounter_creatures x
+                    WHERE x.encounter_id = e.id) AS creature_count
+            FROM encounters e
+            ORDER BY e.name COLLATE NOCASE;
+        "#;
+
+        let rows: Vec<SummaryRow> = sqlx::query_as(query).fetch_all(&self.pool).await?;
+
+        tracing::debug!("fetched {} encounters", rows.len());
+
+        let summaries = rows
+            .into_iter()
+            .map(|row| EncounterSummary {
+                id: row.id,
+                name: row.name,
+                party_size: row.party_size,
+                creature_count: row.creature_count,
+            })
+            .collect();
+
+        Ok(summaries)
+    }
+
+    async fn count(&self) -> Result<u32, Error> {
+        let query = r#"
+            SELECT COUNT(*)
+            FROM encounters;
+        "#;
+
+        let count: u32 = sqlx::query_scalar(query).fetch_one(&self.pool).await?;
+
+        tracing::debug!("counted {count} encounters");
+
+        Ok(count)
+    }
+}
+
+async fn insert_encounter(
+    connection: &mut SqliteConnection,
+    encounter: &NewEncounter,
+) -> Result<(), Error> {
+    let insert_encounter_query = r#"
+        INSERT INTO encounters (
+            id, name
+        ) VALUES ($1,$2);
+    "#;
+    sqlx::query(insert_encounter_query)
+        .bind(encounter.id)
+        .bind(&encounter.name)
+        .execute(&mut *connection)
+        .await?;
+
+    insert_relations(connection, encounter).await
+}
+
+/// Update the encounter of `encounter` in place, returning whether it existed.
+///
+/// The party and creatures are replaced, as they have no identity of their own.
+async fn update_encounter(
+    connection: &mut SqliteConnection,
+    encounter: &NewEncounter,
+) -> Result<bool, Error> {
+    let update_encounter_query = r#"
+        UPDATE encounters
+        SET name = $2
+        WHERE id = $1;
+    "#;
+    let result = sqlx::query(update_encounter_query)
+        .bind(encounter.id)
+        .bind(&encounter.name)
+        .execute(&mut *connection)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    let delete_relation_queries = [
+        "DELETE FROM encounter_characters WHERE encounter_id = $1;",
+        "DELETE FROM encounter_creatures WHERE encounter_id = $1;",
+    ];
+    for delete_relation_query in delete_relation_queries {
+        sqlx::query(delete_relation_query)
+            .bind(encounter.id)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    insert_relations(connection, encounter).await?;
+
+    Ok(true)
+}
+
+/// Insert the party and creatures of `encounter` into their tables.
+async fn insert_relations(
+    connection: &mut SqliteConnection,
+    encounter: &NewEncounter,
+) -> Result<(), Error> {
+    for (position, level) in (0u32..).zip(&encounter.party) {
+        let insert_character_query = r#"
+            INSERT INTO encounter_characters (
+                encounter_id, position, level
+            ) VALUES ($1,$2,$3);
+        "#;
+        sqlx::query(insert_character_query)
+            .bind(encounter.id)
+            .bind(position)
+            .bind(level)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    for creature in &encounter.creatures {
+        let insert_creature_query = r#"
+            INSERT INTO encounter_creatures (
+                encounter_id, creature_id, count
+            ) VALUES ($1,$2,$3);
+        "#;
+        sqlx::query(insert_creature_query)
+            .bind(encounter.id)
+            .bind(creature.creature_id)
+            .bind(creature.count)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Delete the encounter with `id`, returning whether it existed.
+async fn delete_encounter(connection: &mut SqliteConnection, id: Uuid) -> Result<bool, Error> {
+    let query = r#"
+        DELETE FROM encounters
+        WHERE id = $1;
+    "#;
+
+    let result = sqlx::query(query)
+        .bind(id)
+        .execute(&mut *connection)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -1,4 +1,11 @@
+mod activities;
+mod bookmarks;
+mod combats;
+mod creatures;
+mod encounters;
 mod options;
+mod spell_drafts;
+mod spellbooks;
 mod spells;
 
 use crate::Error;
@@ -17,6 +24,9 @@ pub type Pool = SqlitePool;
 pub struct Local {
     pool: SqlitePool,
     images_dir: PathBuf,
+
+    /// The name recorded as the author of changes, which is the user running the app.
+    author: String,
 }
 
 impl Local {
@@ -31,7 +41,48 @@ impl Local {
         // TODO: Maybe move this to some async stuff, but it might not matter.
         std::fs::create_dir_all(&images_dir).expect("failed to create images directory");
 
-        Ok(Self { pool, images_dir })
+        let author = std::env::var("USER")
+            .or_else(|_| std::env::var("USERNAME"))
+            .unwrap_or_else(|_| String::from("Unknown"));
+
+        Ok(Self {
+            pool,
+            images_dir,
+            author,
+        })
+    }
+}
+
+#[cfg(test)]
+impl Local {
+    /// A client on a fresh in-memory database, for testing.
+    pub(crate) async fn in_memory() -> Self {
+        let options = SqliteConnectOptions::new()
+            .in_memory(true)
+            .foreign_keys(true);
+
+        // Every connection opens its own in-memory database, so the pool keeps a single one.
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await
+            .expect("failed to open in-memory database");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        let images_dir = std::env::temp_dir().join(format!("images-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&images_dir).expect("failed to create images directory");
+
+        Self {
+            pool,
+            images_dir,
+            author: String::from("Tester"),
+        }
     }
 }
 
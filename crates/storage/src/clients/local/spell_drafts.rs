@@ -0,0 +1,151 @@
+use crate::Error;
+use crate::clients::local::Local;
+use crate::models::draft::NewSpellDraft;
+use crate::records::ImageRecord;
+use crate::records::draft::SpellDraftRecord;
+use crate::repositories::spell_drafts::SpellDrafts;
+use crate::repositories::spell_drafts::SpellDraftsRepository;
+
+use uuid::Uuid;
+
+#[derive(sqlx::FromRow)]
+struct SpellDraftRow {
+    id: Uuid,
+    spell_id: Option<Uuid>,
+    updated_at: String,
+    data: String,
+}
+
+impl SpellDraftsRepository for Local {
+    fn spell_drafts(&self) -> &dyn SpellDrafts {
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl SpellDrafts for Local {
+    async fn save(&self, draft: NewSpellDraft) -> Result<(), Error> {
+        let Ok(data) = serde_json::to_string(&draft.data) else {
+            tracing::error!("failed to JSON serialize spell draft '{}'", draft.id);
+            return Err(Error::Decode);
+        };
+
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for saving spell draft");
+            return Err(Error::Connection);
+        };
+
+        let upsert_draft_query = r#"
+            INSERT INTO spell_drafts (
+                id, spell_id, data
+            ) VALUES ($1,$2,$3)
+            ON CONFLICT (id)
+            DO UPDATE SET
+                spell_id = excluded.spell_id,
+                updated_at = strftime('%Y-%m-%d %H:%M:%S', 'now'),
+                data = excluded.data;
+        "#;
+        sqlx::query(upsert_draft_query)
+            .bind(draft.id)
+            .bind(draft.spell_id)
+            .bind(data)
+            .execute(&mut *transaction)
+            .await?;
+
+        // The images are replaced as a whole when they changed, as they are few.
+        if let Some(images) = &draft.images {
+            let delete_images_query = r#"
+                DELETE FROM spell_draft_images
+                WHERE draft_id = $1;
+            "#;
+            sqlx::query(delete_images_query)
+                .bind(draft.id)
+                .execute(&mut *transaction)
+                .await?;
+
+            for image in images {
+                let insert_image_query = r#"
+                    INSERT INTO spell_draft_images (
+                        id, draft_id, bytes
+                    ) VALUES ($1,$2,$3);
+                "#;
+                sqlx::query(insert_image_query)
+                    .bind(image.id)
+                    .bind(draft.id)
+                    .bind(&*image.bytes)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+        }
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spell draft transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("saved spell draft '{}'", draft.id);
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let query = r#"
+            DELETE FROM spell_drafts
+            WHERE id = $1;
+        "#;
+
+        sqlx::query(query).bind(id).execute(&self.pool).await?;
+
+        tracing::debug!("deleted spell draft '{id}'");
+
+        Ok(())
+    }
+
+    async fn list(&self) -> Result<Box<[SpellDraftRecord]>, Error> {
+        let drafts_query = r#"
+            SELECT id, spell_id, updated_at, data
+            FROM spell_drafts
+            ORDER BY updated_at, rowid;
+        "#;
+
+        let rows: Vec<SpellDraftRow> = sqlx::query_as(drafts_query).fetch_all(&self.pool).await?;
+
+        let mut drafts = Vec::with_capacity(rows.len());
+        for row in rows {
+            let Ok(data) = serde_json::from_str(&row.data) else {
+                tracing::error!("failed to JSON deserialize spell draft '{}'", row.id);
+                return Err(Error::Decode);
+            };
+
+            let images_query = r#"
+                SELECT id, bytes
+                FROM spell_draft_images
+                WHERE draft_id = $1
+                ORDER BY rowid;
+            "#;
+
+            let images: Vec<(Uuid, Vec<u8>)> = sqlx::query_as(images_query)
+                .bind(row.id)
+                .fetch_all(&self.pool)
+                .await?;
+
+            drafts.push(SpellDraftRecord {
+                id: row.id,
+                spell_id: row.spell_id,
+                updated_at: row.updated_at,
+                data,
+                images: images
+                    .into_iter()
+                    .map(|(id, bytes)| ImageRecord {
+                        id,
+                        bytes: bytes.into_boxed_slice(),
+                    })
+                    .collect(),
+            });
+        }
+
+        tracing::debug!("fetched {} spell drafts", drafts.len());
+
+        Ok(drafts.into_boxed_slice())
+    }
+}
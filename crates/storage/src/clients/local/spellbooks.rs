@@ -0,0 +1,481 @@
+use crate::Error;
+use crate::clients::local::Local;
+use crate::clients::local::activities::record_activity;
+use crate::models::spellbook::NewSpellbook;
+use crate::models::spellbook::NewSpellbookTracker;
+use crate::records::activity::ActivityKind;
+use crate::records::activity::EntityKind;
+use crate::records::spellbook::SpellbookEntryRecord;
+use crate::records::spellbook::SpellbookRecord;
+use crate::records::spellbook::SpellbookSummary;
+use crate::records::spellbook::SpellbookTrackerRecord;
+use crate::repositories::spellbooks::Spellbooks;
+use crate::repositories::spellbooks::SpellbooksRepository;
+
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+#[derive(sqlx::FromRow)]
+struct SpellbookRow {
+    id: Uuid,
+    owner: String,
+    class: String,
+    level: u8,
+    ability: String,
+    ability_score: i32,
+}
+
+#[derive(sqlx::FromRow)]
+struct EntryRow {
+    spell_id: Uuid,
+    name: String,
+    school: String,
+    level: String,
+    concentration: bool,
+    prepared: bool,
+    always_prepared: bool,
+}
+
+#[derive(sqlx::FromRow)]
+struct SummaryRow {
+    id: Uuid,
+    owner: String,
+    class: String,
+    level: u8,
+    spell_count: u32,
+}
+
+impl SpellbooksRepository for Local {
+    fn spellbooks(&self) -> &dyn Spellbooks {
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Spellbooks for Local {
+    async fn create(&self, new_spellbook: NewSpellbook) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for creating spellbook");
+            return Err(Error::Connection);
+        };
+
+        insert_spellbook(&mut transaction, &new_spellbook).await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Created,
+            EntityKind::Spellbook,
+            new_spellbook.id,
+            new_spellbook.owner.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spellbook creation transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("created spellbook '{}'", new_spellbook.id);
+
+        Ok(())
+    }
+
+    async fn update(&self, spellbook: NewSpellbook) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for updating spellbook");
+            return Err(Error::Connection);
+        };
+
+        if !update_spellbook(&mut transaction, &spellbook).await? {
+            tracing::error!(
+                "failed to update spellbook '{}', as it does not exist",
+                spellbook.id
+            );
+            return Err(Error::NotFound);
+        }
+
+        record_activity(
+            &mut transaction,
+            ActivityKind::Updated,
+            EntityKind::Spellbook,
+            spellbook.id,
+            spellbook.owner.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spellbook update transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("updated spellbook '{}'", spellbook.id);
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for deleting spellbook");
+            return Err(Error::Connection);
+        };
+
+        // Spellbooks are known by the name of their owner.
+        let owner_query = r#"
+            SELECT owner
+            FROM spellbooks
+            WHERE id = $1;
+        "#;
+        let owner: Option<String> = sqlx::query_scalar(owner_query)
+            .bind(id)
+            .fetch_optional(&mut *transaction)
+            .await?;
+
+        let Some(owner) = owner else {
+            return Err(Error::NotFound);
+        };
+
+        delete_spellbook(&mut transaction, id).await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Deleted,
+            EntityKind::Spellbook,
+            id,
+            owner,
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spellbook deletion transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        tracing::debug!("deleted spellbook '{id}'");
+
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<SpellbookRecord, Error> {
+        let spellbook_query = r#"
+            SELECT id, owner, class, level, ability, ability_score
+            FROM spellbooks
+            WHERE id = $1;
+        "#;
+
+        let row: SpellbookRow = sqlx::query_as(spellbook_query)
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        let entries_query = r#"
+            SELECT e.spell_id, s.name, s.school, s.level, s.concentration,
+                e.prepared, e.always_prepared
+            FROM spellbook_spells e
+            JOIN spells s ON s.id = e.spell_id
+            WHERE e.spellbook_id = $1
+            ORDER BY s.name COLLATE NOCASE;
+        "#;
+
+        let entries: Vec<EntryRow> = sqlx::query_as(entries_query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let spells = entries
+            .into_iter()
+            .map(|entry| SpellbookEntryRecord {
+                spell_id: entry.spell_id,
+                name: entry.name,
+                school: entry.school,
+                level: entry.level,
+                concentration: entry.concentration,
+                prepared: entry.prepared,
+                always_prepared: entry.always_prepared,
+            })
+            .collect();
+
+        Ok(SpellbookRecord {
+            id: row.id,
+            owner: row.owner,
+            class: row.class,
+            level: row.level,
+            ability: row.ability,
+            ability_score: row.ability_score,
+            spells,
+        })
+    }
+
+    async fn list(&self) -> Result<Box<[SpellbookSummary]>, Error> {
+        let query = r#"
+            SELECT b.id, b.owner, b.class, b.level, COUNT(e.spell_id) AS spell_count
+            FROM spellbooks b
+            LEFT JOIN spellbook_spells e ON e.spellbook_id = b.id
+            GROUP BY b.id
+            ORDER BY b.owner COLLATE NOCASE;
+        "#;
+
+        let rows: Vec<SummaryRow> = sqlx::query_as(query).fetch_all(&self.pool).await?;
+
+        tracing::debug!("fetched {} spellbooks", rows.len());
+
+        let summaries = rows
+            .into_iter()
+            .map(|row| SpellbookSummary {
+                id: row.id,
+                owner: row.owner,
+                class: row.class,
+                level: row.level,
+                spell_count: row.spell_count,
+            })
+            .collect();
+
+        Ok(summaries)
+    }
+
+    async fn count(&self) -> Result<u32, Error> {
+        let query = r#"
+            SELECT COUNT(*)
+            FROM spellbooks;
+        "#;
+
+        let count: u32 = sqlx::query_scalar(query).fetch_one(&self.pool).await?;
+
+        tracing::debug!("counted {count} spellbooks");
+
+        Ok(count)
+    }
+
+    async fn tracker(&self, spellbook_id: Uuid) -> Result<SpellbookTrackerRecord, Error> {
+        let tracker_query = r#"
+            SELECT pact_slots_expended, concentration_spell_id
+            FROM spellbook_trackers
+            WHERE spellbook_id = $1;
+        "#;
+
+        let row: Option<(u32, Option<Uuid>)> = sqlx::query_as(tracker_query)
+            .bind(spellbook_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        // A spellbook that was never tracked has spent nothing.
+        let Some((pact_slots_expended, concentration_spell_id)) = row else {
+            return Ok(SpellbookTrackerRecord {
+                spellbook_id,
+                expended_slots: [0; 9],
+                pact_slots_expended: 0,
+                concentration_spell_id: None,
+            });
+        };
+
+        let slots_query = r#"
+            SELECT level, expended
+            FROM spellbook_expended_slots
+            WHERE spellbook_id = $1;
+        "#;
+
+        let slots: Vec<(u8, u32)> = sqlx::query_as(slots_query)
+            .bind(spellbook_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut expended_slots = [0; 9];
+        for (level, expended) in slots {
+            let index = usize::from(level).checked_sub(1);
+            let Some(slot) = index.and_then(|index| expended_slots.get_mut(index)) else {
+                tracing::error!("invalid slot level '{level}' of spellbook '{spellbook_id}'");
+                return Err(Error::Decode);
+            };
+
+            *slot = expended;
+        }
+
+        Ok(SpellbookTrackerRecord {
+            spellbook_id,
+            expended_slots,
+            pact_slots_expended,
+            concentration_spell_id,
+        })
+    }
+
+    async fn save_tracker(&self, tracker: NewSpellbookTracker) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for saving spellbook tracker");
+            return Err(Error::Connection);
+        };
+
+        let upsert_tracker_query = r#"
+            INSERT INTO spellbook_trackers (
+                spellbook_id, pact_slots_expended, concentration_spell_id
+            ) VALUES ($1,$2,$3)
+            ON CONFLICT (spellbook_id)
+            DO UPDATE SET
+                pact_slots_expended = excluded.pact_slots_expended,
+                concentration_spell_id = excluded.concentration_spell_id;
+        "#;
+        sqlx::query(upsert_tracker_query)
+            .bind(tracker.spellbook_id)
+            .bind(tracker.pact_slots_expended)
+            .bind(tracker.concentration_spell_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let delete_slots_query = r#"
+            DELETE FROM spellbook_expended_slots
+            WHERE spellbook_id = $1;
+        "#;
+        sqlx::query(delete_slots_query)
+            .bind(tracker.spellbook_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        // Only expended slots are stored, so a rested character has no rows.
+        for (level, expended) in (1u8..).zip(tracker.expended_slots) {
+            if expended == 0 {
+                continue;
+            }
+
+            let insert_slot_query = r#"
+                INSERT INTO spellbook_expended_slots (
+                    spellbook_id, level, expended
+                ) VALUES ($1,$2,$3);
+            "#;
+            sqlx::query(insert_slot_query)
+                .bind(tracker.spellbook_id)
+                .bind(level)
+                .bind(expended)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spellbook tracker transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        Ok(())
+    }
+}
+
+async fn insert_spellbook(
+    connection: &mut SqliteConnection,
+    spellbook: &NewSpellbook,
+) -> Result<(), Error> {
+    let insert_spellbook_query = r#"
+        INSERT INTO spellbooks (
+            id, owner, class, level, ability, ability_score
+        ) VALUES ($1,$2,$3,$4,$5,$6);
+    "#;
+    sqlx::query(insert_spellbook_query)
+        .bind(spellbook.id)
+        .bind(&spellbook.owner)
+        .bind(spellbook.class.as_ref())
+        .bind(spellbook.level)
+        .bind(spellbook.ability.to_string())
+        .bind(spellbook.ability_score)
+        .execute(&mut *connection)
+        .await?;
+
+    save_entries(connection, spellbook).await
+}
+
+/// Update the spellbook of `spellbook` in place, returning whether it existed.
+async fn update_spellbook(
+    connection: &mut SqliteConnection,
+    spellbook: &NewSpellbook,
+) -> Result<bool, Error> {
+    let update_spellbook_query = r#"
+        UPDATE spellbooks
+        SET
+            owner = $2,
+            class = $3,
+            level = $4,
+            ability = $5,
+            ability_score = $6
+        WHERE id = $1;
+    "#;
+    let result = sqlx::query(update_spellbook_query)
+        .bind(spellbook.id)
+        .bind(&spellbook.owner)
+        .bind(spellbook.class.as_ref())
+        .bind(spellbook.level)
+        .bind(spellbook.ability.to_string())
+        .bind(spellbook.ability_score)
+        .execute(&mut *connection)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    save_entries(connection, spellbook).await?;
+
+    Ok(true)
+}
+
+/// Make the spells of the stored spellbook match the spells of `spellbook`.
+async fn save_entries(
+    connection: &mut SqliteConnection,
+    spellbook: &NewSpellbook,
+) -> Result<(), Error> {
+    let stored_query = r#"
+        SELECT spell_id
+        FROM spellbook_spells
+        WHERE spellbook_id = $1;
+    "#;
+    let stored: Vec<Uuid> = sqlx::query_scalar(stored_query)
+        .bind(spellbook.id)
+        .fetch_all(&mut *connection)
+        .await?;
+
+    let removed = stored.into_iter().filter(|spell_id| {
+        !spellbook
+            .spells
+            .iter()
+            .any(|entry| entry.spell_id == *spell_id)
+    });
+    for spell_id in removed {
+        let delete_entry_query = r#"
+            DELETE FROM spellbook_spells
+            WHERE spellbook_id = $1 AND spell_id = $2;
+        "#;
+        sqlx::query(delete_entry_query)
+            .bind(spellbook.id)
+            .bind(spell_id)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    for entry in &spellbook.spells {
+        let upsert_entry_query = r#"
+            INSERT INTO spellbook_spells (
+                spellbook_id, spell_id, prepared, always_prepared
+            ) VALUES ($1,$2,$3,$4)
+            ON CONFLICT (spellbook_id, spell_id)
+            DO UPDATE SET
+                prepared = excluded.prepared,
+                always_prepared = excluded.always_prepared;
+        "#;
+        sqlx::query(upsert_entry_query)
+            .bind(spellbook.id)
+            .bind(entry.spell_id)
+            .bind(entry.prepared)
+            .bind(entry.always_prepared)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Delete the spellbook with `id`, returning whether it existed.
+async fn delete_spellbook(connection: &mut SqliteConnection, id: Uuid) -> Result<bool, Error> {
+    let query = r#"
+        DELETE FROM spellbooks
+        WHERE id = $1;
+    "#;
+
+    let result = sqlx::query(query)
+        .bind(id)
+        .execute(&mut *connection)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -1,16 +1,405 @@
 use crate::Error;
 use crate::clients::local::Local;
+use crate::clients::local::activities::record_activity;
+use crate::models::NewImage;
 use crate::models::spell::NewSpell;
+use crate::records::ImageRecord;
+use crate::records::activity::ActivityKind;
+use crate::records::activity::EntityKind;
+use crate::records::spell::RevisionKind;
+use crate::records::spell::SpellRecord;
+use crate::records::spell::SpellRevision;
+use crate::records::spell::SpellSummary;
 use crate::repositories::spells::Spells;
 use crate::repositories::spells::SpellsRepository;
 
+use types::Class;
+
+use sqlx::SqliteConnection;
 use uuid::Uuid;
 
+#[derive(sqlx::FromRow)]
+struct SpellRow {
+    id: Uuid,
+    name: String,
+    school: String,
+    level: String,
+    source: Option<String>,
+    casting_time: String,
+    ritual: bool,
+    concentration: bool,
+    verbal: bool,
+    somatic: bool,
+    material: bool,
+    materials: Option<String>,
+    duration: String,
+    range: String,
+    area: String,
+    shape: String,
+    description: String,
+    at_higher_levels: Option<String>,
+    flavor_text: Option<String>,
+    attribution: Option<String>,
+}
+
+/// The columns of a spell summary: id, name, school, level, range and area.
+pub(super) type SummaryRow = (Uuid, String, String, String, String, String);
+
+impl From<SummaryRow> for SpellSummary {
+    fn from((id, name, school, level, range, area): SummaryRow) -> Self {
+        Self {
+            id,
+            name,
+            school,
+            level,
+            range,
+            area,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct RevisionRow {
+    id: Uuid,
+    spell_id: Uuid,
+    kind: RevisionKind,
+    author: String,
+    created_at: String,
+    snapshot: String,
+}
+
+impl TryFrom<RevisionRow> for SpellRevision {
+    type Error = Error;
+
+    fn try_from(row: RevisionRow) -> Result<Self, Self::Error> {
+        let Ok(snapshot) = serde_json::from_str(&row.snapshot) else {
+            tracing::error!(
+                "failed to JSON deserialize snapshot of revision '{}'",
+                row.id
+            );
+            return Err(Error::Decode);
+        };
+
+        Ok(Self {
+            id: row.id,
+            spell_id: row.spell_id,
+            kind: row.kind,
+            author: row.author,
+            created_at: row.created_at,
+            snapshot,
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl Spells for Local {
     async fn create(&self, new_spell: NewSpell) -> Result<(), Error> {
-        for image in &new_spell.images {
-            let path = self.images_dir.clone().join(image.id.to_string());
+        self.write_images(&new_spell.images).await?;
+
+        let record = SpellRecord::from(&new_spell);
+
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for creating spell");
+            return Err(Error::Connection);
+        };
+
+        insert_spell(&mut transaction, &record).await?;
+        insert_revision(
+            &mut transaction,
+            RevisionKind::Created,
+            &self.author,
+            &record,
+        )
+        .await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Created,
+            EntityKind::Spell,
+            record.id,
+            record.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spell creation transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        Ok(())
+    }
+
+    async fn update(&self, spell: NewSpell) -> Result<(), Error> {
+        self.write_images(&spell.images).await?;
+
+        let record = SpellRecord::from(&spell);
+
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for updating spell");
+            return Err(Error::Connection);
+        };
+
+        if !update_spell(&mut transaction, &record).await? {
+            tracing::error!(
+                "failed to update spell '{}', as it does not exist",
+                record.id
+            );
+            return Err(Error::NotFound);
+        }
+
+        insert_revision(
+            &mut transaction,
+            RevisionKind::Updated,
+            &self.author,
+            &record,
+        )
+        .await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Updated,
+            EntityKind::Spell,
+            record.id,
+            record.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spell update transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for deleting spell");
+            return Err(Error::Connection);
+        };
+
+        let record = fetch_spell(&mut transaction, id).await?;
+
+        delete_spell(&mut transaction, id).await?;
+        insert_revision(
+            &mut transaction,
+            RevisionKind::Deleted,
+            &self.author,
+            &record,
+        )
+        .await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Deleted,
+            EntityKind::Spell,
+            record.id,
+            record.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spell deletion transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<SpellRecord, Error> {
+        let Ok(mut connection) = self.pool.acquire().await else {
+            tracing::error!("failed to acquire connection for fetching spell");
+            return Err(Error::Connection);
+        };
+
+        fetch_spell(&mut connection, id).await
+    }
+
+    async fn list(&self) -> Result<Box<[SpellSummary]>, Error> {
+        let query = r#"
+            SELECT id, name, school, level, range, area
+            FROM spells
+            ORDER BY name COLLATE NOCASE;
+        "#;
+
+        let rows: Vec<SummaryRow> = sqlx::query_as(query).fetch_all(&self.pool).await?;
+
+        tracing::debug!("fetched {} spells", rows.len());
+
+        Ok(rows.into_iter().map(SpellSummary::from).collect())
+    }
+
+    async fn count(&self) -> Result<u32, Error> {
+        let query = r#"
+            SELECT COUNT(*)
+            FROM spells;
+        "#;
+
+        let count: u32 = sqlx::query_scalar(query).fetch_one(&self.pool).await?;
+
+        tracing::debug!("counted {count} spells");
+
+        Ok(count)
+    }
+
+    async fn list_for_class(&self, class: Class) -> Result<Box<[SpellSummary]>, Error> {
+        let query = r#"
+            SELECT s.id, s.name, s.school, s.level, s.range, s.area
+            FROM spells s
+            JOIN spell_classes c ON c.spell_id = s.id
+            WHERE c.class = $1
+            ORDER BY s.name COLLATE NOCASE;
+        "#;
+
+        let rows: Vec<SummaryRow> = sqlx::query_as(query)
+            .bind(class.as_ref())
+            .fetch_all(&self.pool)
+            .await?;
+
+        tracing::debug!("fetched {} spells of class '{class}'", rows.len());
+
+        Ok(rows.into_iter().map(SpellSummary::from).collect())
+    }
+
+    async fn images(&self, id: Uuid) -> Result<Box<[ImageRecord]>, Error> {
+        let query = r#"
+            SELECT id
+            FROM spell_images
+            WHERE spell_id = $1
+            ORDER BY rowid;
+        "#;
+
+        let image_ids: Vec<Uuid> = sqlx::query_scalar(query)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut images = Vec::with_capacity(image_ids.len());
+        for image_id in image_ids {
+            let path = self.images_dir.join(image_id.to_string());
+
+            match tokio::fs::read(path).await {
+                Ok(bytes) => images.push(ImageRecord {
+                    id: image_id,
+                    bytes: bytes.into_boxed_slice(),
+                }),
+                Err(err) => tracing::warn!("failed to read image file '{image_id}': {err}"),
+            }
+        }
+
+        Ok(images.into_boxed_slice())
+    }
+
+    async fn revisions(&self, id: Uuid) -> Result<Box<[SpellRevision]>, Error> {
+        // The rowid breaks ties between revisions made within the same second.
+        let query = r#"
+            SELECT id, spell_id, kind, author, created_at, snapshot
+            FROM spell_revisions
+            WHERE spell_id = $1
+            ORDER BY created_at DESC, rowid DESC;
+        "#;
+
+        let rows: Vec<RevisionRow> = sqlx::query_as(query).bind(id).fetch_all(&self.pool).await?;
+
+        tracing::debug!("fetched {} revisions of spell '{id}'", rows.len());
+
+        rows.into_iter().map(SpellRevision::try_from).collect()
+    }
+
+    async fn deleted(&self) -> Result<Box<[SpellRevision]>, Error> {
+        // A spell is deleted if its latest revision is a deletion, which is the revision shown.
+        let query = r#"
+            SELECT r.id, r.spell_id, r.kind, r.author, r.created_at, r.snapshot
+            FROM spell_revisions r
+            WHERE r.kind = 'deleted'
+            AND r.rowid = (
+                SELECT MAX(rowid)
+                FROM spell_revisions
+                WHERE spell_id = r.spell_id
+            )
+            AND NOT EXISTS (
+                SELECT 1
+                FROM spells
+                WHERE id = r.spell_id
+            )
+            ORDER BY r.created_at DESC, r.rowid DESC;
+        "#;
+
+        let rows: Vec<RevisionRow> = sqlx::query_as(query).fetch_all(&self.pool).await?;
+
+        tracing::debug!("fetched {} deleted spells", rows.len());
+
+        rows.into_iter().map(SpellRevision::try_from).collect()
+    }
+
+    async fn restore(&self, revision_id: Uuid) -> Result<(), Error> {
+        let Ok(mut transaction) = self.pool.begin().await else {
+            tracing::error!("failed to begin transaction for restoring spell");
+            return Err(Error::Connection);
+        };
+
+        let query = r#"
+            SELECT id, spell_id, kind, author, created_at, snapshot
+            FROM spell_revisions
+            WHERE id = $1;
+        "#;
+
+        let row: RevisionRow = sqlx::query_as(query)
+            .bind(revision_id)
+            .fetch_one(&mut *transaction)
+            .await?;
+        let mut record = SpellRevision::try_from(row)?.snapshot;
+
+        // Image files are never rewritten, so only the images still on disk can be restored.
+        let mut images = Vec::with_capacity(record.images.len());
+        for image_id in &record.images {
+            let path = self.images_dir.join(image_id.to_string());
+
+            if tokio::fs::try_exists(path).await.unwrap_or(false) {
+                images.push(*image_id);
+            } else {
+                tracing::warn!("image '{image_id}' no longer exists and cannot be restored");
+            }
+        }
+        record.images = images.into_boxed_slice();
+
+        // A deleted spell is brought back under its original id.
+        if !update_spell(&mut transaction, &record).await? {
+            insert_spell(&mut transaction, &record).await?;
+        }
+        insert_revision(
+            &mut transaction,
+            RevisionKind::Restored,
+            &self.author,
+            &record,
+        )
+        .await?;
+        record_activity(
+            &mut transaction,
+            ActivityKind::Restored,
+            EntityKind::Spell,
+            record.id,
+            record.name.clone(),
+        )
+        .await?;
+
+        if let Err(err) = transaction.commit().await {
+            tracing::error!("failed to commit spell restoration transaction: {err}");
+            return Err(Error::Connection);
+        }
+
+        Ok(())
+    }
+}
+
+impl SpellsRepository for Local {
+    fn spells(&self) -> &dyn Spells {
+        self
+    }
+}
+
+impl Local {
+    /// Write the image files of `images` to the images directory.
+    async fn write_images(&self, images: &[NewImage]) -> Result<(), Error> {
+        for image in images {
+            let path = self.images_dir.join(image.id.to_string());
 
             // Periodic maintenance will run and clean up any images left-over by failing in this step.
             //
@@ -22,182 +411,581 @@ impl Spells for Local {
             }
         }
 
-        let Ok(shape) = serde_json::to_string(&new_spell.shape) else {
-            tracing::error!("failed to JSON serialize spell shape");
-            return Err(Error::Decode);
-        };
+        Ok(())
+    }
+}
 
-        let Ok(materials) = serde_json::to_string(&new_spell.materials) else {
-            tracing::error!("failed to JSON serialize spell materials");
-            return Err(Error::Decode);
-        };
+/// The shape and materials of `record`, serialized to their JSON columns.
+fn json_columns(record: &SpellRecord) -> Result<(String, String), Error> {
+    let Ok(shape) = serde_json::to_string(&record.shape) else {
+        tracing::error!("failed to JSON serialize spell shape");
+        return Err(Error::Decode);
+    };
 
-        let Ok(mut transaction) = self.pool.begin().await else {
-            tracing::error!("failed to begin transaction for creating spell");
-            return Err(Error::Connection);
-        };
+    let Ok(materials) = serde_json::to_string(&record.materials) else {
+        tracing::error!("failed to JSON serialize spell materials");
+        return Err(Error::Decode);
+    };
 
-        let insert_spell_query = r#"
-            INSERT INTO spells
-            (
-                id,
-                name,
-                school,
-                level,
-                source,
-                casting_time,
-                ritual,
-                concentration,
-                verbal,
-                somatic,
-                material,
-                materials,
-                duration,
-                range,
-                area,
-                shape,
-                description,
-                at_higher_levels,
-                flavor_text,
-                attribution
-            )
-            VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20);
+    Ok((shape, materials))
+}
+
+/// Insert `record` into the spells table and its related tables.
+async fn insert_spell(
+    connection: &mut SqliteConnection,
+    record: &SpellRecord,
+) -> Result<(), Error> {
+    let (shape, materials) = json_columns(record)?;
+
+    let insert_spell_query = r#"
+        INSERT INTO spells
+        (
+            id,
+            name,
+            school,
+            level,
+            source,
+            casting_time,
+            ritual,
+            concentration,
+            verbal,
+            somatic,
+            material,
+            materials,
+            duration,
+            range,
+            area,
+            shape,
+            description,
+            at_higher_levels,
+            flavor_text,
+            attribution
+        )
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20);
+    "#;
+    let insert_spell_result = sqlx::query(insert_spell_query)
+        .bind(record.id)
+        .bind(&record.name)
+        .bind(&record.school)
+        .bind(&record.level)
+        .bind(&record.source)
+        .bind(&record.casting_time)
+        .bind(record.ritual)
+        .bind(record.concentration)
+        .bind(record.verbal)
+        .bind(record.somatic)
+        .bind(record.material)
+        .bind(materials)
+        .bind(&record.duration)
+        .bind(&record.range)
+        .bind(&record.area)
+        .bind(shape)
+        .bind(&record.description)
+        .bind(&record.at_higher_levels)
+        .bind(&record.flavor_text)
+        .bind(&record.attribution)
+        .execute(&mut *connection)
+        .await;
+
+    if let Err(err) = insert_spell_result {
+        tracing::error!("failed to insert spell in spells table: {err}");
+        return Err(Error::Query);
+    }
+
+    insert_relations(connection, record).await
+}
+
+/// Update the spell of `record` in place, returning whether it existed.
+///
+/// The rows of the related tables are replaced, as they have no identity of their own.
+async fn update_spell(
+    connection: &mut SqliteConnection,
+    record: &SpellRecord,
+) -> Result<bool, Error> {
+    let (shape, materials) = json_columns(record)?;
+
+    let update_spell_query = r#"
+        UPDATE spells
+        SET
+            name = $2,
+            school = $3,
+            level = $4,
+            source = $5,
+            casting_time = $6,
+            ritual = $7,
+            concentration = $8,
+            verbal = $9,
+            somatic = $10,
+            material = $11,
+            materials = $12,
+            duration = $13,
+            range = $14,
+            area = $15,
+            shape = $16,
+            description = $17,
+            at_higher_levels = $18,
+            flavor_text = $19,
+            attribution = $20
+        WHERE id = $1;
+    "#;
+    let update_spell_result = sqlx::query(update_spell_query)
+        .bind(record.id)
+        .bind(&record.name)
+        .bind(&record.school)
+        .bind(&record.level)
+        .bind(&record.source)
+        .bind(&record.casting_time)
+        .bind(record.ritual)
+        .bind(record.concentration)
+        .bind(record.verbal)
+        .bind(record.somatic)
+        .bind(record.material)
+        .bind(materials)
+        .bind(&record.duration)
+        .bind(&record.range)
+        .bind(&record.area)
+        .bind(shape)
+        .bind(&record.description)
+        .bind(&record.at_higher_levels)
+        .bind(&record.flavor_text)
+        .bind(&record.attribution)
+        .execute(&mut *connection)
+        .await;
+
+    match update_spell_result {
+        Ok(result) if result.rows_affected() == 0 => return Ok(false),
+        Ok(_) => {}
+        Err(err) => {
+            tracing::error!("failed to update spell in spells table: {err}");
+            return Err(Error::Query);
+        }
+    }
+
+    let delete_relation_queries = [
+        "DELETE FROM spell_aliases WHERE spell_id = $1;",
+        "DELETE FROM spell_classes WHERE spell_id = $1;",
+        "DELETE FROM spell_tags WHERE spell_id = $1;",
+        "DELETE FROM spell_images WHERE spell_id = $1;",
+    ];
+    for delete_relation_query in delete_relation_queries {
+        let delete_relation_result = sqlx::query(delete_relation_query)
+            .bind(record.id)
+            .execute(&mut *connection)
+            .await;
+
+        if let Err(err) = delete_relation_result {
+            tracing::error!("failed to delete related rows of spell: {err}");
+            return Err(Error::Query);
+        }
+    }
+
+    insert_relations(connection, record).await?;
+
+    Ok(true)
+}
+
+/// Insert the aliases, classes, tags and images of `record` into their tables.
+async fn insert_relations(
+    connection: &mut SqliteConnection,
+    record: &SpellRecord,
+) -> Result<(), Error> {
+    for alias in &record.aliases {
+        let insert_alias_query = r#"
+            INSERT INTO spell_aliases (
+                spell_id, alias
+            ) VALUES ($1,$2);
         "#;
-        let insert_spell_result = sqlx::query(insert_spell_query)
-            .bind(new_spell.id)
-            .bind(new_spell.name)
-            .bind(new_spell.school)
-            .bind(new_spell.level)
-            .bind(new_spell.source)
-            .bind(new_spell.casting_time)
-            .bind(new_spell.ritual)
-            .bind(new_spell.concentration)
-            .bind(new_spell.verbal)
-            .bind(new_spell.somatic)
-            .bind(new_spell.material)
-            .bind(materials)
-            .bind(new_spell.duration)
-            .bind(new_spell.range)
-            .bind(new_spell.area)
-            .bind(shape)
-            .bind(new_spell.description)
-            .bind(new_spell.at_higher_levels)
-            .bind(new_spell.flavor_text)
-            .bind(new_spell.attribution)
-            .execute(&mut *transaction)
+        let insert_alias_result = sqlx::query(insert_alias_query)
+            .bind(record.id)
+            .bind(alias)
+            .execute(&mut *connection)
             .await;
 
-        if let Err(err) = insert_spell_result {
-            tracing::error!("failed to insert spell in spells table: {err}");
+        if let Err(err) = insert_alias_result {
+            tracing::error!("failed to insert alias in spell_aliases table: {err}");
             return Err(Error::Query);
         }
+    }
 
-        for alias in new_spell.aliases {
-            let insert_alias_query = r#"
-                INSERT INTO spell_aliases (
-                    spell_id, alias
-                ) VALUES ($1,$2);
-            "#;
-            let insert_alias_result = sqlx::query(insert_alias_query)
-                .bind(new_spell.id)
-                .bind(alias)
-                .execute(&mut *transaction)
-                .await;
-
-            if let Err(err) = insert_alias_result {
-                tracing::error!("failed to insert alias in spell_aliases table: {err}");
-                return Err(Error::Query);
-            }
+    for class in &record.classes {
+        let insert_class_query = r#"
+            INSERT INTO spell_classes (
+                spell_id, class
+            ) VALUES ($1,$2);
+        "#;
+        let insert_class_result = sqlx::query(insert_class_query)
+            .bind(record.id)
+            .bind(class)
+            .execute(&mut *connection)
+            .await;
+
+        if let Err(err) = insert_class_result {
+            tracing::error!("failed to insert class in spell_classes table: {err}");
+            return Err(Error::Query);
         }
+    }
+
+    for tag in &record.tags {
+        let initial_tag_id = Uuid::new_v4();
 
-        for class in new_spell.classes {
-            let insert_class_query = r#"
-                INSERT INTO spell_classes (
-                    spell_id, class
-                ) VALUES ($1,$2);
-            "#;
-            let insert_class_result = sqlx::query(insert_class_query)
-                .bind(new_spell.id)
-                .bind(class.to_string())
-                .execute(&mut *transaction)
-                .await;
-
-            if let Err(err) = insert_class_result {
-                tracing::error!("failed to insert class in spell_classes table: {err}");
+        // This query contains a no-op update to get Sqlite to return the id on a conflict.
+        let insert_tag_query = r#"
+            INSERT INTO tags (
+                id, value
+            ) VALUES ($1,$2)
+            ON CONFLICT (value)
+            DO UPDATE SET value = value
+            RETURNING id;
+        "#;
+        let insert_tag_result = sqlx::query_scalar(insert_tag_query)
+            .bind(initial_tag_id)
+            .bind(tag)
+            .fetch_one(&mut *connection)
+            .await;
+        let actual_tag_id: Uuid = match insert_tag_result {
+            Ok(actual_tag_id) => actual_tag_id,
+            Err(err) => {
+                tracing::error!("failed to insert tag in tags table: {err}");
                 return Err(Error::Query);
             }
+        };
+
+        let link_tag_query = r#"
+            INSERT INTO spell_tags (
+                spell_id, tag_id
+            ) VALUES ($1,$2);
+        "#;
+        let link_tag_result = sqlx::query(link_tag_query)
+            .bind(record.id)
+            .bind(actual_tag_id)
+            .execute(&mut *connection)
+            .await;
+        if let Err(err) = link_tag_result {
+            tracing::error!("failed to link tag in spell_tags table: {err}");
+            return Err(Error::Query);
         }
+    }
 
-        for tag in new_spell.tags {
-            let initial_tag_id = Uuid::new_v4();
-
-            // This query contains a no-op update to get Sqlite to return the id on a conflict.
-            let insert_tag_query = r#"
-                INSERT INTO tags (
-                    id, value
-                ) VALUES ($1,$2)
-                ON CONFLICT (value)
-                DO UPDATE SET value = value
-                RETURNING id;
-            "#;
-            let insert_tag_result = sqlx::query_scalar(insert_tag_query)
-                .bind(initial_tag_id)
-                .bind(tag)
-                .fetch_one(&mut *transaction)
-                .await;
-            let actual_tag_id: Uuid = match insert_tag_result {
-                Ok(actual_tag_id) => actual_tag_id,
-                Err(err) => {
-                    tracing::error!("failed to insert tag in tags table: {err}");
-                    return Err(Error::Query);
-                }
-            };
-
-            let link_tag_query = r#"
-                INSERT INTO spell_tags (
-                    spell_id, tag_id
-                ) VALUES ($1,$2);
-            "#;
-            let link_tag_result = sqlx::query(link_tag_query)
-                .bind(new_spell.id)
-                .bind(actual_tag_id)
-                .execute(&mut *transaction)
-                .await;
-            if let Err(err) = link_tag_result {
-                tracing::error!("failed to link tag in spell_tags table: {err}");
-                return Err(Error::Query);
-            }
+    for image_id in &record.images {
+        let insert_image_query = r#"
+            INSERT INTO spell_images (
+                id, spell_id
+            ) VALUES ($1,$2);
+        "#;
+        let insert_image_result = sqlx::query(insert_image_query)
+            .bind(image_id)
+            .bind(record.id)
+            .execute(&mut *connection)
+            .await;
+
+        if let Err(err) = insert_image_result {
+            tracing::error!("failed to insert image in spell_images table: {err}");
+            return Err(Error::Query);
         }
+    }
 
-        for image in new_spell.images {
-            let insert_image_query = r#"
-                INSERT INTO spell_images (
-                    id, spell_id
-                ) VALUES ($1,$2);
-            "#;
-            let insert_image_result = sqlx::query(insert_image_query)
-                .bind(image.id)
-                .bind(new_spell.id)
-                .execute(&mut *transaction)
-                .await;
-
-            if let Err(err) = insert_image_result {
-                tracing::error!("failed to insert image in spell_images table: {err}");
-                return Err(Error::Query);
-            }
+    Ok(())
+}
+
+/// Delete the spell with `id`, returning whether it existed.
+async fn delete_spell(connection: &mut SqliteConnection, id: Uuid) -> Result<bool, Error> {
+    let delete_spell_query = r#"
+        DELETE FROM spells
+        WHERE id = $1;
+    "#;
+    let delete_spell_result = sqlx::query(delete_spell_query)
+        .bind(id)
+        .execute(&mut *connection)
+        .await;
+
+    match delete_spell_result {
+        Ok(result) => Ok(result.rows_affected() > 0),
+        Err(err) => {
+            tracing::error!("failed to delete spell from spells table: {err}");
+            Err(Error::Query)
         }
+    }
+}
 
-        if let Err(err) = transaction.commit().await {
-            tracing::error!("failed to commit spell creation transaction: {err}");
-            return Err(Error::Connection);
+/// Fetch the spell with `id`, along with the contents of its related tables.
+async fn fetch_spell(connection: &mut SqliteConnection, id: Uuid) -> Result<SpellRecord, Error> {
+    let spell_query = r#"
+        SELECT
+            id,
+            name,
+            school,
+            level,
+            source,
+            casting_time,
+            ritual,
+            concentration,
+            verbal,
+            somatic,
+            material,
+            materials,
+            duration,
+            range,
+            area,
+            shape,
+            description,
+            at_higher_levels,
+            flavor_text,
+            attribution
+        FROM spells
+        WHERE id = $1;
+    "#;
+    let row: SpellRow = sqlx::query_as(spell_query)
+        .bind(id)
+        .fetch_one(&mut *connection)
+        .await?;
+
+    // The related tables are ordered by rowid to preserve the order of insertion.
+    let aliases_query = r#"
+        SELECT alias
+        FROM spell_aliases
+        WHERE spell_id = $1
+        ORDER BY rowid;
+    "#;
+    let aliases: Vec<String> = sqlx::query_scalar(aliases_query)
+        .bind(id)
+        .fetch_all(&mut *connection)
+        .await?;
+
+    let classes_query = r#"
+        SELECT class
+        FROM spell_classes
+        WHERE spell_id = $1
+        ORDER BY rowid;
+    "#;
+    let classes: Vec<String> = sqlx::query_scalar(classes_query)
+        .bind(id)
+        .fetch_all(&mut *connection)
+        .await?;
+
+    let tags_query = r#"
+        SELECT tags.value
+        FROM spell_tags
+        JOIN tags ON tags.id = spell_tags.tag_id
+        WHERE spell_tags.spell_id = $1
+        ORDER BY spell_tags.rowid;
+    "#;
+    let tags: Vec<String> = sqlx::query_scalar(tags_query)
+        .bind(id)
+        .fetch_all(&mut *connection)
+        .await?;
+
+    let images_query = r#"
+        SELECT id
+        FROM spell_images
+        WHERE spell_id = $1
+        ORDER BY rowid;
+    "#;
+    let images: Vec<Uuid> = sqlx::query_scalar(images_query)
+        .bind(id)
+        .fetch_all(&mut *connection)
+        .await?;
+
+    let Ok(shape) = serde_json::from_str(&row.shape) else {
+        tracing::error!("failed to JSON deserialize spell shape");
+        return Err(Error::Decode);
+    };
+
+    let materials = match row
+        .materials
+        .as_deref()
+        .map(serde_json::from_str)
+        .transpose()
+    {
+        Ok(materials) => materials.unwrap_or_default(),
+        Err(err) => {
+            tracing::error!("failed to JSON deserialize spell materials: {err}");
+            return Err(Error::Decode);
         }
+    };
 
-        Ok(())
+    Ok(SpellRecord {
+        id: row.id,
+        name: row.name,
+        aliases: aliases.into_boxed_slice(),
+        school: row.school,
+        level: row.level,
+        source: row.source,
+        classes: classes.into_boxed_slice(),
+        tags: tags.into_boxed_slice(),
+        casting_time: row.casting_time,
+        ritual: row.ritual,
+        concentration: row.concentration,
+        verbal: row.verbal,
+        somatic: row.somatic,
+        material: row.material,
+        materials,
+        duration: row.duration,
+        range: row.range,
+        area: row.area,
+        shape,
+        description: row.description,
+        at_higher_levels: row.at_higher_levels,
+        flavor_text: row.flavor_text,
+        attribution: row.attribution,
+        images: images.into_boxed_slice(),
+    })
+}
+
+/// Record a revision of `kind`, with `record` as its snapshot.
+async fn insert_revision(
+    connection: &mut SqliteConnection,
+    kind: RevisionKind,
+    author: &str,
+    record: &SpellRecord,
+) -> Result<(), Error> {
+    let Ok(snapshot) = serde_json::to_string(record) else {
+        tracing::error!("failed to JSON serialize spell snapshot");
+        return Err(Error::Decode);
+    };
+
+    let insert_revision_query = r#"
+        INSERT INTO spell_revisions (
+            id, spell_id, kind, author, snapshot
+        ) VALUES ($1,$2,$3,$4,$5);
+    "#;
+    let insert_revision_result = sqlx::query(insert_revision_query)
+        .bind(Uuid::new_v4())
+        .bind(record.id)
+        .bind(kind)
+        .bind(author)
+        .bind(snapshot)
+        .execute(&mut *connection)
+        .await;
+
+    if let Err(err) = insert_revision_result {
+        tracing::error!("failed to insert revision in spell_revisions table: {err}");
+        return Err(Error::Query);
     }
+
+    Ok(())
 }
 
-impl SpellsRepository for Local {
-    fn spells(&self) -> &dyn Spells {
-        self
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::Area;
+    use types::CastingTime;
+    use types::Distance;
+    use types::Duration;
+    use types::Level;
+    use types::Material;
+    use types::School;
+    use types::Shape;
+    use types::Source;
+    use types::Spell;
+
+    fn spell() -> Spell {
+        Spell {
+            id: Uuid::new_v4(),
+            name: String::from("Fireball"),
+            aliases: Vec::new(),
+            school: School::Evocation.into(),
+            level: Level::Third.into(),
+            source: Some(Source::PlayersHandbook.into()),
+            classes: vec![Class::Sorcerer, Class::Wizard],
+            tags: vec![String::from("fire")],
+            casting_time: CastingTime::Action.into(),
+            ritual: false,
+            concentration: false,
+            verbal: true,
+            somatic: true,
+            material: true,
+            materials: vec![Material {
+                material: String::from("A tiny ball of bat guano and sulfur"),
+                worth: None,
+                consumed: false,
+            }],
+            duration: Duration::Instantaneous.into(),
+            range: Distance::Feet(150).into(),
+            area: Area::Distance(Distance::Feet(20)).into(),
+            shape: Shape::Sphere { radius: 20 },
+            description: String::from("A bright streak flashes from your pointing finger."),
+            at_higher_levels: None,
+            flavor_text: None,
+            attribution: None,
+        }
+    }
+
+    /// Run `future` to completion on a single-threaded runtime.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn revisions_are_recorded_newest_first() {
+        block_on(async {
+            let local = Local::in_memory().await;
+            let mut spell = spell();
+
+            local.create(NewSpell::from(&spell)).await.unwrap();
+            spell.name = String::from("Greater Fireball");
+            local.update(NewSpell::from(&spell)).await.unwrap();
+
+            let revisions = local.revisions(spell.id).await.unwrap();
+            let kinds: Vec<_> = revisions.iter().map(|revision| revision.kind).collect();
+
+            assert_eq!(kinds, [RevisionKind::Updated, RevisionKind::Created]);
+            assert_eq!(revisions[0].snapshot.name, "Greater Fireball");
+            assert_eq!(revisions[1].snapshot.name, "Fireball");
+            assert!(revisions.iter().all(|revision| revision.author == "Tester"));
+        });
+    }
+
+    #[test]
+    fn restore_brings_back_an_earlier_revision() {
+        block_on(async {
+            let local = Local::in_memory().await;
+            let mut spell = spell();
+
+            local.create(NewSpell::from(&spell)).await.unwrap();
+            spell.name = String::from("Greater Fireball");
+            spell.tags = vec![String::from("explosion")];
+            local.update(NewSpell::from(&spell)).await.unwrap();
+
+            let revisions = local.revisions(spell.id).await.unwrap();
+            local.restore(revisions[1].id).await.unwrap();
+
+            let record = local.get(spell.id).await.unwrap();
+            assert_eq!(record, revisions[1].snapshot);
+
+            let revisions = local.revisions(spell.id).await.unwrap();
+            assert_eq!(revisions.len(), 3);
+            assert_eq!(revisions[0].kind, RevisionKind::Restored);
+        });
+    }
+
+    #[test]
+    fn deleted_spell_is_listed_until_restored() {
+        block_on(async {
+            let local = Local::in_memory().await;
+            let spell = spell();
+
+            local.create(NewSpell::from(&spell)).await.unwrap();
+            local.delete(spell.id).await.unwrap();
+
+            assert!(matches!(local.get(spell.id).await, Err(Error::NotFound)));
+
+            let deleted = local.deleted().await.unwrap();
+            assert_eq!(deleted.len(), 1);
+            assert_eq!(deleted[0].kind, RevisionKind::Deleted);
+            assert_eq!(deleted[0].spell_id, spell.id);
+
+            local.restore(deleted[0].id).await.unwrap();
+
+            assert_eq!(
+                local.get(spell.id).await.unwrap(),
+                SpellRecord::from(&spell)
+            );
+            assert!(local.deleted().await.unwrap().is_empty());
+        });
     }
 }
@@ -0,0 +1,11 @@
+use crate::records::activity::ActivityKind;
+use crate::records::activity::EntityKind;
+
+use uuid::Uuid;
+
+pub struct NewActivity {
+    pub kind: ActivityKind,
+    pub entity: EntityKind,
+    pub entity_id: Uuid,
+    pub name: String,
+}
@@ -0,0 +1,8 @@
+use crate::records::combat::CombatData;
+
+use uuid::Uuid;
+
+pub struct NewCombat {
+    pub id: Uuid,
+    pub data: CombatData,
+}
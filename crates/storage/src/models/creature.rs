@@ -0,0 +1,7 @@
+use crate::records::creature::CreatureRecord;
+
+/// A creature to be stored.
+///
+/// Creatures have no parts that are stored differently from how they are read back, like the
+/// images of spells, so this is the record itself.
+pub type NewCreature = CreatureRecord;
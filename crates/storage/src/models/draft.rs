@@ -0,0 +1,13 @@
+use crate::models::NewImage;
+use crate::records::draft::SpellDraftData;
+
+use uuid::Uuid;
+
+pub struct NewSpellDraft {
+    pub id: Uuid,
+    pub spell_id: Option<Uuid>,
+    pub data: SpellDraftData,
+
+    /// The images of the draft, or `None` to keep the images it was last saved with.
+    pub images: Option<Box<[NewImage]>>,
+}
@@ -0,0 +1,15 @@
+use uuid::Uuid;
+
+pub struct NewEncounter {
+    pub id: Uuid,
+    pub name: String,
+
+    /// The levels of the characters of the party.
+    pub party: Box<[u8]>,
+    pub creatures: Box<[NewEncounterCreature]>,
+}
+
+pub struct NewEncounterCreature {
+    pub creature_id: Uuid,
+    pub count: u32,
+}
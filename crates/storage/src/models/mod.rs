@@ -1,4 +1,10 @@
+pub mod activity;
+pub mod combat;
+pub mod creature;
+pub mod draft;
+pub mod encounter;
 pub mod spell;
+pub mod spellbook;
 
 use uuid::Uuid;
 
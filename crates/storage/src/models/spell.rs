@@ -1,5 +1,12 @@
+use crate::Error;
 use crate::models::NewImage;
+use crate::records::spell::SpellMaterialRecord;
+use crate::records::spell::SpellRecord;
+use crate::records::spell::SpellShapeRecord;
 use types::Class;
+use types::Material;
+use types::Shape;
+use types::Spell;
 
 use serde::Serialize;
 use uuid::Uuid;
@@ -49,3 +56,123 @@ pub enum NewSpellShape {
     Line { width: i32, length: i32 },
     Sphere { radius: i32 },
 }
+
+impl From<&NewSpell> for SpellRecord {
+    fn from(new_spell: &NewSpell) -> Self {
+        Self {
+            id: new_spell.id,
+            name: new_spell.name.clone(),
+            aliases: new_spell.aliases.clone(),
+            school: new_spell.school.clone(),
+            level: new_spell.level.clone(),
+            source: new_spell.source.clone(),
+            classes: new_spell.classes.iter().map(ToString::to_string).collect(),
+            tags: new_spell.tags.clone(),
+            casting_time: new_spell.casting_time.clone(),
+            ritual: new_spell.ritual,
+            concentration: new_spell.concentration,
+            verbal: new_spell.verbal,
+            somatic: new_spell.somatic,
+            material: new_spell.material,
+            materials: new_spell.materials.iter().map(Into::into).collect(),
+            duration: new_spell.duration.clone(),
+            range: new_spell.range.clone(),
+            area: new_spell.area.clone(),
+            shape: (&new_spell.shape).into(),
+            description: new_spell.description.clone(),
+            at_higher_levels: new_spell.at_higher_levels.clone(),
+            flavor_text: new_spell.flavor_text.clone(),
+            attribution: new_spell.attribution.clone(),
+            images: new_spell.images.iter().map(|image| image.id).collect(),
+        }
+    }
+}
+
+impl From<&Spell> for NewSpell {
+    /// The spell is created without images, as they are not part of its domain representation.
+    ///
+    /// A spell converts to a new spell and back without loss, but the images of a new spell are
+    /// lost when it is converted to a spell.
+    fn from(spell: &Spell) -> Self {
+        Self {
+            id: spell.id,
+            name: spell.name.clone(),
+            aliases: spell.aliases.iter().cloned().collect(),
+            school: spell.school.to_string(),
+            level: spell.level.to_string(),
+            source: spell.source.as_ref().map(ToString::to_string),
+            classes: spell.classes.iter().copied().collect(),
+            tags: spell.tags.iter().cloned().collect(),
+            casting_time: spell.casting_time.to_string(),
+            ritual: spell.ritual,
+            concentration: spell.concentration,
+            verbal: spell.verbal,
+            somatic: spell.somatic,
+            material: spell.material,
+            materials: spell.materials.iter().map(Into::into).collect(),
+            duration: spell.duration.to_string(),
+            range: spell.range.to_string(),
+            area: spell.area.to_string(),
+            shape: spell.shape.into(),
+            description: spell.description.clone(),
+            at_higher_levels: spell.at_higher_levels.clone(),
+            flavor_text: spell.flavor_text.clone(),
+            attribution: spell.attribution.clone(),
+            images: Box::default(),
+        }
+    }
+}
+
+impl TryFrom<&NewSpell> for Spell {
+    type Error = Error;
+
+    fn try_from(new_spell: &NewSpell) -> Result<Self, Self::Error> {
+        Spell::try_from(&SpellRecord::from(new_spell))
+    }
+}
+
+impl From<&Material> for NewSpellMaterial {
+    fn from(material: &Material) -> Self {
+        Self {
+            material: material.material.clone(),
+            worth: material.worth.clone(),
+            consumed: material.consumed,
+        }
+    }
+}
+
+impl From<&NewSpellMaterial> for SpellMaterialRecord {
+    fn from(material: &NewSpellMaterial) -> Self {
+        Self {
+            material: material.material.clone(),
+            worth: material.worth.clone(),
+            consumed: material.consumed,
+        }
+    }
+}
+
+impl From<Shape> for NewSpellShape {
+    fn from(shape: Shape) -> Self {
+        match SpellShapeRecord::from(shape) {
+            SpellShapeRecord::NoShape => Self::NoShape,
+            SpellShapeRecord::Cone { length } => Self::Cone { length },
+            SpellShapeRecord::Cube { length } => Self::Cube { length },
+            SpellShapeRecord::Cylinder { radius, height } => Self::Cylinder { radius, height },
+            SpellShapeRecord::Line { width, length } => Self::Line { width, length },
+            SpellShapeRecord::Sphere { radius } => Self::Sphere { radius },
+        }
+    }
+}
+
+impl From<&NewSpellShape> for SpellShapeRecord {
+    fn from(shape: &NewSpellShape) -> Self {
+        match *shape {
+            NewSpellShape::NoShape => Self::NoShape,
+            NewSpellShape::Cone { length } => Self::Cone { length },
+            NewSpellShape::Cube { length } => Self::Cube { length },
+            NewSpellShape::Cylinder { radius, height } => Self::Cylinder { radius, height },
+            NewSpellShape::Line { width, length } => Self::Line { width, length },
+            NewSpellShape::Sphere { radius } => Self::Sphere { radius },
+        }
+    }
+}
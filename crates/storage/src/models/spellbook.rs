@@ -0,0 +1,30 @@
+use types::Ability;
+use types::Class;
+
+use uuid::Uuid;
+
+pub struct NewSpellbook {
+    pub id: Uuid,
+    pub owner: String,
+    pub class: Class,
+    pub level: u8,
+    pub ability: Ability,
+    pub ability_score: i32,
+    pub spells: Box<[NewSpellbookEntry]>,
+}
+
+pub struct NewSpellbookEntry {
+    pub spell_id: Uuid,
+    pub prepared: bool,
+    pub always_prepared: bool,
+}
+
+/// The resources spent by the owner of a spellbook since they last rested.
+pub struct NewSpellbookTracker {
+    pub spellbook_id: Uuid,
+
+    /// The expended slots of each level, from 1st to 9th level slots.
+    pub expended_slots: [u32; 9],
+    pub pact_slots_expended: u32,
+    pub concentration_spell_id: Option<Uuid>,
+}
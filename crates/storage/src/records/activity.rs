@@ -0,0 +1,70 @@
+use sqlx::prelude::Type;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use uuid::Uuid;
+
+/// What happened to an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Created,
+    Updated,
+    Deleted,
+    Restored,
+
+    /// A fight was run to its end in the combat tracker.
+    Fought,
+}
+
+/// The kind of entity an activity is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(rename_all = "snake_case")]
+pub enum EntityKind {
+    Spell,
+    Spellbook,
+    Creature,
+    Encounter,
+    Combat,
+}
+
+impl Display for ActivityKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            ActivityKind::Created => "Created",
+            ActivityKind::Updated => "Updated",
+            ActivityKind::Deleted => "Deleted",
+            ActivityKind::Restored => "Restored",
+            ActivityKind::Fought => "Fought",
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+impl Display for EntityKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let entity = match self {
+            EntityKind::Spell => "Spell",
+            EntityKind::Spellbook => "Spellbook",
+            EntityKind::Creature => "Creature",
+            EntityKind::Encounter => "Encounter",
+            EntityKind::Combat => "Combat",
+        };
+
+        write!(f, "{entity}")
+    }
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ActivityRecord {
+    pub id: Uuid,
+    pub kind: ActivityKind,
+    pub entity: EntityKind,
+    pub entity_id: Uuid,
+
+    /// The name of the entity at the time of the activity.
+    pub name: String,
+
+    /// The UTC time of the activity, formatted as 'YYYY-MM-DD HH:MM:SS'.
+    pub occurred_at: String,
+}
@@ -0,0 +1,711 @@
+use crate::Error;
+use types::AppliedCondition;
+use types::Choice;
+use types::CombatEvent;
+use types::CombatLog;
+use types::Combatant;
+use types::CombatantKind;
+use types::Concentration;
+use types::ConditionDuration;
+use types::DamageDefense;
+use types::Encounter;
+use types::HitPoints;
+use types::LogEntry;
+use types::Participant;
+use types::SavingThrow;
+use types::SpellEffect;
+use types::Uses;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// An encounter as it was left in the combat tracker, down to the round and whose turn it is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CombatData {
+    /// The combatants in turn order.
+    pub combatants: Box<[CombatantData]>,
+
+    /// The id of the combatant whose turn it is, or `None` if combat has not started.
+    pub active: Option<Uuid>,
+    pub round: u32,
+    pub effects: Box<[SpellEffectData]>,
+
+    /// The log of the fight, empty for combats persisted before logs were kept.
+    #[serde(default)]
+    pub log: CombatLogData,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CombatantData {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: CombatantKindData,
+    pub initiative_bonus: i32,
+    pub initiative: Option<i32>,
+    pub dexterity: i32,
+    pub tie_breaker: u32,
+    pub hit_points: HitPointsData,
+    pub damage_defenses: Box<[DamageDefenseData]>,
+    pub dead: bool,
+    pub conditions: Box<[AppliedConditionData]>,
+    pub concentration: Option<ConcentrationData>,
+    pub reaction_used: bool,
+    pub legendary_actions: Option<UsesData>,
+    pub legendary_resistances: Option<UsesData>,
+    #[serde(default)]
+    pub experience: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombatantKindData {
+    Creature(Uuid),
+    Character,
+    Lair(Uuid),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HitPointsData {
+    pub current: u32,
+    pub maximum: u32,
+    pub temporary: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DamageDefenseData {
+    pub damage_type: String,
+    pub defense: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AppliedConditionData {
+    pub id: Uuid,
+
+    /// The name of the condition, which is custom if it is not a standard condition.
+    pub condition: String,
+    pub duration: ConditionDurationData,
+    pub save: Option<SavingThrowData>,
+    pub concentration: Option<Uuid>,
+    pub applied_this_turn: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionDurationData {
+    Indefinite,
+    Rounds(u32),
+    UntilEndOfTurn(Uuid),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SavingThrowData {
+    pub ability: String,
+    pub difficulty_class: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ConcentrationData {
+    pub id: Uuid,
+    pub spell: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct UsesData {
+    pub remaining: u32,
+    pub maximum: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SpellEffectData {
+    pub id: Uuid,
+    pub spell: String,
+    pub caster: Uuid,
+    pub targets: Box<[Uuid]>,
+    pub rounds: u32,
+    pub concentration: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CombatLogData {
+    pub participants: Box<[ParticipantData]>,
+    pub entries: Box<[LogEntryData]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ParticipantData {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: CombatantKindData,
+    pub experience: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct LogEntryData {
+    pub round: u32,
+    pub event: CombatEventData,
+}
+
+/// An event of the log, where damage types and conditions are stored by name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombatEventData {
+    Started,
+    TurnStarted {
+        combatant: Uuid,
+    },
+    Damaged {
+        target: Uuid,
+        source: Option<Uuid>,
+        amount: u32,
+        damage_type: Option<String>,
+    },
+    Healed {
+        target: Uuid,
+        amount: u32,
+    },
+    ConditionApplied {
+        target: Uuid,
+        condition: String,
+    },
+    ConditionEnded {
+        target: Uuid,
+        condition: String,
+    },
+    SpellCast {
+        caster: Uuid,
+        spell: String,
+    },
+    Downed {
+        combatant: Uuid,
+    },
+    Died {
+        combatant: Uuid,
+    },
+    Ended,
+}
+
+/// A combat left unfinished, persisted so that it can be resumed in a later session.
+#[derive(Debug, Clone)]
+pub struct CombatRecord {
+    pub id: Uuid,
+    pub updated_at: String,
+    pub data: CombatData,
+}
+
+impl From<&Encounter> for CombatData {
+    fn from(encounter: &Encounter) -> Self {
+        Self {
+            combatants: encounter.combatants().iter().map(Into::into).collect(),
+            active: encounter.active().map(|combatant| combatant.id),
+            round: encounter.round(),
+            effects: encounter.effects().iter().map(Into::into).collect(),
+            log: encounter.log().into(),
+        }
+    }
+}
+
+impl TryFrom<&CombatData> for Encounter {
+    type Error = Error;
+
+    /// This fails if any of the enumerated values, like a damage type, is unknown.
+    fn try_from(data: &CombatData) -> Result<Self, Self::Error> {
+        let combatants = data
+            .combatants
+            .iter()
+            .map(Combatant::try_from)
+            .collect::<Result<_, Error>>()?;
+
+        let effects = data.effects.iter().map(Into::into).collect();
+        let log = CombatLog::try_from(&data.log)?;
+
+        Ok(Encounter::resume(
+            combatants,
+            data.active,
+            data.round,
+            effects,
+            log,
+        ))
+    }
+}
+
+impl From<&Combatant> for CombatantData {
+    fn from(combatant: &Combatant) -> Self {
+        Self {
+            id: combatant.id,
+            name: combatant.name.clone(),
+            kind: combatant.kind.into(),
+            initiative_bonus: combatant.initiative_bonus,
+            initiative: combatant.initiative,
+            dexterity: combatant.dexterity,
+            tie_breaker: combatant.tie_breaker,
+            hit_points: combatant.hit_points.into(),
+            damage_defenses: combatant
+                .damage_defenses
+                .iter()
+                .map(|defense| DamageDefenseData {
+                    damage_type: defense.damage_type.to_string(),
+                    defense: defense.defense.to_string(),
+                })
+                .collect(),
+            dead: combatant.dead,
+            conditions: combatant.conditions.iter().map(Into::into).collect(),
+            concentration: combatant.concentration.as_ref().map(|concentration| {
+                ConcentrationData {
+                    id: concentration.id,
+                    spell: concentration.spell.clone(),
+                }
+            }),
+            reaction_used: combatant.reaction_used,
+            legendary_actions: combatant.legendary_actions.map(Into::into),
+            legendary_resistances: combatant.legendary_resistances.map(Into::into),
+            experience: combatant.experience,
+        }
+    }
+}
+
+impl TryFrom<&CombatantData> for Combatant {
+    type Error = Error;
+
+    fn try_from(data: &CombatantData) -> Result<Self, Self::Error> {
+        let parse = |field: &str, value: &str| {
+            tracing::error!("invalid {field} '{value}' in combatant '{}'", data.id);
+            Error::Decode
+        };
+
+        let damage_defenses = data
+            .damage_defenses
+            .iter()
+            .map(|defense| {
+                Ok(DamageDefense {
+                    damage_type: defense
+                        .damage_type
+                        .parse()
+                        .map_err(|_| parse("damage type", &defense.damage_type))?,
+                    defense: defense
+                        .defense
+                        .parse()
+                        .map_err(|_| parse("defense", &defense.defense))?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let conditions = data
+            .conditions
+            .iter()
+            .map(|applied| {
+                let save = applied
+                    .save
+                    .as_ref()
+                    .map(|save| {
+                        Ok::<_, Error>(SavingThrow {
+                            ability: save
+                                .ability
+                                .parse()
+                                .map_err(|_| parse("ability", &save.ability))?,
+                            difficulty_class: save.difficulty_class,
+                        })
+                    })
+                    .transpose()?;
+
+                let mut condition = AppliedCondition::new(
+                    Choice::parse(&applied.condition),
+                    applied.duration.into(),
+                    save,
+                );
+                condition.id = applied.id;
+                condition.concentration = applied.concentration;
+                condition.applied_this_turn = applied.applied_this_turn;
+
+                Ok(condition)
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let mut combatant = Combatant::new(
+            data.name.clone(),
+            data.kind.into(),
+            data.initiative_bonus,
+            data.hit_points.maximum,
+        );
+        combatant.id = data.id;
+        combatant.initiative = data.initiative;
+        combatant.dexterity = data.dexterity;
+        combatant.tie_breaker = data.tie_breaker;
+        combatant.hit_points = data.hit_points.into();
+        combatant.damage_defenses = damage_defenses;
+        combatant.dead = data.dead;
+        combatant.conditions = conditions;
+        combatant.concentration = data
+            .concentration
+            .as_ref()
+            .map(|concentration| Concentration {
+                id: concentration.id,
+                spell: concentration.spell.clone(),
+            });
+        combatant.reaction_used = data.reaction_used;
+        combatant.legendary_actions = data.legendary_actions.map(Into::into);
+        combatant.legendary_resistances = data.legendary_resistances.map(Into::into);
+        combatant.experience = data.experience;
+
+        Ok(combatant)
+    }
+}
+
+impl From<&CombatLog> for CombatLogData {
+    fn from(log: &CombatLog) -> Self {
+        Self {
+            participants: log
+                .participants
+                .iter()
+                .map(|participant| ParticipantData {
+                    id: participant.id,
+                    name: participant.name.clone(),
+                    kind: participant.kind.into(),
+                    experience: participant.experience,
+                })
+                .collect(),
+            entries: log
+                .entries
+                .iter()
+                .map(|entry| LogEntryData {
+                    round: entry.round,
+                    event: (&entry.event).into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&CombatLogData> for CombatLog {
+    type Error = Error;
+
+    fn try_from(data: &CombatLogData) -> Result<Self, Self::Error> {
+        let entries = data
+            .entries
+            .iter()
+            .map(|entry| {
+                Ok(LogEntry {
+                    round: entry.round,
+                    event: CombatEvent::try_from(&entry.event)?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            participants: data
+                .participants
+                .iter()
+                .map(|participant| Participant {
+                    id: participant.id,
+                    name: participant.name.clone(),
+                    kind: participant.kind.into(),
+                    experience: participant.experience,
+                })
+                .collect(),
+            entries,
+        })
+    }
+}
+
+impl From<&CombatEvent> for CombatEventData {
+    fn from(event: &CombatEvent) -> Self {
+        match event {
+            CombatEvent::Started => Self::Started,
+            CombatEvent::TurnStarted { combatant } => Self::TurnStarted {
+                combatant: *combatant,
+            },
+            CombatEvent::Damaged {
+                target,
+                source,
+                amount,
+                damage_type,
+            } => Self::Damaged {
+                target: *target,
+                source: *source,
+                amount: *amount,
+                damage_type: damage_type.map(|damage_type| damage_type.to_string()),
+            },
+            CombatEvent::Healed { target, amount } => Self::Healed {
+                target: *target,
+                amount: *amount,
+            },
+            CombatEvent::ConditionApplied { target, condition } => Self::ConditionApplied {
+                target: *target,
+                condition: condition.to_string(),
+            },
+            CombatEvent::ConditionEnded { target, condition } => Self::ConditionEnded {
+                target: *target,
+                condition: condition.to_string(),
+            },
+            CombatEvent::SpellCast { caster, spell } => Self::SpellCast {
+                caster: *caster,
+                spell: spell.clone(),
+            },
+            CombatEvent::Downed { combatant } => Self::Downed {
+                combatant: *combatant,
+            },
+            CombatEvent::Died { combatant } => Self::Died {
+                combatant: *combatant,
+            },
+            CombatEvent::Ended => Self::Ended,
+        }
+    }
+}
+
+impl TryFrom<&CombatEventData> for CombatEvent {
+    type Error = Error;
+
+    /// This fails if the damage type of the event is unknown.
+    fn try_from(event: &CombatEventData) -> Result<Self, Self::Error> {
+        let event = match event {
+            CombatEventData::Started => Self::Started,
+            CombatEventData::TurnStarted { combatant } => Self::TurnStarted {
+                combatant: *combatant,
+            },
+            CombatEventData::Damaged {
+                target,
+                source,
+                amount,
+                damage_type,
+            } => Self::Damaged {
+                target: *target,
+                source: *source,
+                amount: *amount,
+                damage_type: damage_type
+                    .as_deref()
+                    .map(|damage_type| {
+                        damage_type.parse().map_err(|_| {
+                            tracing::error!("invalid damage type '{damage_type}' in combat log");
+                            Error::Decode
+                        })
+                    })
+                    .transpose()?,
+            },
+            CombatEventData::Healed { target, amount } => Self::Healed {
+                target: *target,
+                amount: *amount,
+            },
+            CombatEventData::ConditionApplied { target, condition } => Self::ConditionApplied {
+                target: *target,
+                condition: Choice::parse(condition),
+            },
+            CombatEventData::ConditionEnded { target, condition } => Self::ConditionEnded {
+                target: *target,
+                condition: Choice::parse(condition),
+            },
+            CombatEventData::SpellCast { caster, spell } => Self::SpellCast {
+                caster: *caster,
+                spell: spell.clone(),
+            },
+            CombatEventData::Downed { combatant } => Self::Downed {
+                combatant: *combatant,
+            },
+            CombatEventData::Died { combatant } => Self::Died {
+                combatant: *combatant,
+            },
+            CombatEventData::Ended => Self::Ended,
+        };
+
+        Ok(event)
+    }
+}
+
+impl From<CombatantKind> for CombatantKindData {
+    fn from(kind: CombatantKind) -> Self {
+        match kind {
+            CombatantKind::Creature(id) => Self::Creature(id),
+            CombatantKind::Character => Self::Character,
+            CombatantKind::Lair(id) => Self::Lair(id),
+        }
+    }
+}
+
+impl From<CombatantKindData> for CombatantKind {
+    fn from(kind: CombatantKindData) -> Self {
+        match kind {
+            CombatantKindData::Creature(id) => Self::Creature(id),
+            CombatantKindData::Character => Self::Character,
+            CombatantKindData::Lair(id) => Self::Lair(id),
+        }
+    }
+}
+
+impl From<HitPoints> for HitPointsData {
+    fn from(hit_points: HitPoints) -> Self {
+        Self {
+            current: hit_points.current,
+            maximum: hit_points.maximum,
+            temporary: hit_points.temporary,
+        }
+    }
+}
+
+impl From<HitPointsData> for HitPoints {
+    fn from(hit_points: HitPointsData) -> Self {
+        Self {
+            current: hit_points.current,
+            maximum: hit_points.maximum,
+            temporary: hit_points.temporary,
+        }
+    }
+}
+
+impl From<&AppliedCondition> for AppliedConditionData {
+    fn from(applied: &AppliedCondition) -> Self {
+        Self {
+            id: applied.id,
+            condition: applied.condition.to_string(),
+            duration: applied.duration.into(),
+            save: applied.save.map(|save| SavingThrowData {
+                ability: save.ability.to_string(),
+                difficulty_class: save.difficulty_class,
+            }),
+            concentration: applied.concentration,
+            applied_this_turn: applied.applied_this_turn,
+        }
+    }
+}
+
+impl From<ConditionDuration> for ConditionDurationData {
+    fn from(duration: ConditionDuration) -> Self {
+        match duration {
+            ConditionDuration::Indefinite => Self::Indefinite,
+            ConditionDuration::Rounds(rounds) => Self::Rounds(rounds),
+            ConditionDuration::UntilEndOfTurn(id) => Self::UntilEndOfTurn(id),
+        }
+    }
+}
+
+impl From<ConditionDurationData> for ConditionDuration {
+    fn from(duration: ConditionDurationData) -> Self {
+        match duration {
+            ConditionDurationData::Indefinite => Self::Indefinite,
+            ConditionDurationData::Rounds(rounds) => Self::Rounds(rounds),
+            ConditionDurationData::UntilEndOfTurn(id) => Self::UntilEndOfTurn(id),
+        }
+    }
+}
+
+impl From<Uses> for UsesData {
+    fn from(uses: Uses) -> Self {
+        Self {
+            remaining: uses.remaining,
+            maximum: uses.maximum,
+        }
+    }
+}
+
+impl From<UsesData> for Uses {
+    fn from(uses: UsesData) -> Self {
+        Self {
+            remaining: uses.remaining,
+            maximum: uses.maximum,
+        }
+    }
+}
+
+impl From<&SpellEffect> for SpellEffectData {
+    fn from(effect: &SpellEffect) -> Self {
+        Self {
+            id: effect.id,
+            spell: effect.spell.clone(),
+            caster: effect.caster,
+            targets: effect.targets.iter().copied().collect(),
+            rounds: effect.rounds,
+            concentration: effect.concentration,
+        }
+    }
+}
+
+impl From<&SpellEffectData> for SpellEffect {
+    fn from(effect: &SpellEffectData) -> Self {
+        Self {
+            id: effect.id,
+            spell: effect.spell.clone(),
+            caster: effect.caster,
+            targets: effect.targets.to_vec(),
+            rounds: effect.rounds,
+            concentration: effect.concentration,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::Ability;
+    use types::Condition;
+    use types::DamageType;
+    use types::Defense;
+
+    fn encounter() -> Encounter {
+        let mut encounter = Encounter::new();
+
+        let mut goblin = Combatant::new(
+            String::from("Goblin"),
+            CombatantKind::Creature(Uuid::new_v4()),
+            2,
+            7,
+        );
+        goblin.initiative = Some(15);
+        goblin.damage_defenses = vec![DamageDefense {
+            damage_type: DamageType::Fire,
+            defense: Defense::Vulnerability,
+        }];
+        goblin.legendary_resistances = Some(Uses::new(1));
+        let goblin_id = goblin.id;
+
+        let mut fighter = Combatant::new(String::from("Fighter"), CombatantKind::Character, 1, 30);
+        fighter.initiative = Some(12);
+
+        encounter.add(goblin);
+        encounter.add(fighter);
+        encounter.start();
+        encounter.damage(goblin_id, None, 3, Some(DamageType::Piercing));
+        encounter.apply_condition(
+            goblin_id,
+            AppliedCondition::new(
+                Choice::BuiltIn(Condition::Frightened),
+                ConditionDuration::Rounds(2),
+                Some(SavingThrow {
+                    ability: Ability::Wisdom,
+                    difficulty_class: 13,
+                }),
+            ),
+        );
+        encounter.next_turn();
+
+        encounter
+    }
+
+    #[test]
+    fn encounter_round_trips_through_data() {
+        let encounter = encounter();
+        let data = CombatData::from(&encounter);
+        let json = serde_json::to_string(&data).unwrap();
+        let data: CombatData = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(Encounter::try_from(&data).unwrap(), encounter);
+    }
+
+    #[test]
+    fn unknown_ability_fails_to_convert() {
+        let mut data = CombatData::from(&encounter());
+        let save = data.combatants[0].conditions[0].save.as_mut().unwrap();
+        save.ability = String::from("Luck");
+
+        assert!(matches!(Encounter::try_from(&data), Err(Error::Decode)));
+    }
+}
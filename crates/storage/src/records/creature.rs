@@ -0,0 +1,422 @@
+use crate::Error;
+use types::Ability;
+use types::AbilityScores;
+use types::Bonus;
+use types::Choice;
+use types::Creature;
+use types::DamageDefense;
+use types::Feature;
+use types::Senses;
+use types::Skill;
+use types::Speeds;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Display;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// A creature as it is stored, including its damage defenses, languages and features.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatureRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub size: String,
+    pub kind: String,
+    pub alignment: String,
+    pub armor_class: u32,
+    pub armor_description: Option<String>,
+    pub hit_dice: String,
+    pub speeds: CreatureSpeedsRecord,
+
+    /// The ability scores, in the order of [`Ability`].
+    pub abilities: [i32; 6],
+    pub initiative_bonus: i32,
+    pub saving_throws: Box<[BonusRecord]>,
+    pub skills: Box<[BonusRecord]>,
+    pub damage_defenses: Box<[DamageDefenseRecord]>,
+    pub senses: CreatureSensesRecord,
+    pub languages: Box<[String]>,
+    pub challenge_rating: String,
+    pub environments: Box<[String]>,
+    pub source: Option<String>,
+    pub traits: Box<[FeatureRecord]>,
+    pub actions: Box<[FeatureRecord]>,
+    pub reactions: Box<[FeatureRecord]>,
+    pub legendary_actions: Box<[FeatureRecord]>,
+    pub legendary_action_count: u32,
+    pub lair_actions: Box<[FeatureRecord]>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CreatureSpeedsRecord {
+    pub walk: u32,
+    pub burrow: Option<u32>,
+    pub climb: Option<u32>,
+    pub fly: Option<u32>,
+    pub swim: Option<u32>,
+    pub hover: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CreatureSensesRecord {
+    pub blindsight: Option<u32>,
+    pub darkvision: Option<u32>,
+    pub tremorsense: Option<u32>,
+    pub truesight: Option<u32>,
+    pub passive_perception: i32,
+}
+
+/// A bonus to a saving throw or skill, where the target is the name of the ability or skill.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BonusRecord {
+    pub target: String,
+    pub bonus: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamageDefenseRecord {
+    pub damage_type: String,
+    pub defense: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureRecord {
+    pub name: String,
+    pub description: String,
+}
+
+/// The minimal representation of a creature, used for listings.
+#[derive(Debug, Clone)]
+pub struct CreatureSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub size: String,
+    pub kind: String,
+    pub armor_class: u32,
+    pub hit_dice: String,
+    pub challenge_rating: String,
+    pub environments: Box<[String]>,
+    pub source: Option<String>,
+}
+
+impl From<&Creature> for CreatureRecord {
+    fn from(creature: &Creature) -> Self {
+        Self {
+            id: creature.id,
+            name: creature.name.clone(),
+            size: creature.size.to_string(),
+            kind: creature.kind.to_string(),
+            alignment: creature.alignment.to_string(),
+            armor_class: creature.armor_class,
+            armor_description: creature.armor_description.clone(),
+            hit_dice: creature.hit_dice.to_string(),
+            speeds: creature.speeds.into(),
+            abilities: creature.abilities.scores(),
+            initiative_bonus: creature.initiative_bonus,
+            saving_throws: bonuses(&creature.saving_throws),
+            skills: bonuses(&creature.skills),
+            damage_defenses: creature
+                .damage_defenses
+                .iter()
+                .map(|defense| DamageDefenseRecord {
+                    damage_type: defense.damage_type.to_string(),
+                    defense: defense.defense.to_string(),
+                })
+                .collect(),
+            senses: creature.senses.into(),
+            languages: creature.languages.iter().cloned().collect(),
+            challenge_rating: creature.challenge_rating.to_string(),
+            environments: creature
+                .environments
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            source: creature.source.as_ref().map(ToString::to_string),
+            traits: features(&creature.traits),
+            actions: features(&creature.actions),
+            reactions: features(&creature.reactions),
+            legendary_actions: features(&creature.legendary_actions),
+            legendary_action_count: creature.legendary_action_count,
+            lair_actions: features(&creature.lair_actions),
+        }
+    }
+}
+
+impl TryFrom<&CreatureRecord> for Creature {
+    type Error = Error;
+
+    /// This fails if any of the enumerated values, like the size or a damage type, is unknown.
+    fn try_from(record: &CreatureRecord) -> Result<Self, Self::Error> {
+        let parse = |field: &str, value: &str| {
+            tracing::error!("invalid {field} '{value}' in creature '{}'", record.id);
+            Error::Decode
+        };
+
+        let saving_throws = record
+            .saving_throws
+            .iter()
+            .map(|bonus| {
+                bonus
+                    .try_into_bonus::<Ability>()
+                    .ok_or_else(|| parse("saving throw", &bonus.target))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let skills = record
+            .skills
+            .iter()
+            .map(|bonus| {
+                bonus
+                    .try_into_bonus::<Skill>()
+                    .ok_or_else(|| parse("skill", &bonus.target))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let damage_defenses = record
+            .damage_defenses
+            .iter()
+            .map(|defense| {
+                Ok(DamageDefense {
+                    damage_type: defense
+                        .damage_type
+                        .parse()
+                        .map_err(|_| parse("damage type", &defense.damage_type))?,
+                    defense: defense
+                        .defense
+                        .parse()
+                        .map_err(|_| parse("defense", &defense.defense))?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let environments = record
+            .environments
+            .iter()
+            .map(|environment| {
+                environment
+                    .parse()
+                    .map_err(|_| parse("environment", environment))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            id: record.id,
+            name: record.name.clone(),
+            size: record
+                .size
+                .parse()
+                .map_err(|_| parse("size", &record.size))?,
+            kind: record
+                .kind
+                .parse()
+                .map_err(|_| parse("type", &record.kind))?,
+            alignment: record
+                .alignment
+                .parse()
+                .map_err(|_| parse("alignment", &record.alignment))?,
+            armor_class: record.armor_class,
+            armor_description: record.armor_description.clone(),
+            hit_dice: record
+                .hit_dice
+                .parse()
+                .map_err(|_| parse("hit dice", &record.hit_dice))?,
+            speeds: record.speeds.into(),
+            abilities: AbilityScores::new(record.abilities),
+            initiative_bonus: record.initiative_bonus,
+            saving_throws,
+            skills,
+            damage_defenses,
+            senses: record.senses.into(),
+            languages: record.languages.to_vec(),
+            challenge_rating: record
+                .challenge_rating
+                .parse()
+                .map_err(|_| parse("challenge rating", &record.challenge_rating))?,
+            environments,
+            source: record.source.as_deref().map(Choice::parse),
+            traits: record.traits.iter().map(Into::into).collect(),
+            actions: record.actions.iter().map(Into::into).collect(),
+            reactions: record.reactions.iter().map(Into::into).collect(),
+            legendary_actions: record.legendary_actions.iter().map(Into::into).collect(),
+            legendary_action_count: record.legendary_action_count,
+            lair_actions: record.lair_actions.iter().map(Into::into).collect(),
+        })
+    }
+}
+
+impl BonusRecord {
+    /// The bonus with its target parsed, or `None` if the target is unknown.
+    fn try_into_bonus<T: FromStr>(&self) -> Option<Bonus<T>> {
+        let target = self.target.parse().ok()?;
+
+        Some(Bonus {
+            target,
+            bonus: self.bonus,
+        })
+    }
+}
+
+impl From<Speeds> for CreatureSpeedsRecord {
+    fn from(speeds: Speeds) -> Self {
+        Self {
+            walk: speeds.walk,
+            burrow: speeds.burrow,
+            climb: speeds.climb,
+            fly: speeds.fly,
+            swim: speeds.swim,
+            hover: speeds.hover,
+        }
+    }
+}
+
+impl From<CreatureSpeedsRecord> for Speeds {
+    fn from(record: CreatureSpeedsRecord) -> Self {
+        Self {
+            walk: record.walk,
+            burrow: record.burrow,
+            climb: record.climb,
+            fly: record.fly,
+            swim: record.swim,
+            hover: record.hover,
+        }
+    }
+}
+
+impl From<Senses> for CreatureSensesRecord {
+    fn from(senses: Senses) -> Self {
+        Self {
+            blindsight: senses.blindsight,
+            darkvision: senses.darkvision,
+            tremorsense: senses.tremorsense,
+            truesight: senses.truesight,
+            passive_perception: senses.passive_perception,
+        }
+    }
+}
+
+impl From<CreatureSensesRecord> for Senses {
+    fn from(record: CreatureSensesRecord) -> Self {
+        Self {
+            blindsight: record.blindsight,
+            darkvision: record.darkvision,
+            tremorsense: record.tremorsense,
+            truesight: record.truesight,
+            passive_perception: record.passive_perception,
+        }
+    }
+}
+
+impl From<&FeatureRecord> for Feature {
+    fn from(record: &FeatureRecord) -> Self {
+        Self {
+            name: record.name.clone(),
+            description: record.description.clone(),
+        }
+    }
+}
+
+fn bonuses<T: Display>(bonuses: &[Bonus<T>]) -> Box<[BonusRecord]> {
+    bonuses
+        .iter()
+        .map(|bonus| BonusRecord {
+            target: bonus.target.to_string(),
+            bonus: bonus.bonus,
+        })
+        .collect()
+}
+
+fn features(features: &[Feature]) -> Box<[FeatureRecord]> {
+    features
+        .iter()
+        .map(|feature| FeatureRecord {
+            name: feature.name.clone(),
+            description: feature.description.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::Alignment;
+    use types::CreatureType;
+    use types::DamageType;
+    use types::Defense;
+    use types::Dice;
+    use types::Environment;
+    use types::Size;
+    use types::Source;
+
+    fn creature() -> Creature {
+        let mut abilities = AbilityScores::default();
+        abilities[Ability::Strength] = 19;
+        abilities[Ability::Constitution] = 17;
+
+        Creature {
+            id: Uuid::new_v4(),
+            name: String::from("Owlbear"),
+            size: Size::Large,
+            kind: CreatureType::Monstrosity,
+            alignment: Alignment::Unaligned,
+            armor_class: 13,
+            armor_description: Some(String::from("natural armor")),
+            hit_dice: Dice::new(7, 10, 21),
+            speeds: Speeds {
+                walk: 40,
+                ..Speeds::default()
+            },
+            abilities,
+            initiative_bonus: 0,
+            saving_throws: vec![Bonus {
+                target: Ability::Constitution,
+                bonus: 5,
+            }],
+            skills: vec![Bonus {
+                target: Skill::Perception,
+                bonus: 3,
+            }],
+            damage_defenses: vec![DamageDefense {
+                damage_type: DamageType::Cold,
+                defense: Defense::Resistance,
+            }],
+            senses: Senses {
+                darkvision: Some(60),
+                passive_perception: 13,
+                ..Senses::default()
+            },
+            languages: Vec::new(),
+            challenge_rating: "3".parse().unwrap(),
+            environments: vec![Environment::Forest],
+            source: Some(Choice::BuiltIn(Source::MonsterManual)),
+            traits: vec![Feature {
+                name: String::from("Keen Sight and Smell"),
+                description: String::from("The owlbear has advantage on Wisdom checks."),
+            }],
+            actions: Vec::new(),
+            reactions: Vec::new(),
+            legendary_actions: Vec::new(),
+            legendary_action_count: 3,
+            lair_actions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn creature_round_trips_through_record() {
+        let creature = creature();
+        let record = CreatureRecord::from(&creature);
+
+        assert_eq!(Creature::try_from(&record).unwrap(), creature);
+    }
+
+    #[test]
+    fn unknown_damage_type_fails_to_convert() {
+        let mut record = CreatureRecord::from(&creature());
+        record.damage_defenses[0].damage_type = String::from("Sonic");
+
+        assert!(matches!(Creature::try_from(&record), Err(Error::Decode)));
+    }
+}
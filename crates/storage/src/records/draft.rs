@@ -0,0 +1,56 @@
+use crate::records::ImageRecord;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// The values of a spell form as they were written, which may be incomplete or invalid.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SpellDraftData {
+    pub name: String,
+    pub aliases: Box<[String]>,
+    pub school: Option<String>,
+    pub level: Option<String>,
+    pub source: Option<String>,
+    pub classes: Box<[String]>,
+    pub tags: Box<[String]>,
+    pub casting_time: Option<String>,
+    pub ritual: bool,
+    pub concentration: bool,
+    pub verbal: bool,
+    pub somatic: bool,
+    pub material: bool,
+    pub materials: Box<[SpellDraftMaterial]>,
+    pub duration: Option<String>,
+    pub range: Option<String>,
+    pub area: Option<String>,
+    pub shape_kind: Option<String>,
+
+    /// The dimensions of the shape as they were written, in the order of the shape's inputs.
+    pub shape_dimensions: Box<[String]>,
+    pub description: String,
+    pub at_higher_levels: String,
+    pub flavor_text: String,
+    pub attribution: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SpellDraftMaterial {
+    pub material: String,
+    pub worth: String,
+    pub consumed: bool,
+}
+
+/// An autosaved spell form.
+#[derive(Debug, Clone)]
+pub struct SpellDraftRecord {
+    pub id: Uuid,
+
+    /// The spell being edited by the form, if it was not creating a new spell.
+    pub spell_id: Option<Uuid>,
+    pub updated_at: String,
+    pub data: SpellDraftData,
+    pub images: Box<[ImageRecord]>,
+}
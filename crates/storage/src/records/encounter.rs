@@ -0,0 +1,32 @@
+use uuid::Uuid;
+
+/// An encounter as it is stored, including its party and creatures.
+#[derive(Debug, Clone)]
+pub struct EncounterRecord {
+    pub id: Uuid,
+    pub name: String,
+
+    /// The levels of the characters of the party, in the order they were added.
+    pub party: Box<[u8]>,
+
+    /// The creatures of the encounter, ordered by name.
+    pub creatures: Box<[EncounterCreatureRecord]>,
+}
+
+/// A creature of an encounter, along with the summary of the creature itself.
+#[derive(Debug, Clone)]
+pub struct EncounterCreatureRecord {
+    pub creature_id: Uuid,
+    pub name: String,
+    pub challenge_rating: String,
+    pub count: u32,
+}
+
+/// The minimal representation of an encounter, used for listings.
+#[derive(Debug, Clone)]
+pub struct EncounterSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub party_size: u32,
+    pub creature_count: u32,
+}
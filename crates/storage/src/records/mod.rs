@@ -0,0 +1,15 @@
+pub mod activity;
+pub mod combat;
+pub mod creature;
+pub mod draft;
+pub mod encounter;
+pub mod spell;
+pub mod spellbook;
+
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct ImageRecord {
+    pub id: Uuid,
+    pub bytes: Box<[u8]>,
+}
@@ -0,0 +1,646 @@
+use crate::Error;
+use types::Choice;
+use types::Class;
+use types::Material;
+use types::Shape;
+use types::Spell;
+
+use serde::Deserialize;
+use serde::Serialize;
+use sqlx::prelude::Type;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use uuid::Uuid;
+
+/// A spell as it is stored, including its aliases, classes, tags and image references.
+///
+/// This is also the structure of revision snapshots, so changing it must remain
+/// backwards compatible with already serialized snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SpellRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub aliases: Box<[String]>,
+    pub school: String,
+    pub level: String,
+    pub source: Option<String>,
+    pub classes: Box<[String]>,
+    pub tags: Box<[String]>,
+    pub casting_time: String,
+    pub ritual: bool,
+    pub concentration: bool,
+    pub verbal: bool,
+    pub somatic: bool,
+    pub material: bool,
+    pub materials: Box<[SpellMaterialRecord]>,
+    pub duration: String,
+    pub range: String,
+    pub area: String,
+    pub shape: SpellShapeRecord,
+    pub description: String,
+    pub at_higher_levels: Option<String>,
+    pub flavor_text: Option<String>,
+    pub attribution: Option<String>,
+    pub images: Box<[Uuid]>,
+}
+
+impl SpellRecord {
+    /// The field-level changes from `previous` to `self`.
+    ///
+    /// If there is no `previous`, every non-empty field of `self` is reported as a change.
+    pub fn changes(&self, previous: Option<&SpellRecord>) -> Vec<FieldChange> {
+        let after = self.displayed_fields();
+
+        let Some(previous) = previous else {
+            return after
+                .into_iter()
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(field, value)| FieldChange {
+                    field,
+                    before: String::new(),
+                    after: value,
+                })
+                .collect();
+        };
+
+        previous
+            .displayed_fields()
+            .into_iter()
+            .zip(after)
+            .filter(|((_, before), (_, after))| before != after)
+            .map(|((field, before), (_, after))| FieldChange {
+                field,
+                before,
+                after,
+            })
+            .collect()
+    }
+
+    /// Every field of the spell paired with its user-facing name, rendered as text.
+    fn displayed_fields(&self) -> [(&'static str, String); 23] {
+        let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+        let flag = |value: bool| if value { "Yes" } else { "No" }.to_string();
+
+        let materials = self
+            .materials
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        let images = match self.images.len() {
+            0 => String::new(),
+            1 => String::from("1 image"),
+            count => format!("{count} images"),
+        };
+
+        [
+            ("Name", self.name.clone()),
+            ("Aliases", self.aliases.join(", ")),
+            ("School", self.school.clone()),
+            ("Level", self.level.clone()),
+            ("Source", optional(&self.source)),
+            ("Classes", self.classes.join(", ")),
+            ("Tags", self.tags.join(", ")),
+            ("Casting time", self.casting_time.clone()),
+            ("Ritual", flag(self.ritual)),
+            ("Concentration", flag(self.concentration)),
+            ("Verbal", flag(self.verbal)),
+            ("Somatic", flag(self.somatic)),
+            ("Material", flag(self.material)),
+            ("Materials", materials),
+            ("Duration", self.duration.clone()),
+            ("Range", self.range.clone()),
+            ("Area", self.area.clone()),
+            ("Shape", self.shape.to_string()),
+            ("Description", self.description.clone()),
+            ("At higher levels", optional(&self.at_higher_levels)),
+            ("Flavor text", optional(&self.flavor_text)),
+            ("Attribution", optional(&self.attribution)),
+            ("Images", images),
+        ]
+    }
+}
+
+impl From<&Spell> for SpellRecord {
+    /// The record has no images, as they are not part of the domain representation of a spell.
+    ///
+    /// A spell converts to a record and back without loss, but a record with images loses them
+    /// when it is converted to a spell and back.
+    fn from(spell: &Spell) -> Self {
+        Self {
+            id: spell.id,
+            name: spell.name.clone(),
+            aliases: spell.aliases.iter().cloned().collect(),
+            school: spell.school.to_string(),
+            level: spell.level.to_string(),
+            source: spell.source.as_ref().map(ToString::to_string),
+            classes: spell.classes.iter().map(ToString::to_string).collect(),
+            tags: spell.tags.iter().cloned().collect(),
+            casting_time: spell.casting_time.to_string(),
+            ritual: spell.ritual,
+            concentration: spell.concentration,
+            verbal: spell.verbal,
+            somatic: spell.somatic,
+            material: spell.material,
+            materials: spell.materials.iter().map(Into::into).collect(),
+            duration: spell.duration.to_string(),
+            range: spell.range.to_string(),
+            area: spell.area.to_string(),
+            shape: spell.shape.into(),
+            description: spell.description.clone(),
+            at_higher_levels: spell.at_higher_levels.clone(),
+            flavor_text: spell.flavor_text.clone(),
+            attribution: spell.attribution.clone(),
+            images: Box::default(),
+        }
+    }
+}
+
+impl TryFrom<&SpellRecord> for Spell {
+    type Error = Error;
+
+    /// Options are parsed into their built-in values where possible, and kept as custom values
+    /// otherwise. This fails if a class is unknown, or if a shape has an invalid dimension.
+    fn try_from(record: &SpellRecord) -> Result<Self, Self::Error> {
+        let classes = record
+            .classes
+            .iter()
+            .map(|class| {
+                class.parse::<Class>().map_err(|_| {
+                    tracing::error!("unknown class '{class}' in spell '{}'", record.id);
+                    Error::Decode
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let Ok(shape) = Shape::try_from(record.shape) else {
+            tracing::error!("invalid shape dimensions in spell '{}'", record.id);
+            return Err(Error::Decode);
+        };
+
+        Ok(Self {
+            id: record.id,
+            name: record.name.clone(),
+            aliases: record.aliases.to_vec(),
+            school: Choice::parse(&record.school),
+            level: Choice::parse(&record.level),
+            source: record.source.as_deref().map(Choice::parse),
+            classes,
+            tags: record.tags.to_vec(),
+            casting_time: Choice::parse(&record.casting_time),
+            ritual: record.ritual,
+            concentration: record.concentration,
+            verbal: record.verbal,
+            somatic: record.somatic,
+            material: record.material,
+            materials: record.materials.iter().map(Into::into).collect(),
+            duration: Choice::parse(&record.duration),
+            range: Choice::parse(&record.range),
+            area: Choice::parse(&record.area),
+            shape,
+            description: record.description.clone(),
+            at_higher_levels: record.at_higher_levels.clone(),
+            flavor_text: record.flavor_text.clone(),
+            attribution: record.attribution.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SpellMaterialRecord {
+    pub material: String,
+    pub worth: Option<String>,
+    pub consumed: bool,
+}
+
+impl Display for SpellMaterialRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.material)?;
+
+        if let Some(worth) = &self.worth {
+            write!(f, " ({worth})")?;
+        }
+
+        if self.consumed {
+            write!(f, ", consumed")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&Material> for SpellMaterialRecord {
+    fn from(material: &Material) -> Self {
+        Self {
+            material: material.material.clone(),
+            worth: material.worth.clone(),
+            consumed: material.consumed,
+        }
+    }
+}
+
+impl From<&SpellMaterialRecord> for Material {
+    fn from(record: &SpellMaterialRecord) -> Self {
+        Self {
+            material: record.material.clone(),
+            worth: record.worth.clone(),
+            consumed: record.consumed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpellShapeRecord {
+    NoShape,
+    Cone { length: i32 },
+    Cube { length: i32 },
+    Cylinder { radius: i32, height: i32 },
+    Line { width: i32, length: i32 },
+    Sphere { radius: i32 },
+}
+
+impl Display for SpellShapeRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpellShapeRecord::NoShape => write!(f, "No shape"),
+            SpellShapeRecord::Cone { length } => write!(f, "{length} ft. cone"),
+            SpellShapeRecord::Cube { length } => write!(f, "{length} ft. cube"),
+            SpellShapeRecord::Cylinder { radius, height } => {
+                write!(f, "{radius} ft. radius, {height} ft. high cylinder")
+            }
+            SpellShapeRecord::Line { width, length } => {
+                write!(f, "{length} ft. long, {width} ft. wide line")
+            }
+            SpellShapeRecord::Sphere { radius } => write!(f, "{radius} ft. radius sphere"),
+        }
+    }
+}
+
+impl From<Shape> for SpellShapeRecord {
+    fn from(shape: Shape) -> Self {
+        match shape {
+            Shape::NoShape => Self::NoShape,
+            Shape::Cone { length } => Self::Cone {
+                length: length.into(),
+            },
+            Shape::Cube { length } => Self::Cube {
+                length: length.into(),
+            },
+            Shape::Cylinder { radius, height } => Self::Cylinder {
+                radius: radius.into(),
+                height: height.into(),
+            },
+            Shape::Line { width, length } => Self::Line {
+                width: width.into(),
+                length: length.into(),
+            },
+            Shape::Sphere { radius } => Self::Sphere {
+                radius: radius.into(),
+            },
+        }
+    }
+}
+
+impl TryFrom<SpellShapeRecord> for Shape {
+    type Error = std::num::TryFromIntError;
+
+    /// This fails if a dimension is negative or too large to be a valid distance in feet.
+    fn try_from(record: SpellShapeRecord) -> Result<Self, Self::Error> {
+        let shape = match record {
+            SpellShapeRecord::NoShape => Self::NoShape,
+            SpellShapeRecord::Cone { length } => Self::Cone {
+                length: length.try_into()?,
+            },
+            SpellShapeRecord::Cube { length } => Self::Cube {
+                length: length.try_into()?,
+            },
+            SpellShapeRecord::Cylinder { radius, height } => Self::Cylinder {
+                radius: radius.try_into()?,
+                height: height.try_into()?,
+            },
+            SpellShapeRecord::Line { width, length } => Self::Line {
+                width: width.try_into()?,
+                length: length.try_into()?,
+            },
+            SpellShapeRecord::Sphere { radius } => Self::Sphere {
+                radius: radius.try_into()?,
+            },
+        };
+
+        Ok(shape)
+    }
+}
+
+/// The minimal representation of a spell, used for listings.
+#[derive(Debug, Clone)]
+pub struct SpellSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub school: String,
+    pub level: String,
+    pub range: String,
+    pub area: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[sqlx(rename_all = "snake_case")]
+pub enum RevisionKind {
+    Created,
+    Updated,
+    Deleted,
+    Restored,
+}
+
+impl Display for RevisionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            RevisionKind::Created => "Created",
+            RevisionKind::Updated => "Updated",
+            RevisionKind::Deleted => "Deleted",
+            RevisionKind::Restored => "Restored",
+        };
+
+        write!(f, "{kind}")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SpellRevision {
+    pub id: Uuid,
+    pub spell_id: Uuid,
+    pub kind: RevisionKind,
+
+    /// The name of the user who made the change.
+    pub author: String,
+
+    /// The UTC time of the revision, formatted as 'YYYY-MM-DD HH:MM:SS'.
+    pub created_at: String,
+    pub snapshot: SpellRecord,
+}
+
+/// A change of a single field between two revisions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::spell::NewSpell;
+    use types::Area;
+    use types::CastingTime;
+    use types::Distance;
+    use types::Duration;
+    use types::Level;
+    use types::RANGE_PRESETS;
+    use types::School;
+    use types::Source;
+
+    use strum::VariantArray;
+    use uuid::Uuid;
+
+    const DEFAULT_OPTIONS: &str = include_str!("../../migrations/0001_data_driven_defaults.sql");
+
+    fn built_in_spell() -> Spell {
+        Spell {
+            id: Uuid::new_v4(),
+            name: String::from("Fireball"),
+            aliases: vec![String::from("Fire ball")],
+            school: School::Evocation.into(),
+            level: Level::Third.into(),
+            source: Some(Source::PlayersHandbook.into()),
+            classes: vec![Class::Sorcerer, Class::Wizard],
+            tags: vec![String::from("damage"), String::from("fire")],
+            casting_time: CastingTime::Action.into(),
+            ritual: false,
+            concentration: false,
+            verbal: true,
+            somatic: true,
+            material: true,
+            materials: vec![Material {
+                material: String::from("A tiny ball of bat guano and sulfur"),
+                worth: None,
+                consumed: false,
+            }],
+            duration: Duration::Instantaneous.into(),
+            range: Distance::Feet(150).into(),
+            area: Area::Distance(Distance::Feet(20)).into(),
+            shape: Shape::Sphere { radius: 20 },
+            description: String::from("A bright streak flashes from your pointing finger."),
+            at_higher_levels: Some(String::from("The damage increases by 1d6.")),
+            flavor_text: None,
+            attribution: None,
+        }
+    }
+
+    fn custom_spell() -> Spell {
+        Spell {
+            id: Uuid::new_v4(),
+            name: String::from("Chronal Shift"),
+            aliases: Vec::new(),
+            school: Choice::Custom(String::from("Chronurgy")),
+            level: Choice::Custom(String::from("Tenth")),
+            source: Some(Choice::Custom(String::from(
+                "Explorer's Guide to Wildemount",
+            ))),
+            classes: vec![Class::Wizard],
+            tags: Vec::new(),
+            casting_time: Choice::Custom(String::from("1 reaction, when a creature succeeds")),
+            ritual: true,
+            concentration: true,
+            verbal: false,
+            somatic: false,
+            material: true,
+            materials: vec![Material {
+                material: String::from("An hourglass"),
+                worth: Some(String::from("50 gp")),
+                consumed: true,
+            }],
+            duration: Choice::Custom(String::from("2 rounds")),
+            range: Choice::Custom(String::from("Special")),
+            area: Choice::Custom(String::from("Multiple target")),
+            shape: Shape::Line {
+                width: 5,
+                length: u16::MAX,
+            },
+            description: String::from("You alter the flow of time around a creature."),
+            at_higher_levels: None,
+            flavor_text: Some(String::from("Time is a river.")),
+            attribution: Some(String::from("Dunamancy primer")),
+        }
+    }
+
+    /// Whether the `value` is one of the default options of the `variant`.
+    fn is_default_option(variant: &str, value: &str) -> bool {
+        let value = value.replace('\'', "''");
+
+        DEFAULT_OPTIONS.contains(&format!("'{variant}', '{value}',"))
+    }
+
+    #[test]
+    fn built_in_spell_round_trips_through_new_spell() {
+        let spell = built_in_spell();
+
+        assert_eq!(Spell::try_from(&NewSpell::from(&spell)).unwrap(), spell);
+    }
+
+    #[test]
+    fn built_in_spell_round_trips_through_record() {
+        let spell = built_in_spell();
+
+        assert_eq!(Spell::try_from(&SpellRecord::from(&spell)).unwrap(), spell);
+    }
+
+    #[test]
+    fn custom_spell_round_trips_through_new_spell() {
+        let spell = custom_spell();
+
+        assert_eq!(Spell::try_from(&NewSpell::from(&spell)).unwrap(), spell);
+    }
+
+    #[test]
+    fn custom_spell_round_trips_through_record() {
+        let spell = custom_spell();
+
+        assert_eq!(Spell::try_from(&SpellRecord::from(&spell)).unwrap(), spell);
+    }
+
+    #[test]
+    fn record_without_images_round_trips_through_spell() {
+        let mut record = SpellRecord::from(&custom_spell());
+        record.school = String::from("Evocation");
+        record.source = None;
+
+        let spell = Spell::try_from(&record).unwrap();
+
+        assert_eq!(spell.school, Choice::BuiltIn(School::Evocation));
+        assert_eq!(SpellRecord::from(&spell), record);
+    }
+
+    #[test]
+    fn record_loses_its_images_through_spell() {
+        let mut record = SpellRecord::from(&built_in_spell());
+        record.images = Box::new([Uuid::new_v4(), Uuid::new_v4()]);
+
+        let spell = Spell::try_from(&record).unwrap();
+        let converted = SpellRecord::from(&spell);
+
+        assert_eq!(
+            converted,
+            SpellRecord {
+                images: Box::default(),
+                ..record
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_class_fails_to_convert() {
+        let mut record = SpellRecord::from(&built_in_spell());
+        record.classes = Box::new([String::from("Necromancer")]);
+
+        assert!(matches!(Spell::try_from(&record), Err(Error::Decode)));
+    }
+
+    #[test]
+    fn invalid_shape_dimension_fails_to_convert() {
+        let mut record = SpellRecord::from(&built_in_spell());
+
+        record.shape = SpellShapeRecord::Cone { length: -5 };
+        assert!(matches!(Spell::try_from(&record), Err(Error::Decode)));
+
+        record.shape = SpellShapeRecord::Sphere {
+            radius: i32::from(u16::MAX) + 1,
+        };
+        assert!(matches!(Spell::try_from(&record), Err(Error::Decode)));
+    }
+
+    #[test]
+    fn built_in_options_match_default_options() {
+        fn assert_defaults<T: ToString>(variant: &str, values: &[T]) {
+            for value in values {
+                let value = value.to_string();
+
+                assert!(
+                    is_default_option(variant, &value),
+                    "'{value}' is not a default '{variant}' option"
+                );
+            }
+        }
+
+        assert_defaults("school", School::VARIANTS);
+        assert_defaults("level", Level::VARIANTS);
+        assert_defaults("source", Source::VARIANTS);
+        assert_defaults("casting_time", CastingTime::VARIANTS);
+        assert_defaults("duration", Duration::VARIANTS);
+        assert_defaults("range", &RANGE_PRESETS);
+        assert_defaults("area", &Area::PRESETS);
+    }
+
+    #[test]
+    fn default_options_parse_as_built_in() {
+        assert_eq!(
+            Choice::<Area>::parse("Multiple targets"),
+            Choice::BuiltIn(Area::MultipleTargets)
+        );
+        assert_eq!(
+            Choice::<Distance>::parse("Self"),
+            Choice::BuiltIn(Distance::Caster)
+        );
+        assert_eq!(
+            Choice::<Distance>::parse("1 mile"),
+            Choice::BuiltIn(Distance::Miles(1))
+        );
+        assert_eq!(
+            Choice::<Area>::parse("Unlimited"),
+            Choice::BuiltIn(Area::Distance(Distance::Unlimited))
+        );
+        assert_eq!(
+            Choice::<Area>::parse("Multiple target"),
+            Choice::Custom(String::from("Multiple target"))
+        );
+    }
+
+    #[test]
+    fn changes_without_previous_report_non_empty_fields() {
+        let record = SpellRecord::from(&custom_spell());
+
+        let changes = record.changes(None);
+
+        assert!(changes.iter().all(|change| change.before.is_empty()));
+        assert!(changes.iter().any(|change| change.field == "Name"));
+        assert!(!changes.iter().any(|change| change.field == "Aliases"));
+    }
+
+    #[test]
+    fn changes_report_only_changed_fields() {
+        let previous = SpellRecord::from(&built_in_spell());
+        let mut record = previous.clone();
+        record.name = String::from("Greater Fireball");
+        record.ritual = true;
+
+        assert_eq!(
+            record.changes(Some(&previous)),
+            vec![
+                FieldChange {
+                    field: "Name",
+                    before: String::from("Fireball"),
+                    after: String::from("Greater Fireball"),
+                },
+                FieldChange {
+                    field: "Ritual",
+                    before: String::from("No"),
+                    after: String::from("Yes"),
+                },
+            ]
+        );
+        assert!(record.changes(Some(&record)).is_empty());
+    }
+}
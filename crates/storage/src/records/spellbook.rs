@@ -0,0 +1,50 @@
+use uuid::Uuid;
+
+/// A spellbook as it is stored, including the spells written in it.
+#[derive(Debug, Clone)]
+pub struct SpellbookRecord {
+    pub id: Uuid,
+    pub owner: String,
+    pub class: String,
+    pub level: u8,
+    pub ability: String,
+    pub ability_score: i32,
+
+    /// The spells of the spellbook, ordered by level and name.
+    pub spells: Box<[SpellbookEntryRecord]>,
+}
+
+/// A spell written in a spellbook, along with the summary of the spell itself.
+#[derive(Debug, Clone)]
+pub struct SpellbookEntryRecord {
+    pub spell_id: Uuid,
+    pub name: String,
+    pub school: String,
+    pub level: String,
+    pub concentration: bool,
+    pub prepared: bool,
+    pub always_prepared: bool,
+}
+
+/// The minimal representation of a spellbook, used for listings.
+#[derive(Debug, Clone)]
+pub struct SpellbookSummary {
+    pub id: Uuid,
+    pub owner: String,
+    pub class: String,
+    pub level: u8,
+    pub spell_count: u32,
+}
+
+/// The resources spent by the owner of a spellbook since they last rested.
+///
+/// A spellbook without a stored tracker has spent nothing.
+#[derive(Debug, Clone)]
+pub struct SpellbookTrackerRecord {
+    pub spellbook_id: Uuid,
+
+    /// The expended slots of each level, from 1st to 9th level slots.
+    pub expended_slots: [u32; 9],
+    pub pact_slots_expended: u32,
+    pub concentration_spell_id: Option<Uuid>,
+}
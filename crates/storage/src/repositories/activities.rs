@@ -0,0 +1,19 @@
+use crate::Error;
+use crate::models::activity::NewActivity;
+use crate::records::activity::ActivityRecord;
+
+#[async_trait::async_trait]
+pub trait Activities {
+    /// Record `activity` as having just happened.
+    ///
+    /// Changes made through the other repositories are recorded on their own, so this is only
+    /// needed for what happens outside of them, like running a fight.
+    async fn record(&self, activity: NewActivity) -> Result<(), Error>;
+
+    /// Get at most `limit` of the latest activities, newest first.
+    async fn latest(&self, limit: u32) -> Result<Box<[ActivityRecord]>, Error>;
+}
+
+pub trait ActivitiesRepository {
+    fn activities(&self) -> &dyn Activities;
+}
@@ -0,0 +1,28 @@
+use crate::Error;
+use crate::records::spell::SpellSummary;
+
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait Bookmarks {
+    /// Mark the spell with `spell_id` as favourite, or unmark it if `favourite` is false.
+    async fn set_favourite(&self, spell_id: Uuid, favourite: bool) -> Result<(), Error>;
+
+    /// Whether the spell with `spell_id` is marked as favourite.
+    async fn is_favourite(&self, spell_id: Uuid) -> Result<bool, Error>;
+
+    /// Get the favourite spells, ordered by name.
+    async fn favourites(&self) -> Result<Box<[SpellSummary]>, Error>;
+
+    /// Record that the spell with `spell_id` was just opened.
+    ///
+    /// Only the most recently opened spells are kept, older entries are pruned.
+    async fn record_view(&self, spell_id: Uuid) -> Result<(), Error>;
+
+    /// Get at most `limit` of the most recently opened spells, newest first.
+    async fn recents(&self, limit: u32) -> Result<Box<[SpellSummary]>, Error>;
+}
+
+pub trait BookmarksRepository {
+    fn bookmarks(&self) -> &dyn Bookmarks;
+}
@@ -0,0 +1,21 @@
+use crate::Error;
+use crate::models::combat::NewCombat;
+use crate::records::combat::CombatRecord;
+
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait Combats {
+    /// Create the combat, or replace the combat with the same id as `combat`.
+    async fn save(&self, combat: NewCombat) -> Result<(), Error>;
+
+    /// Delete the combat with `id`, which is not an error if the combat does not exist.
+    async fn delete(&self, id: Uuid) -> Result<(), Error>;
+
+    /// Get every combat, oldest first, leaving out the combats that fail to decode.
+    async fn list(&self) -> Result<Box<[CombatRecord]>, Error>;
+}
+
+pub trait CombatsRepository {
+    fn combats(&self) -> &dyn Combats;
+}
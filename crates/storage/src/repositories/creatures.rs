@@ -0,0 +1,27 @@
+use crate::Error;
+use crate::models::creature::NewCreature;
+use crate::records::creature::CreatureRecord;
+use crate::records::creature::CreatureSummary;
+
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait Creatures {
+    async fn create(&self, new_creature: NewCreature) -> Result<(), Error>;
+
+    /// Update the creature with the same id as `creature`, including its features.
+    async fn update(&self, creature: NewCreature) -> Result<(), Error>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error>;
+
+    async fn get(&self, id: Uuid) -> Result<CreatureRecord, Error>;
+
+    async fn list(&self) -> Result<Box<[CreatureSummary]>, Error>;
+
+    /// The number of creatures there are.
+    async fn count(&self) -> Result<u32, Error>;
+}
+
+pub trait CreaturesRepository {
+    fn creatures(&self) -> &dyn Creatures;
+}
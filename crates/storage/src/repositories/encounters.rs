@@ -0,0 +1,27 @@
+use crate::Error;
+use crate::models::encounter::NewEncounter;
+use crate::records::encounter::EncounterRecord;
+use crate::records::encounter::EncounterSummary;
+
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait Encounters {
+    async fn create(&self, new_encounter: NewEncounter) -> Result<(), Error>;
+
+    /// Update the encounter with the same id as `encounter`, including its party and creatures.
+    async fn update(&self, encounter: NewEncounter) -> Result<(), Error>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error>;
+
+    async fn get(&self, id: Uuid) -> Result<EncounterRecord, Error>;
+
+    async fn list(&self) -> Result<Box<[EncounterSummary]>, Error>;
+
+    /// The number of encounters there are.
+    async fn count(&self) -> Result<u32, Error>;
+}
+
+pub trait EncountersRepository {
+    fn encounters(&self) -> &dyn Encounters;
+}
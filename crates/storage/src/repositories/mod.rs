@@ -1,10 +1,39 @@
+pub mod activities;
+pub mod bookmarks;
+pub mod combats;
+pub mod creatures;
+pub mod encounters;
 pub mod options;
+pub mod spell_drafts;
+pub mod spellbooks;
 pub mod spells;
 
+use activities::ActivitiesRepository;
+use bookmarks::BookmarksRepository;
+use combats::CombatsRepository;
+use creatures::CreaturesRepository;
+use encounters::EncountersRepository;
 use options::OptionsRepository;
+use spell_drafts::SpellDraftsRepository;
+use spellbooks::SpellbooksRepository;
 use spells::SpellsRepository;
 
 use std::fmt::Debug;
 
 /// A super-trait marker requiring all repositories to be implemented.
-pub trait Repository: OptionsRepository + SpellsRepository + Debug + Send + Sync + 'static {}
+pub trait Repository:
+    ActivitiesRepository
+    + BookmarksRepository
+    + CombatsRepository
+    + CreaturesRepository
+    + EncountersRepository
+    + OptionsRepository
+    + SpellDraftsRepository
+    + SpellbooksRepository
+    + SpellsRepository
+    + Debug
+    + Send
+    + Sync
+    + 'static
+{
+}
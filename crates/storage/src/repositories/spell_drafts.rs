@@ -0,0 +1,23 @@
+use crate::Error;
+use crate::models::draft::NewSpellDraft;
+use crate::records::draft::SpellDraftRecord;
+
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait SpellDrafts {
+    /// Create the draft, or replace the draft with the same id as `draft`.
+    ///
+    /// The images of the draft are kept if `draft` has none set.
+    async fn save(&self, draft: NewSpellDraft) -> Result<(), Error>;
+
+    /// Delete the draft with `id`, which is not an error if the draft does not exist.
+    async fn delete(&self, id: Uuid) -> Result<(), Error>;
+
+    /// Get every draft, oldest first.
+    async fn list(&self) -> Result<Box<[SpellDraftRecord]>, Error>;
+}
+
+pub trait SpellDraftsRepository {
+    fn spell_drafts(&self) -> &dyn SpellDrafts;
+}
@@ -0,0 +1,36 @@
+use crate::Error;
+use crate::models::spellbook::NewSpellbook;
+use crate::models::spellbook::NewSpellbookTracker;
+use crate::records::spellbook::SpellbookRecord;
+use crate::records::spellbook::SpellbookSummary;
+use crate::records::spellbook::SpellbookTrackerRecord;
+
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait Spellbooks {
+    async fn create(&self, new_spellbook: NewSpellbook) -> Result<(), Error>;
+
+    /// Update the spellbook with the same id as `spellbook`, including its spells.
+    async fn update(&self, spellbook: NewSpellbook) -> Result<(), Error>;
+
+    /// Delete the spellbook with `id`, along with its tracker.
+    async fn delete(&self, id: Uuid) -> Result<(), Error>;
+
+    async fn get(&self, id: Uuid) -> Result<SpellbookRecord, Error>;
+
+    async fn list(&self) -> Result<Box<[SpellbookSummary]>, Error>;
+
+    /// The number of spellbooks there are.
+    async fn count(&self) -> Result<u32, Error>;
+
+    /// Get the tracker of the spellbook with `spellbook_id`.
+    async fn tracker(&self, spellbook_id: Uuid) -> Result<SpellbookTrackerRecord, Error>;
+
+    /// Create the tracker, or replace the tracker of the same spellbook as `tracker`.
+    async fn save_tracker(&self, tracker: NewSpellbookTracker) -> Result<(), Error>;
+}
+
+pub trait SpellbooksRepository {
+    fn spellbooks(&self) -> &dyn Spellbooks;
+}
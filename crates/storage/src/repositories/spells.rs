@@ -1,9 +1,46 @@
 use crate::Error;
 use crate::models::spell::NewSpell;
+use crate::records::ImageRecord;
+use crate::records::spell::SpellRecord;
+use crate::records::spell::SpellRevision;
+use crate::records::spell::SpellSummary;
+
+use types::Class;
+
+use uuid::Uuid;
 
 #[async_trait::async_trait]
 pub trait Spells {
     async fn create(&self, new_spell: NewSpell) -> Result<(), Error>;
+
+    /// Update the spell with the same id as `spell`.
+    async fn update(&self, spell: NewSpell) -> Result<(), Error>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), Error>;
+
+    async fn get(&self, id: Uuid) -> Result<SpellRecord, Error>;
+
+    async fn list(&self) -> Result<Box<[SpellSummary]>, Error>;
+
+    /// The number of spells there are.
+    async fn count(&self) -> Result<u32, Error>;
+
+    /// List the spells that are available to `class`.
+    async fn list_for_class(&self, class: Class) -> Result<Box<[SpellSummary]>, Error>;
+
+    /// Get the images of the spell with `id`, in the order they were added.
+    async fn images(&self, id: Uuid) -> Result<Box<[ImageRecord]>, Error>;
+
+    /// Get the revisions of the spell with `id`, newest first.
+    async fn revisions(&self, id: Uuid) -> Result<Box<[SpellRevision]>, Error>;
+
+    /// Get the revision each deleted spell was deleted in, newest first.
+    async fn deleted(&self) -> Result<Box<[SpellRevision]>, Error>;
+
+    /// Restore the spell to the snapshot of the revision with `revision_id`.
+    ///
+    /// This works for deleted spells as well, in which case the spell is recreated.
+    async fn restore(&self, revision_id: Uuid) -> Result<(), Error>;
 }
 
 pub trait SpellsRepository {
@@ -24,6 +24,9 @@ pub enum ContainerClass {
     Info,
     /// Used for error tooltips.
     Error,
+
+    /// Dims the content behind a dialog.
+    Backdrop,
 }
 
 impl Catalog for Theme {
@@ -75,6 +78,13 @@ impl Catalog for Theme {
             ContainerClass::Surface => (Some(self.surface), None),
             ContainerClass::Outlined => (None, Some(self.primary)),
             ContainerClass::Interaction => (Some(self.interaction), None),
+            ContainerClass::Backdrop => (
+                Some(Color {
+                    a: 0.8,
+                    ..self.background
+                }),
+                None,
+            ),
             ContainerClass::Ghost | ContainerClass::Info | ContainerClass::Error => unreachable!(
                 "the normal, info, and error case are handled in guard clauses before the match"
             ),
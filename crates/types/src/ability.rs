@@ -0,0 +1,34 @@
+use strum::AsRefStr;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, Hash, AsRefStr)]
+pub enum Ability {
+    Strength,
+    Dexterity,
+    Constitution,
+    Intelligence,
+    Wisdom,
+    Charisma,
+}
+
+impl Ability {
+    /// The modifier of an ability `score`, which is rounded down.
+    pub fn modifier(score: i32) -> i32 {
+        (score - 10).div_euclid(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifier_rounds_down() {
+        assert_eq!(Ability::modifier(10), 0);
+        assert_eq!(Ability::modifier(17), 3);
+        assert_eq!(Ability::modifier(9), -1);
+        assert_eq!(Ability::modifier(1), -5);
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// A value of a data-driven option, which is either one of the built-in values of `T`,
+/// or a custom value defined by the user.
+///
+/// Built-in values are written exactly as their defaults in the options table,
+/// so a stored value is parsed back into the same built-in value it was written from.
+///
+/// Built-in values are ordered before custom values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Choice<T> {
+    BuiltIn(T),
+    Custom(String),
+}
+
+impl<T: FromStr> Choice<T> {
+    /// Parse a stored `value`, falling back to a custom value if it is not a built-in value.
+    pub fn parse(value: &str) -> Self {
+        match value.parse() {
+            Ok(built_in) => Self::BuiltIn(built_in),
+            Err(_) => Self::Custom(String::from(value)),
+        }
+    }
+}
+
+impl<T> From<T> for Choice<T> {
+    fn from(built_in: T) -> Self {
+        Self::BuiltIn(built_in)
+    }
+}
+
+impl<T: Display> Display for Choice<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Choice::BuiltIn(built_in) => built_in.fmt(f),
+            Choice::Custom(custom) => f.write_str(custom),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::Ability;
+use crate::SavingThrow;
+
+use uuid::Uuid;
+
+/// A spell a combatant concentrates on, which ends the effects linked to it once broken.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Concentration {
+    pub id: Uuid,
+
+    /// The name of the spell.
+    pub spell: String,
+}
+
+impl Concentration {
+    pub fn new(spell: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            spell,
+        }
+    }
+
+    /// The Constitution saving throw to keep concentrating after taking `damage`, against
+    /// DC 10 or half the damage, whichever is higher.
+    pub fn saving_throw(damage: u32) -> SavingThrow {
+        SavingThrow {
+            ability: Ability::Constitution,
+            difficulty_class: (damage / 2).max(10),
+        }
+    }
+}
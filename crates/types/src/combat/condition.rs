@@ -0,0 +1,148 @@
+use crate::Ability;
+use crate::Choice;
+use crate::SpellEffect;
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+use uuid::Uuid;
+
+/// The standard conditions that alter what a combatant can do.
+#[derive(
+    Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Condition {
+    Blinded,
+    Charmed,
+    Deafened,
+    Exhaustion,
+    Frightened,
+    Grappled,
+    Incapacitated,
+    Invisible,
+    Paralyzed,
+    Petrified,
+    Poisoned,
+    Prone,
+    Restrained,
+    Stunned,
+    Unconscious,
+}
+
+/// How long a condition lasts once applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionDuration {
+    /// Lasts until it is removed by hand.
+    Indefinite,
+
+    /// Lasts for a number of rounds, counted down as each turn of the affected combatant ends.
+    Rounds(u32),
+
+    /// Lasts until the end of the next turn of the combatant with the id, like the one who
+    /// caused it.
+    UntilEndOfTurn(Uuid),
+}
+
+/// A saving throw against a difficulty class, like "DC 13 Wisdom".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SavingThrow {
+    pub ability: Ability,
+    pub difficulty_class: u32,
+}
+
+impl Display for SavingThrow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DC {} {}", self.difficulty_class, self.ability)
+    }
+}
+
+/// A condition affecting a combatant, with what ends it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedCondition {
+    pub id: Uuid,
+    pub condition: Choice<Condition>,
+    pub duration: ConditionDuration,
+
+    /// The saving throw the affected combatant repeats at the end of each of its turns to end
+    /// the condition.
+    pub save: Option<SavingThrow>,
+
+    /// The id of the concentration sustaining the condition, which ends it once broken.
+    pub concentration: Option<Uuid>,
+
+    /// Whether the end of the turn during which the condition was applied is still to come,
+    /// which does not count toward the duration.
+    pub applied_this_turn: bool,
+}
+
+impl AppliedCondition {
+    pub fn new(
+        condition: Choice<Condition>,
+        duration: ConditionDuration,
+        save: Option<SavingThrow>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            condition,
+            duration,
+            save,
+            concentration: None,
+            applied_this_turn: false,
+        }
+    }
+
+    /// Link the condition to the concentration with `id`, so that it ends when it is broken.
+    pub fn with_concentration(mut self, id: Uuid) -> Self {
+        self.concentration = Some(id);
+        self
+    }
+
+    /// The combatant whose turns count toward the duration, if the condition expires at all.
+    pub(super) fn timekeeper(&self, affected: Uuid) -> Option<Uuid> {
+        match self.duration {
+            ConditionDuration::Indefinite => None,
+            ConditionDuration::Rounds(_) => Some(affected),
+            ConditionDuration::UntilEndOfTurn(id) => Some(id),
+        }
+    }
+
+    /// Count the end of the turn of the combatant with `ending` toward the duration of the
+    /// condition on the combatant with `affected`.
+    ///
+    /// Returns whether the condition expired.
+    pub(super) fn end_turn(&mut self, affected: Uuid, ending: Uuid) -> bool {
+        if self.timekeeper(affected) != Some(ending) {
+            return false;
+        }
+
+        if std::mem::take(&mut self.applied_this_turn) {
+            return false;
+        }
+
+        match &mut self.duration {
+            ConditionDuration::Rounds(rounds) => {
+                *rounds = rounds.saturating_sub(1);
+                *rounds == 0
+            }
+            _ => true,
+        }
+    }
+}
+
+/// What happened to the conditions and spell effects when a turn passed on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnEnd {
+    /// The id of the combatant whose turn ended.
+    pub combatant: Uuid,
+
+    /// The conditions that expired, with the id of the combatant each affected.
+    pub expired: Vec<(Uuid, AppliedCondition)>,
+
+    /// The conditions the combatant whose turn ended may now end with a saving throw.
+    pub saves: Vec<AppliedCondition>,
+
+    /// The spell effects that ended as the next turn started.
+    pub ended_effects: Vec<SpellEffect>,
+}
@@ -0,0 +1,211 @@
+use crate::ChallengeRating;
+
+use strum::Display;
+
+/// The XP thresholds of a character of each level from 1 to 20, as easy, medium, hard and deadly.
+const THRESHOLDS: [[u32; 4]; 20] = [
+    [25, 50, 75, 100],
+    [50, 100, 150, 200],
+    [75, 150, 225, 400],
+    [125, 250, 375, 500],
+    [250, 500, 750, 1100],
+    [300, 600, 900, 1400],
+    [350, 750, 1100, 1700],
+    [450, 900, 1400, 2100],
+    [550, 1100, 1600, 2400],
+    [600, 1200, 1900, 2800],
+    [800, 1600, 2400, 3600],
+    [1000, 2000, 3000, 4500],
+    [1100, 2200, 3400, 5100],
+    [1250, 2500, 3800, 5700],
+    [1400, 2800, 4300, 6400],
+    [1600, 3200, 4800, 7200],
+    [2000, 3900, 5900, 8800],
+    [2100, 4200, 6300, 9500],
+    [2400, 4900, 7300, 10900],
+    [2800, 5700, 8500, 12700],
+];
+
+/// The multipliers of the XP of an encounter in halves, from ×0.5 to ×5.
+const MULTIPLIERS: [u32; 8] = [1, 2, 3, 4, 5, 6, 8, 10];
+
+/// How hard an encounter is for a party.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    /// Below the easy threshold of the party.
+    Trivial,
+    Easy,
+    Medium,
+    Hard,
+    Deadly,
+}
+
+/// The XP an encounter must be worth to reach each difficulty for a party.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Thresholds {
+    pub easy: u32,
+    pub medium: u32,
+    pub hard: u32,
+    pub deadly: u32,
+}
+
+impl Thresholds {
+    /// The thresholds of a single character of `level`, which is clamped between 1 and 20.
+    pub fn for_level(level: u8) -> Self {
+        let index = usize::from(level.clamp(1, 20)) - 1;
+        let [easy, medium, hard, deadly] = THRESHOLDS[index];
+
+        Self {
+            easy,
+            medium,
+            hard,
+            deadly,
+        }
+    }
+
+    /// The thresholds of a party, adding up those of its characters of `levels`.
+    pub fn for_party(levels: &[u8]) -> Self {
+        levels.iter().map(|&level| Self::for_level(level)).fold(
+            Self::default(),
+            |party, character| Self {
+                easy: party.easy + character.easy,
+                medium: party.medium + character.medium,
+                hard: party.hard + character.hard,
+                deadly: party.deadly + character.deadly,
+            },
+        )
+    }
+
+    /// The adjusted XP an encounter of `difficulty` is worth, from the threshold of the
+    /// difficulty up to the next one, exclusive.
+    ///
+    /// Deadly encounters are capped at one and a half times the deadly threshold, as anything
+    /// beyond is hardly a fight.
+    pub fn bounds(&self, difficulty: Difficulty) -> (u32, u32) {
+        match difficulty {
+            Difficulty::Trivial => (1, self.easy),
+            Difficulty::Easy => (self.easy, self.medium),
+            Difficulty::Medium => (self.medium, self.hard),
+            Difficulty::Hard => (self.hard, self.deadly),
+            Difficulty::Deadly => (self.deadly, self.deadly.saturating_mul(3) / 2),
+        }
+    }
+
+    /// The difficulty of an encounter worth `adjusted_experience`.
+    pub fn rate(&self, adjusted_experience: u32) -> Difficulty {
+        match adjusted_experience {
+            0 => Difficulty::Trivial,
+            xp if xp >= self.deadly => Difficulty::Deadly,
+            xp if xp >= self.hard => Difficulty::Hard,
+            xp if xp >= self.medium => Difficulty::Medium,
+            xp if xp >= self.easy => Difficulty::Easy,
+            _ => Difficulty::Trivial,
+        }
+    }
+}
+
+/// The XP budget of an encounter, weighing what its creatures are worth against the party.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EncounterRating {
+    /// The XP awarded for defeating every creature.
+    pub experience: u32,
+
+    /// The multiplier for the number of creatures, as more creatures make for a harder fight.
+    pub multiplier: f32,
+
+    /// The XP weighed against the thresholds, which is the experience times the multiplier.
+    pub adjusted_experience: u32,
+    pub thresholds: Thresholds,
+    pub difficulty: Difficulty,
+}
+
+impl EncounterRating {
+    /// Rate an encounter of `creatures`, as challenge ratings with their counts, against a party
+    /// of characters of `levels`.
+    ///
+    /// The multiplier goes up a step for parties of fewer than 3 characters, and down a step for
+    /// parties of 6 or more.
+    pub fn new(levels: &[u8], creatures: impl IntoIterator<Item = (ChallengeRating, u32)>) -> Self {
+        let (experience, count) = creatures.into_iter().fold(
+            (0u32, 0u32),
+            |(experience, count), (challenge_rating, creatures)| {
+                (
+                    experience
+                        .saturating_add(challenge_rating.experience().saturating_mul(creatures)),
+                    count + creatures,
+                )
+            },
+        );
+
+        let step: usize = match count {
+            0 | 1 => 1,
+            2 => 2,
+            3..=6 => 3,
+            7..=10 => 4,
+            11..=14 => 5,
+            _ => 6,
+        };
+        let step = match levels.len() {
+            0 => step,
+            1 | 2 => step + 1,
+            3..=5 => step,
+            _ => step - 1,
+        };
+        let halves = MULTIPLIERS[step];
+
+        let adjusted_experience = experience.saturating_mul(halves) / 2;
+        let thresholds = Thresholds::for_party(levels);
+
+        Self {
+            experience,
+            multiplier: halves as f32 / 2.0,
+            adjusted_experience,
+            thresholds,
+            difficulty: thresholds.rate(adjusted_experience),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(label: &str) -> ChallengeRating {
+        label.parse().unwrap()
+    }
+
+    #[test]
+    fn party_thresholds_add_up() {
+        let thresholds = Thresholds::for_party(&[3, 3, 3, 2]);
+
+        assert_eq!(thresholds.easy, 275);
+        assert_eq!(thresholds.medium, 550);
+        assert_eq!(thresholds.hard, 825);
+        assert_eq!(thresholds.deadly, 1400);
+    }
+
+    #[test]
+    fn more_creatures_weigh_more() {
+        // Four goblins and a bugbear against four 3rd level characters.
+        let creatures = [(rating("1/4"), 4), (rating("1"), 1)];
+        let rating = EncounterRating::new(&[3, 3, 3, 3], creatures);
+
+        assert_eq!(rating.experience, 400);
+        assert_eq!(rating.multiplier, 2.0);
+        assert_eq!(rating.adjusted_experience, 800);
+        assert_eq!(rating.difficulty, Difficulty::Medium);
+    }
+
+    #[test]
+    fn party_size_shifts_the_multiplier() {
+        let ogre = [(ChallengeRating::ZERO, 0), (rating("2"), 1)];
+
+        assert_eq!(EncounterRating::new(&[5, 5], ogre).multiplier, 1.5);
+        assert_eq!(EncounterRating::new(&[5; 4], ogre).multiplier, 1.0);
+        assert_eq!(EncounterRating::new(&[5; 6], ogre).multiplier, 0.5);
+        assert_eq!(
+            EncounterRating::new(&[1], []).difficulty,
+            Difficulty::Trivial
+        );
+    }
+}
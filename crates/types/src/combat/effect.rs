@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+/// The effect of a spell cast in combat, which lasts for a number of rounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellEffect {
+    pub id: Uuid,
+
+    /// The name of the spell.
+    pub spell: String,
+
+    /// The id of the combatant who cast the spell.
+    pub caster: Uuid,
+
+    /// The ids of the combatants the spell affects.
+    pub targets: Vec<Uuid>,
+
+    /// The rounds left, counted down as each turn of the caster starts.
+    pub rounds: u32,
+
+    /// The id of the concentration sustaining the effect, if the spell requires it.
+    pub concentration: Option<Uuid>,
+}
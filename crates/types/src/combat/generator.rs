@@ -0,0 +1,210 @@
+use crate::ChallengeRating;
+use crate::Difficulty;
+use crate::EncounterRating;
+use crate::Random;
+
+use uuid::Uuid;
+
+/// The number of random encounters tried before settling on the one closest to the difficulty.
+const ATTEMPTS: usize = 250;
+
+/// The most different creatures a generated encounter has.
+const MAX_SLOTS: u32 = 3;
+
+/// The most of a single creature a generated encounter has.
+const MAX_COUNT: u32 = 6;
+
+/// A creature the generator can pick, known by the id of its stat block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    pub id: Uuid,
+    pub challenge_rating: ChallengeRating,
+}
+
+/// A creature of a generated encounter, with how many of it take part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncounterSlot {
+    pub creature: Candidate,
+    pub count: u32,
+}
+
+/// Generates encounters of random creatures that make for a fight of a difficulty for a party.
+pub struct EncounterGenerator<'a> {
+    levels: &'a [u8],
+    difficulty: Difficulty,
+
+    /// The creatures to pick from, already narrowed down to those that fit the encounter.
+    candidates: &'a [Candidate],
+}
+
+impl<'a> EncounterGenerator<'a> {
+    pub fn new(levels: &'a [u8], difficulty: Difficulty, candidates: &'a [Candidate]) -> Self {
+        Self {
+            levels,
+            difficulty,
+            candidates,
+        }
+    }
+
+    /// Generate an encounter of different creatures that fits the difficulty, or the one that
+    /// comes closest to it if none of the attempts fit.
+    ///
+    /// Returns no slots if there are no candidates.
+    pub fn generate(&self, random: &mut Random) -> Vec<EncounterSlot> {
+        let mut best = (u32::MAX, Vec::new());
+
+        let most_slots = MAX_SLOTS.min(u32::try_from(self.candidates.len()).unwrap_or(MAX_SLOTS));
+
+        for _ in 0..ATTEMPTS {
+            let mut slots: Vec<EncounterSlot> = Vec::new();
+
+            for _ in 0..random.between(1..=most_slots) {
+                if let Some(slot) = self.random_slot(&slots, random) {
+                    slots.push(slot);
+                }
+            }
+
+            let distance = self.distance(&slots);
+
+            if distance < best.0 {
+                best = (distance, slots);
+            }
+
+            if distance == 0 {
+                break;
+            }
+        }
+
+        best.1
+    }
+
+    /// Pick another creature for the slot at `index`, keeping the other slots as they are.
+    ///
+    /// Returns the slots unchanged if every candidate is already in another slot.
+    pub fn reroll(
+        &self,
+        slots: &[EncounterSlot],
+        index: usize,
+        random: &mut Random,
+    ) -> Vec<EncounterSlot> {
+        let mut best = (u32::MAX, slots.to_vec());
+
+        if index >= slots.len() {
+            return best.1;
+        }
+
+        let others: Vec<_> = slots
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, slot)| *slot)
+            .collect();
+
+        for _ in 0..ATTEMPTS {
+            let Some(slot) = self.random_slot(&others, random) else {
+                break;
+            };
+
+            let mut rerolled = slots.to_vec();
+            rerolled[index] = slot;
+
+            let distance = self.distance(&rerolled);
+
+            // The slot always changes if it can, even if the encounter ends up further off.
+            if slot.creature != slots[index].creature && distance < best.0 {
+                best = (distance, rerolled);
+            }
+
+            if best.0 == 0 {
+                break;
+            }
+        }
+
+        best.1
+    }
+
+    /// A random candidate in a random number that is not already in one of `taken`.
+    fn random_slot(&self, taken: &[EncounterSlot], random: &mut Random) -> Option<EncounterSlot> {
+        let available: Vec<_> = self
+            .candidates
+            .iter()
+            .filter(|candidate| !taken.iter().any(|slot| slot.creature.id == candidate.id))
+            .collect();
+
+        let creature = **random.pick(&available)?;
+
+        Some(EncounterSlot {
+            creature,
+            count: random.between(1..=MAX_COUNT),
+        })
+    }
+
+    /// How far the adjusted XP of `slots` is from the bounds of the difficulty, 0 if within.
+    fn distance(&self, slots: &[EncounterSlot]) -> u32 {
+        let creatures = slots
+            .iter()
+            .map(|slot| (slot.creature.challenge_rating, slot.count));
+        let rating = EncounterRating::new(self.levels, creatures);
+
+        let (lower, upper) = rating.thresholds.bounds(self.difficulty);
+
+        if rating.adjusted_experience < lower {
+            lower - rating.adjusted_experience
+        } else if rating.adjusted_experience >= upper {
+            rating.adjusted_experience - upper + 1
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates() -> Vec<Candidate> {
+        ["1/8", "1/4", "1/2", "1", "2", "3", "5", "8"]
+            .into_iter()
+            .map(|label| Candidate {
+                id: Uuid::new_v4(),
+                challenge_rating: label.parse().unwrap(),
+            })
+            .collect()
+    }
+
+    fn rate(levels: &[u8], slots: &[EncounterSlot]) -> Difficulty {
+        let creatures = slots
+            .iter()
+            .map(|slot| (slot.creature.challenge_rating, slot.count));
+
+        EncounterRating::new(levels, creatures).difficulty
+    }
+
+    #[test]
+    fn generated_encounters_fit_and_repeat() {
+        let candidates = candidates();
+        let levels = [3, 3, 3, 3];
+        let generator = EncounterGenerator::new(&levels, Difficulty::Hard, &candidates);
+
+        let first = generator.generate(&mut Random::new(11));
+        let second = generator.generate(&mut Random::new(11));
+
+        assert_eq!(first, second);
+        assert_eq!(rate(&levels, &first), Difficulty::Hard);
+    }
+
+    #[test]
+    fn reroll_keeps_other_slots() {
+        let candidates = candidates();
+        let levels = [5, 5, 5, 5];
+        let generator = EncounterGenerator::new(&levels, Difficulty::Medium, &candidates);
+        let mut random = Random::new(3);
+
+        let slots = generator.generate(&mut random);
+        let last = slots.len() - 1;
+        let rerolled = generator.reroll(&slots, last, &mut random);
+
+        assert_eq!(rerolled[..last], slots[..last]);
+        assert_ne!(rerolled[last].creature, slots[last].creature);
+    }
+}
@@ -0,0 +1,92 @@
+use strum::Display;
+
+/// The hit points of a combatant, including the temporary ones that are lost first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HitPoints {
+    pub current: u32,
+    pub maximum: u32,
+    pub temporary: u32,
+}
+
+/// How close a combatant is to dying.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum Health {
+    Healthy,
+
+    /// At half of the maximum hit points or less.
+    Bloodied,
+
+    /// At 0 hit points, but still alive.
+    Unconscious,
+    Dead,
+}
+
+impl HitPoints {
+    /// Hit points at their maximum, without temporary ones.
+    pub fn new(maximum: u32) -> Self {
+        Self {
+            current: maximum,
+            maximum,
+            temporary: 0,
+        }
+    }
+
+    /// Lose `damage`, taking it from the temporary hit points first.
+    ///
+    /// Returns the damage that remains after dropping to 0 hit points.
+    pub fn lose(&mut self, damage: u32) -> u32 {
+        let absorbed = damage.min(self.temporary);
+        self.temporary -= absorbed;
+
+        let damage = damage - absorbed;
+        let lost = damage.min(self.current);
+        self.current -= lost;
+
+        damage - lost
+    }
+
+    /// Regain `amount` hit points, up to the maximum.
+    pub fn heal(&mut self, amount: u32) {
+        self.current = self.current.saturating_add(amount).min(self.maximum);
+    }
+
+    /// Gain `amount` temporary hit points, which replace the current ones instead of adding up.
+    pub fn grant_temporary(&mut self, amount: u32) {
+        self.temporary = self.temporary.max(amount);
+    }
+
+    pub fn is_bloodied(&self) -> bool {
+        self.current > 0 && self.current * 2 <= self.maximum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temporary_hit_points_are_lost_first() {
+        let mut hit_points = HitPoints::new(20);
+        hit_points.grant_temporary(5);
+        hit_points.grant_temporary(3);
+
+        assert_eq!(hit_points.lose(8), 0);
+        assert_eq!(hit_points.temporary, 0);
+        assert_eq!(hit_points.current, 17);
+    }
+
+    #[test]
+    fn remaining_damage_is_returned_at_zero() {
+        let mut hit_points = HitPoints::new(10);
+
+        assert_eq!(hit_points.lose(4), 0);
+        assert!(!hit_points.is_bloodied());
+        assert_eq!(hit_points.lose(1), 0);
+        assert!(hit_points.is_bloodied());
+        assert_eq!(hit_points.lose(12), 7);
+        assert_eq!(hit_points.current, 0);
+
+        hit_points.heal(30);
+        assert_eq!(hit_points.current, 10);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::Choice;
+use crate::Combatant;
+use crate::CombatantKind;
+use crate::Condition;
+use crate::DamageType;
+
+use uuid::Uuid;
+
+/// Something that happened during a fight.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CombatEvent {
+    Started,
+    TurnStarted {
+        combatant: Uuid,
+    },
+
+    /// The combatant with `target` lost `amount` hit points, during the turn of `source`.
+    Damaged {
+        target: Uuid,
+        source: Option<Uuid>,
+        amount: u32,
+        damage_type: Option<DamageType>,
+    },
+    Healed {
+        target: Uuid,
+        amount: u32,
+    },
+    ConditionApplied {
+        target: Uuid,
+        condition: Choice<Condition>,
+    },
+    ConditionEnded {
+        target: Uuid,
+        condition: Choice<Condition>,
+    },
+    SpellCast {
+        caster: Uuid,
+        spell: String,
+    },
+
+    /// A character dropped to 0 hit points without dying.
+    Downed {
+        combatant: Uuid,
+    },
+    Died {
+        combatant: Uuid,
+    },
+    Ended,
+}
+
+/// An event of the log, with the round it happened in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub round: u32,
+    pub event: CombatEvent,
+}
+
+/// Someone who took part in a fight, kept in the log even after leaving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Participant {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: CombatantKind,
+
+    /// The experience points awarded for defeating the participant.
+    pub experience: u32,
+}
+
+/// The events of a fight in the order they happened, along with who took part in it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CombatLog {
+    pub participants: Vec<Participant>,
+    pub entries: Vec<LogEntry>,
+}
+
+/// The damage and healing of a participant over a whole fight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticipantSummary {
+    pub id: Uuid,
+    pub name: String,
+    pub damage_dealt: u32,
+    pub damage_taken: u32,
+    pub healing_received: u32,
+}
+
+/// The outcome of a fight, worked out from its log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombatSummary {
+    pub rounds: u32,
+    pub participants: Vec<ParticipantSummary>,
+
+    /// The experience points of the creatures that died.
+    pub experience: u32,
+
+    /// The share of the experience points of each character, if any took part.
+    pub experience_per_character: Option<u32>,
+}
+
+impl Participant {
+    pub fn new(combatant: &Combatant) -> Self {
+        Self {
+            id: combatant.id,
+            name: combatant.name.clone(),
+            kind: combatant.kind,
+            experience: combatant.experience,
+        }
+    }
+}
+
+impl CombatLog {
+    /// A log of a fight between `combatants`, which has yet to start.
+    pub fn new<'a>(combatants: impl IntoIterator<Item = &'a Combatant>) -> Self {
+        Self {
+            participants: combatants.into_iter().map(Participant::new).collect(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Take `combatant` into account, like when it joins the fight after it started.
+    pub fn join(&mut self, combatant: &Combatant) {
+        if !self
+            .participants
+            .iter()
+            .any(|participant| participant.id == combatant.id)
+        {
+            self.participants.push(Participant::new(combatant));
+        }
+    }
+
+    pub fn push(&mut self, round: u32, event: CombatEvent) {
+        self.entries.push(LogEntry { round, event });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The name of the participant with `id`, which is blank if it never took part.
+    pub fn name(&self, id: Uuid) -> &str {
+        self.participants
+            .iter()
+            .find(|participant| participant.id == id)
+            .map_or("", |participant| participant.name.as_str())
+    }
+
+    pub fn summary(&self) -> CombatSummary {
+        let mut participants: Vec<_> = self
+            .participants
+            .iter()
+            .map(|participant| ParticipantSummary {
+                id: participant.id,
+                name: participant.name.clone(),
+                damage_dealt: 0,
+                damage_taken: 0,
+                healing_received: 0,
+            })
+            .collect();
+
+        // The summaries are in the same order as the participants.
+        let index = |id: Uuid| {
+            self.participants
+                .iter()
+                .position(|participant| participant.id == id)
+        };
+        let mut experience = 0;
+
+        for entry in &self.entries {
+            match &entry.event {
+                CombatEvent::Damaged {
+                    target,
+                    source,
+                    amount,
+                    ..
+                } => {
+                    if let Some(index) = index(*target) {
+                        participants[index].damage_taken += amount;
+                    }
+                    if let Some(index) = source.and_then(index) {
+                        participants[index].damage_dealt += amount;
+                    }
+                }
+                CombatEvent::Healed { target, amount } => {
+                    if let Some(index) = index(*target) {
+                        participants[index].healing_received += amount;
+                    }
+                }
+                CombatEvent::Died { combatant } => {
+                    if let Some(index) = index(*combatant) {
+                        experience += self.participants[index].experience;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let characters = self
+            .participants
+            .iter()
+            .filter(|participant| participant.kind == CombatantKind::Character)
+            .count();
+
+        CombatSummary {
+            rounds: self
+                .entries
+                .iter()
+                .map(|entry| entry.round)
+                .max()
+                .unwrap_or(0),
+            participants,
+            experience,
+            experience_per_character: u32::try_from(characters)
+                .ok()
+                .filter(|characters| *characters > 0)
+                .map(|characters| experience / characters),
+        }
+    }
+}
@@ -0,0 +1,1280 @@
+mod concentration;
+mod condition;
+mod difficulty;
+mod effect;
+mod generator;
+mod hit_points;
+mod log;
+mod uses;
+
+use crate::Choice;
+use crate::DamageDefense;
+use crate::DamageType;
+use crate::Defense;
+use crate::Random;
+use crate::Spell;
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub use concentration::Concentration;
+pub use condition::AppliedCondition;
+pub use condition::Condition;
+pub use condition::ConditionDuration;
+pub use condition::SavingThrow;
+pub use condition::TurnEnd;
+pub use difficulty::Difficulty;
+pub use difficulty::EncounterRating;
+pub use difficulty::Thresholds;
+pub use effect::SpellEffect;
+pub use generator::Candidate;
+pub use generator::EncounterGenerator;
+pub use generator::EncounterSlot;
+pub use hit_points::Health;
+pub use hit_points::HitPoints;
+pub use log::CombatEvent;
+pub use log::CombatLog;
+pub use log::CombatSummary;
+pub use log::LogEntry;
+pub use log::Participant;
+pub use log::ParticipantSummary;
+pub use uses::Uses;
+
+/// Who a combatant is, which decides where its statistics come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CombatantKind {
+    /// A creature from the bestiary, with the id of its stat block.
+    Creature(Uuid),
+
+    /// A player character, whose statistics are kept by its player.
+    Character,
+
+    /// The lair of a creature from the bestiary, with the id of its stat block, which takes
+    /// lair actions on initiative count 20.
+    Lair(Uuid),
+}
+
+/// The initiative count lairs act on.
+const LAIR_INITIATIVE: i32 = 20;
+
+/// A participant of an encounter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combatant {
+    pub id: Uuid,
+    pub name: String,
+    pub kind: CombatantKind,
+
+    /// The bonus added to the d20 roll for initiative.
+    pub initiative_bonus: i32,
+
+    /// The initiative of the combatant, `None` until it is rolled or entered.
+    pub initiative: Option<i32>,
+
+    /// The Dexterity score, which breaks ties in initiative.
+    pub dexterity: i32,
+
+    /// The coin flip that breaks ties in initiative left by the Dexterity scores.
+    pub tie_breaker: u32,
+    pub hit_points: HitPoints,
+
+    /// The damage the combatant is resistant, vulnerable or immune to.
+    pub damage_defenses: Vec<DamageDefense>,
+
+    /// Whether a character was killed outright, as creatures already die at 0 hit points.
+    pub dead: bool,
+
+    /// The conditions affecting the combatant, in the order they were applied.
+    pub conditions: Vec<AppliedCondition>,
+
+    /// The spell the combatant concentrates on, if any.
+    pub concentration: Option<Concentration>,
+
+    /// Whether the combatant took its reaction, which it regains at the start of its turn.
+    pub reaction_used: bool,
+
+    /// The legendary actions the combatant takes at the end of the turns of others, regained at
+    /// the start of its turn.
+    pub legendary_actions: Option<Uses>,
+
+    /// The failed saving throws the combatant can still turn into successes today.
+    pub legendary_resistances: Option<Uses>,
+
+    /// The experience points awarded for defeating the combatant.
+    pub experience: u32,
+}
+
+impl Combatant {
+    pub fn new(
+        name: String,
+        kind: CombatantKind,
+        initiative_bonus: i32,
+        maximum_hit_points: u32,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            kind,
+            initiative_bonus,
+            initiative: None,
+            dexterity: 10,
+            tie_breaker: 0,
+            hit_points: HitPoints::new(maximum_hit_points),
+            damage_defenses: Vec::new(),
+            dead: false,
+            conditions: Vec::new(),
+            concentration: None,
+            reaction_used: false,
+            legendary_actions: None,
+            legendary_resistances: None,
+            experience: 0,
+        }
+    }
+
+    /// The lair of the creature with the stat block of `creature`, which acts on initiative
+    /// count 20 and loses every tie.
+    pub fn lair(name: String, creature: Uuid) -> Self {
+        let mut lair = Self::new(name, CombatantKind::Lair(creature), 0, 0);
+        lair.initiative = Some(LAIR_INITIATIVE);
+        lair.dexterity = i32::MIN;
+
+        lair
+    }
+
+    /// Take `damage` of `damage_type`, adjusted by the defense against it.
+    ///
+    /// Characters die outright when the damage remaining at 0 hit points reaches their maximum.
+    /// Returns the damage taken after the adjustment.
+    pub fn take_damage(&mut self, damage: u32, damage_type: Option<DamageType>) -> u32 {
+        let defense = damage_type.and_then(|damage_type| {
+            self.damage_defenses
+                .iter()
+                .find(|defense| defense.damage_type == damage_type)
+                .map(|defense| defense.defense)
+        });
+
+        let damage = match defense {
+            None => damage,
+            Some(Defense::Vulnerability) => damage.saturating_mul(2),
+            Some(Defense::Resistance) => damage / 2,
+            Some(Defense::Immunity) => 0,
+        };
+
+        let remaining = self.hit_points.lose(damage);
+
+        if self.kind == CombatantKind::Character
+            && damage > 0
+            && remaining >= self.hit_points.maximum
+        {
+            self.dead = true;
+        }
+
+        damage
+    }
+
+    /// Regain `amount` hit points, which does not bring back the dead.
+    pub fn heal(&mut self, amount: u32) {
+        if self.health() != Health::Dead {
+            self.hit_points.heal(amount);
+        }
+    }
+
+    /// Where the combatant stands in the initiative order, going before anyone less.
+    fn order(&self) -> (Option<i32>, i32, u32) {
+        (self.initiative, self.dexterity, self.tie_breaker)
+    }
+
+    pub fn health(&self) -> Health {
+        match self.kind {
+            CombatantKind::Lair(_) => Health::Healthy,
+            _ if self.dead => Health::Dead,
+            CombatantKind::Creature(_) if self.hit_points.current == 0 => Health::Dead,
+            CombatantKind::Character if self.hit_points.current == 0 => Health::Unconscious,
+            _ if self.hit_points.is_bloodied() => Health::Bloodied,
+            _ => Health::Healthy,
+        }
+    }
+}
+
+/// The combatants of an encounter in the order they take their turns, and whose turn it is.
+///
+/// The order follows initiative, highest first, but can be changed by hand while fighting.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Encounter {
+    combatants: Vec<Combatant>,
+
+    /// The combatant whose turn it is, `None` while combat has not started.
+    active: Option<Uuid>,
+
+    /// The current round, starting at 1 once combat has started.
+    round: u32,
+
+    /// The effects of the spells cast during the encounter that still last.
+    effects: Vec<SpellEffect>,
+
+    /// What happened during the current fight, or the last one once it ended.
+    log: CombatLog,
+}
+
+impl Encounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An encounter as it was left, like one persisted in the middle of a fight.
+    ///
+    /// The `combatants` are kept in the order given, as it may have been changed by hand.
+    pub fn resume(
+        combatants: Vec<Combatant>,
+        active: Option<Uuid>,
+        round: u32,
+        effects: Vec<SpellEffect>,
+        log: CombatLog,
+    ) -> Self {
+        // A turn of a combatant that is no longer fighting cannot be resumed.
+        let active = active.filter(|id| combatants.iter().any(|combatant| combatant.id == *id));
+
+        Self {
+            combatants,
+            active,
+            round: if active.is_some() { round.max(1) } else { 0 },
+            effects,
+            log,
+        }
+    }
+
+    /// The combatants in turn order.
+    pub fn combatants(&self) -> &[Combatant] {
+        &self.combatants
+    }
+
+    pub fn combatant_mut(&mut self, id: Uuid) -> Option<&mut Combatant> {
+        self.combatants
+            .iter_mut()
+            .find(|combatant| combatant.id == id)
+    }
+
+    /// The current round, which is 0 before combat has started.
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// The lasting spell effects, in the order the spells were cast.
+    pub fn effects(&self) -> &[SpellEffect] {
+        &self.effects
+    }
+
+    pub fn log(&self) -> &CombatLog {
+        &self.log
+    }
+
+    pub fn has_started(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// The combatant whose turn it is, if combat has started.
+    pub fn active(&self) -> Option<&Combatant> {
+        let id = self.active?;
+
+        self.combatants.iter().find(|combatant| combatant.id == id)
+    }
+
+    /// Add `combatant`, in initiative order if it already has an initiative and last otherwise.
+    pub fn add(&mut self, combatant: Combatant) {
+        let index = self.insertion_index(&combatant);
+
+        self.log.join(&combatant);
+        self.combatants.insert(index, combatant);
+    }
+
+    /// Remove the combatant with `id`, passing the turn on if it was theirs.
+    ///
+    /// The conditions lasting until the end of its turn or sustained by its concentration end
+    /// with it.
+    pub fn remove(&mut self, id: Uuid) -> Option<Combatant> {
+        self.position(id)?;
+        self.break_concentration(id);
+
+        if self.active == Some(id) {
+            if self.combatants.len() == 1 {
+                self.end();
+            } else {
+                self.next_turn();
+            }
+        }
+
+        for combatant in &mut self.combatants {
+            combatant
+                .conditions
+                .retain(|applied| applied.duration != ConditionDuration::UntilEndOfTurn(id));
+        }
+
+        let index = self.position(id)?;
+
+        Some(self.combatants.remove(index))
+    }
+
+    /// Deal `damage` of `damage_type` to the combatant with `id`, dealt by the combatant with
+    /// `source` if it came from one.
+    ///
+    /// A concentrating combatant that drops to 0 hit points loses its concentration, otherwise
+    /// this returns the saving throw it makes to keep it.
+    pub fn damage(
+        &mut self,
+        id: Uuid,
+        source: Option<Uuid>,
+        damage: u32,
+        damage_type: Option<DamageType>,
+    ) -> Option<SavingThrow> {
+        let combatant = self.combatant_mut(id)?;
+        let health = combatant.health();
+        let taken = combatant.take_damage(damage, damage_type);
+        let fell = match combatant.health() {
+            Health::Dead if health != Health::Dead => Some(CombatEvent::Died { combatant: id }),
+            Health::Unconscious if health != Health::Unconscious => {
+                Some(CombatEvent::Downed { combatant: id })
+            }
+            _ => None,
+        };
+        let has_concentration = combatant.concentration.is_some();
+        let is_down = combatant.hit_points.current == 0;
+
+        self.record(CombatEvent::Damaged {
+            target: id,
+            source,
+            amount: taken,
+            damage_type,
+        });
+        if let Some(fell) = fell {
+            self.record(fell);
+        }
+
+        if !has_concentration || taken == 0 {
+            return None;
+        }
+
+        if is_down {
+            self.break_concentration(id);
+            return None;
+        }
+
+        Some(Concentration::saving_throw(taken))
+    }
+
+    /// Have the combatant with `id` regain `amount` hit points.
+    pub fn heal(&mut self, id: Uuid, amount: u32) {
+        let Some(combatant) = self.combatant_mut(id) else {
+            return;
+        };
+
+        let current = combatant.hit_points.current;
+        combatant.heal(amount);
+        let regained = combatant.hit_points.current - current;
+
+        if regained > 0 {
+            self.record(CombatEvent::Healed {
+                target: id,
+                amount: regained,
+            });
+        }
+    }
+
+    /// Have the combatant with `caster` cast `spell` on the combatants with `targets`.
+    ///
+    /// A spell requiring concentration breaks the concentration the caster had, which is
+    /// returned. A spell lasting for rounds starts an effect that counts them down.
+    pub fn cast(
+        &mut self,
+        caster: Uuid,
+        spell: &Spell,
+        targets: Vec<Uuid>,
+    ) -> Option<Concentration> {
+        self.position(caster)?;
+        self.record(CombatEvent::SpellCast {
+            caster,
+            spell: spell.name.clone(),
+        });
+
+        let broken = if spell.concentration {
+            self.concentrate(caster, spell.name.clone())
+        } else {
+            None
+        };
+
+        let rounds = match spell.duration {
+            Choice::BuiltIn(duration) => duration.rounds(),
+            Choice::Custom(_) => None,
+        };
+
+        if let Some(rounds) = rounds {
+            let concentration = self
+                .combatant_mut(caster)
+                .and_then(|combatant| combatant.concentration.as_ref())
+                .filter(|_| spell.concentration)
+                .map(|concentration| concentration.id);
+
+            self.effects.push(SpellEffect {
+                id: Uuid::new_v4(),
+                spell: spell.name.clone(),
+                caster,
+                targets,
+                rounds,
+                concentration,
+            });
+        }
+
+        broken
+    }
+
+    /// End the spell effect with `id` early, along with the concentration sustaining it.
+    pub fn end_effect(&mut self, id: Uuid) -> Option<SpellEffect> {
+        let index = self.effects.iter().position(|effect| effect.id == id)?;
+        let effect = self.effects.remove(index);
+
+        self.break_effect_concentration(&effect);
+
+        Some(effect)
+    }
+
+    /// Have the combatant with `id` concentrate on `spell`, breaking the concentration it had.
+    ///
+    /// Returns the broken concentration.
+    pub fn concentrate(&mut self, id: Uuid, spell: String) -> Option<Concentration> {
+        let broken = self.break_concentration(id);
+
+        if let Some(combatant) = self.combatant_mut(id) {
+            combatant.concentration = Some(Concentration::new(spell));
+        }
+
+        broken
+    }
+
+    /// Break the concentration of the combatant with `id`, ending the conditions it sustains.
+    pub fn break_concentration(&mut self, id: Uuid) -> Option<Concentration> {
+        let concentration = self.combatant_mut(id)?.concentration.take()?;
+
+        for combatant in &mut self.combatants {
+            combatant
+                .conditions
+                .retain(|applied| applied.concentration != Some(concentration.id));
+        }
+
+        self.effects
+            .retain(|effect| effect.concentration != Some(concentration.id));
+
+        Some(concentration)
+    }
+
+    /// Apply `condition` to the combatant with `id`.
+    ///
+    /// A condition applied during the turn it is timed by lasts through the end of that turn.
+    pub fn apply_condition(&mut self, id: Uuid, mut condition: AppliedCondition) {
+        let active = self.active;
+
+        let Some(combatant) = self.combatant_mut(id) else {
+            return;
+        };
+
+        condition.applied_this_turn = active.is_some() && condition.timekeeper(id) == active;
+        let event = CombatEvent::ConditionApplied {
+            target: id,
+            condition: condition.condition.clone(),
+        };
+        combatant.conditions.push(condition);
+
+        self.record(event);
+    }
+
+    /// Remove the condition with `condition_id` from the combatant with `id`.
+    pub fn remove_condition(&mut self, id: Uuid, condition_id: Uuid) {
+        let Some(combatant) = self.combatant_mut(id) else {
+            return;
+        };
+
+        let Some(index) = combatant
+            .conditions
+            .iter()
+            .position(|applied| applied.id == condition_id)
+        else {
+            return;
+        };
+
+        let removed = combatant.conditions.remove(index);
+
+        self.record(CombatEvent::ConditionEnded {
+            target: id,
+            condition: removed.condition,
+        });
+    }
+
+    /// Set the initiative of the combatant with `id`, moving it to its place in the order.
+    pub fn set_initiative(&mut self, id: Uuid, initiative: Option<i32>) {
+        let Some(index) = self.position(id) else {
+            return;
+        };
+
+        let mut combatant = self.combatants.remove(index);
+        combatant.initiative = initiative;
+
+        let index = self.insertion_index(&combatant);
+        self.combatants.insert(index, combatant);
+    }
+
+    /// Roll initiative for every creature without one, and flip a coin for every combatant to
+    /// break the ties the Dexterity scores leave, sorting the combatants by the result.
+    ///
+    /// Creatures of the same stat block share a single roll and coin flip if `grouped`, so they
+    /// take their turns one after the other. Characters roll their own initiative.
+    pub fn roll_initiative(&mut self, random: &mut Random, grouped: bool) {
+        let mut groups: HashMap<Uuid, (i32, u32)> = HashMap::new();
+
+        for combatant in &mut self.combatants {
+            // Lairs always act on the same initiative count.
+            if matches!(combatant.kind, CombatantKind::Lair(_)) {
+                continue;
+            }
+
+            let group = match combatant.kind {
+                CombatantKind::Creature(id) if grouped => Some(id),
+                _ => None,
+            };
+
+            if let Some(&(initiative, tie_breaker)) = group.and_then(|id| groups.get(&id)) {
+                combatant.initiative.get_or_insert(initiative);
+                combatant.tie_breaker = tie_breaker;
+                continue;
+            }
+
+            if matches!(combatant.kind, CombatantKind::Creature(_))
+                && combatant.initiative.is_none()
+            {
+                let roll = random.between(1..=20) as i32;
+                combatant.initiative = Some(roll + combatant.initiative_bonus);
+            }
+
+            combatant.tie_breaker = random.between(0..=u32::MAX);
+
+            if let (Some(id), Some(initiative)) = (group, combatant.initiative) {
+                groups.insert(id, (initiative, combatant.tie_breaker));
+            }
+        }
+
+        self.sort_by_initiative();
+    }
+
+    /// Sort the combatants by initiative, keeping the turn with the active combatant.
+    ///
+    /// Combatants without an initiative go last, and ties go to the higher Dexterity score,
+    /// then to the coin flip.
+    pub fn sort_by_initiative(&mut self) {
+        self.combatants
+            .sort_by_key(|combatant| Reverse(combatant.order()));
+    }
+
+    /// Start combat with the first round, sorting the combatants by initiative.
+    ///
+    /// Every combatant starts with its reaction and legendary actions.
+    pub fn start(&mut self) {
+        self.sort_by_initiative();
+
+        for combatant in &mut self.combatants {
+            combatant.reaction_used = false;
+
+            if let Some(legendary_actions) = &mut combatant.legendary_actions {
+                legendary_actions.restore();
+            }
+        }
+
+        self.active = self.combatants.first().map(|combatant| combatant.id);
+        self.round = u32::from(self.active.is_some());
+
+        // Every fight starts with a log of its own.
+        self.log = CombatLog::new(&self.combatants);
+        self.record(CombatEvent::Started);
+
+        if let Some(id) = self.active {
+            self.record(CombatEvent::TurnStarted { combatant: id });
+        }
+    }
+
+    /// End combat, keeping the combatants for another fight.
+    pub fn end(&mut self) {
+        self.record(CombatEvent::Ended);
+
+        self.active = None;
+        self.round = 0;
+    }
+
+    /// Pass the turn to the next combatant, starting a new round after the last one.
+    ///
+    /// Ending the turn counts down the conditions timed by it, removing those that expire.
+    /// Returns what happened to the conditions, or `None` if combat has not started.
+    pub fn next_turn(&mut self) -> Option<TurnEnd> {
+        let index = self.active.and_then(|id| self.position(id))?;
+        let mut turn_end = self.end_turn(self.combatants[index].id);
+
+        let next = if index + 1 < self.combatants.len() {
+            index + 1
+        } else {
+            self.round += 1;
+            0
+        };
+
+        for (affected, applied) in &turn_end.expired {
+            self.record(CombatEvent::ConditionEnded {
+                target: *affected,
+                condition: applied.condition.clone(),
+            });
+        }
+
+        self.active = Some(self.combatants[next].id);
+        self.record(CombatEvent::TurnStarted {
+            combatant: self.combatants[next].id,
+        });
+        turn_end.ended_effects = self.start_turn(self.combatants[next].id, next == 0);
+
+        Some(turn_end)
+    }
+
+    /// Return the turn to the previous combatant, to correct passing it on too early.
+    ///
+    /// Conditions that expired when the turn was passed on are not restored.
+    pub fn previous_turn(&mut self) {
+        let Some(index) = self.active.and_then(|id| self.position(id)) else {
+            return;
+        };
+
+        let previous = match index.checked_sub(1) {
+            Some(previous) => previous,
+            None if self.round > 1 => {
+                self.round -= 1;
+                self.combatants.len() - 1
+            }
+            None => return,
+        };
+
+        self.active = Some(self.combatants[previous].id);
+    }
+
+    /// Delay the turn of the active combatant with `id` until after the next combatant.
+    ///
+    /// The delaying combatant takes the initiative of the one it waits for, and the last
+    /// combatant of a round cannot delay. Returns whether the turn was delayed.
+    pub fn delay(&mut self, id: Uuid) -> bool {
+        if self.active != Some(id) {
+            return false;
+        }
+
+        let Some(index) = self.position(id) else {
+            return false;
+        };
+
+        if index + 1 >= self.combatants.len() {
+            return false;
+        }
+
+        self.combatants[index].initiative = self.combatants[index + 1].initiative;
+        self.combatants.swap(index, index + 1);
+        self.active = Some(self.combatants[index].id);
+
+        true
+    }
+
+    /// Move the combatant with `id` one place earlier in the order, like to break a tie.
+    pub fn move_up(&mut self, id: Uuid) {
+        if let Some(index) = self.position(id)
+            && index > 0
+        {
+            self.combatants.swap(index, index - 1);
+        }
+    }
+
+    /// Move the combatant with `id` one place later in the order.
+    pub fn move_down(&mut self, id: Uuid) {
+        if let Some(index) = self.position(id)
+            && index + 1 < self.combatants.len()
+        {
+            self.combatants.swap(index, index + 1);
+        }
+    }
+
+    /// End the turn of the combatant with `ending`, expiring the conditions timed by it.
+    fn end_turn(&mut self, ending: Uuid) -> TurnEnd {
+        let mut expired = Vec::new();
+        let mut saves = Vec::new();
+
+        for combatant in &mut self.combatants {
+            let affected = combatant.id;
+
+            expired.extend(
+                combatant
+                    .conditions
+                    .extract_if(.., |applied| applied.end_turn(affected, ending))
+                    .map(|applied| (affected, applied)),
+            );
+
+            if affected == ending {
+                saves.extend(
+                    combatant
+                        .conditions
+                        .iter()
+                        .filter(|applied| applied.save.is_some())
+                        .cloned(),
+                );
+            }
+        }
+
+        TurnEnd {
+            combatant: ending,
+            expired,
+            saves,
+            ended_effects: Vec::new(),
+        }
+    }
+
+    /// Start the turn of the combatant with `starting`, who regains its reaction and legendary
+    /// actions, counting down the spell effects it cast.
+    ///
+    /// The effects of casters that left the fight count down as each round starts instead.
+    fn start_turn(&mut self, starting: Uuid, new_round: bool) -> Vec<SpellEffect> {
+        if let Some(combatant) = self.combatant_mut(starting) {
+            combatant.reaction_used = false;
+
+            if let Some(legendary_actions) = &mut combatant.legendary_actions {
+                legendary_actions.restore();
+            }
+        }
+
+        let combatants = &self.combatants;
+
+        let ended: Vec<_> = self
+            .effects
+            .extract_if(.., |effect| {
+                let is_left = !combatants
+                    .iter()
+                    .any(|combatant| combatant.id == effect.caster);
+
+                if effect.caster != starting && !(new_round && is_left) {
+                    return false;
+                }
+
+                effect.rounds = effect.rounds.saturating_sub(1);
+                effect.rounds == 0
+            })
+            .collect();
+
+        for effect in &ended {
+            self.break_effect_concentration(effect);
+        }
+
+        ended
+    }
+
+    /// Break the concentration of the caster of `effect`, if it still sustains it.
+    fn break_effect_concentration(&mut self, effect: &SpellEffect) {
+        let sustained = self
+            .combatant_mut(effect.caster)
+            .and_then(|combatant| combatant.concentration.as_ref())
+            .is_some_and(|concentration| Some(concentration.id) == effect.concentration);
+
+        if sustained {
+            self.break_concentration(effect.caster);
+        }
+    }
+
+    /// Log `event` as happening now, if combat has started.
+    fn record(&mut self, event: CombatEvent) {
+        if self.active.is_some() {
+            self.log.push(self.round, event);
+        }
+    }
+
+    fn position(&self, id: Uuid) -> Option<usize> {
+        self.combatants
+            .iter()
+            .position(|combatant| combatant.id == id)
+    }
+
+    /// The index `combatant` takes, after everyone going before or tied with it.
+    fn insertion_index(&self, combatant: &Combatant) -> usize {
+        if combatant.initiative.is_none() {
+            return self.combatants.len();
+        }
+
+        self.combatants
+            .iter()
+            .position(|other| other.order() < combatant.order())
+            .unwrap_or(self.combatants.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ability;
+    use crate::Area;
+    use crate::CastingTime;
+    use crate::Distance;
+    use crate::Duration;
+    use crate::Level;
+    use crate::School;
+    use crate::Shape;
+
+    fn combatant(name: &str, initiative: Option<i32>) -> Combatant {
+        let mut combatant = Combatant::new(String::from(name), CombatantKind::Character, 0, 10);
+        combatant.initiative = initiative;
+
+        combatant
+    }
+
+    fn spell(name: &str, duration: Duration, concentration: bool) -> Spell {
+        Spell {
+            id: Uuid::new_v4(),
+            name: String::from(name),
+            aliases: Vec::new(),
+            school: School::Enchantment.into(),
+            level: Level::First.into(),
+            source: None,
+            classes: Vec::new(),
+            tags: Vec::new(),
+            casting_time: CastingTime::Action.into(),
+            ritual: false,
+            concentration,
+            verbal: true,
+            somatic: true,
+            material: false,
+            materials: Vec::new(),
+            duration: duration.into(),
+            range: Distance::Feet(30).into(),
+            area: Area::MultipleTargets.into(),
+            shape: Shape::NoShape,
+            description: String::new(),
+            at_higher_levels: None,
+            flavor_text: None,
+            attribution: None,
+        }
+    }
+
+    fn names(encounter: &Encounter) -> Vec<&str> {
+        encounter
+            .combatants()
+            .iter()
+            .map(|combatant| combatant.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn combatants_are_ordered_by_initiative() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Goblin", Some(12)));
+        encounter.add(combatant("Fighter", None));
+        encounter.add(combatant("Wizard", Some(18)));
+        encounter.add(combatant("Rogue", Some(12)));
+
+        assert_eq!(names(&encounter), ["Wizard", "Goblin", "Rogue", "Fighter"]);
+
+        let fighter = encounter.combatants()[3].id;
+        encounter.set_initiative(fighter, Some(15));
+
+        assert_eq!(names(&encounter), ["Wizard", "Fighter", "Goblin", "Rogue"]);
+    }
+
+    #[test]
+    fn rolled_initiative_breaks_ties_and_groups_creatures() {
+        let goblin = Uuid::new_v4();
+        let creature = |name: &str, dexterity: i32| {
+            let mut combatant =
+                Combatant::new(String::from(name), CombatantKind::Creature(goblin), 2, 7);
+            combatant.dexterity = dexterity;
+
+            combatant
+        };
+
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Fighter", Some(12)));
+        encounter.add(creature("Goblin", 14));
+        encounter.add(creature("Goblin 2", 14));
+        encounter.add(combatant("Rogue", None));
+        encounter.add(combatant("Wizard", Some(12)));
+
+        let wizard = encounter.combatants()[1].id;
+        encounter.combatant_mut(wizard).unwrap().dexterity = 16;
+
+        encounter.roll_initiative(&mut Random::new(5), true);
+
+        let combatants = encounter.combatants();
+        let goblins: Vec<_> = combatants
+            .iter()
+            .filter(|combatant| combatant.kind != CombatantKind::Character)
+            .collect();
+
+        // The goblins share a roll, and the characters roll their own.
+        assert_eq!(goblins[0].initiative, goblins[1].initiative);
+        assert!((3..=22).contains(&goblins[0].initiative.unwrap()));
+        assert_eq!(combatants.last().unwrap().name, "Rogue");
+
+        // The wizard and fighter tie, which the Dexterity score of the wizard breaks.
+        let position = |name: &str| combatants.iter().position(|c| c.name == name);
+        assert!(position("Wizard") < position("Fighter"));
+        assert_eq!(
+            position("Goblin")
+                .unwrap()
+                .abs_diff(position("Goblin 2").unwrap()),
+            1
+        );
+    }
+
+    #[test]
+    fn lairs_act_on_twenty_and_turns_restore_reactions() {
+        let dragon = Uuid::new_v4();
+
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Fighter", Some(20)));
+        encounter.add(combatant("Dragon", Some(14)));
+        encounter.add(Combatant::lair(String::from("Lair"), dragon));
+        encounter.add(combatant("Wizard", Some(21)));
+
+        assert_eq!(names(&encounter), ["Wizard", "Fighter", "Lair", "Dragon"]);
+
+        let id = encounter.combatants()[3].id;
+        let dragon = encounter.combatant_mut(id).unwrap();
+        dragon.legendary_actions = Some(Uses::new(3));
+        dragon.reaction_used = true;
+        dragon.legendary_actions.as_mut().unwrap().spend();
+
+        encounter.start();
+        let dragon = encounter.combatant_mut(id).unwrap();
+        assert!(!dragon.reaction_used);
+        assert_eq!(dragon.legendary_actions, Some(Uses::new(3)));
+
+        dragon.reaction_used = true;
+        dragon.legendary_actions.as_mut().unwrap().spend();
+
+        // The reaction and legendary actions only come back as the turn of the dragon starts.
+        encounter.next_turn();
+        encounter.next_turn();
+        assert!(encounter.combatant_mut(id).unwrap().reaction_used);
+
+        encounter.next_turn();
+        let dragon = encounter.combatant_mut(id).unwrap();
+        assert!(!dragon.reaction_used);
+        assert_eq!(dragon.legendary_actions.unwrap().remaining, 3);
+    }
+
+    #[test]
+    fn turns_wrap_into_the_next_round() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Wizard", Some(18)));
+        encounter.add(combatant("Goblin", Some(12)));
+
+        encounter.next_turn();
+        assert_eq!(encounter.round(), 0);
+
+        encounter.start();
+        assert_eq!(encounter.round(), 1);
+        assert_eq!(encounter.active().unwrap().name, "Wizard");
+
+        encounter.next_turn();
+        encounter.next_turn();
+        assert_eq!(encounter.round(), 2);
+        assert_eq!(encounter.active().unwrap().name, "Wizard");
+
+        encounter.previous_turn();
+        assert_eq!(encounter.round(), 1);
+        assert_eq!(encounter.active().unwrap().name, "Goblin");
+    }
+
+    #[test]
+    fn delaying_passes_the_turn_on() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Wizard", Some(18)));
+        encounter.add(combatant("Goblin", Some(12)));
+        encounter.start();
+
+        let wizard = encounter.active().unwrap().id;
+        assert!(encounter.delay(wizard));
+
+        assert_eq!(names(&encounter), ["Goblin", "Wizard"]);
+        assert_eq!(encounter.active().unwrap().name, "Goblin");
+        assert_eq!(encounter.combatants()[1].initiative, Some(12));
+
+        let goblin = encounter.active().unwrap().id;
+        encounter.next_turn();
+        assert!(!encounter.delay(goblin));
+        assert!(!encounter.delay(wizard));
+    }
+
+    #[test]
+    fn removing_the_active_combatant_passes_the_turn_on() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Wizard", Some(18)));
+        encounter.add(combatant("Goblin", Some(12)));
+        encounter.start();
+        encounter.next_turn();
+
+        let goblin = encounter.active().unwrap().id;
+        encounter.remove(goblin);
+
+        assert_eq!(encounter.round(), 2);
+        assert_eq!(encounter.active().unwrap().name, "Wizard");
+
+        let wizard = encounter.active().unwrap().id;
+        encounter.remove(wizard);
+
+        assert!(!encounter.has_started());
+        assert!(encounter.combatants().is_empty());
+    }
+
+    #[test]
+    fn conditions_expire_as_turns_end() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Wizard", Some(18)));
+        encounter.add(combatant("Goblin", Some(12)));
+        encounter.start();
+
+        let wizard = encounter.combatants()[0].id;
+        let goblin = encounter.combatants()[1].id;
+        let save = SavingThrow {
+            ability: Ability::Wisdom,
+            difficulty_class: 13,
+        };
+
+        let frightened = AppliedCondition::new(
+            Condition::Frightened.into(),
+            ConditionDuration::Rounds(2),
+            Some(save),
+        );
+        let prone = AppliedCondition::new(
+            Condition::Prone.into(),
+            ConditionDuration::UntilEndOfTurn(wizard),
+            None,
+        );
+        encounter.apply_condition(goblin, frightened);
+        encounter.apply_condition(goblin, prone);
+
+        // The wizard applied both during its turn, so it lasts through its next one.
+        let turn_end = encounter.next_turn().unwrap();
+        assert!(turn_end.expired.is_empty());
+        assert!(turn_end.saves.is_empty());
+
+        let turn_end = encounter.next_turn().unwrap();
+        assert_eq!(turn_end.combatant, goblin);
+        assert!(turn_end.expired.is_empty());
+        assert_eq!(turn_end.saves.len(), 1);
+
+        let turn_end = encounter.next_turn().unwrap();
+        assert_eq!(turn_end.expired.len(), 1);
+        assert_eq!(turn_end.expired[0].1.condition, Condition::Prone.into());
+
+        let turn_end = encounter.next_turn().unwrap();
+        assert_eq!(turn_end.expired.len(), 1);
+        assert!(turn_end.saves.is_empty());
+        assert!(encounter.combatants()[1].conditions.is_empty());
+    }
+
+    #[test]
+    fn conditions_applied_during_the_affected_turn_last_through_it() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Fighter", Some(15)));
+        encounter.start();
+
+        let fighter = encounter.active().unwrap().id;
+        let poisoned = AppliedCondition::new(
+            Condition::Poisoned.into(),
+            ConditionDuration::Rounds(1),
+            None,
+        );
+        encounter.apply_condition(fighter, poisoned);
+
+        assert!(encounter.next_turn().unwrap().expired.is_empty());
+        assert_eq!(encounter.next_turn().unwrap().expired.len(), 1);
+    }
+
+    #[test]
+    fn breaking_concentration_ends_its_conditions() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Cleric", Some(14)));
+        encounter.add(combatant("Ogre", Some(8)));
+
+        let cleric = encounter.combatants()[0].id;
+        let ogre = encounter.combatants()[1].id;
+
+        assert!(
+            encounter
+                .concentrate(cleric, String::from("Bless"))
+                .is_none()
+        );
+        let broken = encounter.concentrate(cleric, String::from("Hold Person"));
+        assert_eq!(broken.unwrap().spell, "Bless");
+
+        let concentration = encounter.combatants()[0].concentration.clone().unwrap();
+        let paralyzed = AppliedCondition::new(
+            Condition::Paralyzed.into(),
+            ConditionDuration::Indefinite,
+            None,
+        )
+        .with_concentration(concentration.id);
+        encounter.apply_condition(ogre, paralyzed);
+
+        let saving_throw = encounter.damage(cleric, None, 7, None).unwrap();
+        assert_eq!(saving_throw.ability, Ability::Constitution);
+        assert_eq!(saving_throw.difficulty_class, 10);
+        assert_eq!(
+            encounter
+                .damage(cleric, None, 2, None)
+                .unwrap()
+                .difficulty_class,
+            10
+        );
+        assert!(encounter.damage(ogre, None, 4, None).is_none());
+        assert_eq!(Concentration::saving_throw(25).difficulty_class, 12);
+
+        // Dropping to 0 hit points breaks concentration without a saving throw.
+        assert!(encounter.damage(cleric, None, 30, None).is_none());
+        assert!(encounter.combatants()[0].concentration.is_none());
+        assert!(encounter.combatants()[1].conditions.is_empty());
+    }
+
+    #[test]
+    fn spell_effects_count_down_on_the_casters_turns() {
+        let mut encounter = Encounter::new();
+        encounter.add(combatant("Cleric", Some(14)));
+        encounter.add(combatant("Fighter", Some(10)));
+        encounter.start();
+
+        let cleric = encounter.combatants()[0].id;
+        let fighter = encounter.combatants()[1].id;
+
+        let fireball = spell("Fireball", Duration::Instantaneous, false);
+        encounter.cast(cleric, &fireball, vec![fighter]);
+        assert!(encounter.effects().is_empty());
+
+        let bless = spell("Bless", Duration::OneMinute, true);
+        encounter.cast(cleric, &bless, vec![fighter]);
+        assert_eq!(encounter.effects()[0].rounds, 10);
+
+        let shield = spell("Shield of Faith", Duration::TenMinutes, true);
+        encounter.cast(fighter, &shield, vec![fighter]);
+
+        for _ in 0..9 {
+            encounter.next_turn();
+            let turn_end = encounter.next_turn().unwrap();
+            assert!(turn_end.ended_effects.is_empty());
+        }
+
+        encounter.next_turn();
+        let turn_end = encounter.next_turn().unwrap();
+        assert_eq!(turn_end.ended_effects.len(), 1);
+        assert_eq!(turn_end.ended_effects[0].spell, "Bless");
+        assert!(encounter.combatants()[0].concentration.is_none());
+
+        // Breaking concentration ends the effect it sustains.
+        assert_eq!(encounter.effects().len(), 1);
+        encounter.break_concentration(fighter);
+        assert!(encounter.effects().is_empty());
+    }
+
+    #[test]
+    fn damage_honours_defenses() {
+        let mut owlbear = Combatant::new(
+            String::from("Owlbear"),
+            CombatantKind::Creature(Uuid::new_v4()),
+            1,
+            59,
+        );
+        owlbear.damage_defenses = vec![
+            DamageDefense {
+                damage_type: DamageType::Cold,
+                defense: Defense::Resistance,
+            },
+            DamageDefense {
+                damage_type: DamageType::Fire,
+                defense: Defense::Vulnerability,
+            },
+            DamageDefense {
+                damage_type: DamageType::Poison,
+                defense: Defense::Immunity,
+            },
+        ];
+
+        assert_eq!(owlbear.take_damage(9, Some(DamageType::Cold)), 4);
+        assert_eq!(owlbear.take_damage(5, Some(DamageType::Fire)), 10);
+        assert_eq!(owlbear.take_damage(20, Some(DamageType::Poison)), 0);
+        assert_eq!(owlbear.take_damage(16, None), 16);
+        assert_eq!(owlbear.hit_points.current, 29);
+        assert_eq!(owlbear.health(), Health::Bloodied);
+
+        owlbear.take_damage(40, Some(DamageType::Slashing));
+        assert_eq!(owlbear.health(), Health::Dead);
+
+        owlbear.heal(10);
+        assert_eq!(owlbear.hit_points.current, 0);
+    }
+
+    #[test]
+    fn characters_fall_unconscious_unless_the_damage_is_massive() {
+        let mut fighter = combatant("Fighter", None);
+
+        fighter.take_damage(15, None);
+        assert_eq!(fighter.health(), Health::Unconscious);
+
+        fighter.heal(3);
+        assert_eq!(fighter.health(), Health::Bloodied);
+
+        fighter.take_damage(13, None);
+        assert_eq!(fighter.health(), Health::Dead);
+    }
+
+    #[test]
+    fn log_sums_up_damage_and_experience_of_the_fight() {
+        let mut encounter = Encounter::new();
+
+        let fighter = combatant("Fighter", Some(18));
+        let fighter_id = fighter.id;
+        let mut goblin = Combatant::new(
+            String::from("Goblin"),
+            CombatantKind::Creature(Uuid::new_v4()),
+            2,
+            7,
+        );
+        goblin.initiative = Some(12);
+        goblin.experience = 50;
+        let goblin_id = goblin.id;
+
+        encounter.add(fighter);
+        encounter.add(goblin);
+        encounter.start();
+
+        encounter.damage(goblin_id, Some(fighter_id), 4, None);
+        encounter.next_turn();
+        encounter.damage(fighter_id, Some(goblin_id), 6, Some(DamageType::Slashing));
+        encounter.next_turn();
+        encounter.heal(fighter_id, 10);
+        encounter.damage(goblin_id, Some(fighter_id), 9, None);
+        encounter.end();
+
+        let log = encounter.log();
+        assert!(log.entries.contains(&LogEntry {
+            round: 2,
+            event: CombatEvent::Died {
+                combatant: goblin_id
+            },
+        }));
+        assert_eq!(log.entries.last().unwrap().event, CombatEvent::Ended);
+
+        let summary = log.summary();
+        assert_eq!(summary.rounds, 2);
+        assert_eq!(summary.experience, 50);
+        assert_eq!(summary.experience_per_character, Some(50));
+
+        let fighter = &summary.participants[0];
+        assert_eq!(
+            (
+                fighter.damage_dealt,
+                fighter.damage_taken,
+                fighter.healing_received
+            ),
+            (13, 6, 6)
+        );
+    }
+}
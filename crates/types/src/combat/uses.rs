@@ -0,0 +1,35 @@
+/// A number of uses that are spent and regained, like the legendary actions of a creature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Uses {
+    pub remaining: u32,
+    pub maximum: u32,
+}
+
+impl Uses {
+    pub fn new(maximum: u32) -> Self {
+        Self {
+            remaining: maximum,
+            maximum,
+        }
+    }
+
+    /// Spend a use, returning whether there was one left.
+    pub fn spend(&mut self) -> bool {
+        let Some(remaining) = self.remaining.checked_sub(1) else {
+            return false;
+        };
+
+        self.remaining = remaining;
+        true
+    }
+
+    /// Regain a single spent use, like one spent by mistake.
+    pub fn regain(&mut self) {
+        self.remaining = (self.remaining + 1).min(self.maximum);
+    }
+
+    /// Regain every spent use.
+    pub fn restore(&mut self) {
+        self.remaining = self.maximum;
+    }
+}
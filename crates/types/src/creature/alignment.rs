@@ -0,0 +1,27 @@
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    #[strum(to_string = "Lawful Good")]
+    LawfulGood,
+    #[strum(to_string = "Neutral Good")]
+    NeutralGood,
+    #[strum(to_string = "Chaotic Good")]
+    ChaoticGood,
+    #[strum(to_string = "Lawful Neutral")]
+    LawfulNeutral,
+    Neutral,
+    #[strum(to_string = "Chaotic Neutral")]
+    ChaoticNeutral,
+    #[strum(to_string = "Lawful Evil")]
+    LawfulEvil,
+    #[strum(to_string = "Neutral Evil")]
+    NeutralEvil,
+    #[strum(to_string = "Chaotic Evil")]
+    ChaoticEvil,
+    Unaligned,
+    #[strum(to_string = "Any Alignment")]
+    Any,
+}
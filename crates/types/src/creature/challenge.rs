@@ -0,0 +1,99 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The written challenge ratings, from 0 to 30.
+const LABELS: [&str; 34] = [
+    "0", "1/8", "1/4", "1/2", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13",
+    "14", "15", "16", "17", "18", "19", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29",
+    "30",
+];
+
+/// The experience points awarded for defeating a creature of each challenge rating.
+///
+/// Creatures of challenge 0 award 10 XP if they have effective attacks, which is assumed.
+const EXPERIENCE: [u32; 34] = [
+    10, 25, 50, 100, 200, 450, 700, 1100, 1800, 2300, 2900, 3900, 5000, 5900, 7200, 8400, 10000,
+    11500, 13000, 15000, 18000, 20000, 22000, 25000, 33000, 41000, 50000, 62000, 75000, 90000,
+    105000, 120000, 135000, 155000,
+];
+
+/// The challenge rating of a creature, like "1/4" or "5".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChallengeRating(u8);
+
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("'{0}' is not a challenge rating")]
+pub struct ParseChallengeRatingError(String);
+
+impl ChallengeRating {
+    pub const ZERO: ChallengeRating = ChallengeRating(0);
+
+    /// Every challenge rating, from lowest to highest.
+    pub fn all() -> impl Iterator<Item = ChallengeRating> {
+        (0..LABELS.len() as u8).map(ChallengeRating)
+    }
+
+    /// The experience points awarded for defeating a creature of the challenge rating.
+    pub fn experience(self) -> u32 {
+        EXPERIENCE[usize::from(self.0)]
+    }
+
+    /// The proficiency bonus of a creature of the challenge rating.
+    pub fn proficiency_bonus(self) -> i32 {
+        // The fractional ratings come first, so whole ratings start at index 4 with 1.
+        let rating = i32::from(self.0.saturating_sub(3));
+
+        2 + (rating - 1).max(0) / 4
+    }
+}
+
+impl Display for ChallengeRating {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", LABELS[usize::from(self.0)])
+    }
+}
+
+impl FromStr for ChallengeRating {
+    type Err = ParseChallengeRatingError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        LABELS
+            .iter()
+            .position(|label| *label == value)
+            .and_then(|index| u8::try_from(index).ok())
+            .map(ChallengeRating)
+            .ok_or_else(|| ParseChallengeRatingError(String::from(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rating(value: &str) -> ChallengeRating {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn ratings_order_by_value() {
+        assert!(rating("1/8") < rating("1/2"));
+        assert!(rating("1/2") < rating("1"));
+        assert!(rating("9") < rating("10"));
+        assert!("1/3".parse::<ChallengeRating>().is_err());
+    }
+
+    #[test]
+    fn proficiency_bonus_follows_rating() {
+        assert_eq!(rating("0").proficiency_bonus(), 2);
+        assert_eq!(rating("1/4").proficiency_bonus(), 2);
+        assert_eq!(rating("4").proficiency_bonus(), 2);
+        assert_eq!(rating("5").proficiency_bonus(), 3);
+        assert_eq!(rating("17").proficiency_bonus(), 6);
+        assert_eq!(rating("30").proficiency_bonus(), 9);
+        assert_eq!(rating("5").experience(), 1800);
+    }
+}
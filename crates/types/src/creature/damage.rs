@@ -0,0 +1,41 @@
+use strum::AsRefStr;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, Hash, AsRefStr)]
+pub enum DamageType {
+    Acid,
+    Bludgeoning,
+    Cold,
+    Fire,
+    Force,
+    Lightning,
+    Necrotic,
+    Piercing,
+    Poison,
+    Psychic,
+    Radiant,
+    Slashing,
+    Thunder,
+}
+
+/// How a creature is affected by a type of damage, if differently than usual.
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, Hash, AsRefStr)]
+pub enum Defense {
+    /// The damage is doubled.
+    Vulnerability,
+
+    /// The damage is halved, rounded down.
+    Resistance,
+
+    /// The damage is ignored.
+    Immunity,
+}
+
+/// The defense of a creature against a single type of damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DamageDefense {
+    pub damage_type: DamageType,
+    pub defense: Defense,
+}
@@ -0,0 +1,20 @@
+use strum::AsRefStr;
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+
+/// The environment a creature is encountered in, like forest or underdark.
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, Hash, AsRefStr)]
+pub enum Environment {
+    Arctic,
+    Coastal,
+    Desert,
+    Forest,
+    Grassland,
+    Hill,
+    Mountain,
+    Swamp,
+    Underdark,
+    Underwater,
+    Urban,
+}
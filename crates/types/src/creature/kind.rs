@@ -0,0 +1,22 @@
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+
+/// The type of a creature, like beast or undead.
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, Hash)]
+pub enum CreatureType {
+    Aberration,
+    Beast,
+    Celestial,
+    Construct,
+    Dragon,
+    Elemental,
+    Fey,
+    Fiend,
+    Giant,
+    Humanoid,
+    Monstrosity,
+    Ooze,
+    Plant,
+    Undead,
+}
@@ -0,0 +1,287 @@
+mod alignment;
+mod challenge;
+mod damage;
+mod environment;
+mod kind;
+mod size;
+mod skill;
+
+use crate::Ability;
+use crate::Choice;
+use crate::Dice;
+use crate::Source;
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Index;
+use std::ops::IndexMut;
+use uuid::Uuid;
+
+pub use alignment::Alignment;
+pub use challenge::ChallengeRating;
+pub use challenge::ParseChallengeRatingError;
+pub use damage::DamageDefense;
+pub use damage::DamageType;
+pub use damage::Defense;
+pub use environment::Environment;
+pub use kind::CreatureType;
+pub use size::Size;
+pub use skill::Skill;
+
+/// A creature in its domain representation, as described by its stat block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Creature {
+    pub id: Uuid,
+    pub name: String,
+    pub size: Size,
+    pub kind: CreatureType,
+    pub alignment: Alignment,
+    pub armor_class: u32,
+
+    /// What the armor class is derived from, like "natural armor".
+    pub armor_description: Option<String>,
+    pub hit_dice: Dice,
+    pub speeds: Speeds,
+    pub abilities: AbilityScores,
+
+    /// The bonus to initiative on top of the Dexterity modifier, like that of the Alert feat.
+    pub initiative_bonus: i32,
+    pub saving_throws: Vec<Bonus<Ability>>,
+    pub skills: Vec<Bonus<Skill>>,
+    pub damage_defenses: Vec<DamageDefense>,
+    pub senses: Senses,
+    pub languages: Vec<String>,
+    pub challenge_rating: ChallengeRating,
+
+    /// The environments the creature is encountered in.
+    pub environments: Vec<Environment>,
+    pub source: Option<Choice<Source>>,
+    pub traits: Vec<Feature>,
+    pub actions: Vec<Feature>,
+    pub reactions: Vec<Feature>,
+    pub legendary_actions: Vec<Feature>,
+
+    /// The number of legendary actions the creature can take each round, if it has any.
+    pub legendary_action_count: u32,
+    pub lair_actions: Vec<Feature>,
+}
+
+impl Creature {
+    /// The average hit points of the creature.
+    pub fn hit_points(&self) -> i32 {
+        self.hit_dice.average().max(1)
+    }
+
+    /// The bonus the creature adds to its initiative rolls.
+    pub fn initiative_modifier(&self) -> i32 {
+        self.abilities.modifier(Ability::Dexterity) + self.initiative_bonus
+    }
+
+    /// The defense of the creature against `damage_type`, if it has any.
+    pub fn defense(&self, damage_type: DamageType) -> Option<Defense> {
+        self.damage_defenses
+            .iter()
+            .find(|defense| defense.damage_type == damage_type)
+            .map(|defense| defense.defense)
+    }
+
+    /// The number of legendary actions the creature can take each round, 0 if it has none.
+    pub fn legendary_action_uses(&self) -> u32 {
+        if self.legendary_actions.is_empty() {
+            0
+        } else {
+            self.legendary_action_count
+        }
+    }
+
+    /// The number of failed saving throws the creature can turn into successes each day, read
+    /// from a trait like "Legendary Resistance (3/Day)".
+    pub fn legendary_resistances(&self) -> u32 {
+        self.traits
+            .iter()
+            .find_map(|feature| legendary_resistance_uses(&feature.name))
+            .unwrap_or(0)
+    }
+}
+
+/// The uses per day of a trait named like "Legendary Resistance (3/Day)", `None` for any other.
+fn legendary_resistance_uses(name: &str) -> Option<u32> {
+    let uses = name.strip_prefix("Legendary Resistance")?;
+    let uses = uses.trim().trim_start_matches('(').split('/').next()?;
+
+    // The trait grants a single use if it does not say otherwise.
+    Some(uses.trim().parse().unwrap_or(1))
+}
+
+/// The six ability scores of a creature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AbilityScores([i32; 6]);
+
+impl AbilityScores {
+    pub fn new(scores: [i32; 6]) -> Self {
+        Self(scores)
+    }
+
+    /// The modifier of the score of `ability`.
+    pub fn modifier(&self, ability: Ability) -> i32 {
+        Ability::modifier(self[ability])
+    }
+
+    /// The scores in the order of [`Ability`].
+    pub fn scores(&self) -> [i32; 6] {
+        self.0
+    }
+}
+
+impl Default for AbilityScores {
+    fn default() -> Self {
+        Self([10; 6])
+    }
+}
+
+impl Index<Ability> for AbilityScores {
+    type Output = i32;
+
+    fn index(&self, index: Ability) -> &Self::Output {
+        &self.0[index as usize]
+    }
+}
+
+impl IndexMut<Ability> for AbilityScores {
+    fn index_mut(&mut self, index: Ability) -> &mut Self::Output {
+        &mut self.0[index as usize]
+    }
+}
+
+/// A bonus to the checks or saving throws of `target`, like "Dex +5".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bonus<T> {
+    pub target: T,
+    pub bonus: i32,
+}
+
+impl<T: Display> Display for Bonus<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:+}", self.target, self.bonus)
+    }
+}
+
+/// The speeds of a creature in feet, where only walking is always present.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Speeds {
+    pub walk: u32,
+    pub burrow: Option<u32>,
+    pub climb: Option<u32>,
+    pub fly: Option<u32>,
+    pub swim: Option<u32>,
+
+    /// Whether the creature can hover while flying.
+    pub hover: bool,
+}
+
+impl Display for Speeds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ft.", self.walk)?;
+
+        for (name, speed) in [
+            ("burrow", self.burrow),
+            ("climb", self.climb),
+            ("fly", self.fly),
+            ("swim", self.swim),
+        ] {
+            let Some(speed) = speed else {
+                continue;
+            };
+
+            write!(f, ", {name} {speed} ft.")?;
+
+            if name == "fly" && self.hover {
+                write!(f, " (hover)")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The special senses of a creature in feet, and its passive perception.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Senses {
+    pub blindsight: Option<u32>,
+    pub darkvision: Option<u32>,
+    pub tremorsense: Option<u32>,
+    pub truesight: Option<u32>,
+    pub passive_perception: i32,
+}
+
+impl Display for Senses {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, range) in [
+            ("blindsight", self.blindsight),
+            ("darkvision", self.darkvision),
+            ("tremorsense", self.tremorsense),
+            ("truesight", self.truesight),
+        ] {
+            if let Some(range) = range {
+                write!(f, "{name} {range} ft., ")?;
+            }
+        }
+
+        write!(f, "passive Perception {}", self.passive_perception)
+    }
+}
+
+/// A named trait, action, reaction or legendary action of a creature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Feature {
+    pub name: String,
+    pub description: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn speeds_list_every_movement() {
+        let speeds = Speeds {
+            walk: 30,
+            fly: Some(60),
+            swim: Some(30),
+            hover: true,
+            ..Speeds::default()
+        };
+
+        assert_eq!(
+            speeds.to_string(),
+            "30 ft., fly 60 ft. (hover), swim 30 ft."
+        );
+    }
+
+    #[test]
+    fn legendary_resistances_are_read_from_the_trait() {
+        assert_eq!(
+            legendary_resistance_uses("Legendary Resistance (3/Day)"),
+            Some(3)
+        );
+        assert_eq!(legendary_resistance_uses("Legendary Resistance"), Some(1));
+        assert_eq!(legendary_resistance_uses("Amphibious"), None);
+    }
+
+    #[test]
+    fn ability_modifiers_follow_scores() {
+        let mut abilities = AbilityScores::default();
+        abilities[Ability::Dexterity] = 15;
+
+        assert_eq!(abilities.modifier(Ability::Dexterity), 2);
+        assert_eq!(abilities.modifier(Ability::Strength), 0);
+        assert_eq!(
+            Bonus {
+                target: Ability::Dexterity,
+                bonus: 4
+            }
+            .to_string(),
+            "Dexterity +4"
+        );
+    }
+}
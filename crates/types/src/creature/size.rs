@@ -0,0 +1,15 @@
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+
+#[derive(
+    Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Size {
+    Tiny,
+    Small,
+    Medium,
+    Large,
+    Huge,
+    Gargantuan,
+}
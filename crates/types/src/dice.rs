@@ -0,0 +1,154 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A dice formula like "2d8 + 4", rolling `count` dice of `sides` and adding `modifier`.
+///
+/// A formula without dice is a flat value, like "5".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dice {
+    pub count: u32,
+    pub sides: u32,
+    pub modifier: i32,
+}
+
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("'{0}' is not a dice formula")]
+pub struct ParseDiceError(String);
+
+impl Dice {
+    pub fn new(count: u32, sides: u32, modifier: i32) -> Self {
+        Self {
+            count,
+            sides,
+            modifier,
+        }
+    }
+
+    /// A flat value, without any dice to roll.
+    pub fn flat(value: i32) -> Self {
+        Self::new(0, 0, value)
+    }
+
+    /// The average result, rounded down as stat blocks do.
+    pub fn average(self) -> i32 {
+        let total = i64::from(self.count) * (i64::from(self.sides) + 1) / 2;
+
+        i32::try_from(total)
+            .unwrap_or(i32::MAX)
+            .saturating_add(self.modifier)
+    }
+
+    /// The lowest possible result.
+    pub fn minimum(self) -> i32 {
+        let dice = if self.sides == 0 { 0 } else { self.count };
+
+        i32::try_from(dice)
+            .unwrap_or(i32::MAX)
+            .saturating_add(self.modifier)
+    }
+
+    /// The highest possible result.
+    pub fn maximum(self) -> i32 {
+        let total = i64::from(self.count) * i64::from(self.sides);
+
+        i32::try_from(total)
+            .unwrap_or(i32::MAX)
+            .saturating_add(self.modifier)
+    }
+}
+
+impl Display for Dice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.count == 0 || self.sides == 0 {
+            return write!(f, "{}", self.modifier);
+        }
+
+        write!(f, "{}d{}", self.count, self.sides)?;
+
+        match self.modifier {
+            0 => Ok(()),
+            modifier if modifier > 0 => write!(f, " + {modifier}"),
+            modifier => write!(f, " - {}", modifier.unsigned_abs()),
+        }
+    }
+}
+
+impl FromStr for Dice {
+    type Err = ParseDiceError;
+
+    /// Parse a formula like "2d8 + 4", "2d8-1", "d20" or a flat value like "5".
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || ParseDiceError(String::from(value));
+        let formula: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        if formula.is_empty() {
+            return Err(error());
+        }
+
+        let Some((count, rest)) = formula.split_once('d') else {
+            return formula.parse().map(Dice::flat).map_err(|_| error());
+        };
+
+        let count = match count {
+            "" => 1,
+            count => count.parse().map_err(|_| error())?,
+        };
+
+        let (sides, modifier) = match rest.find(['+', '-']) {
+            Some(index) => {
+                let (sides, modifier) = rest.split_at(index);
+                let modifier = modifier
+                    .trim_start_matches('+')
+                    .parse()
+                    .map_err(|_| error())?;
+
+                (sides, modifier)
+            }
+            None => (rest, 0),
+        };
+
+        let sides = sides.parse().map_err(|_| error())?;
+        if sides == 0 {
+            return Err(error());
+        }
+
+        Ok(Dice::new(count, sides, modifier))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dice_parse_in_written_forms() {
+        assert_eq!("2d8 + 4".parse(), Ok(Dice::new(2, 8, 4)));
+        assert_eq!("2D8-1".parse(), Ok(Dice::new(2, 8, -1)));
+        assert_eq!("d20".parse(), Ok(Dice::new(1, 20, 0)));
+        assert_eq!("7".parse(), Ok(Dice::flat(7)));
+        assert!("2d".parse::<Dice>().is_err());
+        assert!("2d0".parse::<Dice>().is_err());
+        assert!("".parse::<Dice>().is_err());
+    }
+
+    #[test]
+    fn dice_display_round_trips() {
+        for formula in ["2d8 + 4", "12d10 - 2", "1d6", "5"] {
+            assert_eq!(formula.parse::<Dice>().unwrap().to_string(), formula);
+        }
+    }
+
+    #[test]
+    fn average_rounds_down() {
+        assert_eq!(Dice::new(2, 8, 4).average(), 13);
+        assert_eq!(Dice::new(3, 6, 0).average(), 10);
+        assert_eq!(Dice::new(2, 8, 4).minimum(), 6);
+        assert_eq!(Dice::new(2, 8, 4).maximum(), 20);
+    }
+}
@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The number of feet in a mile.
+const FEET_PER_MILE: u32 = 5280;
+
+/// A distance, either measured in feet or miles, or one of the special distances of spells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Distance {
+    /// The distance to the caster itself, written as "Self".
+    Caster,
+    Touch,
+    Feet(u32),
+    Miles(u32),
+    Sight,
+    Unlimited,
+}
+
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("'{0}' is not a distance")]
+pub struct ParseDistanceError(String);
+
+impl Distance {
+    /// The distance in feet, where touching reaches 5 feet.
+    ///
+    /// Returns `None` for distances that are not bounded, like sight.
+    pub fn feet(self) -> Option<u32> {
+        match self {
+            Distance::Caster => Some(0),
+            Distance::Touch => Some(5),
+            Distance::Feet(feet) => Some(feet),
+            Distance::Miles(miles) => Some(miles.saturating_mul(FEET_PER_MILE)),
+            Distance::Sight | Distance::Unlimited => None,
+        }
+    }
+
+    /// Parse a distance however it is written, like "self", "30 feet", "30-foot" or "2 Miles".
+    ///
+    /// Unlike parsing with [`FromStr`], this accepts more than the form distances are displayed in,
+    /// which makes it fit for measuring text rather than storing it.
+    pub fn parse_written(value: &str) -> Result<Self, ParseDistanceError> {
+        let error = || ParseDistanceError(String::from(value));
+        let trimmed = value.trim();
+
+        let special = match trimmed.to_lowercase().as_str() {
+            "self" => Some(Distance::Caster),
+            "touch" => Some(Distance::Touch),
+            "sight" => Some(Distance::Sight),
+            "unlimited" => Some(Distance::Unlimited),
+            _ => None,
+        };
+        if let Some(special) = special {
+            return Ok(special);
+        }
+
+        let split = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(error)?;
+        let (amount, unit) = trimmed.split_at(split);
+        let amount = amount.parse().map_err(|_| error())?;
+
+        let unit = unit.trim_start_matches([' ', '-']).to_lowercase();
+        match unit.as_str() {
+            "ft." | "ft" | "foot" | "feet" => Ok(Distance::Feet(amount)),
+            "mile" | "miles" => Ok(Distance::Miles(amount)),
+            _ => Err(error()),
+        }
+    }
+
+    /// Whether the distance is measured in a unit, rather than being a special distance.
+    pub fn is_measured(self) -> bool {
+        matches!(self, Distance::Feet(_) | Distance::Miles(_))
+    }
+
+    /// The position of the distance among distances of equal length in feet.
+    fn rank(self) -> u8 {
+        match self {
+            Distance::Caster => 0,
+            Distance::Touch => 1,
+            Distance::Feet(_) => 2,
+            Distance::Miles(_) => 3,
+            Distance::Sight => 4,
+            Distance::Unlimited => 5,
+        }
+    }
+}
+
+impl Ord for Distance {
+    /// Distances are ordered by their length, with unbounded distances after all others.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let feet = |distance: &Distance| distance.feet().unwrap_or(u32::MAX);
+
+        feet(self)
+            .cmp(&feet(other))
+            .then_with(|| self.rank().cmp(&other.rank()))
+    }
+}
+
+impl PartialOrd for Distance {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Distance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Distance::Caster => write!(f, "Self"),
+            Distance::Touch => write!(f, "Touch"),
+            Distance::Feet(feet) => write!(f, "{feet} ft."),
+            Distance::Miles(1) => write!(f, "1 mile"),
+            Distance::Miles(miles) => write!(f, "{miles} miles"),
+            Distance::Sight => write!(f, "Sight"),
+            Distance::Unlimited => write!(f, "Unlimited"),
+        }
+    }
+}
+
+impl FromStr for Distance {
+    type Err = ParseDistanceError;
+
+    /// Parse a distance written exactly as it is displayed, like "Self", "30 ft." or "2 miles".
+    ///
+    /// Any other form is rejected, so a parsed distance displays as the text it was parsed from.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let distance = Self::parse_written(value)?;
+
+        if distance.to_string() != value {
+            return Err(ParseDistanceError(String::from(value)));
+        }
+
+        Ok(distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Choice;
+
+    #[test]
+    fn distances_parse_in_written_forms() {
+        for value in ["45 ft.", "45 ft", "45 feet", "45-foot", "45 Feet"] {
+            assert_eq!(Distance::parse_written(value), Ok(Distance::Feet(45)));
+        }
+
+        assert_eq!(Distance::parse_written("2 miles"), Ok(Distance::Miles(2)));
+        assert_eq!(Distance::parse_written("touch"), Ok(Distance::Touch));
+        assert!(Distance::parse_written("Special").is_err());
+        assert!(Distance::parse_written("ft.").is_err());
+    }
+
+    #[test]
+    fn only_displayed_forms_parse() {
+        assert_eq!("45 ft.".parse(), Ok(Distance::Feet(45)));
+        assert_eq!("1 mile".parse(), Ok(Distance::Miles(1)));
+        assert_eq!("Self".parse(), Ok(Distance::Caster));
+
+        for value in [
+            "45 feet", "45-foot", "045 ft.", "1 miles", "touch", " Touch",
+        ] {
+            assert!(value.parse::<Distance>().is_err(), "'{value}' parsed");
+        }
+    }
+
+    #[test]
+    fn written_forms_round_trip_as_custom_choices() {
+        for value in ["30 feet", "30-foot", "self"] {
+            let choice = Choice::<Distance>::parse(value);
+
+            assert_eq!(choice, Choice::Custom(String::from(value)));
+            assert_eq!(choice.to_string(), value);
+        }
+
+        assert_eq!(
+            Choice::<Distance>::parse("30 ft.").to_string(),
+            Distance::Feet(30).to_string()
+        );
+    }
+
+    #[test]
+    fn distances_order_by_length() {
+        let mut distances = vec![
+            Distance::Unlimited,
+            Distance::Miles(1),
+            Distance::Feet(18),
+            Distance::Sight,
+            Distance::Touch,
+            Distance::Feet(6000),
+            Distance::Caster,
+        ];
+        distances.sort();
+
+        assert_eq!(
+            distances,
+            [
+                Distance::Caster,
+                Distance::Touch,
+                Distance::Feet(18),
+                Distance::Miles(1),
+                Distance::Feet(6000),
+                Distance::Sight,
+                Distance::Unlimited,
+            ]
+        );
+    }
+}
@@ -1,5 +1,21 @@
+mod ability;
+mod choice;
+mod combat;
+mod creature;
+mod dice;
+mod distance;
 mod form_mode;
+mod random;
 mod spell;
 
+pub use ability::Ability;
+pub use choice::Choice;
+pub use combat::*;
+pub use creature::*;
+pub use dice::Dice;
+pub use dice::ParseDiceError;
+pub use distance::Distance;
+pub use distance::ParseDistanceError;
 pub use form_mode::FormMode;
+pub use random::Random;
 pub use spell::*;
@@ -0,0 +1,83 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+use std::ops::RangeInclusive;
+
+/// A seedable pseudo-random number generator, so that whatever is generated from a seed can be
+/// generated again from it.
+///
+/// This is SplitMix64, which is plenty random for rolling dice and picking creatures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// A fresh seed, using the random keys the standard library seeds every hasher with.
+    pub fn seed() -> u64 {
+        RandomState::new().build_hasher().finish()
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number within `range`, which is the start of it if the range is empty.
+    pub fn between(&mut self, range: RangeInclusive<u32>) -> u32 {
+        let (start, end) = range.into_inner();
+
+        if end <= start {
+            return start;
+        }
+
+        let span = u64::from(end - start) + 1;
+
+        // The span is tiny next to 2^64, so the bias of the modulo is negligible.
+        start + (self.next_u64() % span) as u32
+    }
+
+    /// One of `items` picked at random, `None` if there are none.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        let last = u32::try_from(items.len().checked_sub(1)?).unwrap_or(u32::MAX);
+
+        items.get(self.between(0..=last) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut first = Random::new(42);
+        let mut second = Random::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+
+        assert_ne!(Random::new(1).next_u64(), Random::new(2).next_u64());
+    }
+
+    #[test]
+    fn between_stays_within_range() {
+        let mut random = Random::new(7);
+
+        for _ in 0..1000 {
+            assert!((1..=20).contains(&random.between(1..=20)));
+        }
+
+        assert_eq!(random.between(5..=5), 5);
+        assert_eq!(random.pick::<u32>(&[]), None);
+    }
+}
@@ -1,29 +1,64 @@
-use strum::Display;
-use strum::VariantArray;
+use crate::Distance;
 
-#[derive(Debug, Display, VariantArray, Clone, Copy, PartialEq)]
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The area a spell affects, either a number of targets or a distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Area {
-    #[strum(to_string = "Single target")]
     SingleTarget,
-    #[strum(to_string = "Multiple target")]
     MultipleTargets,
-    #[strum(to_string = "All creatures in range")]
     AllCreaturesInRange,
-    #[strum(to_string = "5 ft.")]
-    Feet5,
-    #[strum(to_string = "10 ft.")]
-    Feet10,
-    #[strum(to_string = "15 ft.")]
-    Feet15,
-    #[strum(to_string = "20 ft.")]
-    Feet20,
-    #[strum(to_string = "25 ft.")]
-    Feet25,
-    #[strum(to_string = "30 ft.")]
-    Feet30,
-    #[strum(to_string = "35 ft.")]
-    Feet35,
-    #[strum(to_string = "40 ft.")]
-    Feet40,
-    Unlimited,
+    Distance(Distance),
+}
+
+#[derive(Debug, Error, Clone, PartialEq)]
+#[error("'{0}' is not an area")]
+pub struct ParseAreaError(String);
+
+impl Area {
+    /// The areas that are built into the default options.
+    pub const PRESETS: [Area; 12] = [
+        Area::SingleTarget,
+        Area::MultipleTargets,
+        Area::AllCreaturesInRange,
+        Area::Distance(Distance::Feet(5)),
+        Area::Distance(Distance::Feet(10)),
+        Area::Distance(Distance::Feet(15)),
+        Area::Distance(Distance::Feet(20)),
+        Area::Distance(Distance::Feet(25)),
+        Area::Distance(Distance::Feet(30)),
+        Area::Distance(Distance::Feet(35)),
+        Area::Distance(Distance::Feet(40)),
+        Area::Distance(Distance::Unlimited),
+    ];
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Area::SingleTarget => write!(f, "Single target"),
+            Area::MultipleTargets => write!(f, "Multiple targets"),
+            Area::AllCreaturesInRange => write!(f, "All creatures in range"),
+            Area::Distance(distance) => distance.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Area {
+    type Err = ParseAreaError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "Single target" => Ok(Area::SingleTarget),
+            "Multiple targets" => Ok(Area::MultipleTargets),
+            "All creatures in range" => Ok(Area::AllCreaturesInRange),
+            _ => value
+                .parse()
+                .map(Area::Distance)
+                .map_err(|_| ParseAreaError(String::from(value))),
+        }
+    }
 }
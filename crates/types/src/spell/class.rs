@@ -1,9 +1,12 @@
+use crate::Ability;
+
 use std::ops::Index;
 use std::ops::IndexMut;
 
 use strum::AsRefStr;
 use strum::Display;
 use strum::EnumCount;
+use strum::EnumString;
 use strum::VariantArray;
 
 /// The list of all spell casting classes.
@@ -19,7 +22,7 @@ pub const SPELLCASTING_CLASSES: [Class; 9] = [
     Class::Wizard,
 ];
 
-#[derive(Debug, Display, VariantArray, Clone, Copy, EnumCount, PartialEq, AsRefStr)]
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumCount, EnumString, PartialEq, AsRefStr)]
 pub enum Class {
     Artificer,
     Barbarian,
@@ -36,6 +39,63 @@ pub enum Class {
     Wizard,
 }
 
+/// The number of spells known at each level by classes that know rather than prepare spells.
+const BARD_SPELLS_KNOWN: [u32; 20] = [
+    4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 15, 15, 16, 18, 19, 19, 20, 22, 22, 22,
+];
+const RANGER_SPELLS_KNOWN: [u32; 20] = [
+    0, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11,
+];
+const SORCERER_SPELLS_KNOWN: [u32; 20] = [
+    2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 12, 13, 13, 14, 14, 15, 15, 15, 15,
+];
+const WARLOCK_SPELLS_KNOWN: [u32; 20] = [
+    2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 11, 11, 12, 12, 13, 13, 14, 14, 15, 15,
+];
+
+impl Class {
+    /// The ability the class casts its spells with, if it is a spellcasting class.
+    pub fn spellcasting_ability(self) -> Option<Ability> {
+        match self {
+            Class::Artificer | Class::Wizard => Some(Ability::Intelligence),
+            Class::Cleric | Class::Druid | Class::Ranger => Some(Ability::Wisdom),
+            Class::Bard | Class::Paladin | Class::Sorcerer | Class::Warlock => {
+                Some(Ability::Charisma)
+            }
+            Class::Barbarian | Class::Fighter | Class::Monk | Class::Rogue => None,
+        }
+    }
+
+    /// The number of spells, excluding cantrips, a character of the class can have prepared at
+    /// `level` with the spellcasting ability `modifier`.
+    ///
+    /// Classes that know their spells rather than prepare them are limited by the number of spells
+    /// they know instead. Always prepared spells do not count towards the limit.
+    pub fn prepared_limit(self, level: u8, modifier: i32) -> u32 {
+        let level = level.clamp(1, 20);
+        let index = usize::from(level - 1);
+
+        // Prepared spells are never fewer than one, regardless of the modifier.
+        let prepared = |casting_level: u8| {
+            u32::try_from(i32::from(casting_level) + modifier)
+                .unwrap_or_default()
+                .max(1)
+        };
+
+        match self {
+            Class::Cleric | Class::Druid | Class::Wizard => prepared(level),
+            Class::Artificer => prepared(level / 2),
+            Class::Paladin if level < 2 => 0,
+            Class::Paladin => prepared(level / 2),
+            Class::Bard => BARD_SPELLS_KNOWN[index],
+            Class::Ranger => RANGER_SPELLS_KNOWN[index],
+            Class::Sorcerer => SORCERER_SPELLS_KNOWN[index],
+            Class::Warlock => WARLOCK_SPELLS_KNOWN[index],
+            Class::Barbarian | Class::Fighter | Class::Monk | Class::Rogue => 0,
+        }
+    }
+}
+
 impl Index<Class> for [bool; Class::COUNT] {
     type Output = bool;
 
@@ -49,3 +109,19 @@ impl IndexMut<Class> for [bool; Class::COUNT] {
         &mut self[index as usize]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prepared_limit_follows_level_and_modifier() {
+        assert_eq!(Class::Wizard.prepared_limit(5, 3), 8);
+        assert_eq!(Class::Paladin.prepared_limit(5, 2), 4);
+        assert_eq!(Class::Paladin.prepared_limit(1, 2), 0);
+        assert_eq!(Class::Artificer.prepared_limit(1, 1), 1);
+        assert_eq!(Class::Cleric.prepared_limit(1, -3), 1);
+        assert_eq!(Class::Sorcerer.prepared_limit(20, 5), 15);
+        assert_eq!(Class::Fighter.prepared_limit(10, 3), 0);
+    }
+}
@@ -1,7 +1,8 @@
 use strum::Display;
+use strum::EnumString;
 use strum::VariantArray;
 
-#[derive(Debug, Display, VariantArray, Clone, Copy, PartialEq)]
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq)]
 pub enum Duration {
     Instantaneous,
     #[strum(to_string = "1 round")]
@@ -22,3 +23,20 @@ pub enum Duration {
     #[strum(to_string = "Until dispelled")]
     UntilDispelled,
 }
+
+impl Duration {
+    /// The number of rounds of 6 seconds the duration lasts in combat, or `None` if it is not
+    /// timed by rounds, like instantaneous or permanent spells.
+    pub fn rounds(self) -> Option<u32> {
+        match self {
+            Duration::Instantaneous | Duration::Permanent | Duration::UntilDispelled => None,
+            Duration::OneRound => Some(1),
+            Duration::OneMinute => Some(10),
+            Duration::TenMinutes => Some(100),
+            Duration::OneHour => Some(600),
+            Duration::EightHours => Some(4_800),
+            Duration::OneDay => Some(14_400),
+            Duration::SevenDays => Some(100_800),
+        }
+    }
+}
@@ -1,5 +1,6 @@
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct Material {
     pub material: String,
+    pub worth: Option<String>,
     pub consumed: bool,
 }
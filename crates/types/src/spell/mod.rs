@@ -7,41 +7,60 @@ mod material;
 mod range;
 mod school;
 mod shape;
+mod slots;
+mod source;
+
+use crate::Choice;
+use crate::Distance;
 
 use uuid::Uuid;
 
 pub use area::Area;
+pub use area::ParseAreaError;
 pub use casting_time::CastingTime;
 pub use class::Class;
 pub use class::SPELLCASTING_CLASSES;
 pub use duration::Duration;
 pub use level::Level;
 pub use material::Material;
-pub use range::Range;
+pub use range::RANGE_PRESETS;
 pub use school::School;
 pub use shape::Shape;
 pub use shape::ShapeKind;
+pub use slots::MAX_SLOT_LEVEL;
+pub use slots::PactMagic;
+pub use slots::Rest;
+pub use slots::Slot;
+pub use slots::SpellSlots;
+pub use source::Source;
 
+/// A spell in its domain representation.
+///
+/// Options that the user can extend, like the school or range, are a [`Choice`] between
+/// the built-in values and custom values.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Spell {
-    pub id: Option<Uuid>,
+    pub id: Uuid,
     pub name: String,
-    pub school: School,
-    pub level: Level,
-    // pub source: Source,
+    pub aliases: Vec<String>,
+    pub school: Choice<School>,
+    pub level: Choice<Level>,
+    pub source: Option<Choice<Source>>,
     pub classes: Vec<Class>,
+    pub tags: Vec<String>,
+    pub casting_time: Choice<CastingTime>,
+    pub ritual: bool,
+    pub concentration: bool,
     pub verbal: bool,
     pub somatic: bool,
     pub material: bool,
     pub materials: Vec<Material>,
-    pub ritual: bool,
-    pub concentration: bool,
-    pub casting_time: CastingTime,
-    pub duration: Duration,
-    pub range: Range,
-    pub area: Area,
+    pub duration: Choice<Duration>,
+    pub range: Choice<Distance>,
+    pub area: Choice<Area>,
     pub shape: Shape,
     pub description: String,
     pub at_higher_levels: Option<String>,
-    pub quote_text: String,
-    pub quote_source: String,
+    pub flavor_text: Option<String>,
+    pub attribution: Option<String>,
 }
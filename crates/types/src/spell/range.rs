@@ -1,27 +1,17 @@
-use strum::Display;
-use strum::VariantArray;
+use crate::Distance;
 
-#[derive(Debug, Display, VariantArray, Clone, Copy, PartialEq)]
-pub enum Range {
-    #[strum(to_string = "Self")]
-    Caster,
-    Touch,
-    Sight,
-    #[strum(to_string = "30 ft.")]
-    Feet30,
-    #[strum(to_string = "60 ft.")]
-    Feet60,
-    #[strum(to_string = "90 ft.")]
-    Feet90,
-    #[strum(to_string = "120 ft.")]
-    Feet120,
-    #[strum(to_string = "150 ft.")]
-    Feet150,
-    #[strum(to_string = "300 ft.")]
-    Feet300,
-    #[strum(to_string = "500 ft.")]
-    Feet500,
-    #[strum(to_string = "1 mile")]
-    OneMile,
-    Unlimited,
-}
+/// The ranges that are built into the default options.
+pub const RANGE_PRESETS: [Distance; 12] = [
+    Distance::Caster,
+    Distance::Touch,
+    Distance::Sight,
+    Distance::Feet(30),
+    Distance::Feet(60),
+    Distance::Feet(90),
+    Distance::Feet(120),
+    Distance::Feet(150),
+    Distance::Feet(300),
+    Distance::Feet(500),
+    Distance::Miles(1),
+    Distance::Unlimited,
+];
@@ -1,7 +1,8 @@
 use strum::Display;
+use strum::EnumString;
 use strum::VariantArray;
 
-#[derive(Debug, Display, VariantArray, Clone, Copy, PartialEq)]
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq)]
 pub enum ShapeKind {
     #[strum(to_string = "No shape")]
     NoShape,
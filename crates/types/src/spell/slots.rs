@@ -0,0 +1,312 @@
+use crate::Class;
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// The spell slots of a full caster at each level, from 1st to 9th level slots.
+///
+/// Half casters use the same progression at half their level, rounded up.
+const FULL_CASTER_SLOTS: [[u32; 9]; 20] = [
+    [2, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 0, 0, 0, 0, 0, 0, 0, 0],
+    [4, 2, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 2, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 1, 0, 0, 0, 0, 0],
+    [4, 3, 3, 2, 0, 0, 0, 0, 0],
+    [4, 3, 3, 3, 1, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 2, 1, 1],
+];
+
+/// The highest level of a spell slot.
+pub const MAX_SLOT_LEVEL: u8 = 9;
+
+/// The pact magic slots of a warlock, which are all of the same level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PactMagic {
+    pub slots: u32,
+    pub level: u8,
+}
+
+/// A slot a spell can be cast with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    /// A spell slot of the given level.
+    Spell(u8),
+
+    /// A pact magic slot, which is always of the pact magic level.
+    Pact,
+}
+
+/// A rest, restoring expended resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rest {
+    /// Restores pact magic slots.
+    Short,
+
+    /// Restores every slot.
+    Long,
+}
+
+impl Class {
+    /// The spell slots of a character of the class at `level`, from 1st to 9th level slots.
+    ///
+    /// Pact magic slots are separate, see [`Class::pact_magic`].
+    pub fn spell_slots(self, level: u8) -> [u32; 9] {
+        let level = level.clamp(1, 20);
+
+        let caster_level = match self {
+            Class::Bard | Class::Cleric | Class::Druid | Class::Sorcerer | Class::Wizard => level,
+            Class::Artificer => level.div_ceil(2),
+            Class::Paladin | Class::Ranger if level < 2 => 0,
+            Class::Paladin | Class::Ranger => level.div_ceil(2),
+            Class::Warlock | Class::Barbarian | Class::Fighter | Class::Monk | Class::Rogue => 0,
+        };
+
+        match caster_level {
+            0 => [0; 9],
+            caster_level => FULL_CASTER_SLOTS[usize::from(caster_level - 1)],
+        }
+    }
+
+    /// The pact magic slots of a character of the class at `level`, if the class has them.
+    pub fn pact_magic(self, level: u8) -> Option<PactMagic> {
+        if self != Class::Warlock {
+            return None;
+        }
+
+        let level = level.clamp(1, 20);
+
+        let slots = match level {
+            1 => 1,
+            2..=10 => 2,
+            11..=16 => 3,
+            _ => 4,
+        };
+
+        Some(PactMagic {
+            slots,
+            level: level.div_ceil(2).min(5),
+        })
+    }
+}
+
+/// The spell slots of a character, and how many of them have been expended since resting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpellSlots {
+    maximum: [u32; 9],
+    expended: [u32; 9],
+    pact: Option<PactMagic>,
+    pact_expended: u32,
+}
+
+impl SpellSlots {
+    /// The slots of a fully rested character of `class` at `level`.
+    pub fn new(class: Class, level: u8) -> Self {
+        Self {
+            maximum: class.spell_slots(level),
+            expended: [0; 9],
+            pact: class.pact_magic(level),
+            pact_expended: 0,
+        }
+    }
+
+    /// Set the expended slots, limited to the slots that exist.
+    ///
+    /// The character may have lost levels since the slots were expended.
+    pub fn with_expended(mut self, expended: [u32; 9], pact_expended: u32) -> Self {
+        for (expended, (current, maximum)) in expended
+            .into_iter()
+            .zip(self.expended.iter_mut().zip(self.maximum))
+        {
+            *current = expended.min(maximum);
+        }
+
+        self.pact_expended = pact_expended.min(self.pact.map_or(0, |pact| pact.slots));
+
+        self
+    }
+
+    /// The number of slots of `level`, from 1 to 9.
+    pub fn maximum(&self, level: u8) -> u32 {
+        index(level).map_or(0, |index| self.maximum[index])
+    }
+
+    /// The number of slots of `level` that have not been expended.
+    pub fn available(&self, level: u8) -> u32 {
+        index(level).map_or(0, |index| self.maximum[index] - self.expended[index])
+    }
+
+    /// The expended slots of each level, from 1st to 9th level slots.
+    pub fn expended(&self) -> [u32; 9] {
+        self.expended
+    }
+
+    pub fn pact(&self) -> Option<PactMagic> {
+        self.pact
+    }
+
+    /// The number of pact magic slots that have not been expended.
+    pub fn pact_available(&self) -> u32 {
+        self.pact.map_or(0, |pact| pact.slots - self.pact_expended)
+    }
+
+    pub fn pact_expended(&self) -> u32 {
+        self.pact_expended
+    }
+
+    /// The available slots a spell of `spell_level` can be cast with, lowest level first.
+    ///
+    /// Cantrips need no slot, so they have none.
+    pub fn castable(&self, spell_level: u8) -> Vec<Slot> {
+        if spell_level == 0 {
+            return Vec::new();
+        }
+
+        let mut slots: Vec<_> = (spell_level..=MAX_SLOT_LEVEL)
+            .filter(|level| self.available(*level) > 0)
+            .map(Slot::Spell)
+            .collect();
+
+        if let Some(pact) = self.pact
+            && pact.level >= spell_level
+            && self.pact_available() > 0
+        {
+            slots.push(Slot::Pact);
+        }
+
+        slots
+    }
+
+    /// Expend one `slot`, returning whether one was available.
+    pub fn expend(&mut self, slot: Slot) -> bool {
+        match slot {
+            Slot::Spell(level) => {
+                let Some(index) = index(level) else {
+                    return false;
+                };
+
+                if self.expended[index] >= self.maximum[index] {
+                    return false;
+                }
+
+                self.expended[index] += 1;
+            }
+            Slot::Pact => {
+                if self.pact_available() == 0 {
+                    return false;
+                }
+
+                self.pact_expended += 1;
+            }
+        }
+
+        true
+    }
+
+    /// Regain one expended `slot`, to correct mistakes.
+    pub fn regain(&mut self, slot: Slot) {
+        match slot {
+            Slot::Spell(level) => {
+                if let Some(index) = index(level) {
+                    self.expended[index] = self.expended[index].saturating_sub(1);
+                }
+            }
+            Slot::Pact => self.pact_expended = self.pact_expended.saturating_sub(1),
+        }
+    }
+
+    /// Restore the slots regained by taking `rest`.
+    pub fn rest(&mut self, rest: Rest) {
+        self.pact_expended = 0;
+
+        if rest == Rest::Long {
+            self.expended = [0; 9];
+        }
+    }
+}
+
+impl Display for Slot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Slot::Spell(level) => write!(f, "Level {level}"),
+            Slot::Pact => write!(f, "Pact"),
+        }
+    }
+}
+
+/// The index of the slots of `level` in the slot arrays.
+fn index(level: u8) -> Option<usize> {
+    (1..=MAX_SLOT_LEVEL)
+        .contains(&level)
+        .then(|| usize::from(level - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_casters_round_up_from_second_level() {
+        assert_eq!(Class::Paladin.spell_slots(1), [0; 9]);
+        assert_eq!(Class::Paladin.spell_slots(3), [3, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Class::Ranger.spell_slots(5), [4, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Class::Artificer.spell_slots(1), [2, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Class::Wizard.spell_slots(20), [4, 3, 3, 3, 3, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn pact_magic_scales_with_warlock_level() {
+        assert_eq!(Class::Wizard.pact_magic(5), None);
+        assert_eq!(
+            Class::Warlock.pact_magic(1),
+            Some(PactMagic { slots: 1, level: 1 })
+        );
+        assert_eq!(
+            Class::Warlock.pact_magic(11),
+            Some(PactMagic { slots: 3, level: 5 })
+        );
+    }
+
+    #[test]
+    fn short_rest_only_restores_pact_slots() {
+        let mut wizard = SpellSlots::new(Class::Wizard, 3);
+        assert!(wizard.expend(Slot::Spell(1)));
+        assert_eq!(wizard.castable(2), vec![Slot::Spell(2)]);
+
+        wizard.rest(Rest::Short);
+        assert_eq!(wizard.available(1), 3);
+
+        wizard.rest(Rest::Long);
+        assert_eq!(wizard.available(1), 4);
+
+        let mut warlock = SpellSlots::new(Class::Warlock, 3);
+        assert_eq!(warlock.castable(1), vec![Slot::Pact]);
+        assert!(warlock.expend(Slot::Pact));
+        assert!(warlock.expend(Slot::Pact));
+        assert!(!warlock.expend(Slot::Pact));
+        assert!(warlock.castable(1).is_empty());
+
+        warlock.rest(Rest::Short);
+        assert_eq!(warlock.pact_available(), 2);
+    }
+
+    #[test]
+    fn expended_slots_are_limited_to_existing_slots() {
+        let slots = SpellSlots::new(Class::Cleric, 1).with_expended([5, 1, 0, 0, 0, 0, 0, 0, 0], 1);
+
+        assert_eq!(slots.expended(), [2, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(slots.pact_expended(), 0);
+    }
+}
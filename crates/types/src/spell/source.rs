@@ -0,0 +1,18 @@
+use strum::Display;
+use strum::EnumString;
+use strum::VariantArray;
+
+#[derive(Debug, Display, VariantArray, Clone, Copy, EnumString, PartialEq)]
+pub enum Source {
+    #[strum(to_string = "Player's Handbook")]
+    PlayersHandbook,
+    #[strum(to_string = "Dungeon Master's Guide")]
+    DungeonMastersGuide,
+    #[strum(to_string = "Monster Manual")]
+    MonsterManual,
+    #[strum(to_string = "Xanathar's Guide to Everything")]
+    XanatharsGuideToEverything,
+    #[strum(to_string = "Tasha's Cauldron of Everything")]
+    TashasCauldronOfEverything,
+    Homebrew,
+}